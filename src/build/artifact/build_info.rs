@@ -0,0 +1,140 @@
+//!
+//! The artifact build info.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::build::artifact::foundry::Bytecode;
+
+///
+/// The artifact build info.
+///
+/// Written once per build and referenced by its identifier from each artifact.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct BuildInfo {
+    /// The build info format identifier.
+    #[serde(rename = "_format")]
+    pub format: String,
+    /// The build identifier, which is the hash of the input.
+    pub id: String,
+    /// The sources and settings of the build.
+    pub input: Input,
+    /// The output of the build.
+    pub output: Output,
+}
+
+///
+/// The build info input.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Input {
+    /// The source code language.
+    pub language: String,
+    /// The source code, keyed by the source name.
+    pub sources: BTreeMap<String, Source>,
+    /// The project metadata with the compiler versions and settings.
+    pub settings: serde_json::Value,
+}
+
+///
+/// The build info source.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Source {
+    /// The source code.
+    pub content: String,
+}
+
+///
+/// The build info output.
+///
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct Output {
+    /// The contract output, keyed by the source name and the contract name.
+    pub contracts: BTreeMap<String, BTreeMap<String, Contract>>,
+    /// The source identifiers, keyed by the source name.
+    pub sources: BTreeMap<String, SourceId>,
+}
+
+///
+/// The build info source identifier.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct SourceId {
+    /// The source index in the build.
+    pub id: usize,
+}
+
+///
+/// The build info contract output.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// The contract ABI.
+    pub abi: serde_json::Value,
+    /// The bytecode output.
+    pub evm: ContractEVM,
+    /// The storage layout.
+    pub storage_layout: serde_json::Value,
+    /// The EraVM bytecode hash.
+    pub hash: String,
+    /// The factory dependencies, keyed by bytecode hash.
+    pub factory_dependencies: BTreeMap<String, String>,
+}
+
+///
+/// The build info contract bytecode output.
+///
+/// The naming follows the `solc` standard JSON, which is expected by the ZKsync tooling.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractEVM {
+    /// The deploy bytecode.
+    pub bytecode: Bytecode,
+    /// The runtime bytecode.
+    /// On EraVM, it is the same as above, as the deploy and runtime code are not separated.
+    pub deployed_bytecode: Bytecode,
+    /// The method identifiers.
+    pub method_identifiers: BTreeMap<String, String>,
+}
+
+impl BuildInfo {
+    /// The build info directory name.
+    pub const DIRECTORY_NAME: &'static str = "build-info";
+
+    /// The source code language.
+    pub const LANGUAGE: &'static str = "Vyper";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(format: &str, input: Input, output: Output) -> Self {
+        let id = hex::encode(
+            era_compiler_common::Keccak256Hash::from_slice(
+                serde_json::to_vec(&input).expect("Always valid").as_slice(),
+            )
+            .as_bytes(),
+        );
+        Self {
+            format: format.to_owned(),
+            id,
+            input,
+            output,
+        }
+    }
+
+    ///
+    /// Returns the build info file path relative to the output directory.
+    ///
+    pub fn file_name(&self) -> String {
+        format!(
+            "{}/{}.{}",
+            Self::DIRECTORY_NAME,
+            self.id,
+            era_compiler_common::EXTENSION_JSON
+        )
+    }
+}
@@ -0,0 +1,39 @@
+//!
+//! The artifact layout format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The artifact layout format.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The `foundry-zksync` layout.
+    Foundry,
+    /// The `hardhat-zksync` layout.
+    Hardhat,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> anyhow::Result<Self> {
+        Ok(match string {
+            "foundry" => Self::Foundry,
+            "hardhat" => Self::Hardhat,
+            string => anyhow::bail!(
+                "Unknown artifact format `{string}`. Available formats: `foundry`, `hardhat`."
+            ),
+        })
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Foundry => write!(f, "foundry"),
+            Self::Hardhat => write!(f, "hardhat"),
+        }
+    }
+}
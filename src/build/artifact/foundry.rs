@@ -0,0 +1,44 @@
+//!
+//! The `foundry-zksync` contract artifact.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The `foundry-zksync` contract artifact.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Foundry {
+    /// The contract ABI.
+    pub abi: serde_json::Value,
+    /// The deploy bytecode.
+    pub bytecode: Bytecode,
+    /// The runtime bytecode.
+    /// On EraVM, it is the same as above, as the deploy and runtime code are not separated.
+    pub deployed_bytecode: Bytecode,
+    /// The method identifiers.
+    pub method_identifiers: BTreeMap<String, String>,
+    /// The storage layout.
+    pub storage_layout: serde_json::Value,
+    /// The EraVM bytecode hash.
+    pub hash: String,
+    /// The factory dependencies, keyed by bytecode hash.
+    pub factory_dependencies: BTreeMap<String, String>,
+    /// The build info identifier.
+    pub build_info_id: String,
+}
+
+///
+/// The `foundry-zksync` artifact bytecode.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Bytecode {
+    /// The bytecode hexadecimal string.
+    pub object: String,
+}
+
+impl Foundry {
+    /// The build info format identifier.
+    pub const BUILD_INFO_FORMAT: &'static str = "zkvyper-foundry-build-info-1";
+}
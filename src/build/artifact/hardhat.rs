@@ -0,0 +1,66 @@
+//!
+//! The `hardhat-zksync` contract artifact.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The `hardhat-zksync` contract artifact.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hardhat {
+    /// The artifact format identifier.
+    #[serde(rename = "_format")]
+    pub format: String,
+    /// The contract name.
+    pub contract_name: String,
+    /// The source path relative to the base path.
+    pub source_name: String,
+    /// The contract ABI.
+    pub abi: serde_json::Value,
+    /// The deploy bytecode hexadecimal string.
+    pub bytecode: String,
+    /// The runtime bytecode hexadecimal string.
+    /// On EraVM, it is the same as above, as the deploy and runtime code are not separated.
+    pub deployed_bytecode: String,
+    /// The deploy bytecode library references, which are always empty for Vyper.
+    pub link_references: BTreeMap<String, serde_json::Value>,
+    /// The runtime bytecode library references, which are always empty for Vyper.
+    pub deployed_link_references: BTreeMap<String, serde_json::Value>,
+    /// The factory dependencies, keyed by bytecode hash.
+    pub factory_deps: BTreeMap<String, String>,
+    /// The EraVM bytecode hash.
+    pub bytecode_hash: String,
+    /// The method identifiers.
+    pub method_identifiers: BTreeMap<String, String>,
+    /// The storage layout.
+    pub storage_layout: serde_json::Value,
+}
+
+///
+/// The `hardhat-zksync` debug file referencing the build info.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Debug {
+    /// The debug file format identifier.
+    #[serde(rename = "_format")]
+    pub format: String,
+    /// The build info file path relative to the artifact.
+    pub build_info: String,
+}
+
+impl Hardhat {
+    /// The artifact format identifier.
+    pub const FORMAT: &'static str = "hh-zkvyper-artifact-1";
+
+    /// The debug file format identifier.
+    pub const DEBUG_FORMAT: &'static str = "hh-sol-dbg-1";
+
+    /// The build info format identifier.
+    pub const BUILD_INFO_FORMAT: &'static str = "hh-zkvyper-build-info-1";
+
+    /// The debug file extension.
+    pub const DEBUG_EXTENSION: &'static str = "dbg.json";
+}
@@ -0,0 +1,8 @@
+//!
+//! The contract artifacts in the layouts of the ZKsync tooling.
+//!
+
+pub mod build_info;
+pub mod format;
+pub mod foundry;
+pub mod hardhat;
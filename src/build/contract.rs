@@ -3,12 +3,24 @@
 //!
 
 use std::collections::BTreeMap;
-use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
+use crate::build::artifact::build_info::BuildInfo;
+use crate::build::artifact::build_info::Contract as BuildInfoContract;
+use crate::build::artifact::build_info::ContractEVM as BuildInfoContractEVM;
+use crate::build::artifact::format::Format as ArtifactFormat;
+use crate::build::artifact::foundry::Bytecode as FoundryArtifactBytecode;
+use crate::build::artifact::foundry::Foundry as FoundryArtifact;
+use crate::build::artifact::hardhat::Debug as HardhatArtifactDebug;
+use crate::build::artifact::hardhat::Hardhat as HardhatArtifact;
+use crate::build::output_directory::OutputDirectory;
+use crate::build::shared_output::SharedOutput;
+use crate::estimator::estimate::Estimate;
 use crate::project::contract::vyper::ast::AST;
+use crate::project::contract::vyper::call_graph::CallGraph;
 use crate::project::contract::vyper::expression::Expression as IR;
+use crate::tuning::candidate::Candidate as TuningCandidate;
 use crate::vyper::combined_json::contract::warning::Warning as CombinedJsonContractWarning;
 use crate::vyper::combined_json::contract::Contract as CombinedJsonContract;
 use crate::vyper::selector::Selector as VyperSelector;
@@ -20,8 +32,14 @@ use crate::vyper::selector::Selector as VyperSelector;
 pub struct Contract {
     /// The LLVM module build.
     pub build: era_compiler_llvm_context::EraVMBuild,
+    /// The upstream EVM deploy bytecode built by `vyper` from the same sources.
+    pub evm_bytecode: Option<Vec<u8>>,
+    /// The upstream EVM runtime bytecode built by `vyper` from the same sources.
+    pub evm_bytecode_runtime: Option<Vec<u8>>,
     /// The LLL IR parsed from JSON.
     pub ir_json: Option<IR>,
+    /// The LLL IR s-expressions.
+    pub ir: Option<String>,
     /// The contract AST.
     pub ast: Option<AST>,
     /// The `vyper` ABI output.
@@ -34,8 +52,16 @@ pub struct Contract {
     pub userdoc: Option<serde_json::Value>,
     /// The `vyper` devdoc output.
     pub devdoc: Option<serde_json::Value>,
+    /// The static ergs estimates of external functions.
+    pub ergs_estimates: Option<BTreeMap<String, Estimate>>,
+    /// The call graph of functions.
+    pub call_graph: Option<CallGraph>,
+    /// The chain-independent output forwarded from `vyper` as is.
+    pub forwarded_outputs: BTreeMap<VyperSelector, String>,
     /// The compilation warnings.
     pub warnings: Vec<CombinedJsonContractWarning>,
+    /// The optimization candidates tried by auto-tuning.
+    pub optimization_candidates: Option<Vec<TuningCandidate>>,
 }
 
 impl Contract {
@@ -44,25 +70,38 @@ impl Contract {
     ///
     pub fn new(
         build: era_compiler_llvm_context::EraVMBuild,
+        evm_bytecode: Option<Vec<u8>>,
+        evm_bytecode_runtime: Option<Vec<u8>>,
+        forwarded_outputs: BTreeMap<VyperSelector, String>,
         ir_json: Option<IR>,
+        ir: Option<String>,
         ast: Option<AST>,
         abi: Option<serde_json::Value>,
         method_identifiers: Option<BTreeMap<String, String>>,
         layout: Option<serde_json::Value>,
         userdoc: Option<serde_json::Value>,
         devdoc: Option<serde_json::Value>,
+        ergs_estimates: Option<BTreeMap<String, Estimate>>,
+        call_graph: Option<CallGraph>,
         warnings: Vec<CombinedJsonContractWarning>,
     ) -> Self {
         Self {
             build,
+            evm_bytecode,
+            evm_bytecode_runtime,
+            forwarded_outputs,
             ir_json,
+            ir,
             ast,
             abi,
             method_identifiers,
             layout,
             userdoc,
             devdoc,
+            ergs_estimates,
+            call_graph,
             warnings,
+            optimization_candidates: None,
         }
     }
 
@@ -72,13 +111,19 @@ impl Contract {
     pub fn new_inner(build: era_compiler_llvm_context::EraVMBuild) -> Self {
         Self::new(
             build,
+            None,
+            None,
+            BTreeMap::new(),
             Some(IR::default()),
+            Some(IR::default().to_string()),
             Some(AST::default()),
             Some(serde_json::json!([])),
             Some(BTreeMap::new()),
             Some(serde_json::json!({})),
             Some(serde_json::json!({})),
             Some(serde_json::json!({})),
+            Some(BTreeMap::new()),
+            Some(CallGraph::default()),
             vec![],
         )
     }
@@ -94,6 +139,24 @@ impl Contract {
         Self::new_inner(build)
     }
 
+    ///
+    /// Borrows the output shared with the EVM target.
+    ///
+    pub fn shared_output(&self) -> SharedOutput<'_> {
+        SharedOutput {
+            ir_json: self.ir_json.as_ref(),
+            ir: self.ir.as_deref(),
+            ast: self.ast.as_ref(),
+            abi: self.abi.as_ref(),
+            method_identifiers: self.method_identifiers.as_ref(),
+            layout: self.layout.as_ref(),
+            userdoc: self.userdoc.as_ref(),
+            devdoc: self.devdoc.as_ref(),
+            call_graph: self.call_graph.as_ref(),
+            forwarded_outputs: &self.forwarded_outputs,
+        }
+    }
+
     ///
     /// Writes the contract to the terminal.
     ///
@@ -105,75 +168,57 @@ impl Contract {
         for warning in self.warnings.iter() {
             writeln!(std::io::stderr(), "\n{warning}")?;
         }
+        self.write_optimization_candidates(path.as_str())?;
 
         writeln!(std::io::stderr(), "Contract `{path}`:")?;
-        writeln!(std::io::stdout(), "0x{}", hex::encode(self.build.bytecode))?;
+        writeln!(
+            std::io::stdout(),
+            "0x{}",
+            hex::encode(self.build.bytecode.as_slice())
+        )?;
 
+        let shared_output = self.shared_output();
         for flag in selection.iter() {
             match flag {
-                VyperSelector::IRJson => {
-                    serde_json::to_writer(
-                        std::io::stdout(),
-                        self.ir_json.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(std::io::stdout())?;
-                }
-                VyperSelector::AST => {
-                    serde_json::to_writer(
-                        std::io::stdout(),
-                        self.ast.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(std::io::stdout())?;
-                }
-                VyperSelector::ABI => {
-                    serde_json::to_writer(
-                        std::io::stdout(),
-                        self.abi.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(std::io::stdout())?;
-                }
-                VyperSelector::MethodIdentifiers => {
-                    serde_json::to_writer(
-                        std::io::stdout(),
-                        self.method_identifiers.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(std::io::stdout())?;
+                VyperSelector::EVMBytecode => {
+                    if let Some(bytecode) = self.evm_bytecode.as_deref() {
+                        writeln!(std::io::stderr(), "Contract `{path}` EVM bytecode:")?;
+                        writeln!(std::io::stdout(), "0x{}", hex::encode(bytecode))?;
+                    }
                 }
-                VyperSelector::Layout => {
-                    serde_json::to_writer(
-                        std::io::stdout(),
-                        self.layout.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(std::io::stdout())?;
+                VyperSelector::EVMBytecodeRuntime => {
+                    if let Some(bytecode) = self.evm_bytecode_runtime.as_deref() {
+                        writeln!(std::io::stderr(), "Contract `{path}` EVM runtime bytecode:")?;
+                        writeln!(std::io::stdout(), "0x{}", hex::encode(bytecode))?;
+                    }
                 }
-                VyperSelector::UserDocumentation => {
-                    serde_json::to_writer(
+
+                VyperSelector::EraVMAssembly => {
+                    writeln!(std::io::stderr(), "Contract `{path}` assembly:")?;
+                    writeln!(
                         std::io::stdout(),
-                        self.userdoc.as_ref().expect("Always exists"),
+                        "{}",
+                        self.build.assembly.as_ref().expect("Always exists")
                     )?;
-                    writeln!(std::io::stdout())?;
                 }
-                VyperSelector::DeveloperDocumentation => {
+                VyperSelector::ErgsEstimates => {
                     serde_json::to_writer(
                         std::io::stdout(),
-                        self.devdoc.as_ref().expect("Always exists"),
+                        self.ergs_estimates.as_ref().expect("Always exists"),
                     )?;
                     writeln!(std::io::stdout())?;
                 }
-
-                VyperSelector::EraVMAssembly => {
-                    writeln!(std::io::stderr(), "Contract `{path}` assembly:")?;
+                VyperSelector::Bytecode => {}
+                VyperSelector::BytecodeRuntime => {
+                    writeln!(std::io::stderr(), "Contract `{path}` runtime bytecode:")?;
                     writeln!(
                         std::io::stdout(),
-                        "{}",
-                        self.build.assembly.as_ref().expect("Always exists")
+                        "0x{}",
+                        hex::encode(self.build.bytecode.as_slice())
                     )?;
                 }
-                VyperSelector::ProjectMetadata => {}
 
-                VyperSelector::CombinedJson => {
-                    panic!("Combined JSON is printed with another pipeline");
-                }
+                flag => shared_output.write_to_terminal(flag, path.as_str())?,
             }
         }
 
@@ -183,143 +228,265 @@ impl Contract {
     ///
     /// Writes the contract output to the directory.
     ///
+    /// Returns the written files keyed by the output name, which are listed in the manifest.
+    ///
     pub fn write_to_directory(
         self,
         selection: &[VyperSelector],
         output_directory: &Path,
-        contract_path: &Path,
+        output_path: &Path,
         overwrite: bool,
-    ) -> anyhow::Result<()> {
+        legacy_layout: bool,
+    ) -> anyhow::Result<BTreeMap<String, String>> {
         for warning in self.warnings.iter() {
             writeln!(std::io::stderr(), "\n{warning}")?;
         }
 
-        let contract_path = crate::path_to_posix(contract_path)?;
-        let file_name = contract_path
-            .file_name()
-            .ok_or_else(|| anyhow::anyhow!("File name not found in path {contract_path:?}"))?
-            .to_string_lossy();
+        let output_path = output_path.to_string_lossy().to_string();
+        let source_path = output_path.clone();
+        self.write_optimization_candidates(output_path.as_str())?;
+        let mut output_directory = OutputDirectory::new(output_directory, output_path, overwrite);
 
-        let binary_file_name = format!(
-            "{}.{}",
-            file_name,
-            era_compiler_common::EXTENSION_ERAVM_BINARY
-        );
-        let mut binary_file_path = output_directory.to_owned();
-        binary_file_path.push(binary_file_name);
-        if binary_file_path.exists() && !overwrite {
-            anyhow::bail!(
-                "Refusing to overwrite an existing file {binary_file_path:?} (use --overwrite to force).",
-            );
-        }
-        std::fs::write(
-            &binary_file_path,
+        output_directory.write_with_extension(
+            "bytecode",
+            era_compiler_common::EXTENSION_ERAVM_BINARY,
             format!("0x{}", hex::encode(self.build.bytecode.as_slice())).as_bytes(),
-        )
-        .map_err(|error| anyhow::anyhow!("File {binary_file_path:?} writing error: {error}"))?;
+        )?;
 
         if selection.is_empty() {
-            return Ok(());
+            return Ok(output_directory.into_files());
         }
-        let mut extra_output_file_path = output_directory.to_owned();
-        extra_output_file_path.push(file_name.as_ref());
-        if extra_output_file_path.exists() && !overwrite {
-            anyhow::bail!(
-                "Refusing to overwrite an existing file {extra_output_file_path:?} (use --overwrite to force).",
-            );
+        if legacy_layout {
+            output_directory.enable_legacy_layout()?;
         }
-        let extra_output_file =
-            File::create(extra_output_file_path.as_path()).map_err(|error| {
-                anyhow::anyhow!("File {extra_output_file_path:?} creating: {error}")
-            })?;
+        let shared_output = self.shared_output();
         for flag in selection.iter() {
             match flag {
-                VyperSelector::IRJson => {
-                    serde_json::to_writer(
-                        &extra_output_file,
-                        self.ir_json.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(&extra_output_file)?;
-                }
-                VyperSelector::AST => {
-                    serde_json::to_writer(
-                        &extra_output_file,
-                        self.ast.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(&extra_output_file)?;
-                }
-                VyperSelector::ABI => {
-                    serde_json::to_writer(
-                        &extra_output_file,
-                        self.abi.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(&extra_output_file)?;
-                }
-                VyperSelector::MethodIdentifiers => {
-                    serde_json::to_writer(
-                        &extra_output_file,
-                        self.method_identifiers.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(&extra_output_file)?;
-                }
-                VyperSelector::Layout => {
-                    serde_json::to_writer(
-                        &extra_output_file,
-                        self.layout.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(&extra_output_file)?;
-                }
-                VyperSelector::UserDocumentation => {
-                    serde_json::to_writer(
-                        &extra_output_file,
-                        self.userdoc.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(&extra_output_file)?;
+                VyperSelector::EVMBytecode => {
+                    if let Some(bytecode) = self.evm_bytecode.as_deref() {
+                        output_directory.write_with_extension(
+                            "evm_bytecode",
+                            format!("evm.{}", crate::r#const::EXTENSION_EVM_BINARY).as_str(),
+                            format!("0x{}", hex::encode(bytecode)).as_bytes(),
+                        )?;
+                    }
                 }
-                VyperSelector::DeveloperDocumentation => {
-                    serde_json::to_writer(
-                        &extra_output_file,
-                        self.devdoc.as_ref().expect("Always exists"),
-                    )?;
-                    writeln!(&extra_output_file)?;
+                VyperSelector::EVMBytecodeRuntime => {
+                    if let Some(bytecode) = self.evm_bytecode_runtime.as_deref() {
+                        output_directory.write_with_extension(
+                            "evm_bytecode_runtime",
+                            format!(
+                                "evm{}.{}",
+                                crate::r#const::EVM_RUNTIME_CODE_SUFFIX,
+                                crate::r#const::EXTENSION_EVM_BINARY
+                            )
+                            .as_str(),
+                            format!("0x{}", hex::encode(bytecode)).as_bytes(),
+                        )?;
+                    }
                 }
 
                 VyperSelector::EraVMAssembly => {
-                    let assembly_file_name = format!(
-                        "{}.{}",
-                        file_name,
-                        era_compiler_common::EXTENSION_ERAVM_ASSEMBLY
-                    );
-                    let mut assembly_file_path = output_directory.to_owned();
-                    assembly_file_path.push(assembly_file_name);
-                    if assembly_file_path.exists() && !overwrite {
-                        anyhow::bail!(
-                            "Refusing to overwrite an existing file {assembly_file_path:?} (use --overwrite to force).",
-                        );
-                    }
-                    std::fs::write(
-                        &assembly_file_path,
+                    output_directory.write_with_extension(
+                        flag.to_string().as_str(),
+                        era_compiler_common::EXTENSION_ERAVM_ASSEMBLY,
                         self.build
                             .assembly
                             .as_ref()
                             .expect("Always exists")
                             .as_bytes(),
-                    )
-                    .map_err(|error| {
-                        anyhow::anyhow!("File {assembly_file_path:?} writing error: {error}")
-                    })?;
+                    )?;
                 }
-                VyperSelector::ProjectMetadata => {}
-
-                VyperSelector::CombinedJson => {
-                    panic!("Combined JSON is printed with another pipeline");
+                VyperSelector::ErgsEstimates => {
+                    output_directory.write_json(
+                        flag.to_string().as_str(),
+                        self.ergs_estimates.as_ref().expect("Always exists"),
+                    )?;
+                }
+                VyperSelector::Bytecode => {}
+                VyperSelector::BytecodeRuntime => {
+                    output_directory.write_with_extension(
+                        "bytecode_runtime",
+                        format!(
+                            "{}.{}",
+                            crate::r#const::EVM_RUNTIME_CODE_SUFFIX.trim_start_matches('.'),
+                            era_compiler_common::EXTENSION_ERAVM_BINARY
+                        )
+                        .as_str(),
+                        format!("0x{}", hex::encode(self.build.bytecode.as_slice())).as_bytes(),
+                    )?;
                 }
+
+                flag => shared_output.write_to_directory(
+                    flag,
+                    &mut output_directory,
+                    source_path.as_str(),
+                )?,
+            }
+        }
+
+        Ok(output_directory.into_files())
+    }
+
+    ///
+    /// Writes the contract to the specified directory as an artifact of the ZKsync tooling.
+    ///
+    /// `source_name` is the source path relative to the base path. The artifact is written to
+    /// `<source name>/<contract>.json` in the `hardhat` layout, and to
+    /// `<source file name>/<contract>.json` in the `foundry` layout.
+    ///
+    pub fn write_artifact(
+        self,
+        format: ArtifactFormat,
+        output_directory: &Path,
+        source_name: &Path,
+        contract_name: String,
+        build_info: &BuildInfo,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        for warning in self.warnings.iter() {
+            writeln!(std::io::stderr(), "\n{warning}")?;
+        }
+
+        let source_name_string = source_name.to_string_lossy().to_string();
+        self.write_optimization_candidates(source_name_string.as_str())?;
+
+        match format {
+            ArtifactFormat::Foundry => {
+                let source_file_name = source_name
+                    .file_name()
+                    .ok_or_else(|| anyhow::anyhow!("File name not found in path {source_name:?}"))?
+                    .to_string_lossy()
+                    .to_string();
+                let mut output_directory = OutputDirectory::new(
+                    output_directory,
+                    format!("{source_file_name}/{contract_name}"),
+                    overwrite,
+                );
+                let artifact = self.into_foundry_artifact(build_info.id.as_str());
+                output_directory.write_with_extension(
+                    "artifact",
+                    era_compiler_common::EXTENSION_JSON,
+                    serde_json::to_vec_pretty(&artifact)
+                        .expect("Always valid")
+                        .as_slice(),
+                )?;
+            }
+            ArtifactFormat::Hardhat => {
+                let mut output_directory = OutputDirectory::new(
+                    output_directory,
+                    format!("{source_name_string}/{contract_name}"),
+                    overwrite,
+                );
+                let debug = HardhatArtifactDebug {
+                    format: HardhatArtifact::DEBUG_FORMAT.to_owned(),
+                    build_info: format!(
+                        "{}{}",
+                        "../".repeat(source_name.components().count()),
+                        build_info.file_name()
+                    ),
+                };
+                let artifact = self.into_hardhat_artifact(contract_name, source_name_string);
+                output_directory.write_with_extension(
+                    "artifact",
+                    era_compiler_common::EXTENSION_JSON,
+                    serde_json::to_vec_pretty(&artifact)
+                        .expect("Always valid")
+                        .as_slice(),
+                )?;
+                output_directory.write_with_extension(
+                    "debug",
+                    HardhatArtifact::DEBUG_EXTENSION,
+                    serde_json::to_vec_pretty(&debug)
+                        .expect("Always valid")
+                        .as_slice(),
+                )?;
             }
         }
 
         Ok(())
     }
 
+    ///
+    /// Returns the contract output written to the build info.
+    ///
+    pub fn to_build_info_contract(&self) -> BuildInfoContract {
+        let bytecode = format!("0x{}", hex::encode(self.build.bytecode.as_slice()));
+        BuildInfoContract {
+            abi: self.abi.clone().unwrap_or_else(|| serde_json::json!([])),
+            evm: BuildInfoContractEVM {
+                bytecode: FoundryArtifactBytecode {
+                    object: bytecode.clone(),
+                },
+                deployed_bytecode: FoundryArtifactBytecode { object: bytecode },
+                method_identifiers: self.method_identifiers.clone().unwrap_or_default(),
+            },
+            storage_layout: self.layout.clone().unwrap_or_else(|| serde_json::json!({})),
+            hash: hex::encode(self.build.bytecode_hash.expect("Always exists")),
+            factory_dependencies: self.build.factory_dependencies.clone(),
+        }
+    }
+
+    ///
+    /// Converts the contract to the `foundry-zksync` artifact.
+    ///
+    pub fn into_foundry_artifact(self, build_info_id: &str) -> FoundryArtifact {
+        let bytecode = format!("0x{}", hex::encode(self.build.bytecode));
+        FoundryArtifact {
+            abi: self.abi.unwrap_or_else(|| serde_json::json!([])),
+            bytecode: FoundryArtifactBytecode {
+                object: bytecode.clone(),
+            },
+            deployed_bytecode: FoundryArtifactBytecode { object: bytecode },
+            method_identifiers: self.method_identifiers.unwrap_or_default(),
+            storage_layout: self.layout.unwrap_or_else(|| serde_json::json!({})),
+            hash: hex::encode(self.build.bytecode_hash.expect("Always exists")),
+            factory_dependencies: self.build.factory_dependencies,
+            build_info_id: build_info_id.to_owned(),
+        }
+    }
+
+    ///
+    /// Converts the contract to the `hardhat-zksync` artifact.
+    ///
+    pub fn into_hardhat_artifact(
+        self,
+        contract_name: String,
+        source_name: String,
+    ) -> HardhatArtifact {
+        let bytecode = format!("0x{}", hex::encode(self.build.bytecode));
+        HardhatArtifact {
+            format: HardhatArtifact::FORMAT.to_owned(),
+            contract_name,
+            source_name,
+            abi: self.abi.unwrap_or_else(|| serde_json::json!([])),
+            bytecode: bytecode.clone(),
+            deployed_bytecode: bytecode,
+            link_references: BTreeMap::new(),
+            deployed_link_references: BTreeMap::new(),
+            factory_deps: self.build.factory_dependencies,
+            bytecode_hash: hex::encode(self.build.bytecode_hash.expect("Always exists")),
+            method_identifiers: self.method_identifiers.unwrap_or_default(),
+            storage_layout: self.layout.unwrap_or_else(|| serde_json::json!({})),
+        }
+    }
+
+    ///
+    /// Writes the optimization candidates tried by auto-tuning to the terminal.
+    ///
+    fn write_optimization_candidates(&self, path: &str) -> anyhow::Result<()> {
+        if let Some(candidates) = self.optimization_candidates.as_ref() {
+            writeln!(
+                std::io::stderr(),
+                "Contract `{path}` optimization candidates:"
+            )?;
+            for candidate in candidates.iter() {
+                writeln!(std::io::stderr(), "    {candidate}")?;
+            }
+        }
+        Ok(())
+    }
+
     ///
     /// Converts the contract to the combined JSON.
     ///
@@ -328,6 +495,12 @@ impl Contract {
         CombinedJsonContract {
             bytecode: bytecode.clone(),
             bytecode_runtime: bytecode,
+            evm_bytecode: self
+                .evm_bytecode
+                .map(|bytecode| format!("0x{}", hex::encode(bytecode))),
+            evm_bytecode_runtime: self
+                .evm_bytecode_runtime
+                .map(|bytecode| format!("0x{}", hex::encode(bytecode))),
 
             ir_json: self
                 .ir_json
@@ -344,6 +517,7 @@ impl Contract {
             assembly: self.build.assembly,
             factory_deps: Some(self.build.factory_dependencies),
             warnings: Some(self.warnings),
+            optimization_candidates: self.optimization_candidates,
         }
     }
 }
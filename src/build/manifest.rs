@@ -0,0 +1,48 @@
+//!
+//! The output directory manifest.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+
+///
+/// The output directory manifest.
+///
+/// Lists the files written for each contract, so consumers do not have to know the layout.
+///
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct Manifest {
+    /// The written files of each contract, keyed by the output name.
+    pub contracts: BTreeMap<String, BTreeMap<String, String>>,
+    /// The project metadata file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_metadata: Option<String>,
+}
+
+impl Manifest {
+    /// The manifest file name.
+    pub const FILE_NAME: &'static str = "manifest.json";
+
+    ///
+    /// Writes the manifest to the output directory.
+    ///
+    pub fn write_to_directory(
+        &self,
+        output_directory: &std::path::Path,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        let mut manifest_file_path = output_directory.to_owned();
+        manifest_file_path.push(Self::FILE_NAME);
+        if manifest_file_path.exists() && !overwrite {
+            anyhow::bail!(
+                "Refusing to overwrite an existing file {manifest_file_path:?} (use --overwrite to force).",
+            );
+        }
+        std::fs::write(
+            &manifest_file_path,
+            serde_json::to_vec_pretty(self).expect("Always valid"),
+        )
+        .map_err(|error| Error::io(manifest_file_path.as_path(), "writing", error).into())
+    }
+}
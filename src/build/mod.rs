@@ -2,7 +2,11 @@
 //! The Vyper project build.
 //!
 
+pub mod artifact;
 pub mod contract;
+pub mod manifest;
+pub mod output_directory;
+pub mod shared_output;
 
 use std::collections::BTreeMap;
 use std::io::Write;
@@ -11,17 +15,28 @@ use std::path::PathBuf;
 
 use normpath::PathExt;
 
+use crate::error::Error;
 use crate::vyper::combined_json::extra_data::ExtraData as CombinedJsonExtraData;
 use crate::vyper::combined_json::CombinedJson;
 use crate::vyper::selector::Selector as VyperSelector;
 use crate::vyper::Compiler as VyperCompiler;
 
+use self::artifact::build_info::BuildInfo;
+use self::artifact::build_info::Input as BuildInfoInput;
+use self::artifact::build_info::Output as BuildInfoOutput;
+use self::artifact::build_info::Source as BuildInfoSource;
+use self::artifact::build_info::SourceId as BuildInfoSourceId;
+use self::artifact::format::Format as ArtifactFormat;
+use self::artifact::foundry::Foundry as FoundryArtifact;
+use self::artifact::hardhat::Hardhat as HardhatArtifact;
 use self::contract::Contract;
+use self::manifest::Manifest;
+use self::output_directory::OutputDirectory;
 
 ///
 /// The Vyper project build.
 ///
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct Build {
     /// The contract data,
     pub contracts: BTreeMap<String, Contract>,
@@ -105,23 +120,34 @@ impl Build {
         self,
         selection: &[VyperSelector],
         output_directory: &Path,
+        base_path: Option<&Path>,
         overwrite: bool,
+        legacy_layout: bool,
     ) -> anyhow::Result<()> {
+        let mut output_paths =
+            Self::output_paths(self.contracts.keys().map(String::as_str), base_path)?;
+
         std::fs::create_dir_all(output_directory)?;
 
+        let mut manifest = Manifest::default();
         for (contract_path, contract) in self.contracts.into_iter() {
-            contract.write_to_directory(
+            let output_path = output_paths
+                .remove(contract_path.as_str())
+                .expect("Always exists");
+            let files = contract.write_to_directory(
                 selection,
                 output_directory,
-                PathBuf::from(contract_path).as_path(),
+                output_path.as_path(),
                 overwrite,
+                legacy_layout,
             )?;
+            manifest.contracts.insert(contract_path, files);
         }
 
         if selection.contains(&VyperSelector::ProjectMetadata) {
             let metadata_file_name = format!("meta.{}", era_compiler_common::EXTENSION_JSON);
             let mut metadata_file_path = output_directory.to_owned();
-            metadata_file_path.push(metadata_file_name);
+            metadata_file_path.push(metadata_file_name.as_str());
             if metadata_file_path.exists() && !overwrite {
                 anyhow::bail!(
                     "Refusing to overwrite an existing file {metadata_file_path:?} (use --overwrite to force).",
@@ -133,14 +159,191 @@ impl Build {
                     .expect("Always valid")
                     .as_bytes(),
             )
-            .map_err(|error| {
-                anyhow::anyhow!("File {metadata_file_path:?} writing error: {error}")
-            })?;
+            .map_err(|error| Error::io(metadata_file_path.as_path(), "writing", error))?;
+            manifest.project_metadata = Some(metadata_file_name);
+        }
+
+        if !legacy_layout {
+            manifest.write_to_directory(output_directory, overwrite)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts to the specified directory as artifacts of the ZKsync tooling.
+    ///
+    /// Source names are relative to `base_path`, which defaults to the current directory, that is,
+    /// the project root of the ZKsync tooling.
+    ///
+    pub fn write_artifacts(
+        self,
+        format: ArtifactFormat,
+        output_directory: &Path,
+        base_path: Option<&Path>,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        let base_path = match base_path {
+            Some(base_path) => base_path.to_owned(),
+            None => std::env::current_dir()?,
+        };
+        let mut source_names = Self::output_paths(
+            self.contracts.keys().map(String::as_str),
+            Some(base_path.as_path()),
+        )?;
+
+        if let ArtifactFormat::Foundry = format {
+            let mut file_names: BTreeMap<&std::ffi::OsStr, &str> = BTreeMap::new();
+            for (contract_path, source_name) in source_names.iter() {
+                let Some(file_name) = source_name.file_name() else {
+                    continue;
+                };
+                if let Some(other_path) = file_names.insert(file_name, contract_path.as_str()) {
+                    anyhow::bail!(
+                        "Sources {other_path:?} and {contract_path:?} are both written to {file_name:?} in the `foundry` layout."
+                    );
+                }
+            }
+        }
+
+        let mut input = BuildInfoInput {
+            language: BuildInfo::LANGUAGE.to_owned(),
+            sources: BTreeMap::new(),
+            settings: self.project_metadata,
+        };
+        let mut output = BuildInfoOutput::default();
+        let mut contract_names = BTreeMap::new();
+        for (contract_path, contract) in self.contracts.iter() {
+            let source_name = source_names
+                .get(contract_path.as_str())
+                .expect("Always exists");
+            let contract_name = source_name
+                .file_stem()
+                .ok_or_else(|| anyhow::anyhow!("File name not found in path {source_name:?}"))?
+                .to_string_lossy()
+                .to_string();
+            let source_name = source_name.to_string_lossy().to_string();
+
+            if contract_path.as_str() != crate::r#const::MINIMAL_PROXY_CONTRACT_NAME {
+                let content = std::fs::read_to_string(contract_path.as_str()).map_err(|error| {
+                    Error::io(Path::new(contract_path.as_str()), "reading", error)
+                })?;
+                output.sources.insert(
+                    source_name.clone(),
+                    BuildInfoSourceId {
+                        id: input.sources.len(),
+                    },
+                );
+                input
+                    .sources
+                    .insert(source_name.clone(), BuildInfoSource { content });
+            }
+            output
+                .contracts
+                .entry(source_name)
+                .or_default()
+                .insert(contract_name.clone(), contract.to_build_info_contract());
+            contract_names.insert(contract_path.to_owned(), contract_name);
+        }
+
+        let build_info_format = match format {
+            ArtifactFormat::Foundry => FoundryArtifact::BUILD_INFO_FORMAT,
+            ArtifactFormat::Hardhat => HardhatArtifact::BUILD_INFO_FORMAT,
+        };
+        let build_info = BuildInfo::new(build_info_format, input, output);
+
+        std::fs::create_dir_all(output_directory)
+            .map_err(|error| Error::io(output_directory, "creating", error))?;
+
+        for (contract_path, contract) in self.contracts.into_iter() {
+            let source_name = source_names
+                .remove(contract_path.as_str())
+                .expect("Always exists");
+            let contract_name = contract_names
+                .remove(contract_path.as_str())
+                .expect("Always exists");
+            contract.write_artifact(
+                format,
+                output_directory,
+                source_name.as_path(),
+                contract_name,
+                &build_info,
+                overwrite,
+            )?;
         }
 
+        OutputDirectory::new(output_directory, String::new(), overwrite).write_file(
+            "build_info",
+            build_info.file_name().as_str(),
+            serde_json::to_vec_pretty(&build_info)
+                .expect("Always valid")
+                .as_slice(),
+        )?;
+
         Ok(())
     }
 
+    ///
+    /// Returns the output paths of contracts relative to the output directory.
+    ///
+    /// The source directory structure is mirrored relative to `base_path`, which defaults to
+    /// the current directory. Without an explicit `base_path`, sources outside the current
+    /// directory are written by their file name. Sources mapped to the same output path
+    /// are reported before anything is written. The minimal proxy contract, which has no source,
+    /// is written to the root of the output directory.
+    ///
+    pub fn output_paths<'a>(
+        contract_paths: impl Iterator<Item = &'a str>,
+        base_path: Option<&Path>,
+    ) -> anyhow::Result<BTreeMap<String, PathBuf>> {
+        let normalize = |path: &Path| -> anyhow::Result<PathBuf> {
+            path.normalize()
+                .map(|path| path.into_path_buf())
+                .map_err(|error| anyhow::anyhow!("Path {path:?} normalization error: {error}"))
+        };
+
+        let (minimal_proxy_paths, contract_paths): (Vec<&str>, Vec<&str>) =
+            contract_paths.partition(|path| *path == crate::r#const::MINIMAL_PROXY_CONTRACT_NAME);
+        let contract_paths = contract_paths
+            .into_iter()
+            .map(|path| Ok((path, normalize(Path::new(path))?)))
+            .collect::<anyhow::Result<Vec<(&str, PathBuf)>>>()?;
+
+        let mut output_paths = BTreeMap::new();
+        let mut sources: BTreeMap<PathBuf, &str> = BTreeMap::new();
+        for path in minimal_proxy_paths.into_iter() {
+            output_paths.insert(path.to_owned(), PathBuf::from(path));
+            sources.insert(PathBuf::from(path), path);
+        }
+
+        let (base_path, is_base_path_explicit) = match base_path {
+            Some(base_path) => (normalize(base_path)?, true),
+            None => (normalize(std::env::current_dir()?.as_path())?, false),
+        };
+
+        for (contract_path, normalized_path) in contract_paths.into_iter() {
+            let output_path = match normalized_path.strip_prefix(base_path.as_path()) {
+                Ok(output_path) => output_path,
+                Err(_) if !is_base_path_explicit => {
+                    normalized_path.file_name().map(Path::new).ok_or_else(|| {
+                        anyhow::anyhow!("File name not found in path {contract_path:?}")
+                    })?
+                }
+                Err(_) => anyhow::bail!(
+                    "Source {contract_path:?} is outside of the base path {base_path:?}."
+                ),
+            };
+            let output_path = crate::path_to_posix(output_path)?;
+            if let Some(other_path) = sources.insert(output_path.clone(), contract_path) {
+                anyhow::bail!(
+                    "Sources {other_path:?} and {contract_path:?} are both written to {output_path:?} in the output directory. Use `--base-path` to disambiguate them."
+                );
+            }
+            output_paths.insert(contract_path.to_owned(), output_path);
+        }
+        Ok(output_paths)
+    }
+
     ///
     /// Writes all contracts to the combined JSON.
     ///
@@ -153,29 +356,8 @@ impl Build {
             .contracts
             .into_iter()
             .map(|(path, contract)| {
-                let contract_path = PathBuf::from(path.as_str());
-                let contract_path = contract_path
-                    .normalize()
-                    .map(|path| path.into_path_buf())
-                    .unwrap_or(contract_path);
-
-                let contract_path = if version < Some(&VyperCompiler::FIRST_VERSION_ABSOLUTE_PATHS)
-                {
-                    std::env::current_dir()
-                        .map_err(anyhow::Error::from)
-                        .and_then(|path| crate::path_to_posix(path.as_path()))
-                        .and_then(|path| {
-                            contract_path
-                                .strip_prefix(path)
-                                .map_err(anyhow::Error::from)
-                        })
-                        .unwrap_or(contract_path.as_path())
-                } else {
-                    contract_path.as_path()
-                };
-
                 (
-                    contract_path.to_string_lossy().to_string(),
+                    Self::combined_json_contract_path(path.as_str(), version),
                     contract.into_combined_json(),
                 )
             })
@@ -185,4 +367,33 @@ impl Build {
 
         CombinedJson::new(contracts, extra_data, version, zkvyper_version)
     }
+
+    ///
+    /// Returns the contract path as printed by `vyper` in the combined JSON.
+    ///
+    /// Before v0.4.0, `vyper` printed paths relative to the current directory.
+    ///
+    pub fn combined_json_contract_path(path: &str, version: Option<&semver::Version>) -> String {
+        let contract_path = PathBuf::from(path);
+        let contract_path = contract_path
+            .normalize()
+            .map(|path| path.into_path_buf())
+            .unwrap_or(contract_path);
+
+        let contract_path = if version < Some(&VyperCompiler::FIRST_VERSION_ABSOLUTE_PATHS) {
+            std::env::current_dir()
+                .map_err(anyhow::Error::from)
+                .and_then(|path| crate::path_to_posix(path.as_path()))
+                .and_then(|path| {
+                    contract_path
+                        .strip_prefix(path)
+                        .map_err(anyhow::Error::from)
+                })
+                .unwrap_or(contract_path.as_path())
+        } else {
+            contract_path.as_path()
+        };
+
+        contract_path.to_string_lossy().to_string()
+    }
 }
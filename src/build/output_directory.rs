@@ -0,0 +1,143 @@
+//!
+//! The contract output directory writer.
+//!
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::error::Error;
+
+///
+/// The contract output directory writer.
+///
+/// By default, each selected output is written to a separate file, and the written files are
+/// collected for the manifest. In the legacy layout, JSON output is concatenated into a single
+/// extension-less file named after the source, one JSON per line.
+///
+#[derive(Debug)]
+pub struct OutputDirectory<'a> {
+    /// The output directory path.
+    pub path: &'a Path,
+    /// The source path relative to the output directory, which is the prefix of the output file names.
+    pub file_name: String,
+    /// Whether to overwrite existing files.
+    pub overwrite: bool,
+    /// The legacy concatenated output file, if the legacy layout is used.
+    pub legacy_file: Option<File>,
+    /// The written files, keyed by the output name.
+    pub files: BTreeMap<String, String>,
+}
+
+impl<'a> OutputDirectory<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: &'a Path, file_name: String, overwrite: bool) -> Self {
+        Self {
+            path,
+            file_name,
+            overwrite,
+            legacy_file: None,
+            files: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Switches to the legacy layout, creating the concatenated output file.
+    ///
+    pub fn enable_legacy_layout(&mut self) -> anyhow::Result<()> {
+        let mut legacy_file_path = self.path.to_owned();
+        legacy_file_path.push(self.file_name.as_str());
+        if legacy_file_path.exists() && !self.overwrite {
+            anyhow::bail!(
+                "Refusing to overwrite an existing file {legacy_file_path:?} (use --overwrite to force).",
+            );
+        }
+        Self::create_parent_directory(legacy_file_path.as_path())?;
+        let legacy_file = File::create(legacy_file_path.as_path())
+            .map_err(|error| Error::io(legacy_file_path.as_path(), "creating", error))?;
+        self.legacy_file = Some(legacy_file);
+        Ok(())
+    }
+
+    ///
+    /// Writes the JSON output to `<source>.<name>.json`, or appends it to the legacy file.
+    ///
+    pub fn write_json<T>(&mut self, name: &str, value: &T) -> anyhow::Result<()>
+    where
+        T: serde::Serialize,
+    {
+        match self.legacy_file.as_ref() {
+            Some(mut legacy_file) => {
+                serde_json::to_writer(legacy_file, value)?;
+                writeln!(legacy_file)?;
+                Ok(())
+            }
+            None => {
+                let file_name = format!(
+                    "{}.{name}.{}",
+                    self.file_name,
+                    era_compiler_common::EXTENSION_JSON
+                );
+                let contents = serde_json::to_vec(value).expect("Always valid");
+                self.write_file(name, file_name.as_str(), contents.as_slice())
+            }
+        }
+    }
+
+    ///
+    /// Writes the output to `<source>.<extension>`.
+    ///
+    pub fn write_with_extension(
+        &mut self,
+        name: &str,
+        extension: &str,
+        contents: &[u8],
+    ) -> anyhow::Result<()> {
+        let file_name = format!("{}.{extension}", self.file_name);
+        self.write_file(name, file_name.as_str(), contents)
+    }
+
+    ///
+    /// Writes the output to the file in the directory, refusing to overwrite it if not allowed.
+    ///
+    pub fn write_file(
+        &mut self,
+        name: &str,
+        file_name: &str,
+        contents: &[u8],
+    ) -> anyhow::Result<()> {
+        let mut file_path = self.path.to_owned();
+        file_path.push(file_name);
+        if file_path.exists() && !self.overwrite {
+            anyhow::bail!(
+                "Refusing to overwrite an existing file {file_path:?} (use --overwrite to force).",
+            );
+        }
+        Self::create_parent_directory(file_path.as_path())?;
+        std::fs::write(&file_path, contents)
+            .map_err(|error| Error::io(file_path.as_path(), "writing", error))?;
+        self.files.insert(name.to_owned(), file_name.to_owned());
+        Ok(())
+    }
+
+    ///
+    /// Creates the directory of the output file mirroring the source directory structure.
+    ///
+    fn create_parent_directory(file_path: &Path) -> anyhow::Result<()> {
+        if let Some(parent_directory) = file_path.parent() {
+            std::fs::create_dir_all(parent_directory)
+                .map_err(|error| Error::io(parent_directory, "creating", error))?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the written files, keyed by the output name.
+    ///
+    pub fn into_files(self) -> BTreeMap<String, String> {
+        self.files
+    }
+}
@@ -0,0 +1,227 @@
+//!
+//! The target-independent Vyper contract output.
+//!
+
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use crate::build::output_directory::OutputDirectory;
+use crate::project::contract::vyper::ast::AST;
+use crate::project::contract::vyper::call_graph::CallGraph;
+use crate::project::contract::vyper::expression::Expression as IR;
+use crate::vyper::selector::Selector as VyperSelector;
+
+///
+/// The target-independent Vyper contract output.
+///
+/// Borrows the outputs shared by the EraVM and EVM contract builds, so both targets write them
+/// the same way.
+///
+#[derive(Debug)]
+pub struct SharedOutput<'a> {
+    /// The LLL IR parsed from JSON.
+    pub ir_json: Option<&'a IR>,
+    /// The LLL IR s-expressions.
+    pub ir: Option<&'a str>,
+    /// The contract AST.
+    pub ast: Option<&'a AST>,
+    /// The `vyper` ABI output.
+    pub abi: Option<&'a serde_json::Value>,
+    /// The `vyper` method identifiers output.
+    pub method_identifiers: Option<&'a BTreeMap<String, String>>,
+    /// The `vyper` layout output.
+    pub layout: Option<&'a serde_json::Value>,
+    /// The `vyper` userdoc output.
+    pub userdoc: Option<&'a serde_json::Value>,
+    /// The `vyper` devdoc output.
+    pub devdoc: Option<&'a serde_json::Value>,
+    /// The call graph of functions.
+    pub call_graph: Option<&'a CallGraph>,
+    /// The chain-independent output forwarded from `vyper` as is.
+    pub forwarded_outputs: &'a BTreeMap<VyperSelector, String>,
+}
+
+impl SharedOutput<'_> {
+    ///
+    /// Writes the output requested by `flag` to the terminal.
+    ///
+    /// Returns an error if `flag` is target-specific, as such output must be written by the
+    /// target's contract build.
+    ///
+    pub fn write_to_terminal(&self, flag: &VyperSelector, path: &str) -> anyhow::Result<()> {
+        match flag {
+            VyperSelector::IRJson => {
+                serde_json::to_writer(std::io::stdout(), self.ir_json.expect("Always exists"))?;
+                writeln!(std::io::stdout())?;
+            }
+            VyperSelector::IR => {
+                writeln!(std::io::stderr(), "Contract `{path}` LLL IR:")?;
+                writeln!(std::io::stdout(), "{}", self.ir.expect("Always exists"))?;
+            }
+            VyperSelector::AST => {
+                serde_json::to_writer(std::io::stdout(), self.ast.expect("Always exists"))?;
+                writeln!(std::io::stdout())?;
+            }
+            VyperSelector::ABI => {
+                serde_json::to_writer(std::io::stdout(), self.abi.expect("Always exists"))?;
+                writeln!(std::io::stdout())?;
+            }
+            VyperSelector::MethodIdentifiers => {
+                serde_json::to_writer(
+                    std::io::stdout(),
+                    self.method_identifiers.expect("Always exists"),
+                )?;
+                writeln!(std::io::stdout())?;
+            }
+            VyperSelector::Layout => {
+                serde_json::to_writer(std::io::stdout(), self.layout.expect("Always exists"))?;
+                writeln!(std::io::stdout())?;
+            }
+            VyperSelector::UserDocumentation => {
+                serde_json::to_writer(std::io::stdout(), self.userdoc.expect("Always exists"))?;
+                writeln!(std::io::stdout())?;
+            }
+            VyperSelector::DeveloperDocumentation => {
+                serde_json::to_writer(std::io::stdout(), self.devdoc.expect("Always exists"))?;
+                writeln!(std::io::stdout())?;
+            }
+            VyperSelector::CallGraph => {
+                let call_graph = self.call_graph.expect("Always exists");
+                serde_json::to_writer(std::io::stdout(), call_graph)?;
+                writeln!(std::io::stdout())?;
+                writeln!(std::io::stderr(), "Contract `{path}` call graph:")?;
+                write!(std::io::stdout(), "{}", call_graph.to_dot(path))?;
+            }
+            VyperSelector::Interface
+            | VyperSelector::ExternalInterface
+            | VyperSelector::AnnotatedAST
+            | VyperSelector::Metadata
+            | VyperSelector::IRRuntime
+            | VyperSelector::BasicBlocks
+            | VyperSelector::BasicBlocksRuntime
+            | VyperSelector::ControlFlowGraph
+            | VyperSelector::ControlFlowGraphRuntime
+            | VyperSelector::Integrity
+            | VyperSelector::SolcJson
+            | VyperSelector::Archive => {
+                if let Some(output) = self.forwarded_outputs.get(flag) {
+                    writeln!(std::io::stderr(), "Contract `{path}` {flag}:")?;
+                    write!(std::io::stdout(), "{output}")?;
+                }
+            }
+            VyperSelector::ProjectMetadata => {}
+
+            flag => Self::unsupported(flag)?,
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the output requested by `flag` to the output directory.
+    ///
+    /// Returns an error if `flag` is target-specific, as such output must be written by the
+    /// target's contract build.
+    ///
+    pub fn write_to_directory(
+        &self,
+        flag: &VyperSelector,
+        output_directory: &mut OutputDirectory,
+        source_path: &str,
+    ) -> anyhow::Result<()> {
+        match flag {
+            VyperSelector::IRJson => {
+                output_directory.write_json(
+                    flag.to_string().as_str(),
+                    self.ir_json.expect("Always exists"),
+                )?;
+            }
+            VyperSelector::IR => {
+                output_directory.write_with_extension(
+                    flag.to_string().as_str(),
+                    crate::r#const::EXTENSION_LLL,
+                    self.ir.expect("Always exists").as_bytes(),
+                )?;
+            }
+            VyperSelector::AST => {
+                output_directory
+                    .write_json(flag.to_string().as_str(), self.ast.expect("Always exists"))?;
+            }
+            VyperSelector::ABI => {
+                output_directory
+                    .write_json(flag.to_string().as_str(), self.abi.expect("Always exists"))?;
+            }
+            VyperSelector::MethodIdentifiers => {
+                output_directory.write_json(
+                    flag.to_string().as_str(),
+                    self.method_identifiers.expect("Always exists"),
+                )?;
+            }
+            VyperSelector::Layout => {
+                output_directory.write_json(
+                    flag.to_string().as_str(),
+                    self.layout.expect("Always exists"),
+                )?;
+            }
+            VyperSelector::UserDocumentation => {
+                output_directory.write_json(
+                    flag.to_string().as_str(),
+                    self.userdoc.expect("Always exists"),
+                )?;
+            }
+            VyperSelector::DeveloperDocumentation => {
+                output_directory.write_json(
+                    flag.to_string().as_str(),
+                    self.devdoc.expect("Always exists"),
+                )?;
+            }
+            VyperSelector::CallGraph => {
+                let call_graph = self.call_graph.expect("Always exists");
+                output_directory.write_json(flag.to_string().as_str(), call_graph)?;
+                output_directory.write_with_extension(
+                    format!("{flag}_dot").as_str(),
+                    crate::r#const::EXTENSION_DOT,
+                    call_graph.to_dot(source_path).as_bytes(),
+                )?;
+            }
+            VyperSelector::Interface
+            | VyperSelector::ExternalInterface
+            | VyperSelector::AnnotatedAST
+            | VyperSelector::Metadata
+            | VyperSelector::IRRuntime
+            | VyperSelector::BasicBlocks
+            | VyperSelector::BasicBlocksRuntime
+            | VyperSelector::ControlFlowGraph
+            | VyperSelector::ControlFlowGraphRuntime
+            | VyperSelector::Integrity
+            | VyperSelector::SolcJson
+            | VyperSelector::Archive => {
+                if let Some(output) = self.forwarded_outputs.get(flag) {
+                    let name = flag.to_string();
+                    output_directory.write_with_extension(
+                        name.as_str(),
+                        name.as_str(),
+                        output.as_bytes(),
+                    )?;
+                }
+            }
+            VyperSelector::ProjectMetadata => {}
+
+            flag => Self::unsupported(flag)?,
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the error for the output that is not written by the shared writers.
+    ///
+    fn unsupported(flag: &VyperSelector) -> anyhow::Result<()> {
+        match flag {
+            VyperSelector::CombinedJson => {
+                anyhow::bail!("Combined JSON is printed with another pipeline.")
+            }
+            flag => anyhow::bail!("Output `{flag}` is not available for the target."),
+        }
+    }
+}
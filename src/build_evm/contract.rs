@@ -0,0 +1,229 @@
+//!
+//! The Vyper contract EVM build.
+//!
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+
+use crate::build::output_directory::OutputDirectory;
+use crate::build::shared_output::SharedOutput;
+use crate::project::contract::vyper::ast::AST;
+use crate::project::contract::vyper::call_graph::CallGraph;
+use crate::project::contract::vyper::expression::Expression as IR;
+use crate::vyper::combined_json::contract::warning::Warning as CombinedJsonContractWarning;
+use crate::vyper::combined_json::contract::Contract as CombinedJsonContract;
+use crate::vyper::selector::Selector as VyperSelector;
+
+///
+/// The Vyper contract EVM build.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Contract {
+    /// The deploy bytecode, which returns the runtime bytecode.
+    pub deploy_bytecode: Vec<u8>,
+    /// The runtime bytecode.
+    pub runtime_bytecode: Vec<u8>,
+    /// The LLL IR parsed from JSON.
+    pub ir_json: Option<IR>,
+    /// The LLL IR s-expressions.
+    pub ir: Option<String>,
+    /// The contract AST.
+    pub ast: Option<AST>,
+    /// The `vyper` ABI output.
+    pub abi: Option<serde_json::Value>,
+    /// The `vyper` method identifiers output.
+    pub method_identifiers: Option<BTreeMap<String, String>>,
+    /// The `vyper` layout output.
+    pub layout: Option<serde_json::Value>,
+    /// The `vyper` userdoc output.
+    pub userdoc: Option<serde_json::Value>,
+    /// The `vyper` devdoc output.
+    pub devdoc: Option<serde_json::Value>,
+    /// The call graph of functions.
+    pub call_graph: Option<CallGraph>,
+    /// The chain-independent output forwarded from `vyper` as is.
+    pub forwarded_outputs: BTreeMap<VyperSelector, String>,
+    /// The compilation warnings.
+    pub warnings: Vec<CombinedJsonContractWarning>,
+}
+
+impl Contract {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        deploy_bytecode: Vec<u8>,
+        runtime_bytecode: Vec<u8>,
+        forwarded_outputs: BTreeMap<VyperSelector, String>,
+        ir_json: Option<IR>,
+        ir: Option<String>,
+        ast: Option<AST>,
+        abi: Option<serde_json::Value>,
+        method_identifiers: Option<BTreeMap<String, String>>,
+        layout: Option<serde_json::Value>,
+        userdoc: Option<serde_json::Value>,
+        devdoc: Option<serde_json::Value>,
+        call_graph: Option<CallGraph>,
+        warnings: Vec<CombinedJsonContractWarning>,
+    ) -> Self {
+        Self {
+            deploy_bytecode,
+            runtime_bytecode,
+            forwarded_outputs,
+            ir_json,
+            ir,
+            ast,
+            abi,
+            method_identifiers,
+            layout,
+            userdoc,
+            devdoc,
+            call_graph,
+            warnings,
+        }
+    }
+
+    ///
+    /// Borrows the output shared with the EraVM target.
+    ///
+    pub fn shared_output(&self) -> SharedOutput<'_> {
+        SharedOutput {
+            ir_json: self.ir_json.as_ref(),
+            ir: self.ir.as_deref(),
+            ast: self.ast.as_ref(),
+            abi: self.abi.as_ref(),
+            method_identifiers: self.method_identifiers.as_ref(),
+            layout: self.layout.as_ref(),
+            userdoc: self.userdoc.as_ref(),
+            devdoc: self.devdoc.as_ref(),
+            call_graph: self.call_graph.as_ref(),
+            forwarded_outputs: &self.forwarded_outputs,
+        }
+    }
+
+    ///
+    /// Writes the contract to the terminal.
+    ///
+    pub fn write_to_terminal(
+        self,
+        path: String,
+        selection: &[VyperSelector],
+    ) -> anyhow::Result<()> {
+        for warning in self.warnings.iter() {
+            writeln!(std::io::stderr(), "\n{warning}")?;
+        }
+
+        writeln!(std::io::stderr(), "Contract `{path}`:")?;
+        writeln!(
+            std::io::stdout(),
+            "0x{}",
+            hex::encode(self.deploy_bytecode.as_slice())
+        )?;
+        writeln!(std::io::stderr(), "Contract `{path}` runtime:")?;
+        writeln!(
+            std::io::stdout(),
+            "0x{}",
+            hex::encode(self.runtime_bytecode.as_slice())
+        )?;
+
+        let shared_output = self.shared_output();
+        for flag in selection.iter() {
+            match flag {
+                VyperSelector::Bytecode | VyperSelector::BytecodeRuntime => {}
+
+                flag => shared_output.write_to_terminal(flag, path.as_str())?,
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the contract output to the directory.
+    ///
+    /// Returns the written files keyed by the output name, which are listed in the manifest.
+    ///
+    pub fn write_to_directory(
+        self,
+        selection: &[VyperSelector],
+        output_directory: &Path,
+        output_path: &Path,
+        overwrite: bool,
+        legacy_layout: bool,
+    ) -> anyhow::Result<BTreeMap<String, String>> {
+        for warning in self.warnings.iter() {
+            writeln!(std::io::stderr(), "\n{warning}")?;
+        }
+
+        let output_path = output_path.to_string_lossy().to_string();
+        let source_path = output_path.clone();
+        let mut output_directory = OutputDirectory::new(output_directory, output_path, overwrite);
+
+        output_directory.write_with_extension(
+            "bytecode",
+            crate::r#const::EXTENSION_EVM_BINARY,
+            format!("0x{}", hex::encode(self.deploy_bytecode.as_slice())).as_bytes(),
+        )?;
+        output_directory.write_with_extension(
+            "bytecode_runtime",
+            format!(
+                "{}.{}",
+                crate::r#const::EVM_RUNTIME_CODE_SUFFIX.trim_start_matches('.'),
+                crate::r#const::EXTENSION_EVM_BINARY
+            )
+            .as_str(),
+            format!("0x{}", hex::encode(self.runtime_bytecode.as_slice())).as_bytes(),
+        )?;
+
+        if selection.is_empty() {
+            return Ok(output_directory.into_files());
+        }
+        if legacy_layout {
+            output_directory.enable_legacy_layout()?;
+        }
+        let shared_output = self.shared_output();
+        for flag in selection.iter() {
+            match flag {
+                VyperSelector::Bytecode | VyperSelector::BytecodeRuntime => {}
+
+                flag => shared_output.write_to_directory(
+                    flag,
+                    &mut output_directory,
+                    source_path.as_str(),
+                )?,
+            }
+        }
+
+        Ok(output_directory.into_files())
+    }
+
+    ///
+    /// Converts the contract to the combined JSON.
+    ///
+    pub fn into_combined_json(self) -> CombinedJsonContract {
+        CombinedJsonContract {
+            bytecode: format!("0x{}", hex::encode(self.deploy_bytecode)),
+            bytecode_runtime: format!("0x{}", hex::encode(self.runtime_bytecode)),
+            evm_bytecode: None,
+            evm_bytecode_runtime: None,
+
+            ir_json: self
+                .ir_json
+                .map(|ir_json| serde_json::to_value(ir_json).expect("Always valid")),
+            ast: self
+                .ast
+                .map(|ast| serde_json::to_value(ast).expect("Always valid")),
+            abi: self.abi,
+            method_identifiers: self.method_identifiers,
+            layout: self.layout,
+            userdoc: self.userdoc,
+            devdoc: self.devdoc,
+
+            assembly: None,
+            factory_deps: None,
+            warnings: Some(self.warnings),
+            optimization_candidates: None,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//!
+//! The Vyper project EVM build.
+//!
+
+pub mod contract;
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+
+use crate::build::manifest::Manifest;
+use crate::build::Build as EraVMBuild;
+use crate::error::Error;
+use crate::vyper::combined_json::extra_data::ExtraData as CombinedJsonExtraData;
+use crate::vyper::combined_json::CombinedJson;
+use crate::vyper::selector::Selector as VyperSelector;
+
+use self::contract::Contract;
+
+///
+/// The Vyper project EVM build.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Build {
+    /// The contract data,
+    pub contracts: BTreeMap<String, Contract>,
+    /// The project metadata.
+    pub project_metadata: serde_json::Value,
+}
+
+impl Build {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(project_metadata: serde_json::Value) -> Self {
+        Self {
+            contracts: BTreeMap::new(),
+            project_metadata,
+        }
+    }
+
+    ///
+    /// Writes all contracts to the terminal.
+    ///
+    pub fn write_to_terminal(self, selection: &[VyperSelector]) -> anyhow::Result<()> {
+        for (path, contract) in self.contracts.into_iter() {
+            contract.write_to_terminal(path, selection)?;
+        }
+
+        if selection.contains(&VyperSelector::ProjectMetadata) {
+            writeln!(std::io::stderr(), "Project metadata:")?;
+            writeln!(std::io::stdout(), "{}", self.project_metadata)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts to the specified directory.
+    ///
+    pub fn write_to_directory(
+        self,
+        selection: &[VyperSelector],
+        output_directory: &Path,
+        base_path: Option<&Path>,
+        overwrite: bool,
+        legacy_layout: bool,
+    ) -> anyhow::Result<()> {
+        let mut output_paths =
+            EraVMBuild::output_paths(self.contracts.keys().map(String::as_str), base_path)?;
+
+        std::fs::create_dir_all(output_directory)?;
+
+        let mut manifest = Manifest::default();
+        for (contract_path, contract) in self.contracts.into_iter() {
+            let output_path = output_paths
+                .remove(contract_path.as_str())
+                .expect("Always exists");
+            let files = contract.write_to_directory(
+                selection,
+                output_directory,
+                output_path.as_path(),
+                overwrite,
+                legacy_layout,
+            )?;
+            manifest.contracts.insert(contract_path, files);
+        }
+
+        if selection.contains(&VyperSelector::ProjectMetadata) {
+            let metadata_file_name = format!("meta.{}", era_compiler_common::EXTENSION_JSON);
+            let mut metadata_file_path = output_directory.to_owned();
+            metadata_file_path.push(metadata_file_name.as_str());
+            if metadata_file_path.exists() && !overwrite {
+                anyhow::bail!(
+                    "Refusing to overwrite an existing file {metadata_file_path:?} (use --overwrite to force).",
+                );
+            }
+            std::fs::write(
+                &metadata_file_path,
+                serde_json::to_string(&self.project_metadata)
+                    .expect("Always valid")
+                    .as_bytes(),
+            )
+            .map_err(|error| Error::io(metadata_file_path.as_path(), "writing", error))?;
+            manifest.project_metadata = Some(metadata_file_name);
+        }
+
+        if !legacy_layout {
+            manifest.write_to_directory(output_directory, overwrite)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts to the combined JSON.
+    ///
+    pub fn into_combined_json(
+        self,
+        version: Option<&semver::Version>,
+        zkvyper_version: &semver::Version,
+    ) -> CombinedJson {
+        let contracts = self
+            .contracts
+            .into_iter()
+            .map(|(path, contract)| {
+                (
+                    EraVMBuild::combined_json_contract_path(path.as_str(), version),
+                    contract.into_combined_json(),
+                )
+            })
+            .collect();
+
+        let extra_data = CombinedJsonExtraData::new(self.project_metadata);
+
+        CombinedJson::new(contracts, extra_data, version, zkvyper_version)
+    }
+}
@@ -0,0 +1,183 @@
+//!
+//! The on-disk compilation cache.
+//!
+
+pub mod source_key;
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+/// The counter making temporary file names unique within the process.
+static TEMPORARY_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+///
+/// The on-disk compilation cache.
+///
+/// Stores two kinds of entries in the same directory:
+/// - project builds keyed by the `SourceKey` of their sources and imports, which is known before
+///   `vyper` is run, so a hit skips both `vyper` and LLVM;
+/// - outputs of recursive process calls keyed by the hash of their input, which contains the IR
+///   produced by `vyper`, so a hit skips the LLVM compilation of a contract whose project has
+///   been changed elsewhere.
+///
+/// The compiler version and LLVM build are added to both keys, so entries never leak between
+/// compiler builds.
+///
+#[derive(Debug, Clone)]
+pub struct Cache {
+    /// The cache directory.
+    pub directory: PathBuf,
+}
+
+impl Cache {
+    /// The cache directory name inside the user cache directory.
+    pub const DIRECTORY_NAME: &'static str = "zkvyper";
+
+    /// The cache entry file extension.
+    pub const ENTRY_EXTENSION: &'static str = "bin";
+
+    /// The temporary file extension used while writing entries.
+    pub const TEMPORARY_EXTENSION: &'static str = "tmp";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    ///
+    /// Returns the default cache directory inside the user cache directory.
+    ///
+    /// Is `None` if the user cache directory cannot be determined from the environment.
+    ///
+    pub fn default_directory() -> Option<PathBuf> {
+        let user_cache_directory = std::env::var_os("XDG_CACHE_HOME")
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("LOCALAPPDATA")
+                    .filter(|path| !path.is_empty())
+                    .map(PathBuf::from)
+            })
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .filter(|path| !path.is_empty())
+                    .map(|path| PathBuf::from(path).join(".cache"))
+            })?;
+        Some(user_cache_directory.join(Self::DIRECTORY_NAME))
+    }
+
+    ///
+    /// Returns the cache key of the serialized process input.
+    ///
+    pub fn key(input: &[u8]) -> String {
+        let mut preimage = Vec::with_capacity(input.len() + 64);
+        preimage.extend_from_slice(env!("CARGO_PKG_VERSION").as_bytes());
+        preimage.push(0);
+        preimage.extend_from_slice(inkwell::support::get_commit_id().to_string().as_bytes());
+        preimage.push(0);
+        preimage.extend_from_slice(input);
+        hex::encode(era_compiler_common::Keccak256Hash::from_slice(preimage.as_slice()).as_bytes())
+    }
+
+    ///
+    /// Returns the cached output, if any.
+    ///
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        std::fs::read(self.entry_path(key)).ok()
+    }
+
+    ///
+    /// Writes the output to the cache.
+    ///
+    /// The entry is written to a temporary file first, so concurrent readers never see
+    /// partially written entries.
+    ///
+    pub fn put(&self, key: &str, output: &[u8]) -> anyhow::Result<()> {
+        std::fs::create_dir_all(self.directory.as_path()).map_err(|error| {
+            anyhow::anyhow!(
+                "Cache directory {:?} creating error: {error}",
+                self.directory
+            )
+        })?;
+
+        let temporary_path = self.directory.join(format!(
+            "{key}.{}.{}.{}",
+            std::process::id(),
+            TEMPORARY_FILE_COUNTER.fetch_add(1, Ordering::Relaxed),
+            Self::TEMPORARY_EXTENSION,
+        ));
+        std::fs::write(temporary_path.as_path(), output).map_err(|error| {
+            anyhow::anyhow!("Cache entry {temporary_path:?} writing error: {error}")
+        })?;
+
+        let entry_path = self.entry_path(key);
+        std::fs::rename(temporary_path.as_path(), entry_path.as_path()).map_err(|error| {
+            let _ = std::fs::remove_file(temporary_path.as_path());
+            anyhow::anyhow!("Cache entry {entry_path:?} writing error: {error}")
+        })?;
+        Ok(())
+    }
+
+    ///
+    /// Removes all entries from the cache, returning the number of removed files.
+    ///
+    pub fn prune(&self) -> anyhow::Result<usize> {
+        let entries = match std::fs::read_dir(self.directory.as_path()) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(error) => anyhow::bail!(
+                "Cache directory {:?} reading error: {error}",
+                self.directory
+            ),
+        };
+
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if !path.is_file() || !Self::is_cache_file(path.as_path()) {
+                continue;
+            }
+
+            std::fs::remove_file(path.as_path())
+                .map_err(|error| anyhow::anyhow!("Cache entry {path:?} removing error: {error}"))?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
+    ///
+    /// Whether the file is a cache entry or a temporary file left by an interrupted write.
+    ///
+    /// Other files in the cache directory are never touched.
+    ///
+    fn is_cache_file(path: &Path) -> bool {
+        let is_cache_extension = path
+            .extension()
+            .map(|extension| {
+                extension == Self::ENTRY_EXTENSION || extension == Self::TEMPORARY_EXTENSION
+            })
+            .unwrap_or_default();
+        let is_key_prefixed = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .and_then(|file_name| file_name.split_once('.'))
+            .map(|(key, _)| {
+                key.len() == era_compiler_common::BYTE_LENGTH_FIELD * 2
+                    && key.chars().all(|character| character.is_ascii_hexdigit())
+            })
+            .unwrap_or_default();
+        is_cache_extension && is_key_prefixed
+    }
+
+    ///
+    /// Returns the path of the cache entry.
+    ///
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.directory
+            .join(format!("{key}.{}", Self::ENTRY_EXTENSION))
+    }
+}
@@ -0,0 +1,234 @@
+//!
+//! The source-level cache key.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+///
+/// The source-level cache key of a Vyper project.
+///
+/// Is computed from the source files and the modules and interfaces they import before `vyper`
+/// is run, so a hit skips both `vyper` and LLVM.
+///
+/// Imports are resolved the way `vyper` does: relative imports against the importing file's
+/// directory, and absolute ones against the importing file's directory, the search paths and the
+/// current directory. Every existing candidate file is hashed, so a file shadowing an import
+/// also changes the key. Imports that resolve to no file, such as the built-in interfaces, are
+/// hashed by name, as they only depend on the `vyper` version.
+///
+#[derive(Debug, Default)]
+pub struct SourceKey {
+    /// The contents of the sources and imported files.
+    pub files: BTreeMap<PathBuf, Vec<u8>>,
+    /// The imports not resolved to files.
+    pub unresolved_imports: BTreeSet<String>,
+}
+
+impl SourceKey {
+    /// The extensions of importable files.
+    pub const IMPORT_EXTENSIONS: [&'static str; 3] = ["vy", "vyi", "json"];
+
+    ///
+    /// Reads the sources at `input_paths` and all files they import.
+    ///
+    pub fn new(input_paths: &[PathBuf], search_paths: &[String]) -> anyhow::Result<Self> {
+        let mut source_key = Self::default();
+        let mut queue: Vec<PathBuf> = input_paths.to_vec();
+        while let Some(path) = queue.pop() {
+            if source_key.files.contains_key(path.as_path()) {
+                continue;
+            }
+
+            let source_code = std::fs::read(path.as_path())
+                .map_err(|error| anyhow::anyhow!("Source {path:?} reading error: {error}"))?;
+            if path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+                let directory = path.parent().unwrap_or_else(|| Path::new(""));
+                for (level, module) in Self::imports(String::from_utf8_lossy(&source_code).as_ref())
+                {
+                    let candidates =
+                        Self::candidates(directory, level, module.as_slice(), search_paths);
+                    if candidates.is_empty() {
+                        source_key.unresolved_imports.insert(format!(
+                            "{}{}",
+                            ".".repeat(level),
+                            module.join(".")
+                        ));
+                    }
+                    queue.extend(candidates);
+                }
+            }
+            source_key.files.insert(path, source_code);
+        }
+        Ok(source_key)
+    }
+
+    ///
+    /// Returns the cache key of the sources compiled with `settings`.
+    ///
+    /// The compiler version and LLVM build are added to the key, so entries never leak between
+    /// compiler builds.
+    ///
+    pub fn hash(&self, settings: &serde_json::Value) -> String {
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(env!("CARGO_PKG_VERSION").as_bytes());
+        preimage.push(0);
+        preimage.extend_from_slice(inkwell::support::get_commit_id().to_string().as_bytes());
+        preimage.push(0);
+        preimage.extend_from_slice(settings.to_string().as_bytes());
+        preimage.push(0);
+        for (path, source_code) in self.files.iter() {
+            preimage.extend_from_slice(path.to_string_lossy().as_bytes());
+            preimage.push(0);
+            preimage.extend_from_slice((source_code.len() as u64).to_le_bytes().as_slice());
+            preimage.extend_from_slice(source_code.as_slice());
+        }
+        for import in self.unresolved_imports.iter() {
+            preimage.extend_from_slice(import.as_bytes());
+            preimage.push(0);
+        }
+        hex::encode(era_compiler_common::Keccak256Hash::from_slice(preimage.as_slice()).as_bytes())
+    }
+
+    ///
+    /// Returns the imported modules of the source code as the relative import level and the
+    /// module path segments.
+    ///
+    /// Both `import a.b` and `from a import b, c` are recognized, including parenthesized
+    /// name lists spanning several lines.
+    ///
+    pub fn imports(source_code: &str) -> Vec<(usize, Vec<String>)> {
+        let mut imports = Vec::new();
+        let mut lines = source_code
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default().trim());
+        while let Some(line) = lines.next() {
+            if let Some(modules) = line.strip_prefix("import ") {
+                for module in modules.split(',') {
+                    let module = Self::strip_alias(module);
+                    if !module.is_empty() {
+                        imports.push((0, Self::segments(module)));
+                    }
+                }
+            } else if let Some(statement) = line.strip_prefix("from ") {
+                let Some((package, names)) = statement.split_once(" import ") else {
+                    continue;
+                };
+                let mut names = names.to_owned();
+                if names.contains('(') {
+                    while !names.contains(')') {
+                        match lines.next() {
+                            Some(line) => {
+                                names.push(' ');
+                                names.push_str(line);
+                            }
+                            None => break,
+                        }
+                    }
+                }
+
+                let package = package.trim();
+                let level = package
+                    .chars()
+                    .take_while(|character| *character == '.')
+                    .count();
+                let package = Self::segments(&package[level..]);
+                if !package.is_empty() {
+                    imports.push((level, package.clone()));
+                }
+                for name in names
+                    .split(|character| matches!(character, ',' | '(' | ')'))
+                    .map(Self::strip_alias)
+                    .filter(|name| !name.is_empty())
+                {
+                    let mut module = package.clone();
+                    module.push(name.to_owned());
+                    imports.push((level, module));
+                }
+            }
+        }
+        imports
+    }
+
+    ///
+    /// Returns the existing files the module may be imported from.
+    ///
+    fn candidates(
+        directory: &Path,
+        level: usize,
+        module: &[String],
+        search_paths: &[String],
+    ) -> Vec<PathBuf> {
+        let bases: Vec<PathBuf> = if level > 0 {
+            let mut base = directory.to_path_buf();
+            for _ in 1..level {
+                base.push("..");
+            }
+            vec![base]
+        } else {
+            std::iter::once(directory.to_path_buf())
+                .chain(search_paths.iter().map(PathBuf::from))
+                .chain(std::iter::once(PathBuf::new()))
+                .collect()
+        };
+
+        let mut candidates = Vec::new();
+        for base in bases.into_iter() {
+            let module_path = module.iter().fold(base, |path, segment| path.join(segment));
+            for extension in Self::IMPORT_EXTENSIONS.into_iter() {
+                let path = Self::normalize(module_path.with_extension(extension).as_path());
+                if path.is_file() && !candidates.contains(&path) {
+                    candidates.push(path);
+                }
+            }
+        }
+        candidates
+    }
+
+    ///
+    /// Removes the `.` and `..` components of the path lexically.
+    ///
+    /// Keeps relative paths relative, and prevents mutually importing modules from producing
+    /// ever longer paths to the same files.
+    ///
+    fn normalize(path: &Path) -> PathBuf {
+        let mut normalized = PathBuf::new();
+        for component in path.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir
+                    if matches!(
+                        normalized.components().next_back(),
+                        Some(Component::Normal(_))
+                    ) =>
+                {
+                    normalized.pop();
+                }
+                component => normalized.push(component),
+            }
+        }
+        normalized
+    }
+
+    ///
+    /// Removes the `as` alias from the imported name.
+    ///
+    fn strip_alias(name: &str) -> &str {
+        name.split(" as ").next().unwrap_or_default().trim()
+    }
+
+    ///
+    /// Splits the dotted module path into segments.
+    ///
+    fn segments(module: &str) -> Vec<String> {
+        module
+            .split('.')
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+}
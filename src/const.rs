@@ -21,9 +21,27 @@ pub fn version() -> String {
     env!("CARGO_PKG_VERSION").to_owned()
 }
 
+/// The LLL IR s-expressions file extension.
+pub static EXTENSION_LLL: &str = "lll";
+
+/// The DOT graph file extension.
+pub static EXTENSION_DOT: &str = "dot";
+
+/// The EVM binary file extension.
+pub static EXTENSION_EVM_BINARY: &str = "bin";
+
+/// The EVM runtime code object identifier suffix.
+pub static EVM_RUNTIME_CODE_SUFFIX: &str = ".runtime";
+
 /// The worker thread stack size.
 pub const WORKER_THREAD_STACK_SIZE: usize = 64 * 1024 * 1024;
 
+/// The EraVM bytecode size limit in bytes.
+///
+/// The bytecode hash encodes the length in 32-byte words as a 16-bit number.
+pub const ERAVM_BYTECODE_SIZE_LIMIT: usize =
+    (u16::MAX as usize) * era_compiler_common::BYTE_LENGTH_FIELD;
+
 /// The `FREE_VAR_SPACE` offset.
 pub const OFFSET_FREE_VAR_SPACE: usize = 0;
 
@@ -47,6 +65,9 @@ pub const DEFAULT_SEQUENCE_IDENTIFIER: &str = "seq";
 /// The default pass identifier.
 pub const DEFAULT_PASS_IDENTIFIER: &str = "pass";
 
+/// The code length identifier used by `vyper` older than v0.3.8 to address constructor arguments.
+pub const CODE_LENGTH_IDENTIFIER: &str = "~codelen";
+
 /// The internal function prefix.
 pub const FUNCTION_PREFIX_INTERNAL: &str = "internal";
 
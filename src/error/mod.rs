@@ -0,0 +1,241 @@
+//!
+//! The compiler error.
+//!
+
+pub mod source_location;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::supervisor::error::Error as SupervisorError;
+use crate::supervisor::error::ErrorKind as SupervisorErrorKind;
+
+use self::source_location::SourceLocation;
+
+///
+/// The compiler error.
+///
+/// Library functions return it as the root of `anyhow::Error`, so callers can tell failures apart
+/// with `downcast_ref`. Errors of compilations on worker processes are sent back in the
+/// negotiated encoding, which keeps them typed across the process boundary.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum Error {
+    /// `vyper` has rejected the sources, for example, on a syntax or type error.
+    Vyper {
+        /// The `vyper` executable.
+        executable: String,
+        /// The `vyper` error message.
+        message: String,
+    },
+    /// The sources use an instruction or built-in function that is not available on the target.
+    Unsupported {
+        /// The error message.
+        message: String,
+        /// The location in the Vyper source code, if known.
+        location: Option<SourceLocation>,
+        /// The label of the LLL IR function being translated, if known.
+        lll_function: Option<String>,
+    },
+    /// The bytecode exceeds the EraVM size limit, or every optimization candidate exceeds the
+    /// size budget of auto-tuning.
+    SizeLimit {
+        /// The bytecode size in bytes, or the smallest one of the optimization candidates.
+        size: usize,
+        /// The size limit or budget in bytes.
+        limit: usize,
+        /// The error message, with the tried optimization candidates if any.
+        message: String,
+    },
+    /// A file could not be read or written.
+    Io {
+        /// The file path.
+        path: PathBuf,
+        /// The failed operation, such as `reading`.
+        operation: String,
+        /// The I/O error message.
+        message: String,
+    },
+    /// A subprocess, such as a compiler worker with LLVM, has crashed or exceeded its limits.
+    ///
+    /// It is not sent by workers, which report it as `Compilation` instead.
+    Subprocess(SupervisorError),
+    /// The code generation or LLVM has failed.
+    Compilation {
+        /// The error message.
+        message: String,
+    },
+    /// Some contracts have failed, each with its own error.
+    Contracts(BTreeMap<String, Error>),
+}
+
+impl Error {
+    /// The exit code of failures not covered by other codes.
+    pub const EXIT_CODE_COMPILATION: i32 = era_compiler_common::EXIT_CODE_FAILURE;
+
+    /// The exit code of sources rejected by `vyper`.
+    pub const EXIT_CODE_VYPER: i32 = 3;
+
+    /// The exit code of unsupported instructions and built-in functions.
+    pub const EXIT_CODE_UNSUPPORTED: i32 = 4;
+
+    /// The exit code of bytecode exceeding the size limit.
+    pub const EXIT_CODE_SIZE_LIMIT: i32 = 5;
+
+    /// The exit code of crashed, killed or timed out subprocesses.
+    pub const EXIT_CODE_SUBPROCESS: i32 = 6;
+
+    /// The exit code of file I/O failures.
+    pub const EXIT_CODE_IO: i32 = 7;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn vyper(executable: &str, message: String) -> Self {
+        Self::Vyper {
+            executable: executable.to_owned(),
+            message,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn unsupported(message: String) -> Self {
+        Self::Unsupported {
+            message,
+            location: None,
+            lll_function: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn io(path: &Path, operation: &str, error: std::io::Error) -> Self {
+        Self::Io {
+            path: path.to_owned(),
+            operation: operation.to_owned(),
+            message: error.to_string(),
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Wraps the error of the contract at `path`, keeping it typed if it is already.
+    ///
+    pub fn contract(path: &str, error: anyhow::Error) -> Self {
+        Self::Contracts(BTreeMap::from([(
+            path.to_owned(),
+            Self::from_anyhow(error),
+        )]))
+    }
+
+    ///
+    /// Converts the error, keeping it typed if it is already.
+    ///
+    /// Other errors are considered failures of the code generation or LLVM.
+    ///
+    pub fn from_anyhow(error: anyhow::Error) -> Self {
+        let error = match error.downcast::<Self>() {
+            Ok(error) => return error,
+            Err(error) => error,
+        };
+        match error.downcast::<SupervisorError>() {
+            Ok(error) => Self::Subprocess(error),
+            Err(error) => Self::Compilation {
+                message: error.to_string(),
+            },
+        }
+    }
+
+    ///
+    /// Sets the LLL IR function of an unsupported feature error.
+    ///
+    pub fn in_lll_function(error: anyhow::Error, label: &str) -> anyhow::Error {
+        match error.downcast::<Self>() {
+            Ok(Self::Unsupported {
+                message,
+                location,
+                lll_function: None,
+            }) => Self::Unsupported {
+                message,
+                location,
+                lll_function: Some(label.to_owned()),
+            }
+            .into(),
+            Ok(error) => error.into(),
+            Err(error) => error,
+        }
+    }
+
+    ///
+    /// Returns the CLI exit code of the error.
+    ///
+    /// If several contracts have failed, the code of the first one in the path order is returned.
+    ///
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Vyper { .. } => Self::EXIT_CODE_VYPER,
+            Self::Unsupported { .. } => Self::EXIT_CODE_UNSUPPORTED,
+            Self::SizeLimit { .. } => Self::EXIT_CODE_SIZE_LIMIT,
+            Self::Io { .. } => Self::EXIT_CODE_IO,
+            Self::Subprocess(error) => match error.kind {
+                SupervisorErrorKind::Spawn(_) | SupervisorErrorKind::Io(_) => Self::EXIT_CODE_IO,
+                _ => Self::EXIT_CODE_SUBPROCESS,
+            },
+            Self::Compilation { .. } => Self::EXIT_CODE_COMPILATION,
+            Self::Contracts(errors) => errors
+                .values()
+                .next()
+                .map(Self::exit_code)
+                .unwrap_or(Self::EXIT_CODE_COMPILATION),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Vyper {
+                executable,
+                message,
+            } => write!(f, "{executable} error: {message}"),
+            Self::Unsupported {
+                message,
+                location,
+                lll_function,
+            } => {
+                write!(f, "{message}")?;
+                if let Some(location) = location {
+                    write!(f, " ({location})")?;
+                }
+                if let Some(lll_function) = lll_function {
+                    write!(f, " in LLL IR function `{lll_function}`")?;
+                }
+                Ok(())
+            }
+            Self::SizeLimit { message, .. } => write!(f, "{message}"),
+            Self::Io {
+                path,
+                operation,
+                message,
+            } => write!(f, "File {path:?} {operation} error: {message}"),
+            Self::Subprocess(error) => write!(f, "{error}"),
+            Self::Compilation { message } => write!(f, "{message}"),
+            Self::Contracts(errors) => write!(
+                f,
+                "{}",
+                errors
+                    .iter()
+                    .map(|(path, error)| format!("Contract `{path}`: {error}"))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            ),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//!
+//! The source code location of an error.
+//!
+
+///
+/// The source code location of an error.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SourceLocation {
+    /// The one-based line number.
+    pub line: usize,
+    /// The zero-based column number, as reported by `vyper`.
+    pub column: usize,
+}
+
+impl SourceLocation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    ///
+    /// Returns the location of the byte `offset` in the source code.
+    ///
+    pub fn from_offset(source_code: &str, offset: usize) -> Self {
+        let preceding = &source_code[..offset];
+        let line = preceding.matches('\n').count() + 1;
+        let column = preceding
+            .rfind('\n')
+            .map(|newline| offset - newline - 1)
+            .unwrap_or(offset);
+        Self::new(line, column)
+    }
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}:{}", self.line, self.column)
+    }
+}
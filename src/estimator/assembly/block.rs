@@ -0,0 +1,57 @@
+//!
+//! The EraVM assembly basic block.
+//!
+
+use crate::estimator::assembly::instruction::Instruction;
+use crate::estimator::assembly::instruction::Kind as InstructionKind;
+
+///
+/// The EraVM assembly basic block.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Block {
+    /// The block label, if any.
+    pub label: Option<String>,
+    /// The block instructions.
+    pub instructions: Vec<Instruction>,
+    /// The successor block indexes within the function.
+    pub successors: Vec<usize>,
+    /// The exit kind, if the block may leave the function.
+    pub exit: Option<Exit>,
+}
+
+///
+/// The function exit kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    /// A successful return.
+    Success,
+    /// A revert or panic.
+    Failure,
+}
+
+impl Block {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(label: Option<String>) -> Self {
+        Self {
+            label,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Whether the block ends with an instruction that never falls through.
+    ///
+    pub fn is_terminated(&self) -> bool {
+        self.instructions.last().is_some_and(|instruction| {
+            instruction.condition.is_none()
+                && matches!(
+                    instruction.kind(),
+                    InstructionKind::Jump | InstructionKind::Return | InstructionKind::Revert
+                )
+        })
+    }
+}
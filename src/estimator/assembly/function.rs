@@ -0,0 +1,301 @@
+//!
+//! The EraVM assembly function.
+//!
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+
+use crate::estimator::assembly::block::Block;
+use crate::estimator::assembly::block::Exit;
+use crate::estimator::assembly::instruction::Instruction;
+use crate::estimator::assembly::instruction::Kind as InstructionKind;
+use crate::estimator::cost::Cost;
+
+///
+/// The EraVM assembly function.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The function name.
+    pub name: String,
+    /// The basic blocks. The first one is the function entry.
+    pub blocks: Vec<Block>,
+}
+
+///
+/// The longest path search result.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LongestPath {
+    /// No target is reachable.
+    Unreachable,
+    /// The longest path cost.
+    Bounded(Cost),
+    /// A loop or an unbounded call lies on a path to the target.
+    Unbounded,
+}
+
+///
+/// The longest path search node state.
+///
+#[derive(Debug, Clone, Copy)]
+enum Visit {
+    /// The node has not been visited yet.
+    New,
+    /// The node is on the current search path.
+    Active,
+    /// The node has been visited, and its result is known.
+    /// `None` means the path from the node is unbounded.
+    Done(Option<Cost>),
+}
+
+impl Function {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            blocks: vec![Block::new(None)],
+        }
+    }
+
+    ///
+    /// Appends a label, starting a new block if the current one is not empty.
+    ///
+    pub fn push_label(&mut self, label: String) {
+        let last = self.blocks.last_mut().expect("Always exists");
+        if last.label.is_none() && last.instructions.is_empty() {
+            last.label = Some(label);
+        } else {
+            self.blocks.push(Block::new(Some(label)));
+        }
+    }
+
+    ///
+    /// Appends an instruction, starting a new block if the current one has been terminated.
+    ///
+    pub fn push_instruction(&mut self, instruction: Instruction) {
+        let is_block_finished = self
+            .blocks
+            .last()
+            .expect("Always exists")
+            .instructions
+            .last()
+            .is_some_and(|instruction| {
+                matches!(
+                    instruction.kind(),
+                    InstructionKind::Jump | InstructionKind::Return | InstructionKind::Revert
+                )
+            });
+        if is_block_finished {
+            self.blocks.push(Block::new(None));
+        }
+        self.blocks
+            .last_mut()
+            .expect("Always exists")
+            .instructions
+            .push(instruction);
+    }
+
+    ///
+    /// Resolves the block successors and exits once all instructions have been pushed.
+    ///
+    pub fn finalize(&mut self) {
+        let labels: HashMap<String, usize> = self
+            .blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, block)| block.label.clone().map(|label| (label, index)))
+            .collect();
+
+        let length = self.blocks.len();
+        for (index, block) in self.blocks.iter_mut().enumerate() {
+            let fallthrough = if index + 1 < length {
+                Some(index + 1)
+            } else {
+                None
+            };
+
+            let (mut successors, exit, is_conditional) = match block.instructions.last() {
+                Some(instruction) if instruction.kind() == InstructionKind::Jump => {
+                    match instruction.labels().next() {
+                        Some(label) => match labels.get(label) {
+                            Some(target) => (vec![*target], None, instruction.condition.is_some()),
+                            None if label.contains("REVERT") || label.contains("UNWIND") => {
+                                (vec![], Some(Exit::Failure), instruction.condition.is_some())
+                            }
+                            None => (vec![], Some(Exit::Success), instruction.condition.is_some()),
+                        },
+                        None => (vec![], Some(Exit::Success), instruction.condition.is_some()),
+                    }
+                }
+                Some(instruction) if instruction.kind() == InstructionKind::Return => {
+                    (vec![], Some(Exit::Success), instruction.condition.is_some())
+                }
+                Some(instruction) if instruction.kind() == InstructionKind::Revert => {
+                    (vec![], Some(Exit::Failure), instruction.condition.is_some())
+                }
+                _ => (vec![], None, true),
+            };
+            if is_conditional {
+                successors.extend(fallthrough);
+            }
+
+            block.successors = successors;
+            block.exit = exit;
+        }
+    }
+
+    ///
+    /// Returns the block index by its label.
+    ///
+    pub fn block_index(&self, label: &str) -> Option<usize> {
+        self.blocks
+            .iter()
+            .position(|block| block.label.as_deref() == Some(label))
+    }
+
+    ///
+    /// Returns the cheapest path cost from the `from` block to any block satisfying `is_target`.
+    ///
+    /// The costs of both the `from` and target blocks are included.
+    ///
+    pub fn shortest_path<F>(&self, costs: &[Cost], from: usize, is_target: F) -> Option<Cost>
+    where
+        F: Fn(usize) -> bool,
+    {
+        let mut distances: Vec<Option<Cost>> = vec![None; self.blocks.len()];
+        let mut queue = BinaryHeap::new();
+
+        distances[from] = Some(costs[from]);
+        queue.push(Reverse((costs[from], from)));
+        while let Some(Reverse((cost, index))) = queue.pop() {
+            if is_target(index) {
+                return Some(cost);
+            }
+            if distances[index].is_some_and(|distance| distance < cost) {
+                continue;
+            }
+
+            for successor in self.blocks[index].successors.iter().copied() {
+                let next = cost + costs[successor];
+                if distances[successor].is_none_or(|distance| next < distance) {
+                    distances[successor] = Some(next);
+                    queue.push(Reverse((next, successor)));
+                }
+            }
+        }
+
+        None
+    }
+
+    ///
+    /// Returns the most expensive path cost from the `from` block to any block satisfying `is_target`.
+    ///
+    /// Blocks with `None` cost are considered unbounded.
+    /// Only loops that lie on a path to a target make the result unbounded.
+    ///
+    pub fn longest_path<F>(&self, costs: &[Option<Cost>], from: usize, is_target: F) -> LongestPath
+    where
+        F: Fn(usize) -> bool,
+    {
+        let reaching = self.reaching(&is_target);
+        if !reaching[from] {
+            return LongestPath::Unreachable;
+        }
+
+        let mut states = vec![Visit::New; self.blocks.len()];
+        match self.longest_path_inner(costs, from, &is_target, reaching.as_slice(), &mut states) {
+            Some(cost) => LongestPath::Bounded(cost),
+            None => LongestPath::Unbounded,
+        }
+    }
+
+    ///
+    /// The longest path search step.
+    ///
+    /// Returns `None` if the path is unbounded.
+    ///
+    fn longest_path_inner<F>(
+        &self,
+        costs: &[Option<Cost>],
+        index: usize,
+        is_target: &F,
+        reaching: &[bool],
+        states: &mut [Visit],
+    ) -> Option<Cost>
+    where
+        F: Fn(usize) -> bool,
+    {
+        states[index] = Visit::Active;
+
+        let mut longest = if is_target(index) {
+            Some(Cost::default())
+        } else {
+            None
+        };
+        let mut is_unbounded = costs[index].is_none();
+        for successor in self.blocks[index].successors.iter().copied() {
+            if is_unbounded {
+                break;
+            }
+            if !reaching[successor] {
+                continue;
+            }
+
+            let result = match states[successor] {
+                Visit::New => {
+                    self.longest_path_inner(costs, successor, is_target, reaching, states)
+                }
+                Visit::Active => None,
+                Visit::Done(result) => result,
+            };
+            match result {
+                Some(cost) => longest = longest.max(Some(cost)),
+                None => is_unbounded = true,
+            }
+        }
+
+        let result = if is_unbounded {
+            None
+        } else {
+            Some(costs[index].expect("Always exists") + longest.expect("Always exists"))
+        };
+        states[index] = Visit::Done(result);
+        result
+    }
+
+    ///
+    /// Returns the flags of blocks that can reach any block satisfying `is_target`.
+    ///
+    fn reaching<F>(&self, is_target: &F) -> Vec<bool>
+    where
+        F: Fn(usize) -> bool,
+    {
+        let mut predecessors = vec![vec![]; self.blocks.len()];
+        for (index, block) in self.blocks.iter().enumerate() {
+            for successor in block.successors.iter().copied() {
+                predecessors[successor].push(index);
+            }
+        }
+
+        let mut reaching = vec![false; self.blocks.len()];
+        let mut stack: Vec<usize> = (0..self.blocks.len())
+            .filter(|index| is_target(*index))
+            .collect();
+        for index in stack.iter().copied() {
+            reaching[index] = true;
+        }
+        while let Some(index) = stack.pop() {
+            for predecessor in predecessors[index].iter().copied() {
+                if !reaching[predecessor] {
+                    reaching[predecessor] = true;
+                    stack.push(predecessor);
+                }
+            }
+        }
+        reaching
+    }
+}
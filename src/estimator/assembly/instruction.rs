@@ -0,0 +1,163 @@
+//!
+//! The EraVM assembly instruction.
+//!
+
+///
+/// The EraVM assembly instruction.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    /// The mnemonic without the condition and flag-setting suffixes, e.g. `callf.st`.
+    pub mnemonic: String,
+    /// The condition suffix, e.g. `eq` in `jump.eq`.
+    pub condition: Option<String>,
+    /// Whether the instruction sets flags, e.g. `sub.s!`.
+    pub sets_flags: bool,
+    /// The comma-separated operands.
+    pub operands: Vec<String>,
+}
+
+///
+/// The instruction class relevant to the cost model and control flow.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// A jump to a label or register.
+    Jump,
+    /// A successful return from a function or contract.
+    Return,
+    /// A revert or panic.
+    Revert,
+    /// A near call to a function within the same contract.
+    NearCall,
+    /// A far call to another contract.
+    FarCall,
+    /// A storage read.
+    StorageRead,
+    /// A storage write.
+    StorageWrite,
+    /// A transient storage read or write.
+    TransientStorage,
+    /// An event emission.
+    Event,
+    /// An L1 message.
+    L1Message,
+    /// Any other instruction.
+    Other,
+}
+
+impl Instruction {
+    /// The condition suffixes.
+    pub const CONDITIONS: [&'static str; 7] = ["eq", "ne", "lt", "gt", "ge", "le", "gtlt"];
+
+    ///
+    /// Parses an instruction from a trimmed assembly line.
+    ///
+    pub fn parse(line: &str) -> Self {
+        let (mnemonic, operands) = match line.split_once(char::is_whitespace) {
+            Some((mnemonic, operands)) => (mnemonic, operands.trim()),
+            None => (line, ""),
+        };
+
+        let (mnemonic, sets_flags) = match mnemonic.strip_suffix('!') {
+            Some(mnemonic) => (mnemonic, true),
+            None => (mnemonic, false),
+        };
+        let (mnemonic, condition) = match mnemonic.rsplit_once('.') {
+            Some((mnemonic, condition)) if Self::CONDITIONS.contains(&condition) => {
+                (mnemonic, Some(condition.to_owned()))
+            }
+            _ => (mnemonic, None),
+        };
+
+        let operands = if operands.is_empty() {
+            vec![]
+        } else {
+            operands
+                .split(',')
+                .map(|operand| operand.trim().to_owned())
+                .collect()
+        };
+
+        Self {
+            mnemonic: mnemonic.to_owned(),
+            condition,
+            sets_flags,
+            operands,
+        }
+    }
+
+    ///
+    /// Returns the instruction class.
+    ///
+    pub fn kind(&self) -> Kind {
+        let base = self
+            .mnemonic
+            .split_once('.')
+            .map(|(base, _)| base)
+            .unwrap_or(self.mnemonic.as_str());
+        match (base, self.mnemonic.as_str()) {
+            ("jump", _) => Kind::Jump,
+            ("ret", mnemonic)
+                if mnemonic.starts_with("ret.revert") || mnemonic.starts_with("ret.panic") =>
+            {
+                Kind::Revert
+            }
+            ("ret" | "retl", _) => Kind::Return,
+            ("rev" | "revl" | "pnc" | "pncl", _) => Kind::Revert,
+            ("call" | "near_call", _) => Kind::NearCall,
+            ("callf" | "calld" | "callm" | "far_call", _) => Kind::FarCall,
+            ("sload" | "sld", _) | (_, "log.sread") => Kind::StorageRead,
+            ("sstore" | "sst", _) | (_, "log.swrite") => Kind::StorageWrite,
+            ("tload" | "tstore" | "ldt" | "stt", _) => Kind::TransientStorage,
+            ("event", _) | (_, "log.event") => Kind::Event,
+            ("tol1", _) | (_, "log.to_l1") => Kind::L1Message,
+            _ => Kind::Other,
+        }
+    }
+
+    ///
+    /// Whether the instruction reads or writes stack or code memory.
+    ///
+    pub fn has_rich_addressing(&self) -> bool {
+        self.operands
+            .iter()
+            .any(|operand| operand.contains("stack") || operand.contains("code["))
+    }
+
+    ///
+    /// Returns the label operands without the `@` prefix.
+    ///
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.operands
+            .iter()
+            .filter_map(|operand| operand.strip_prefix('@'))
+    }
+
+    ///
+    /// Returns the constant pool labels referenced by the operands, e.g. `CPI0_1` in `code[@CPI0_1]`.
+    ///
+    pub fn constant_references(&self) -> impl Iterator<Item = &str> {
+        self.operands.iter().filter_map(|operand| {
+            let start = operand.find("code[@")? + "code[@".len();
+            let end = operand[start..].find(|character: char| {
+                !(character.is_ascii_alphanumeric() || character == '_' || character == '.')
+            })?;
+            Some(&operand[start..start + end])
+        })
+    }
+
+    ///
+    /// Returns the immediate operand values.
+    ///
+    pub fn immediates(&self) -> impl Iterator<Item = u128> + '_ {
+        self.operands
+            .iter()
+            .filter_map(|operand| match operand.strip_prefix("0x") {
+                Some(hexadecimal) => {
+                    u128::from_str_radix(hexadecimal, era_compiler_common::BASE_HEXADECIMAL).ok()
+                }
+                None => operand.parse::<u128>().ok(),
+            })
+    }
+}
@@ -0,0 +1,124 @@
+//!
+//! The EraVM text assembly.
+//!
+
+pub mod block;
+pub mod function;
+pub mod instruction;
+
+use std::collections::BTreeMap;
+
+use self::function::Function;
+use self::instruction::Instruction;
+
+///
+/// The EraVM text assembly.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Assembly {
+    /// The functions in order of appearance.
+    pub functions: Vec<Function>,
+    /// The constant pool cells by their labels.
+    pub constants: BTreeMap<String, u128>,
+}
+
+///
+/// The assembly section being parsed.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    /// The code section.
+    Text,
+    /// The constant data section.
+    Data,
+}
+
+impl Assembly {
+    ///
+    /// Parses the EraVM text assembly.
+    ///
+    /// Constant pool cells that do not fit into 128 bits are ignored, as they cannot be function selectors.
+    ///
+    pub fn parse(text: &str) -> Self {
+        let mut assembly = Self::default();
+        let mut section = Section::Text;
+        let mut constant_label: Option<String> = None;
+
+        for line in text.lines() {
+            let line = match line.split_once(';') {
+                Some((line, _comment)) => line,
+                None => line,
+            }
+            .trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(label) = line.strip_suffix(':') {
+                match section {
+                    Section::Data => constant_label = Some(label.to_owned()),
+                    Section::Text if !label.starts_with('.') => {
+                        assembly.functions.push(Function::new(label.to_owned()))
+                    }
+                    Section::Text => {
+                        if let Some(function) = assembly.functions.last_mut() {
+                            function.push_label(label.to_owned());
+                        }
+                    }
+                }
+                continue;
+            }
+
+            if line.starts_with('.') {
+                let (directive, argument) = match line.split_once(char::is_whitespace) {
+                    Some((directive, argument)) => (directive, argument.trim()),
+                    None => (line, ""),
+                };
+                match directive {
+                    ".text" => section = Section::Text,
+                    ".rodata" | ".data" => section = Section::Data,
+                    ".cell" if section == Section::Data => {
+                        if let (Some(label), Ok(value)) =
+                            (constant_label.take(), argument.parse::<u128>())
+                        {
+                            assembly.constants.insert(label, value);
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            if section == Section::Text {
+                if let Some(function) = assembly.functions.last_mut() {
+                    function.push_instruction(Instruction::parse(line));
+                }
+            }
+        }
+
+        for function in assembly.functions.iter_mut() {
+            function.finalize();
+        }
+        assembly
+    }
+
+    ///
+    /// Returns the function index by its name.
+    ///
+    pub fn function_index(&self, name: &str) -> Option<usize> {
+        self.functions
+            .iter()
+            .position(|function| function.name == name)
+    }
+
+    ///
+    /// Whether the instruction operands contain the specified value,
+    /// either as an immediate or as a constant pool reference.
+    ///
+    pub fn references_value(&self, instruction: &Instruction, value: u128) -> bool {
+        instruction.immediates().any(|immediate| immediate == value)
+            || instruction
+                .constant_references()
+                .any(|label| self.constants.get(label) == Some(&value))
+    }
+}
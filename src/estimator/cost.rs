@@ -0,0 +1,119 @@
+//!
+//! The EraVM execution cost.
+//!
+
+use crate::estimator::assembly::instruction::Instruction;
+use crate::estimator::assembly::instruction::Kind as InstructionKind;
+
+///
+/// The EraVM execution cost.
+///
+/// The field order matters, as the derived ordering compares ergs first.
+///
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct Cost {
+    /// The ergs spent.
+    pub ergs: u64,
+    /// The VM cycles spent.
+    pub cycles: u64,
+}
+
+impl Cost {
+    /// The VM cycle cost in ergs.
+    pub const ERGS_VM_CYCLE: u64 = 4;
+    /// The RAM permutation cost in ergs.
+    pub const ERGS_RAM_PERMUTATION: u64 = 1;
+    /// The log demuxer cost in ergs.
+    pub const ERGS_LOG_DEMUXER: u64 = 1;
+    /// The storage sorter cost in ergs.
+    pub const ERGS_STORAGE_SORTER: u64 = 2;
+    /// The events and L1 messages sorter cost in ergs.
+    pub const ERGS_EVENTS_SORTER: u64 = 1;
+
+    /// The storage read I/O price in ergs.
+    pub const ERGS_STORAGE_READ_IO: u64 = 150;
+    /// The storage write I/O price in ergs.
+    pub const ERGS_STORAGE_WRITE_IO: u64 = 250;
+    /// The transient storage I/O price in ergs.
+    pub const ERGS_TRANSIENT_STORAGE_IO: u64 = 15;
+    /// The event I/O price in ergs.
+    pub const ERGS_EVENT_IO: u64 = 25;
+    /// The L1 message I/O price in ergs.
+    pub const ERGS_L1_MESSAGE_IO: u64 = 100;
+    /// The far call base price in ergs, excluding the callee execution.
+    pub const ERGS_FAR_CALL: u64 = 20;
+
+    /// The average instruction cost in ergs.
+    pub const ERGS_AVERAGE: u64 = Self::ERGS_VM_CYCLE + Self::ERGS_RAM_PERMUTATION;
+    /// The cost of instructions with stack or code operands in ergs.
+    pub const ERGS_RICH_ADDRESSING: u64 = Self::ERGS_VM_CYCLE + 2 * Self::ERGS_RAM_PERMUTATION;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(ergs: u64, cycles: u64) -> Self {
+        Self { ergs, cycles }
+    }
+
+    ///
+    /// Returns the approximate cost of a single instruction.
+    ///
+    /// The callee execution cost of near calls is not included and must be added separately.
+    ///
+    pub fn of(instruction: &Instruction) -> Self {
+        let base = if instruction.has_rich_addressing() {
+            Self::ERGS_RICH_ADDRESSING
+        } else {
+            Self::ERGS_AVERAGE
+        };
+        let extra = match instruction.kind() {
+            InstructionKind::StorageRead => {
+                Self::ERGS_STORAGE_READ_IO + Self::ERGS_LOG_DEMUXER + Self::ERGS_STORAGE_SORTER
+            }
+            InstructionKind::StorageWrite => {
+                Self::ERGS_STORAGE_WRITE_IO + Self::ERGS_LOG_DEMUXER + Self::ERGS_STORAGE_SORTER
+            }
+            InstructionKind::TransientStorage => {
+                Self::ERGS_TRANSIENT_STORAGE_IO + Self::ERGS_LOG_DEMUXER
+            }
+            InstructionKind::Event => {
+                Self::ERGS_EVENT_IO + Self::ERGS_LOG_DEMUXER + Self::ERGS_EVENTS_SORTER
+            }
+            InstructionKind::L1Message => {
+                Self::ERGS_L1_MESSAGE_IO + Self::ERGS_LOG_DEMUXER + Self::ERGS_EVENTS_SORTER
+            }
+            InstructionKind::FarCall => Self::ERGS_FAR_CALL,
+            _ => 0,
+        };
+        Self::new(base + extra, 1)
+    }
+}
+
+impl std::ops::Add for Cost {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(
+            self.ergs.saturating_add(other.ergs),
+            self.cycles.saturating_add(other.cycles),
+        )
+    }
+}
+
+impl std::ops::AddAssign for Cost {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
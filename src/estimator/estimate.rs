@@ -0,0 +1,32 @@
+//!
+//! The external function execution estimate.
+//!
+
+use crate::estimator::cost::Cost;
+
+///
+/// The external function execution estimate.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Estimate {
+    /// The cheapest path from the contract entry to a successful return.
+    pub best_case: Cost,
+    /// The most expensive path from the contract entry to any exit.
+    /// Is `None` if the function contains unbounded loops or recursion.
+    pub worst_case: Option<Cost>,
+    /// Whether the function contains unbounded loops or recursion.
+    pub unbounded_loops: bool,
+}
+
+impl Estimate {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(best_case: Cost, worst_case: Option<Cost>) -> Self {
+        Self {
+            best_case,
+            unbounded_loops: worst_case.is_none(),
+            worst_case,
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//!
+//! The static ergs estimator.
+//!
+//! Walks the EraVM assembly control flow graph from the contract entry through the
+//! selector dispatch of each external function, using an approximate per-instruction cost model.
+//!
+
+pub mod assembly;
+pub mod cost;
+pub mod estimate;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use self::assembly::block::Exit;
+use self::assembly::function::Function;
+use self::assembly::function::LongestPath;
+use self::assembly::instruction::Kind as InstructionKind;
+use self::assembly::Assembly;
+use self::cost::Cost;
+use self::estimate::Estimate;
+
+///
+/// Estimates the cost of each external function of the contract.
+///
+/// Functions whose selector dispatch cannot be found in the assembly are omitted.
+///
+pub fn estimate(
+    assembly: &str,
+    method_identifiers: &BTreeMap<String, String>,
+) -> anyhow::Result<BTreeMap<String, Estimate>> {
+    let assembly = Assembly::parse(assembly);
+    let mut estimator = Estimator::new(&assembly);
+
+    let mut estimates = BTreeMap::new();
+    for (signature, identifier) in method_identifiers.iter() {
+        let selector = u128::from_str_radix(
+            identifier.strip_prefix("0x").unwrap_or(identifier.as_str()),
+            era_compiler_common::BASE_HEXADECIMAL,
+        )
+        .map_err(|error| {
+            anyhow::anyhow!("Method identifier `{identifier}` of `{signature}` is invalid: {error}")
+        })?;
+        if let Some(estimate) = estimator.estimate(selector) {
+            estimates.insert(signature.to_owned(), estimate);
+        }
+    }
+    Ok(estimates)
+}
+
+///
+/// The function cost summary used for near calls.
+///
+#[derive(Debug, Clone, Copy)]
+struct Summary {
+    /// The cheapest path cost.
+    best: Cost,
+    /// The most expensive path cost, if bounded.
+    worst: Option<Cost>,
+}
+
+///
+/// The block costs of a function.
+///
+/// The first vector contains the best-case costs, and the second one contains the worst-case costs,
+/// which are `None` for blocks calling functions with unbounded execution.
+///
+type BlockCosts = (Vec<Cost>, Vec<Option<Cost>>);
+
+///
+/// The static ergs estimator.
+///
+struct Estimator<'a> {
+    /// The parsed assembly.
+    assembly: &'a Assembly,
+    /// The near call summaries by function index.
+    summaries: HashMap<usize, Summary>,
+    /// The functions whose summaries are being computed, used to detect recursion.
+    active: HashSet<usize>,
+    /// The block costs by function index.
+    block_costs: HashMap<usize, BlockCosts>,
+}
+
+impl<'a> Estimator<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(assembly: &'a Assembly) -> Self {
+        Self {
+            assembly,
+            summaries: HashMap::new(),
+            active: HashSet::new(),
+            block_costs: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Estimates the external function with the specified selector.
+    ///
+    pub fn estimate(&mut self, selector: u128) -> Option<Estimate> {
+        let assembly = self.assembly;
+        let (function_index, function, dispatch, target) = assembly
+            .functions
+            .iter()
+            .enumerate()
+            .find_map(|(function_index, function)| {
+                (0..function.blocks.len()).find_map(|block_index| {
+                    Self::dispatch_target(assembly, function, block_index, selector)
+                        .map(|target| (function_index, function, block_index, target))
+                })
+            })?;
+
+        let (best_costs, worst_costs) = self.block_costs(function_index);
+        let is_success = |index: usize| function.blocks[index].exit == Some(Exit::Success);
+        let is_exit = |index: usize| function.blocks[index].exit.is_some();
+
+        let best_case = function
+            .shortest_path(best_costs.as_slice(), 0, |index| index == dispatch)?
+            + function
+                .shortest_path(best_costs.as_slice(), target, is_success)
+                .or_else(|| function.shortest_path(best_costs.as_slice(), target, is_exit))?;
+        let worst_case = match (
+            function.longest_path(worst_costs.as_slice(), 0, |index| index == dispatch),
+            function.longest_path(worst_costs.as_slice(), target, is_exit),
+        ) {
+            (LongestPath::Bounded(prefix), LongestPath::Bounded(suffix)) => Some(prefix + suffix),
+            _ => None,
+        };
+
+        Some(Estimate::new(best_case, worst_case))
+    }
+
+    ///
+    /// Returns the block the dispatch jumps to if the `block_index` block compares the selector.
+    ///
+    fn dispatch_target(
+        assembly: &Assembly,
+        function: &Function,
+        block_index: usize,
+        selector: u128,
+    ) -> Option<usize> {
+        let block = &function.blocks[block_index];
+        let terminator = block.instructions.last()?;
+        if terminator.kind() != InstructionKind::Jump {
+            return None;
+        }
+
+        let comparison = block
+            .instructions
+            .iter()
+            .rev()
+            .skip(1)
+            .find(|instruction| instruction.sets_flags)?;
+        if !assembly.references_value(comparison, selector) {
+            return None;
+        }
+
+        match terminator.condition.as_deref()? {
+            "eq" => function.block_index(terminator.labels().next()?),
+            "ne" if block_index + 1 < function.blocks.len() => Some(block_index + 1),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the block costs of the function, including the near call costs.
+    ///
+    fn block_costs(&mut self, function_index: usize) -> BlockCosts {
+        if let Some(block_costs) = self.block_costs.get(&function_index) {
+            return block_costs.clone();
+        }
+
+        let assembly = self.assembly;
+        let function = &assembly.functions[function_index];
+        let mut best_costs = Vec::with_capacity(function.blocks.len());
+        let mut worst_costs = Vec::with_capacity(function.blocks.len());
+        for block in function.blocks.iter() {
+            let mut best = Cost::default();
+            let mut worst = Some(Cost::default());
+            for instruction in block.instructions.iter() {
+                let cost = Cost::of(instruction);
+                best += cost;
+                worst = worst.map(|worst| worst + cost);
+
+                if instruction.kind() == InstructionKind::NearCall {
+                    if let Some(callee) = instruction
+                        .labels()
+                        .find_map(|label| assembly.function_index(label))
+                    {
+                        let summary = self.summary(callee);
+                        best += summary.best;
+                        worst = worst
+                            .zip(summary.worst)
+                            .map(|(worst, callee)| worst + callee);
+                    }
+                }
+            }
+            best_costs.push(best);
+            worst_costs.push(worst);
+        }
+
+        let block_costs = (best_costs, worst_costs);
+        self.block_costs.insert(function_index, block_costs.clone());
+        block_costs
+    }
+
+    ///
+    /// Returns the function cost summary used for near calls.
+    ///
+    /// Recursive calls are considered free in the best case and unbounded in the worst case.
+    ///
+    fn summary(&mut self, function_index: usize) -> Summary {
+        if let Some(summary) = self.summaries.get(&function_index) {
+            return *summary;
+        }
+        if !self.active.insert(function_index) {
+            return Summary {
+                best: Cost::default(),
+                worst: None,
+            };
+        }
+
+        let (best_costs, worst_costs) = self.block_costs(function_index);
+        let function = &self.assembly.functions[function_index];
+        let is_success = |index: usize| function.blocks[index].exit == Some(Exit::Success);
+        let is_exit = |index: usize| function.blocks[index].exit.is_some();
+
+        let best = function
+            .shortest_path(best_costs.as_slice(), 0, is_success)
+            .or_else(|| function.shortest_path(best_costs.as_slice(), 0, is_exit))
+            .unwrap_or_default();
+        let worst = match function.longest_path(worst_costs.as_slice(), 0, is_exit) {
+            LongestPath::Bounded(cost) => Some(cost),
+            LongestPath::Unreachable => Some(best),
+            LongestPath::Unbounded => None,
+        };
+
+        let summary = Summary { best, worst };
+        self.active.remove(&function_index);
+        self.summaries.insert(function_index, summary);
+        summary
+    }
+}
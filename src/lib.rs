@@ -9,21 +9,58 @@
 #![allow(clippy::assigning_clones)]
 
 pub mod build;
+pub mod build_evm;
+pub mod cache;
 pub mod r#const;
+pub mod error;
+pub mod estimator;
+pub mod optimization_level;
+pub mod optimization_override;
 pub mod process;
 pub mod project;
+pub mod reproducer;
+pub mod server;
+pub mod session;
+pub mod supervisor;
+pub mod tuning;
 pub mod vyper;
 pub mod warning_type;
 
+pub use self::build::artifact::format::Format as ArtifactFormat;
 pub use self::build::contract::Contract as ContractBuild;
 pub use self::build::Build;
+pub use self::build_evm::contract::Contract as EVMContractBuild;
+pub use self::build_evm::Build as EVMBuild;
+pub use self::cache::source_key::SourceKey;
+pub use self::cache::Cache;
+pub use self::error::source_location::SourceLocation;
+pub use self::error::Error;
+pub use self::optimization_level::OptimizationLevel;
+pub use self::optimization_override::OptimizationOverride;
+pub use self::process::encoding::Encoding as ProcessEncoding;
 pub use self::process::input::Input as ProcessInput;
 pub use self::process::output::Output as ProcessOutput;
 pub use self::process::run as run_recursive;
+pub use self::process::run_worker as run_recursive_worker;
+pub use self::process::strategy::Strategy as ProcessStrategy;
+pub use self::process::CACHE;
 pub use self::process::EXECUTABLE;
+pub use self::process::IPC_ENCODING;
+pub use self::process::REPRODUCER_DIR;
 pub use self::project::contract::Contract;
 pub use self::project::Project;
 pub use self::r#const::*;
+pub use self::reproducer::Reproducer;
+pub use self::session::config::Config as CompilationConfig;
+pub use self::session::Session as CompilerSession;
+pub use self::supervisor::error::Error as SupervisorError;
+pub use self::supervisor::error::ErrorKind as SupervisorErrorKind;
+pub use self::supervisor::limits::Limits as ProcessLimits;
+pub use self::supervisor::PROCESS_LIMITS;
+pub use self::tuning::candidate::Candidate as TuningCandidate;
+pub use self::tuning::objective::Objective as TuningObjective;
+pub use self::tuning::Tuning;
+pub use self::vyper::combined_json::contract::warning::Warning as VyperCompilerCombinedJsonContractWarning;
 pub use self::vyper::combined_json::contract::Contract as VyperCompilerCombinedJsonContract;
 pub use self::vyper::combined_json::CombinedJson as VyperCompilerCombinedJson;
 pub use self::vyper::selector::Selector as VyperSelector;
@@ -49,9 +86,42 @@ use path_slash::PathExt;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
+///
+/// Runs the LLL IR mode.
+///
+/// A shortcut for [`CompilerSession::lll`], kept for compatibility with the positional API.
+///
+#[deprecated(note = "Use `CompilerSession::lll` with `CompilationConfig` instead.")]
+pub fn lll(
+    input_paths: Vec<PathBuf>,
+    output_selection: &[VyperSelector],
+    metadata_hash_type: era_compiler_common::MetadataHashType,
+    append_bytecode_metadata: bool,
+    optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    llvm_options: Vec<String>,
+    optimization_overrides: Vec<OptimizationOverride>,
+    suppressed_warnings: Vec<WarningType>,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+) -> anyhow::Result<Build> {
+    let config = CompilationConfig {
+        metadata_hash_type,
+        append_bytecode_metadata,
+        optimizer_settings,
+        llvm_options,
+        optimization_overrides,
+        suppressed_warnings,
+        debug_config,
+        ..CompilationConfig::default()
+    };
+    CompilerSession::new(config).lll(input_paths, output_selection)
+}
+
 ///
 /// Runs the LLVM IR mode.
 ///
+/// A shortcut for [`CompilerSession::llvm_ir`], kept for compatibility with the positional API.
+///
+#[deprecated(note = "Use `CompilerSession::llvm_ir` with `CompilationConfig` instead.")]
 pub fn llvm_ir(
     input_paths: Vec<PathBuf>,
     output_selection: &[VyperSelector],
@@ -59,28 +129,29 @@ pub fn llvm_ir(
     append_bytecode_metadata: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    optimization_overrides: Vec<OptimizationOverride>,
     suppressed_warnings: Vec<WarningType>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<Build> {
-    let paths: Vec<&Path> = input_paths.iter().map(|path| path.as_path()).collect();
-    let project = Project::try_from_llvm_ir_paths(paths.as_slice(), output_selection)?;
-
-    let mut build = project.compile(
-        None,
+    let config = CompilationConfig {
         metadata_hash_type,
         append_bytecode_metadata,
         optimizer_settings,
         llvm_options,
+        optimization_overrides,
         suppressed_warnings,
         debug_config,
-    )?;
-    build.link(BTreeMap::new())?;
-    Ok(build)
+        ..CompilationConfig::default()
+    };
+    CompilerSession::new(config).llvm_ir(input_paths, output_selection)
 }
 
 ///
 /// Runs the EraVM assembly mode.
 ///
+/// A shortcut for [`CompilerSession::eravm_assembly`], kept for compatibility with the positional API.
+///
+#[deprecated(note = "Use `CompilerSession::eravm_assembly` with `CompilationConfig` instead.")]
 pub fn eravm_assembly(
     input_paths: Vec<PathBuf>,
     output_selection: &[VyperSelector],
@@ -90,26 +161,23 @@ pub fn eravm_assembly(
     suppressed_warnings: Vec<WarningType>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<Build> {
-    let paths: Vec<&Path> = input_paths.iter().map(|path| path.as_path()).collect();
-    let project = Project::try_from_eravm_assembly_paths(paths.as_slice(), output_selection)?;
-
-    let optimizer_settings = era_compiler_llvm_context::OptimizerSettings::cycles();
-    let mut build = project.compile(
-        None,
+    let config = CompilationConfig {
         metadata_hash_type,
         append_bytecode_metadata,
-        optimizer_settings,
         llvm_options,
         suppressed_warnings,
         debug_config,
-    )?;
-    build.link(BTreeMap::new())?;
-    Ok(build)
+        ..CompilationConfig::default()
+    };
+    CompilerSession::new(config).eravm_assembly(input_paths, output_selection)
 }
 
 ///
 /// Runs the standard output mode.
 ///
+/// A shortcut for [`CompilerSession::standard_output`], kept for compatibility with the positional API.
+///
+#[deprecated(note = "Use `CompilerSession::standard_output` with `CompilationConfig` instead.")]
 pub fn standard_output(
     input_paths: Vec<PathBuf>,
     vyper: &VyperCompiler,
@@ -120,46 +188,40 @@ pub fn standard_output(
     metadata_hash_type: era_compiler_common::MetadataHashType,
     append_bytecode_metadata: bool,
     vyper_optimizer_enabled: bool,
+    experimental_codegen: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    optimization_overrides: Vec<OptimizationOverride>,
+    tuning: Option<Tuning>,
     suppressed_warnings: Vec<WarningType>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<Build> {
-    let project = vyper.batch(
-        &vyper.version.default,
-        input_paths,
-        output_selection,
+    let config = CompilationConfig {
+        vyper: vyper.executable.clone(),
         evm_version,
         enable_decimals,
         search_paths,
         vyper_optimizer_enabled,
-    )?;
-
-    if let Some(ref debug_config) = debug_config {
-        for (path, contract) in project.contracts.iter() {
-            debug_config.dump_lll(
-                path.as_str(),
-                contract.ir_string().unwrap_or_default().as_str(),
-            )?;
-        }
-    }
-
-    let mut build = project.compile(
-        evm_version,
+        experimental_codegen,
         metadata_hash_type,
         append_bytecode_metadata,
         optimizer_settings,
         llvm_options,
+        optimization_overrides,
+        tuning,
         suppressed_warnings,
         debug_config,
-    )?;
-    build.link(BTreeMap::new())?;
-    Ok(build)
+        ..CompilationConfig::default()
+    };
+    CompilerSession::new(config).standard_output(input_paths, output_selection)
 }
 
 ///
 /// Runs the combined JSON mode.
 ///
+/// A shortcut for [`CompilerSession::combined_json`], kept for compatibility with the positional API.
+///
+#[deprecated(note = "Use `CompilerSession::combined_json` with `CompilationConfig` instead.")]
 pub fn combined_json(
     input_paths: Vec<PathBuf>,
     vyper: &VyperCompiler,
@@ -169,58 +231,109 @@ pub fn combined_json(
     metadata_hash_type: era_compiler_common::MetadataHashType,
     append_bytecode_metadata: bool,
     vyper_optimizer_enabled: bool,
+    experimental_codegen: bool,
+    evm_bytecode: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    optimization_overrides: Vec<OptimizationOverride>,
+    tuning: Option<Tuning>,
     suppressed_warnings: Vec<WarningType>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<VyperCompilerCombinedJson> {
-    let zkvyper_version = semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid");
-
-    let output_selection: Vec<VyperSelector> = vec![
-        VyperSelector::IRJson,
-        VyperSelector::AST,
-        VyperSelector::ABI,
-        VyperSelector::MethodIdentifiers,
-        VyperSelector::Layout,
-        VyperSelector::UserDocumentation,
-        VyperSelector::DeveloperDocumentation,
-        VyperSelector::EraVMAssembly,
-        VyperSelector::ProjectMetadata,
-    ];
-
-    let project: Project = vyper.batch(
-        &vyper.version.default,
-        input_paths,
-        output_selection.as_slice(),
+    let config = CompilationConfig {
+        vyper: vyper.executable.clone(),
         evm_version,
         enable_decimals,
         search_paths,
         vyper_optimizer_enabled,
-    )?;
-
-    if let Some(ref debug_config) = debug_config {
-        for (path, contract) in project.contracts.iter() {
-            debug_config.dump_lll(
-                path.as_str(),
-                contract.ir_string().unwrap_or_default().as_str(),
-            )?;
-        }
-    }
-
-    let mut build = project.compile(
-        evm_version,
+        experimental_codegen,
+        evm_bytecode,
         metadata_hash_type,
         append_bytecode_metadata,
         optimizer_settings,
         llvm_options,
+        optimization_overrides,
+        tuning,
         suppressed_warnings,
         debug_config,
-    )?;
-    build.link(BTreeMap::new())?;
+        ..CompilationConfig::default()
+    };
+    CompilerSession::new(config).combined_json(input_paths)
+}
 
-    let combined_json = build.into_combined_json(Some(&vyper.version.default), &zkvyper_version);
+///
+/// Runs the standard output mode for EVM.
+///
+/// A shortcut for [`CompilerSession::standard_output_evm`], kept for compatibility with the positional API.
+///
+#[deprecated(note = "Use `CompilerSession::standard_output_evm` with `CompilationConfig` instead.")]
+pub fn standard_output_evm(
+    input_paths: Vec<PathBuf>,
+    vyper: &VyperCompiler,
+    output_selection: &[VyperSelector],
+    evm_version: Option<era_compiler_common::EVMVersion>,
+    enable_decimals: bool,
+    search_paths: Option<Vec<String>>,
+    metadata_hash_type: era_compiler_common::MetadataHashType,
+    vyper_optimizer_enabled: bool,
+    optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    llvm_options: Vec<String>,
+    optimization_overrides: Vec<OptimizationOverride>,
+    suppressed_warnings: Vec<WarningType>,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+) -> anyhow::Result<EVMBuild> {
+    let config = CompilationConfig {
+        vyper: vyper.executable.clone(),
+        evm_version,
+        enable_decimals,
+        search_paths,
+        vyper_optimizer_enabled,
+        metadata_hash_type,
+        optimizer_settings,
+        llvm_options,
+        optimization_overrides,
+        suppressed_warnings,
+        debug_config,
+        ..CompilationConfig::default()
+    };
+    CompilerSession::new(config).standard_output_evm(input_paths, output_selection)
+}
 
-    Ok(combined_json)
+///
+/// Runs the combined JSON mode for EVM.
+///
+/// A shortcut for [`CompilerSession::combined_json_evm`], kept for compatibility with the positional API.
+///
+#[deprecated(note = "Use `CompilerSession::combined_json_evm` with `CompilationConfig` instead.")]
+pub fn combined_json_evm(
+    input_paths: Vec<PathBuf>,
+    vyper: &VyperCompiler,
+    evm_version: Option<era_compiler_common::EVMVersion>,
+    enable_decimals: bool,
+    search_paths: Option<Vec<String>>,
+    metadata_hash_type: era_compiler_common::MetadataHashType,
+    vyper_optimizer_enabled: bool,
+    optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    llvm_options: Vec<String>,
+    optimization_overrides: Vec<OptimizationOverride>,
+    suppressed_warnings: Vec<WarningType>,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+) -> anyhow::Result<VyperCompilerCombinedJson> {
+    let config = CompilationConfig {
+        vyper: vyper.executable.clone(),
+        evm_version,
+        enable_decimals,
+        search_paths,
+        vyper_optimizer_enabled,
+        metadata_hash_type,
+        optimizer_settings,
+        llvm_options,
+        optimization_overrides,
+        suppressed_warnings,
+        debug_config,
+        ..CompilationConfig::default()
+    };
+    CompilerSession::new(config).combined_json_evm(input_paths)
 }
 
 ///
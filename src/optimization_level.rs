@@ -0,0 +1,48 @@
+//!
+//! The optimization level.
+//!
+
+use std::str::FromStr;
+
+///
+/// The optimization level.
+///
+/// Is either a fixed level accepted by the `-O` option, or the auto-tuning mode.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptimizationLevel {
+    /// The fixed level, such as `3` or `z`.
+    Fixed(char),
+    /// The per-contract auto-tuning mode.
+    Auto,
+}
+
+impl FromStr for OptimizationLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> anyhow::Result<Self> {
+        if string == "auto" {
+            return Ok(Self::Auto);
+        }
+
+        let mut chars = string.chars();
+        match (chars.next(), chars.next()) {
+            (Some(level), None) => {
+                era_compiler_llvm_context::OptimizerSettings::try_from_cli(level)?;
+                Ok(Self::Fixed(level))
+            }
+            _ => anyhow::bail!(
+                "Invalid optimization level `{string}`. Available levels: `0`, `1`, `2`, `3`, `s`, `z`, `auto`."
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for OptimizationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fixed(level) => write!(f, "{level}"),
+            Self::Auto => write!(f, "auto"),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//!
+//! Per-contract optimization override.
+//!
+
+use std::path::Path;
+use std::str::FromStr;
+
+///
+/// Per-contract optimization override.
+///
+/// Overrides the optimizer settings and LLVM options of contracts matching the glob pattern.
+/// Patterns containing `/` are matched against the contract path as passed to the compiler,
+/// and other patterns are matched against the file name only.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OptimizationOverride {
+    /// The glob pattern matching contract paths.
+    pub pattern: String,
+    /// The optimization level, as in the `-O` option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimization: Option<char>,
+    /// Whether to try to recompile with -Oz if the bytecode is too large.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fallback_to_optimizing_for_size: bool,
+    /// The extra LLVM arguments replacing the global ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub llvm_options: Option<Vec<String>>,
+}
+
+impl OptimizationOverride {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Validates the glob pattern and optimization level.
+    ///
+    pub fn new(
+        pattern: String,
+        optimization: Option<char>,
+        fallback_to_optimizing_for_size: bool,
+        llvm_options: Option<Vec<String>>,
+    ) -> anyhow::Result<Self> {
+        globset::Glob::new(pattern.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid contract pattern `{pattern}`: {error}"))?;
+        if let Some(level) = optimization {
+            era_compiler_llvm_context::OptimizerSettings::try_from_cli(level)?;
+        }
+
+        Ok(Self {
+            pattern,
+            optimization,
+            fallback_to_optimizing_for_size,
+            llvm_options,
+        })
+    }
+
+    ///
+    /// Whether the override applies to the contract at `path`.
+    ///
+    pub fn is_match(&self, path: &str) -> bool {
+        let matcher = match globset::Glob::new(self.pattern.as_str()) {
+            Ok(glob) => glob.compile_matcher(),
+            Err(_) => return false,
+        };
+        if self.pattern.contains('/') {
+            matcher.is_match(path)
+        } else {
+            Path::new(path)
+                .file_name()
+                .map(|file_name| matcher.is_match(file_name))
+                .unwrap_or_default()
+        }
+    }
+
+    ///
+    /// Returns the settings of the contract at `path` with all matching overrides applied in order.
+    ///
+    pub fn resolve(
+        overrides: &[Self],
+        path: &str,
+        optimizer_settings: &era_compiler_llvm_context::OptimizerSettings,
+        llvm_options: &[String],
+    ) -> anyhow::Result<(era_compiler_llvm_context::OptimizerSettings, Vec<String>)> {
+        let mut optimizer_settings = optimizer_settings.to_owned();
+        let mut llvm_options = llvm_options.to_owned();
+
+        for optimization_override in overrides
+            .iter()
+            .filter(|optimization_override| optimization_override.is_match(path))
+        {
+            if let Some(level) = optimization_override.optimization {
+                let mut settings =
+                    era_compiler_llvm_context::OptimizerSettings::try_from_cli(level)?;
+                if optimizer_settings.is_fallback_to_size_enabled() {
+                    settings.enable_fallback_to_size();
+                }
+                settings.is_verify_each_enabled = optimizer_settings.is_verify_each_enabled;
+                settings.is_debug_logging_enabled = optimizer_settings.is_debug_logging_enabled;
+                optimizer_settings = settings;
+            }
+            if optimization_override.fallback_to_optimizing_for_size {
+                optimizer_settings.enable_fallback_to_size();
+            }
+            if let Some(options) = optimization_override.llvm_options.as_ref() {
+                llvm_options = options.to_owned();
+            }
+        }
+
+        Ok((optimizer_settings, llvm_options))
+    }
+}
+
+impl FromStr for OptimizationOverride {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> anyhow::Result<Self> {
+        let (pattern, level) = string.rsplit_once('=').ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid optimization override `{string}`. Expected `<pattern>=<level>`."
+            )
+        })?;
+        let mut level_chars = level.chars();
+        let optimization = match (level_chars.next(), level_chars.next()) {
+            (Some(level), None) => level,
+            _ => anyhow::bail!(
+                "Invalid optimization level `{level}` in optimization override `{string}`."
+            ),
+        };
+        Self::new(pattern.to_owned(), Some(optimization), false, None)
+    }
+}
@@ -0,0 +1,81 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The encoding of worker inputs and outputs.
+//!
+
+use std::str::FromStr;
+
+///
+/// The encoding of worker inputs and outputs.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    /// The CBOR encoding.
+    ///
+    /// JSON numbers, such as the ones in the AST, keep their arbitrary-precision representation.
+    #[default]
+    Binary,
+    /// The human-readable JSON encoding, mostly for debugging.
+    Json,
+}
+
+impl Encoding {
+    ///
+    /// Encodes the value.
+    ///
+    pub fn encode<T>(&self, value: &T) -> Vec<u8>
+    where
+        T: serde::Serialize,
+    {
+        match self {
+            Self::Binary => {
+                let mut buffer = Vec::new();
+                ciborium::ser::into_writer(value, &mut buffer).expect("Always valid");
+                buffer
+            }
+            Self::Json => serde_json::to_vec(value).expect("Always valid"),
+        }
+    }
+
+    ///
+    /// Decodes the value.
+    ///
+    /// The nesting depth is not limited, as LLL IR trees of large contracts are deep. Decoding
+    /// must therefore run on a thread with a large stack.
+    ///
+    pub fn decode<T>(&self, bytes: &[u8]) -> anyhow::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        match self {
+            Self::Binary => ciborium::de::from_reader_with_recursion_limit(bytes, usize::MAX)
+                .map_err(|error| anyhow::anyhow!("CBOR decoding error: {error}")),
+            Self::Json => era_compiler_common::deserialize_from_slice(bytes),
+        }
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> anyhow::Result<Self> {
+        match string {
+            "binary" => Ok(Self::Binary),
+            "json" => Ok(Self::Json),
+            string => anyhow::bail!(
+                "Invalid IPC encoding `{string}`. Available encodings: `binary`, `json`."
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Binary => write!(f, "binary"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
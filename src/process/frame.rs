@@ -0,0 +1,58 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The length-prefixed frame of the worker protocol.
+//!
+
+use std::io::Read;
+use std::io::Write;
+
+/// The maximum frame payload length.
+///
+/// Guards against allocating unbounded memory for a corrupted length prefix.
+pub const MAX_PAYLOAD_LENGTH: u64 = 1 << 30;
+
+///
+/// Reads the next frame payload.
+///
+/// Returns `None` if the input ends before the frame starts, and an `InvalidData` error if the
+/// payload length exceeds `MAX_PAYLOAD_LENGTH`.
+///
+pub fn read<R>(reader: &mut R) -> std::io::Result<Option<Vec<u8>>>
+where
+    R: Read,
+{
+    let mut length = [0u8; std::mem::size_of::<u64>()];
+    let mut offset = 0;
+    while offset < length.len() {
+        match reader.read(&mut length[offset..])? {
+            0 if offset == 0 => return Ok(None),
+            0 => return Err(std::io::ErrorKind::UnexpectedEof.into()),
+            size => offset += size,
+        }
+    }
+
+    let length = u64::from_le_bytes(length);
+    if length > MAX_PAYLOAD_LENGTH {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("frame length {length} exceeds the limit of {MAX_PAYLOAD_LENGTH} bytes"),
+        ));
+    }
+
+    let mut payload = vec![0u8; length as usize];
+    reader.read_exact(payload.as_mut_slice())?;
+    Ok(Some(payload))
+}
+
+///
+/// Writes the frame with the payload.
+///
+pub fn write<W>(writer: &mut W, payload: &[u8]) -> std::io::Result<()>
+where
+    W: Write,
+{
+    writer.write_all((payload.len() as u64).to_le_bytes().as_slice())?;
+    writer.write_all(payload)?;
+    writer.flush()
+}
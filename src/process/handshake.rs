@@ -0,0 +1,63 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The worker protocol handshake.
+//!
+
+use super::encoding::Encoding;
+
+///
+/// The worker protocol handshake.
+///
+/// Is always encoded as JSON. The parent sends it first, and the worker replies with its own, so
+/// that a worker executable of another compiler version fails loudly instead of misreading the
+/// inputs.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Handshake {
+    /// The version of the input and output schema.
+    pub protocol_version: u32,
+    /// The compiler version.
+    pub compiler_version: String,
+    /// The encoding of the following inputs and outputs.
+    pub encoding: Encoding,
+}
+
+impl Handshake {
+    /// The version of the input and output schema.
+    ///
+    /// Must be bumped on every change of `Input`, `Output`, `Error`, or the types they contain.
+    pub const PROTOCOL_VERSION: u32 = 3;
+
+    ///
+    /// Creates the handshake of this executable.
+    ///
+    pub fn new(encoding: Encoding) -> Self {
+        Self {
+            protocol_version: Self::PROTOCOL_VERSION,
+            compiler_version: env!("CARGO_PKG_VERSION").to_owned(),
+            encoding,
+        }
+    }
+
+    ///
+    /// Checks that the other side speaks the same protocol and is the same compiler version.
+    ///
+    /// The compiler version is compared as well, as the types sent over the protocol may change
+    /// between versions without a protocol version bump.
+    ///
+    pub fn check(&self, other: &Self) -> anyhow::Result<()> {
+        if self.protocol_version != other.protocol_version
+            || self.compiler_version != other.compiler_version
+        {
+            anyhow::bail!(
+                "Recursive process protocol mismatch: v{} of zkvyper v{} is expected, but the other side speaks v{} of zkvyper v{}. Make sure the worker executable matches the compiler.",
+                self.protocol_version,
+                self.compiler_version,
+                other.protocol_version,
+                other.compiler_version,
+            );
+        }
+        Ok(())
+    }
+}
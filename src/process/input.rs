@@ -19,6 +19,8 @@ pub struct Input<'a> {
     pub full_path: Cow<'a, String>,
     /// The contract representation.
     pub contract: Cow<'a, Contract>,
+    /// The target machine.
+    pub target: era_compiler_common::Target,
     /// The metadata hash.
     pub metadata_hash: Option<era_compiler_common::Hash>,
     /// Do not include the metadata in the bytecode.
@@ -42,6 +44,7 @@ impl<'a> Input<'a> {
     pub fn new(
         full_path: Cow<'a, String>,
         contract: Cow<'a, Contract>,
+        target: era_compiler_common::Target,
         metadata_hash: Option<era_compiler_common::Hash>,
         append_bytecode_metadata: bool,
         output_selection: Vec<VyperSelector>,
@@ -53,6 +56,7 @@ impl<'a> Input<'a> {
         Self {
             full_path,
             contract,
+            target,
             metadata_hash,
             append_bytecode_metadata,
             output_selection,
@@ -2,50 +2,66 @@
 //! Process for compiling a single compilation unit.
 //!
 
+pub mod encoding;
+pub mod frame;
+pub mod handshake;
 pub mod input;
 pub mod output;
+pub mod output_evm;
+pub mod pool;
+pub mod strategy;
+pub mod worker;
 
+use std::io::Read;
 use std::io::Write;
 use std::path::PathBuf;
-use std::process::Command;
 use std::sync::OnceLock;
 use std::thread::Builder;
 
+use crate::cache::Cache;
+use crate::error::Error;
+use crate::reproducer::Reproducer;
+use crate::supervisor::error::Error as SupervisorError;
+use crate::supervisor::error::ErrorKind as SupervisorErrorKind;
+
+use self::encoding::Encoding;
+use self::handshake::Handshake;
 use self::input::Input;
 use self::output::Output;
+use self::output_evm::Output as EVMOutput;
+use self::pool::Pool;
+use self::strategy::Compile;
+use self::worker::Worker;
 
 /// The overridden executable name used when the compiler is run as a library.
 pub static EXECUTABLE: OnceLock<PathBuf> = OnceLock::new();
 
+/// The compilation cache of recursive process outputs, if enabled.
+pub static CACHE: OnceLock<Cache> = OnceLock::new();
+
+/// The directory to write crash reproducer bundles to, if enabled.
+pub static REPRODUCER_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// The encoding of worker inputs and outputs, if overridden for debugging.
+pub static IPC_ENCODING: OnceLock<Encoding> = OnceLock::new();
+
+/// The pool of worker processes, started on the first compilation.
+static POOL: OnceLock<Pool> = OnceLock::new();
+
 ///
 /// Read input from `stdin`, compile a contract, and write the output to `stdout`.
 ///
+/// Both the input and output are JSON, so that the process can be driven by hand for debugging.
+///
 pub fn run() -> anyhow::Result<()> {
-    let input_json = std::io::read_to_string(std::io::stdin()).expect("Stdin reading error");
-    let input: Input = era_compiler_common::deserialize_from_str(input_json.as_str())
+    let mut input_json = Vec::new();
+    std::io::stdin()
+        .read_to_end(&mut input_json)
         .expect("Stdin reading error");
 
-    let build = Builder::new()
-        .stack_size(crate::WORKER_THREAD_STACK_SIZE)
-        .spawn(move || {
-            input.contract.into_owned().compile(
-                input.full_path.as_str(),
-                input.metadata_hash,
-                input.append_bytecode_metadata,
-                input.optimizer_settings,
-                input.llvm_options,
-                input.output_selection,
-                input.suppressed_warnings,
-                input.debug_config,
-            )
-        })
-        .expect("Threading error")
-        .join()
-        .expect("Threading error")?;
+    let output_json = compile(input_json, Encoding::Json)?;
     unsafe { inkwell::support::shutdown_llvm() };
 
-    let output = Output::new(build);
-    let output_json = serde_json::to_vec(&output).expect("Always valid");
     std::io::stdout()
         .write_all(output_json.as_slice())
         .expect("Stdout writing error");
@@ -53,59 +69,164 @@ pub fn run() -> anyhow::Result<()> {
 }
 
 ///
-/// Runs this process recursively to compile a single contract.
+/// Read framed inputs from `stdin`, compile the contracts, and write framed outputs to `stdout`.
+///
+/// The first frame is the JSON handshake, which is answered with the handshake of this
+/// executable. The following frames are inputs in the negotiated encoding, and run until `stdin`
+/// is closed. Each output frame starts with the status byte, followed by the encoded output on
+/// success or the encoded error on failure.
+///
+pub fn run_worker() -> anyhow::Result<()> {
+    let Some(handshake) = frame::read(&mut std::io::stdin())? else {
+        return Ok(());
+    };
+    let handshake: Handshake = serde_json::from_slice(handshake.as_slice())
+        .map_err(|error| anyhow::anyhow!("Handshake parsing error: {error}"))?;
+    let own_handshake = Handshake::new(handshake.encoding);
+    frame::write(
+        &mut std::io::stdout().lock(),
+        serde_json::to_vec(&own_handshake)
+            .expect("Always valid")
+            .as_slice(),
+    )?;
+    own_handshake.check(&handshake)?;
+
+    while let Some(input) = frame::read(&mut std::io::stdin())? {
+        let mut output = Vec::new();
+        match compile(input, handshake.encoding) {
+            Ok(encoded_output) => {
+                output.push(Worker::STATUS_SUCCESS);
+                output.extend(encoded_output);
+            }
+            Err(error) => {
+                output.push(Worker::STATUS_FAILURE);
+                let error = match Error::from_anyhow(error) {
+                    Error::Subprocess(error) => Error::Compilation {
+                        message: error.to_string(),
+                    },
+                    error => error,
+                };
+                output.extend(handshake.encoding.encode(&error));
+            }
+        }
+        frame::write(&mut std::io::stdout().lock(), output.as_slice())?;
+    }
+    unsafe { inkwell::support::shutdown_llvm() };
+
+    Ok(())
+}
+
+///
+/// Decodes the input, compiles the contract, and encodes the output.
+///
+/// Runs on a thread with a large stack, as both decoding and compilation of deep LLL IR trees
+/// are recursive.
+///
+pub fn compile(input: Vec<u8>, encoding: Encoding) -> anyhow::Result<Vec<u8>> {
+    Builder::new()
+        .stack_size(crate::WORKER_THREAD_STACK_SIZE)
+        .spawn(move || -> anyhow::Result<Vec<u8>> {
+            let input: Input = encoding
+                .decode(input.as_slice())
+                .map_err(|error| anyhow::anyhow!("Stdin reading error: {error}"))?;
+            let output = match input.target {
+                era_compiler_common::Target::EraVM => encoding.encode(&Output::compile(input)?),
+                era_compiler_common::Target::EVM => encoding.encode(&EVMOutput::compile(input)?),
+            };
+            Ok(output)
+        })
+        .expect("Threading error")
+        .join()
+        .expect("Threading error")
+}
+
+///
+/// Compiles a single contract on a worker process of this executable.
+///
+/// If the cache is enabled, the output of an identical input is reused instead. The input is
+/// the `vyper` output, so only the LLVM stage is skipped on a hit. Only successfully decoded
+/// outputs are cached, so neither failures nor malformed worker responses are ever reused.
 ///
 pub fn call<I, O>(path: &str, input: I) -> anyhow::Result<O>
 where
     I: serde::Serialize,
-    O: serde::de::DeserializeOwned,
+    O: serde::Serialize + serde::de::DeserializeOwned,
 {
-    let executable = EXECUTABLE
+    let encoding = IPC_ENCODING.get().copied().unwrap_or_default();
+    let stdin_input = encoding.encode(&input);
+
+    let cache = CACHE
         .get()
-        .cloned()
-        .unwrap_or_else(|| std::env::current_exe().expect("Current executable path getting error"));
-
-    let mut command = Command::new(executable.as_path());
-    command.stdin(std::process::Stdio::piped());
-    command.stdout(std::process::Stdio::piped());
-    command.stderr(std::process::Stdio::piped());
-    command.arg("--recursive-process");
-
-    let mut process = command
-        .spawn()
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess spawning: {error:?}"));
-
-    let stdin = process
-        .stdin
-        .as_mut()
-        .unwrap_or_else(|| panic!("{executable:?} subprocess stdin getting error"));
-    let stdin_input = serde_json::to_vec(&input).expect("Always valid");
-    stdin
-        .write_all(stdin_input.as_slice())
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess stdin writing: {error:?}",));
-
-    let result = process
-        .wait_with_output()
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess output reading: {error:?}"));
-
-    if result.status.code() != Some(era_compiler_common::EXIT_CODE_SUCCESS) {
-        anyhow::bail!(
-            "{executable:?} subprocess compiling `{path}` failed with exit code {:?}:\n{}\n{}",
-            result.status.code(),
-            String::from_utf8_lossy(result.stdout.as_slice()),
-            String::from_utf8_lossy(result.stderr.as_slice()),
-        );
+        .map(|cache| (cache, Cache::key(stdin_input.as_slice())));
+    if let Some((cache, key)) = cache.as_ref() {
+        if let Some(output) = cache
+            .get(key.as_str())
+            .and_then(|output| encoding.decode::<O>(output.as_slice()).ok())
+        {
+            return Ok(output);
+        }
     }
 
-    let output = match era_compiler_common::deserialize_from_slice::<O>(result.stdout.as_slice()) {
-        Ok(output) => output,
+    let pool = match POOL.get() {
+        Some(pool) => pool,
+        None => {
+            let executable = match EXECUTABLE.get() {
+                Some(executable) => executable.to_owned(),
+                None => std::env::current_exe().map_err(|error| {
+                    anyhow::anyhow!("Current executable path getting error: {error}")
+                })?,
+            };
+            POOL.get_or_init(|| Pool::new(executable, rayon::current_num_threads(), encoding))
+        }
+    };
+    let stdout = match pool.call(path, stdin_input.as_slice()) {
+        Ok(stdout) => stdout,
         Err(error) => {
-            panic!(
-                "{executable:?} subprocess stdout parsing error: {error:?}\n{}\n{}",
-                String::from_utf8_lossy(result.stdout.as_slice()),
-                String::from_utf8_lossy(result.stderr.as_slice()),
-            );
+            write_reproducer(path, &input, &error);
+            return Err(error);
         }
     };
+
+    let output = encoding.decode::<O>(stdout.as_slice()).map_err(|error| {
+        SupervisorError::new(
+            pool.executable.to_string_lossy().to_string(),
+            SupervisorErrorKind::Protocol(error.to_string()),
+            String::new(),
+        )
+    })?;
+
+    if let Some((cache, key)) = cache {
+        if let Err(error) = cache.put(key.as_str(), encoding.encode(&output).as_slice()) {
+            eprintln!("Warning: {error}");
+        }
+    }
+
     Ok(output)
 }
+
+///
+/// Writes the reproducer bundle of the failed compilation, if enabled.
+///
+/// The bundle path is reported on `stderr`, leaving the compilation error itself intact.
+///
+fn write_reproducer<I>(path: &str, input: &I, error: &anyhow::Error)
+where
+    I: serde::Serialize,
+{
+    let Some(directory) = REPRODUCER_DIR.get() else {
+        return;
+    };
+
+    let reproducer = Reproducer::new(
+        path.to_owned(),
+        error.to_string(),
+        serde_json::to_value(input).expect("Always valid"),
+    );
+    match reproducer.write(directory.as_path()) {
+        Ok(bundle_path) => eprintln!(
+            "The reproducer of `{path}` has been written to {bundle_path:?}. Attach it to bug reports, and replay it with `zkvyper --replay {}`.",
+            bundle_path.to_string_lossy(),
+        ),
+        Err(error) => eprintln!("Warning: {error}"),
+    }
+}
@@ -5,6 +5,8 @@
 //!
 
 use crate::build::contract::Contract as ContractBuild;
+use crate::process::input::Input;
+use crate::process::strategy::Compile;
 
 ///
 /// The output data.
@@ -23,3 +25,19 @@ impl Output {
         Self { build }
     }
 }
+
+impl Compile for Output {
+    fn compile(input: Input) -> anyhow::Result<Self> {
+        let build = input.contract.into_owned().compile(
+            input.full_path.as_str(),
+            input.metadata_hash,
+            input.append_bytecode_metadata,
+            input.optimizer_settings,
+            input.llvm_options,
+            input.output_selection,
+            input.suppressed_warnings,
+            input.debug_config,
+        )?;
+        Ok(Self::new(build))
+    }
+}
@@ -0,0 +1,42 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The EVM output data.
+//!
+
+use crate::build_evm::contract::Contract as EVMContractBuild;
+use crate::process::input::Input;
+use crate::process::strategy::Compile;
+
+///
+/// The EVM output data.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Output {
+    /// The contract build.
+    pub build: EVMContractBuild,
+}
+
+impl Output {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(build: EVMContractBuild) -> Self {
+        Self { build }
+    }
+}
+
+impl Compile for Output {
+    fn compile(input: Input) -> anyhow::Result<Self> {
+        let build = input.contract.into_owned().compile_to_evm(
+            input.full_path.as_str(),
+            input.metadata_hash,
+            input.optimizer_settings,
+            input.llvm_options,
+            input.output_selection,
+            input.suppressed_warnings,
+            input.debug_config,
+        )?;
+        Ok(Self::new(build))
+    }
+}
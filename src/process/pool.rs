@@ -0,0 +1,126 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The bounded pool of long-lived worker processes.
+//!
+
+use std::path::PathBuf;
+use std::sync::Condvar;
+use std::sync::Mutex;
+
+use crate::error::Error;
+use crate::supervisor::error::Error as SupervisorError;
+use crate::supervisor::error::ErrorKind as SupervisorErrorKind;
+
+use super::encoding::Encoding;
+use super::worker::Worker;
+
+///
+/// The bounded pool of long-lived worker processes.
+///
+/// Workers are spawned on demand up to the capacity and reused afterwards, so LLVM is
+/// initialized once per worker instead of once per contract. A worker that dies is discarded,
+/// and a new one is spawned in its place by the next request.
+///
+#[derive(Debug)]
+pub struct Pool {
+    /// The worker executable.
+    pub executable: PathBuf,
+    /// The maximum number of workers.
+    capacity: usize,
+    /// The encoding negotiated with the workers.
+    encoding: Encoding,
+    /// The idle workers and the number of workers alive.
+    state: Mutex<(Vec<Worker>, usize)>,
+    /// Signals that a worker has been released.
+    released: Condvar,
+}
+
+impl Pool {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(executable: PathBuf, capacity: usize, encoding: Encoding) -> Self {
+        Self {
+            executable,
+            capacity: capacity.max(1),
+            encoding,
+            state: Mutex::new((Vec::with_capacity(capacity), 0)),
+            released: Condvar::new(),
+        }
+    }
+
+    ///
+    /// Compiles the input of the contract at `path` on a worker, returning the output.
+    ///
+    /// If the compilation fails, the error is the typed `Error` sent by the worker. If the worker
+    /// crashes or times out, the error is a `SupervisorError`, and the worker is replaced before
+    /// the next compilation.
+    ///
+    pub fn call(&self, path: &str, input: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let mut worker = self.acquire()?;
+        match worker.call(input) {
+            Ok(Ok(output)) => {
+                self.release(Some(worker));
+                Ok(output)
+            }
+            Ok(Err(error)) => {
+                let stderr = worker.stderr();
+                self.release(Some(worker));
+                match self.encoding.decode::<Error>(error.as_slice()) {
+                    Ok(Error::Compilation { message }) if !stderr.trim().is_empty() => {
+                        Err(Error::Compilation {
+                            message: format!("{message}\n{}", stderr.trim_end()),
+                        }
+                        .into())
+                    }
+                    Ok(error) => Err(error.into()),
+                    Err(error) => Err(SupervisorError::new(
+                        self.executable.to_string_lossy().to_string(),
+                        SupervisorErrorKind::Protocol(format!("compiling `{path}`: {error}")),
+                        stderr,
+                    )
+                    .into()),
+                }
+            }
+            Err(error) => {
+                let failure = worker.terminate(error);
+                self.release(None);
+                Err(failure.into())
+            }
+        }
+    }
+
+    ///
+    /// Takes an idle worker, spawns a new one if the capacity allows, or waits for a release.
+    ///
+    fn acquire(&self) -> anyhow::Result<Worker> {
+        let mut state = self.state.lock().expect("Sync");
+        loop {
+            let (idle, alive) = &mut *state;
+            if let Some(worker) = idle.pop() {
+                return Ok(worker);
+            }
+            if *alive < self.capacity {
+                *alive += 1;
+                drop(state);
+                return Worker::spawn(self.executable.as_path(), self.encoding).inspect_err(|_| {
+                    self.release(None);
+                });
+            }
+            state = self.released.wait(state).expect("Sync");
+        }
+    }
+
+    ///
+    /// Returns the worker to the pool, or frees its slot if it has been terminated.
+    ///
+    fn release(&self, worker: Option<Worker>) {
+        let mut state = self.state.lock().expect("Sync");
+        match worker {
+            Some(worker) => state.0.push(worker),
+            None => state.1 -= 1,
+        }
+        self.released.notify_one();
+    }
+}
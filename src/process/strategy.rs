@@ -0,0 +1,66 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The execution strategy.
+//!
+
+use std::thread::Builder;
+
+use crate::process::input::Input;
+
+///
+/// The execution strategy of single contract compilations.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Compile on worker processes of the `zkvyper` executable.
+    ///
+    /// Isolates crashes of LLVM and enables the compilation cache, but requires the executable,
+    /// which is resolved from `process::EXECUTABLE` or the current executable.
+    #[default]
+    Subprocess,
+    /// Compile on a dedicated large-stack thread of this process.
+    ///
+    /// Does not require the `zkvyper` executable, which suits embedding the compiler as a library.
+    /// LLVM must be initialized with `era_compiler_llvm_context::initialize_target` beforehand.
+    InProcess,
+}
+
+impl Strategy {
+    ///
+    /// Compiles a single contract with the strategy.
+    ///
+    pub fn call<O>(self, path: &str, input: Input) -> anyhow::Result<O>
+    where
+        O: Compile,
+    {
+        match self {
+            Self::Subprocess => crate::process::call(path, input),
+            Self::InProcess => std::thread::scope(|scope| {
+                Builder::new()
+                    .stack_size(crate::WORKER_THREAD_STACK_SIZE)
+                    .spawn_scoped(scope, move || O::compile(input))
+                    .expect("Threading error")
+                    .join()
+                    .unwrap_or_else(|payload| {
+                        let message = payload
+                            .downcast_ref::<&str>()
+                            .map(|message| message.to_string())
+                            .or_else(|| payload.downcast_ref::<String>().cloned())
+                            .unwrap_or_else(|| "unknown error".to_owned());
+                        Err(anyhow::anyhow!("The compilation has panicked: {message}"))
+                    })
+            }),
+        }
+    }
+}
+
+///
+/// The output of a single contract compilation.
+///
+pub trait Compile: serde::Serialize + serde::de::DeserializeOwned + Send {
+    ///
+    /// Compiles the contract on the current thread.
+    ///
+    fn compile(input: Input) -> anyhow::Result<Self>;
+}
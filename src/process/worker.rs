@@ -0,0 +1,228 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The long-lived worker process.
+//!
+
+use std::io::Read;
+use std::path::Path;
+use std::process::Child;
+use std::process::ChildStdin;
+use std::process::ChildStdout;
+use std::process::Command;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+
+use crate::supervisor::error::Error as SupervisorError;
+use crate::supervisor::error::ErrorKind as SupervisorErrorKind;
+use crate::supervisor::limits::Limits;
+use crate::supervisor::watchdog::Watchdog;
+
+use super::encoding::Encoding;
+use super::frame;
+use super::handshake::Handshake;
+
+///
+/// The long-lived worker process.
+///
+/// Runs `--recursive-worker` and compiles one framed input at a time, after the handshake
+/// checking that it speaks the same protocol. The worker's `stderr` is
+/// drained in the background, so that it can be reported if a compilation fails.
+///
+/// The worker runs under the process-wide limits: the memory limit applies to the whole worker,
+/// and the timeout to each compilation.
+///
+#[derive(Debug)]
+pub struct Worker {
+    /// The worker executable.
+    executable: String,
+    /// The worker process, shared with the watchdog.
+    process: Arc<Mutex<Child>>,
+    /// The resource limits.
+    limits: Limits,
+    /// Whether the last compilation has been killed on timeout.
+    is_timed_out: bool,
+    /// The worker input stream.
+    stdin: Option<ChildStdin>,
+    /// The worker output stream.
+    stdout: ChildStdout,
+    /// The `stderr` written by the worker since the current request started.
+    stderr: Arc<Mutex<Vec<u8>>>,
+    /// The thread draining `stderr`.
+    stderr_reader: Option<JoinHandle<()>>,
+}
+
+impl Worker {
+    /// The response status of a successful compilation.
+    pub const STATUS_SUCCESS: u8 = 0;
+
+    /// The response status of a failed compilation.
+    pub const STATUS_FAILURE: u8 = 1;
+
+    ///
+    /// Spawns the worker process and performs the handshake.
+    ///
+    pub fn spawn(executable: &Path, encoding: Encoding) -> anyhow::Result<Self> {
+        let limits = Limits::global();
+        let mut command = Command::new(executable);
+        command
+            .arg("--recursive-worker")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+        limits.apply(&mut command);
+        let mut process = command.spawn().map_err(|error| {
+            SupervisorError::new(
+                executable.to_string_lossy().to_string(),
+                SupervisorErrorKind::Spawn(error.to_string()),
+                String::new(),
+            )
+        })?;
+
+        let stdin = process.stdin.take().expect("Always exists");
+        let stdout = process.stdout.take().expect("Always exists");
+        let mut stderr_stream = process.stderr.take().expect("Always exists");
+
+        let stderr = Arc::new(Mutex::new(Vec::new()));
+        let stderr_buffer = stderr.clone();
+        let stderr_reader = std::thread::spawn(move || {
+            let mut buffer = [0u8; 4096];
+            while let Ok(size) = stderr_stream.read(&mut buffer) {
+                if size == 0 {
+                    break;
+                }
+                stderr_buffer
+                    .lock()
+                    .expect("Sync")
+                    .extend_from_slice(&buffer[..size]);
+            }
+        });
+
+        let mut worker = Self {
+            executable: executable.to_string_lossy().to_string(),
+            process: Arc::new(Mutex::new(process)),
+            limits,
+            is_timed_out: false,
+            stdin: Some(stdin),
+            stdout,
+            stderr,
+            stderr_reader: Some(stderr_reader),
+        };
+        if let Err(error) = worker.handshake(encoding) {
+            let failure = worker.terminate(std::io::Error::other(error.to_string()));
+            anyhow::bail!("Worker handshake failed: {failure}");
+        }
+        Ok(worker)
+    }
+
+    ///
+    /// Sends the input to the worker and waits for the response.
+    ///
+    /// Returns the encoded output or the encoded compilation error, or an I/O error if the worker
+    /// has died or has been killed on timeout. A dead worker must be passed to `terminate` and
+    /// not reused.
+    ///
+    pub fn call(&mut self, input: &[u8]) -> std::io::Result<Result<Vec<u8>, Vec<u8>>> {
+        self.stderr.lock().expect("Sync").clear();
+
+        let watchdog = self
+            .limits
+            .timeout
+            .map(|timeout| Watchdog::start(self.process.clone(), timeout));
+        let response = self.exchange(input);
+        self.is_timed_out = watchdog.map(Watchdog::stop).unwrap_or_default();
+        let mut response = response?;
+
+        if response.is_empty() {
+            return Err(std::io::ErrorKind::InvalidData.into());
+        }
+        let body = response.split_off(1);
+        match response[0] {
+            Self::STATUS_SUCCESS => Ok(Ok(body)),
+            Self::STATUS_FAILURE => Ok(Err(body)),
+            _ => Err(std::io::ErrorKind::InvalidData.into()),
+        }
+    }
+
+    ///
+    /// Writes the input frame and reads the response frame.
+    ///
+    fn exchange(&mut self, input: &[u8]) -> std::io::Result<Vec<u8>> {
+        let stdin = self.stdin.as_mut().ok_or(std::io::ErrorKind::BrokenPipe)?;
+        frame::write(stdin, input)?;
+        frame::read(&mut self.stdout)?.ok_or_else(|| std::io::ErrorKind::UnexpectedEof.into())
+    }
+
+    ///
+    /// Sends the handshake of this executable and checks the one of the worker.
+    ///
+    fn handshake(&mut self, encoding: Encoding) -> anyhow::Result<()> {
+        let handshake = Handshake::new(encoding);
+        let stdin = self.stdin.as_mut().expect("Always exists");
+        frame::write(
+            stdin,
+            serde_json::to_vec(&handshake)
+                .expect("Always valid")
+                .as_slice(),
+        )?;
+
+        let response = frame::read(&mut self.stdout)?
+            .ok_or_else(|| anyhow::anyhow!("The worker exited before the handshake"))?;
+        let worker_handshake: Handshake = serde_json::from_slice(response.as_slice())
+            .map_err(|error| anyhow::anyhow!("Handshake parsing error: {error}"))?;
+        handshake.check(&worker_handshake)
+    }
+
+    ///
+    /// Stops the worker after the `error`, returning the failure with the `stderr` of the last
+    /// request.
+    ///
+    /// Malformed responses, such as oversized frames, are reported as protocol errors rather
+    /// than as the termination they cause.
+    ///
+    pub fn terminate(mut self, error: std::io::Error) -> SupervisorError {
+        self.stdin.take();
+        let status = {
+            let mut process = self.process.lock().expect("Sync");
+            if let Ok(None) = process.try_wait() {
+                let _ = process.kill();
+            }
+            process.wait()
+        };
+        if let Some(stderr_reader) = self.stderr_reader.take() {
+            let _ = stderr_reader.join();
+        }
+
+        let stderr = self.stderr();
+        let timeout = self.limits.timeout.filter(|_| self.is_timed_out);
+        let kind = match status {
+            _ if error.kind() == std::io::ErrorKind::InvalidData => {
+                SupervisorErrorKind::Protocol(error.to_string())
+            }
+            Ok(status) => match crate::supervisor::failure(
+                self.executable.as_str(),
+                status,
+                stderr.clone(),
+                timeout,
+            ) {
+                Some(failure) => return failure,
+                None => match status.code() {
+                    Some(code) if code != era_compiler_common::EXIT_CODE_SUCCESS => {
+                        SupervisorErrorKind::Exit(Some(code))
+                    }
+                    _ => SupervisorErrorKind::Io(error.to_string()),
+                },
+            },
+            Err(status_error) => SupervisorErrorKind::Io(format!("{error}; {status_error}")),
+        };
+        SupervisorError::new(self.executable, kind, stderr)
+    }
+
+    ///
+    /// Returns the `stderr` written since the current request started.
+    ///
+    pub fn stderr(&self) -> String {
+        String::from_utf8_lossy(self.stderr.lock().expect("Sync").as_slice()).to_string()
+    }
+}
@@ -2,6 +2,13 @@
 //! The Vyper contract metadata.
 //!
 
+use std::collections::BTreeMap;
+
+use crate::optimization_override::OptimizationOverride;
+use crate::tuning::Tuning;
+use crate::vyper::settings::Settings as VyperSettings;
+use crate::warning_type::WarningType;
+
 ///
 /// The Vyper contract metadata.
 ///
@@ -21,6 +28,23 @@ pub struct Metadata<'a> {
     pub optimizer_settings: String,
     /// The LLVM extra arguments.
     pub llvm_options: &'a [String],
+    /// The `vyper` settings, if `vyper` has been used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vyper_settings: Option<&'a VyperSettings>,
+    /// The metadata hash type.
+    pub metadata_hash_type: era_compiler_common::MetadataHashType,
+    /// The suppressed warnings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppressed_warnings: Option<&'a [WarningType]>,
+    /// The per-contract optimization overrides.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimization_overrides: Option<&'a [OptimizationOverride]>,
+    /// The optimization auto-tuning settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tuning: Option<&'a Tuning>,
+    /// The metadata of contracts compiled with overridden settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contracts: Option<&'a BTreeMap<String, serde_json::Value>>,
 }
 
 impl<'a> Metadata<'a> {
@@ -34,6 +58,12 @@ impl<'a> Metadata<'a> {
         zk_version: semver::Version,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: &'a [String],
+        vyper_settings: Option<&'a VyperSettings>,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        suppressed_warnings: &'a [WarningType],
+        optimization_overrides: &'a [OptimizationOverride],
+        tuning: Option<&'a Tuning>,
+        contracts: &'a BTreeMap<String, serde_json::Value>,
     ) -> Self {
         Self {
             source_code_hash,
@@ -42,6 +72,13 @@ impl<'a> Metadata<'a> {
             zk_version,
             optimizer_settings: optimizer_settings.to_string(),
             llvm_options,
+            vyper_settings,
+            metadata_hash_type,
+            suppressed_warnings: (!suppressed_warnings.is_empty()).then_some(suppressed_warnings),
+            optimization_overrides: (!optimization_overrides.is_empty())
+                .then_some(optimization_overrides),
+            tuning,
+            contracts: (!contracts.is_empty()).then_some(contracts),
         }
     }
 }
@@ -8,6 +8,8 @@ pub mod metadata;
 pub mod vyper;
 
 use crate::build::contract::Contract as ContractBuild;
+use crate::build_evm::contract::Contract as EVMContractBuild;
+use crate::error::Error;
 use crate::vyper::selector::Selector as VyperSelector;
 use crate::warning_type::WarningType;
 
@@ -61,7 +63,7 @@ impl Contract {
         suppressed_warnings: Vec<WarningType>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<ContractBuild> {
-        match self {
+        let build = match self {
             Self::Vyper(inner) => inner.compile(
                 contract_path,
                 metadata_hash,
@@ -92,6 +94,52 @@ impl Contract {
                 suppressed_warnings,
                 debug_config,
             ),
+        }?;
+
+        let size = build.build.bytecode.len();
+        let limit = crate::r#const::ERAVM_BYTECODE_SIZE_LIMIT;
+        if size > limit {
+            return Err(Error::SizeLimit {
+                size,
+                limit,
+                message: format!(
+                    "Bytecode size of {size} bytes exceeds the EraVM limit of {limit} bytes"
+                ),
+            }
+            .into());
+        }
+        Ok(build)
+    }
+
+    ///
+    /// Compiles the contract to EVM, returning the build.
+    ///
+    pub fn compile_to_evm(
+        self,
+        contract_path: &str,
+        metadata_hash: Option<era_compiler_common::Hash>,
+        optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+        llvm_options: Vec<String>,
+        output_selection: Vec<VyperSelector>,
+        suppressed_warnings: Vec<WarningType>,
+        debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    ) -> anyhow::Result<EVMContractBuild> {
+        match self {
+            Self::Vyper(inner) => inner.compile_to_evm(
+                contract_path,
+                metadata_hash,
+                optimizer_settings,
+                llvm_options,
+                output_selection,
+                suppressed_warnings,
+                debug_config,
+            ),
+            Self::LLVMIR(_) => {
+                anyhow::bail!("LLVM IR contracts cannot be compiled for the EVM target")
+            }
+            Self::EraVMAssembly(_) => {
+                anyhow::bail!("EraVM assembly contracts cannot be compiled for the EVM target")
+            }
         }
     }
 
@@ -111,9 +159,7 @@ impl Contract {
     ///
     pub fn ir_string(&self) -> Option<String> {
         match self {
-            Self::Vyper(inner) => {
-                Some(serde_json::to_string_pretty(&inner.ir).expect("Always valid"))
-            }
+            Self::Vyper(inner) => Some(inner.ir.to_string()),
             _ => None,
         }
     }
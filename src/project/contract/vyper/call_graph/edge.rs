@@ -0,0 +1,89 @@
+//!
+//! The call graph edge.
+//!
+
+///
+/// The call graph edge.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct Edge {
+    /// The caller node identifier.
+    pub from: String,
+    /// The callee node identifier.
+    pub to: String,
+    /// The edge kind.
+    pub kind: Kind,
+    /// The number of call sites.
+    pub count: usize,
+}
+
+///
+/// The call graph edge kind.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    /// The LLL `goto` to another function.
+    Goto,
+    /// The `CALL` opcode.
+    Call,
+    /// The `STATICCALL` opcode.
+    StaticCall,
+    /// The `DELEGATECALL` opcode.
+    DelegateCall,
+    /// The `CREATE` opcode.
+    Create,
+    /// The `CREATE2` opcode.
+    Create2,
+    /// The `LOG0` opcode.
+    Log0,
+    /// The `LOG1` opcode.
+    Log1,
+    /// The `LOG2` opcode.
+    Log2,
+    /// The `LOG3` opcode.
+    Log3,
+    /// The `LOG4` opcode.
+    Log4,
+}
+
+impl Kind {
+    ///
+    /// Returns the opcode edge kind by the LLL instruction name.
+    ///
+    pub fn from_opcode(name: &str) -> Option<Self> {
+        Some(match name {
+            "call" => Self::Call,
+            "staticcall" => Self::StaticCall,
+            "delegatecall" => Self::DelegateCall,
+            "create" => Self::Create,
+            "create2" => Self::Create2,
+            "log0" => Self::Log0,
+            "log1" => Self::Log1,
+            "log2" => Self::Log2,
+            "log3" => Self::Log3,
+            "log4" => Self::Log4,
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Goto => write!(f, "goto"),
+            Self::Call => write!(f, "CALL"),
+            Self::StaticCall => write!(f, "STATICCALL"),
+            Self::DelegateCall => write!(f, "DELEGATECALL"),
+            Self::Create => write!(f, "CREATE"),
+            Self::Create2 => write!(f, "CREATE2"),
+            Self::Log0 => write!(f, "LOG0"),
+            Self::Log1 => write!(f, "LOG1"),
+            Self::Log2 => write!(f, "LOG2"),
+            Self::Log3 => write!(f, "LOG3"),
+            Self::Log4 => write!(f, "LOG4"),
+        }
+    }
+}
@@ -0,0 +1,395 @@
+//!
+//! The Vyper contract call graph.
+//!
+
+pub mod edge;
+pub mod node;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use crate::project::contract::vyper::expression::Expression;
+
+use self::edge::Edge;
+use self::edge::Kind as EdgeKind;
+use self::node::CodeSegment;
+use self::node::Kind as NodeKind;
+use self::node::Node;
+
+///
+/// The Vyper contract call graph.
+///
+/// Nodes are the deploy and runtime code entries, `external` and `internal` functions,
+/// and sinks of EVM opcodes that interact with the outside world.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CallGraph {
+    /// The graph nodes.
+    pub nodes: Vec<Node>,
+    /// The graph edges.
+    pub edges: Vec<Edge>,
+}
+
+///
+/// The data collected from a function or entry body.
+///
+#[derive(Debug)]
+struct Scope {
+    /// The node identifier.
+    id: String,
+    /// The LLL label name.
+    name: String,
+    /// The node kind.
+    kind: NodeKind,
+    /// The code segment.
+    code_segment: CodeSegment,
+    /// The `goto` targets.
+    gotos: Vec<String>,
+    /// The opcode calls.
+    opcodes: Vec<EdgeKind>,
+    /// The labels defined in the body.
+    labels: Vec<String>,
+}
+
+impl Scope {
+    ///
+    /// Creates the scope, collecting the data from the LLL expression.
+    ///
+    pub fn new(
+        name: String,
+        kind: NodeKind,
+        code_segment: CodeSegment,
+        body: &serde_json::Value,
+    ) -> Self {
+        let mut scope = Self {
+            id: name.clone(),
+            name,
+            kind,
+            code_segment,
+            gotos: vec![],
+            opcodes: vec![],
+            labels: vec![],
+        };
+        scope.collect(body);
+        scope
+    }
+
+    ///
+    /// Recursively collects the `goto` targets, opcode calls, and labels.
+    ///
+    fn collect(&mut self, value: &serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (name, arguments) in map.iter() {
+                    let first = arguments
+                        .as_array()
+                        .and_then(|arguments| arguments.first())
+                        .and_then(|argument| argument.as_str())
+                        .map(|argument| argument.to_owned());
+                    match name.as_str() {
+                        "goto" => self.gotos.extend(first),
+                        "label" => self.labels.extend(first),
+                        name => self.opcodes.extend(EdgeKind::from_opcode(name)),
+                    }
+                    self.collect(arguments);
+                }
+            }
+            serde_json::Value::Array(values) => {
+                for value in values.iter() {
+                    self.collect(value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl CallGraph {
+    ///
+    /// Builds the call graph from the contract LLL IR.
+    ///
+    pub fn try_from_ir(ir: &Expression) -> anyhow::Result<Self> {
+        let mut ir = ir.clone();
+        let (runtime_code, _immutables_size) = ir.extract_runtime_code()?.unwrap_or_default();
+        let deploy_code = ir.try_into_deploy_code()?;
+
+        let mut scopes = Vec::new();
+        for (mut code, entry, code_segment) in [
+            (
+                deploy_code,
+                era_compiler_llvm_context::EraVMRuntime::FUNCTION_DEPLOY_CODE,
+                CodeSegment::Deploy,
+            ),
+            (
+                runtime_code,
+                era_compiler_llvm_context::EraVMRuntime::FUNCTION_RUNTIME_CODE,
+                CodeSegment::Runtime,
+            ),
+        ] {
+            let functions = code.extract_functions()?;
+            scopes.push(Scope::new(
+                entry.to_owned(),
+                NodeKind::Entry,
+                code_segment,
+                &serde_json::to_value(&code)?,
+            ));
+            for (name, expression) in functions.into_iter() {
+                let kind = if name.starts_with(crate::r#const::FUNCTION_PREFIX_EXTERNAL) {
+                    NodeKind::External
+                } else {
+                    NodeKind::Internal
+                };
+                scopes.push(Scope::new(
+                    name,
+                    kind,
+                    code_segment,
+                    &serde_json::to_value(&expression)?,
+                ));
+            }
+        }
+
+        let runtime_names: HashSet<String> = scopes
+            .iter()
+            .filter(|scope| scope.code_segment == CodeSegment::Runtime)
+            .map(|scope| scope.name.clone())
+            .collect();
+        for scope in scopes.iter_mut() {
+            if scope.code_segment == CodeSegment::Deploy && runtime_names.contains(&scope.name) {
+                scope.id = format!("{} (deploy)", scope.name);
+            }
+        }
+
+        Ok(Self::from_scopes(scopes))
+    }
+
+    ///
+    /// Returns the graph in the DOT format.
+    ///
+    pub fn to_dot(&self, name: &str) -> String {
+        let mut dot = format!("digraph \"{}\" {{\n", Self::escape(name));
+        for node in self.nodes.iter() {
+            let mut attributes = vec![format!(
+                "shape={}",
+                match node.kind {
+                    NodeKind::Entry => "doubleoctagon",
+                    NodeKind::External => "box",
+                    NodeKind::Internal => "ellipse",
+                    NodeKind::Opcode => "diamond",
+                }
+            )];
+            if node.recursive {
+                attributes.push("color=red".to_owned());
+            }
+            if !node.reachable {
+                attributes.push("style=dashed".to_owned());
+            }
+            dot.push_str(
+                format!(
+                    "    \"{}\" [{}];\n",
+                    Self::escape(node.id.as_str()),
+                    attributes.join(", ")
+                )
+                .as_str(),
+            );
+        }
+        for edge in self.edges.iter() {
+            let label = if edge.count > 1 {
+                format!("{} x{}", edge.kind, edge.count)
+            } else {
+                edge.kind.to_string()
+            };
+            dot.push_str(
+                format!(
+                    "    \"{}\" -> \"{}\" [label=\"{label}\"];\n",
+                    Self::escape(edge.from.as_str()),
+                    Self::escape(edge.to.as_str()),
+                )
+                .as_str(),
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    ///
+    /// Builds the graph from the collected scopes.
+    ///
+    fn from_scopes(scopes: Vec<Scope>) -> Self {
+        let mut functions: HashMap<(CodeSegment, &str), &str> = HashMap::new();
+        let mut label_owners: HashMap<(CodeSegment, &str), &str> = HashMap::new();
+        for scope in scopes.iter() {
+            functions.insert((scope.code_segment, scope.name.as_str()), scope.id.as_str());
+            for label in scope.labels.iter() {
+                label_owners.insert((scope.code_segment, label.as_str()), scope.id.as_str());
+            }
+        }
+
+        let mut edges: BTreeMap<(String, String, EdgeKind), usize> = BTreeMap::new();
+        let mut opcodes: BTreeMap<String, Node> = BTreeMap::new();
+        for scope in scopes.iter() {
+            for target in scope.gotos.iter() {
+                if target.ends_with(crate::r#const::LABEL_SUFFIX_CLEANUP)
+                    || target.as_str() == crate::r#const::FUNCTION_IDENTIFIER_FALLBACK
+                {
+                    continue;
+                }
+
+                let key = (scope.code_segment, target.as_str());
+                let callee = match (functions.get(&key), label_owners.get(&key)) {
+                    (Some(callee), _) => *callee,
+                    (None, Some(owner)) if *owner != scope.id.as_str() => *owner,
+                    _ => continue,
+                };
+                *edges
+                    .entry((scope.id.clone(), callee.to_owned(), EdgeKind::Goto))
+                    .or_default() += 1;
+            }
+            for kind in scope.opcodes.iter().copied() {
+                let opcode = kind.to_string();
+                opcodes
+                    .entry(opcode.clone())
+                    .or_insert_with(|| Node::new_opcode(opcode.clone()));
+                *edges.entry((scope.id.clone(), opcode, kind)).or_default() += 1;
+            }
+        }
+
+        let mut nodes: Vec<Node> = scopes
+            .iter()
+            .map(|scope| Node::new_function(scope.id.clone(), scope.kind, scope.code_segment))
+            .collect();
+        let edges: Vec<Edge> = edges
+            .into_iter()
+            .map(|((from, to, kind), count)| Edge {
+                from,
+                to,
+                kind,
+                count,
+            })
+            .collect();
+
+        let indexes: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.id.as_str(), index))
+            .collect();
+        let mut successors = vec![vec![]; nodes.len()];
+        for edge in edges.iter().filter(|edge| edge.kind == EdgeKind::Goto) {
+            if let (Some(from), Some(to)) = (
+                indexes.get(edge.from.as_str()),
+                indexes.get(edge.to.as_str()),
+            ) {
+                successors[*from].push(*to);
+            }
+        }
+
+        let reachable = Self::reachable(&nodes, successors.as_slice());
+        let recursive = Self::recursive(successors.as_slice());
+        for (index, node) in nodes.iter_mut().enumerate() {
+            node.reachable = reachable[index];
+            node.recursive = recursive[index];
+        }
+        nodes.extend(opcodes.into_values());
+
+        Self { nodes, edges }
+    }
+
+    ///
+    /// Returns the flags of nodes reachable from the entries.
+    ///
+    fn reachable(nodes: &[Node], successors: &[Vec<usize>]) -> Vec<bool> {
+        let mut reachable = vec![false; nodes.len()];
+        let mut queue: VecDeque<usize> = nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.kind == NodeKind::Entry)
+            .map(|(index, _)| index)
+            .collect();
+        for index in queue.iter().copied() {
+            reachable[index] = true;
+        }
+        while let Some(index) = queue.pop_front() {
+            for successor in successors[index].iter().copied() {
+                if !reachable[successor] {
+                    reachable[successor] = true;
+                    queue.push_back(successor);
+                }
+            }
+        }
+        reachable
+    }
+
+    ///
+    /// Returns the flags of nodes that are parts of call cycles, using Tarjan's algorithm.
+    ///
+    fn recursive(successors: &[Vec<usize>]) -> Vec<bool> {
+        struct State {
+            index: usize,
+            indexes: Vec<Option<usize>>,
+            lowlinks: Vec<usize>,
+            stack: Vec<usize>,
+            on_stack: Vec<bool>,
+            recursive: Vec<bool>,
+        }
+
+        fn connect(node: usize, successors: &[Vec<usize>], state: &mut State) {
+            state.indexes[node] = Some(state.index);
+            state.lowlinks[node] = state.index;
+            state.index += 1;
+            state.stack.push(node);
+            state.on_stack[node] = true;
+
+            for successor in successors[node].iter().copied() {
+                match state.indexes[successor] {
+                    None => {
+                        connect(successor, successors, state);
+                        state.lowlinks[node] = state.lowlinks[node].min(state.lowlinks[successor]);
+                    }
+                    Some(index) if state.on_stack[successor] => {
+                        state.lowlinks[node] = state.lowlinks[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if Some(state.lowlinks[node]) == state.indexes[node] {
+                let mut component = Vec::new();
+                while let Some(member) = state.stack.pop() {
+                    state.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                if component.len() > 1 || successors[node].contains(&node) {
+                    for member in component.into_iter() {
+                        state.recursive[member] = true;
+                    }
+                }
+            }
+        }
+
+        let mut state = State {
+            index: 0,
+            indexes: vec![None; successors.len()],
+            lowlinks: vec![0; successors.len()],
+            stack: vec![],
+            on_stack: vec![false; successors.len()],
+            recursive: vec![false; successors.len()],
+        };
+        for node in 0..successors.len() {
+            if state.indexes[node].is_none() {
+                connect(node, successors, &mut state);
+            }
+        }
+        state.recursive
+    }
+
+    ///
+    /// Escapes the DOT identifier.
+    ///
+    fn escape(identifier: &str) -> String {
+        identifier.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+}
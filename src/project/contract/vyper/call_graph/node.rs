@@ -0,0 +1,77 @@
+//!
+//! The call graph node.
+//!
+
+///
+/// The call graph node.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Node {
+    /// The unique node identifier, which is the LLL label name for functions.
+    pub id: String,
+    /// The node kind.
+    pub kind: Kind,
+    /// The code segment of the function. Is `None` for opcode nodes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_segment: Option<CodeSegment>,
+    /// Whether the function is a part of a recursive call cycle.
+    pub recursive: bool,
+    /// Whether the function is reachable from the deploy or runtime code entry.
+    pub reachable: bool,
+}
+
+///
+/// The call graph node kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    /// The deploy or runtime code entry.
+    Entry,
+    /// An `external` function.
+    External,
+    /// An `internal` function.
+    Internal,
+    /// An EVM opcode sink, such as `CALL` or `LOG1`.
+    Opcode,
+}
+
+///
+/// The code segment of the function.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeSegment {
+    /// The deploy code.
+    Deploy,
+    /// The runtime code.
+    Runtime,
+}
+
+impl Node {
+    ///
+    /// A shortcut constructor for functions and entries.
+    ///
+    pub fn new_function(id: String, kind: Kind, code_segment: CodeSegment) -> Self {
+        Self {
+            id,
+            kind,
+            code_segment: Some(code_segment),
+            recursive: false,
+            reachable: false,
+        }
+    }
+
+    ///
+    /// A shortcut constructor for opcode sinks.
+    ///
+    pub fn new_opcode(id: String) -> Self {
+        Self {
+            id,
+            kind: Kind::Opcode,
+            code_segment: None,
+            recursive: false,
+            reachable: true,
+        }
+    }
+}
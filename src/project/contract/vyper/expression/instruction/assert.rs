@@ -2,32 +2,34 @@
 //! The `assert` instruction.
 //!
 
-use era_compiler_llvm_context::IContext;
-
+use crate::project::contract::vyper::expression::lowering::Lowering;
 use crate::project::contract::vyper::expression::Expression;
 
 ///
 /// The Vyper LLL-specific `assert` instruction.
 ///
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
-pub struct Assert([Box<Expression>; 1]);
+pub struct Assert(pub [Box<Expression>; 1]);
 
 impl Assert {
     ///
     /// Converts the entity to an LLVM value.
     ///
-    pub fn into_llvm_value(
+    pub fn into_llvm_value<'ctx, C>(
         self,
-        context: &mut era_compiler_llvm_context::EraVMContext,
+        context: &mut C,
         is_unreachable: bool,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<()>
+    where
+        C: Lowering<'ctx>,
+    {
         let [condition] = self.0;
 
         let error_block = context.append_basic_block("if_error");
         let join_block = context.append_basic_block("if_join");
 
-        let condition = condition
-            .into_llvm_value(context)?
+        let condition = context
+            .lower_expression(*condition)?
             .expect("Always exists")
             .into_int_value();
         let condition = context.builder().build_int_z_extend_or_bit_cast(
@@ -45,13 +47,9 @@ impl Assert {
 
         context.set_basic_block(error_block);
         if is_unreachable {
-            era_compiler_llvm_context::eravm_evm_return::invalid(context)?;
+            context.lower_invalid()?;
         } else {
-            era_compiler_llvm_context::eravm_evm_return::revert(
-                context,
-                context.field_const(0),
-                context.field_const(0),
-            )?;
+            context.lower_revert(context.field_const(0), context.field_const(0))?;
         }
 
         context.set_basic_block(join_block);
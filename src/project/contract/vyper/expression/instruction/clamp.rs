@@ -4,18 +4,21 @@
 
 use inkwell::values::BasicValue;
 
-use era_compiler_llvm_context::IContext;
+use crate::project::contract::vyper::expression::lowering::Lowering;
 
 ///
 /// Translates the two-sides bounded clamp.
 ///
-pub fn ordinary<'ctx>(
-    context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
+pub fn ordinary<'ctx, C>(
+    context: &mut C,
     operand_1: inkwell::values::IntValue<'ctx>,
     operand_2: inkwell::values::IntValue<'ctx>,
     operand_3: inkwell::values::IntValue<'ctx>,
     is_signed: bool,
-) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>>
+where
+    C: Lowering<'ctx>,
+{
     let error_block = context.append_basic_block("if_error");
     let join_block = context.append_basic_block("if_join");
 
@@ -43,11 +46,7 @@ pub fn ordinary<'ctx>(
     context.build_conditional_branch(condition, join_block, error_block)?;
 
     context.set_basic_block(error_block);
-    era_compiler_llvm_context::eravm_evm_return::revert(
-        context,
-        context.field_const(0),
-        context.field_const(0),
-    )?;
+    context.lower_revert(context.field_const(0), context.field_const(0))?;
 
     context.set_basic_block(join_block);
 
@@ -57,12 +56,15 @@ pub fn ordinary<'ctx>(
 ///
 /// Translates the one-side bounded clamp with predicate.
 ///
-pub fn with_predicate<'ctx>(
-    context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
+pub fn with_predicate<'ctx, C>(
+    context: &mut C,
     operand_1: inkwell::values::IntValue<'ctx>,
     operand_2: inkwell::values::IntValue<'ctx>,
     predicate: inkwell::IntPredicate,
-) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>>
+where
+    C: Lowering<'ctx>,
+{
     let error_block = context.append_basic_block("clamp_single_error");
     let join_block = context.append_basic_block("clamp_single_join");
 
@@ -75,11 +77,7 @@ pub fn with_predicate<'ctx>(
     context.build_conditional_branch(condition, join_block, error_block)?;
 
     context.set_basic_block(error_block);
-    era_compiler_llvm_context::eravm_evm_return::revert(
-        context,
-        context.field_const(0),
-        context.field_const(0),
-    )?;
+    context.lower_revert(context.field_const(0), context.field_const(0))?;
 
     context.set_basic_block(join_block);
 
@@ -0,0 +1,182 @@
+//!
+//! The EVM code layout instructions.
+//!
+//! On EVM, the deploy code copies the runtime code to memory and returns it with the immutables
+//! appended, so the constructor arguments and immutables are addressed relative to the code end.
+//!
+
+use era_compiler_llvm_context::IContext;
+
+/// The deploy code memory size global variable name.
+pub const GLOBAL_DEPLOY_MEMORY_SIZE: &str = "vyper_deploy_memory_size";
+
+/// The immutables size global variable name.
+pub const GLOBAL_IMMUTABLES_SIZE: &str = "vyper_immutables_size";
+
+///
+/// Declares a constant global variable read by the code layout instructions.
+///
+pub fn declare_constant(
+    context: &mut era_compiler_llvm_context::EVMContext,
+    name: &str,
+    value: u64,
+) {
+    let global = context
+        .module()
+        .add_global(context.field_type(), None, name);
+    global.set_initializer(&context.field_const(value));
+    global.set_constant(true);
+    global.set_linkage(inkwell::module::Linkage::Private);
+}
+
+///
+/// Returns the runtime code object identifier, which is derived from the deploy code module name.
+///
+pub fn runtime_code_identifier(context: &era_compiler_llvm_context::EVMContext) -> String {
+    format!(
+        "{}{}",
+        context.module().get_name().to_string_lossy(),
+        crate::r#const::EVM_RUNTIME_CODE_SUFFIX
+    )
+}
+
+///
+/// Translates the code end offset, which is `_sym_code_end` in `vyper`.
+///
+/// In the deploy code, it is the end of the runtime code object, followed by the constructor
+/// arguments. In the runtime code, it is the start of the immutables appended by the deploy code.
+///
+pub fn end<'ctx>(
+    context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+) -> anyhow::Result<inkwell::values::IntValue<'ctx>> {
+    match context
+        .code_segment()
+        .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
+    {
+        era_compiler_common::CodeSegment::Deploy => {
+            let identifier = runtime_code_identifier(context);
+            let offset =
+                era_compiler_llvm_context::evm_code::data_offset(context, identifier.as_str())?;
+            let size =
+                era_compiler_llvm_context::evm_code::data_size(context, identifier.as_str())?;
+            Ok(context.builder().build_int_add(
+                offset.into_int_value(),
+                size.into_int_value(),
+                "code_end",
+            )?)
+        }
+        era_compiler_common::CodeSegment::Runtime => {
+            let immutables_size = constant(context, GLOBAL_IMMUTABLES_SIZE)?;
+            let code_size = era_compiler_llvm_context::evm_code::size(context)?;
+            Ok(context.builder().build_int_sub(
+                code_size.into_int_value(),
+                immutables_size,
+                "code_end",
+            )?)
+        }
+    }
+}
+
+///
+/// Translates the offset of the immutables in the deploy code memory.
+///
+/// The runtime code is copied to memory after the memory used by the deploy code, so the
+/// immutables are stored right after the runtime code.
+///
+pub fn immutables_offset<'ctx>(
+    context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+) -> anyhow::Result<inkwell::values::IntValue<'ctx>> {
+    if let era_compiler_common::CodeSegment::Runtime = context
+        .code_segment()
+        .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
+    {
+        anyhow::bail!("Immutables can only be written in the deploy code");
+    }
+
+    let memory_size = constant(context, GLOBAL_DEPLOY_MEMORY_SIZE)?;
+    let identifier = runtime_code_identifier(context);
+    let runtime_size =
+        era_compiler_llvm_context::evm_code::data_size(context, identifier.as_str())?;
+    Ok(context.builder().build_int_add(
+        memory_size,
+        runtime_size.into_int_value(),
+        "immutables_offset",
+    )?)
+}
+
+///
+/// Translates the `deploy` instruction, which copies the runtime code to memory and returns it
+/// together with the immutables.
+///
+pub fn deploy<'ctx>(
+    context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+    memory_size: inkwell::values::IntValue<'ctx>,
+    immutables_size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()> {
+    let identifier = runtime_code_identifier(context);
+    let offset = era_compiler_llvm_context::evm_code::data_offset(context, identifier.as_str())?;
+    let size = era_compiler_llvm_context::evm_code::data_size(context, identifier.as_str())?;
+    era_compiler_llvm_context::evm_code::copy(
+        context,
+        memory_size,
+        offset.into_int_value(),
+        size.into_int_value(),
+    )?;
+
+    let return_size = context.builder().build_int_add(
+        size.into_int_value(),
+        immutables_size,
+        "deploy_return_size",
+    )?;
+    era_compiler_llvm_context::evm_return::r#return(context, memory_size, return_size)
+}
+
+///
+/// Translates the `dload` instruction, which reads a word from the code end.
+///
+pub fn load<'ctx>(
+    context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+    offset: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+    load_bytes(
+        context,
+        context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
+        offset,
+        context.field_const(era_compiler_common::BYTE_LENGTH_FIELD as u64),
+    )?;
+    era_compiler_llvm_context::evm_memory::load(
+        context,
+        context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
+    )
+}
+
+///
+/// Translates the `dloadbytes` instruction, which copies bytes from the code end to memory.
+///
+pub fn load_bytes<'ctx>(
+    context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+    destination: inkwell::values::IntValue<'ctx>,
+    offset: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()> {
+    let code_end = end(context)?;
+    let source = context
+        .builder()
+        .build_int_add(code_end, offset, "code_data_offset")?;
+    era_compiler_llvm_context::evm_code::copy(context, destination, source, size)
+}
+
+///
+/// Returns the constant declared with [`declare_constant`].
+///
+fn constant<'ctx>(
+    context: &era_compiler_llvm_context::EVMContext<'ctx>,
+    name: &str,
+) -> anyhow::Result<inkwell::values::IntValue<'ctx>> {
+    context
+        .module()
+        .get_global(name)
+        .and_then(|global| global.get_initializer())
+        .map(|value| value.into_int_value())
+        .ok_or_else(|| anyhow::anyhow!("Global `{name}` is not declared"))
+}
@@ -2,6 +2,7 @@
 //! The `deploy` instruction.
 //!
 
+use crate::project::contract::vyper::expression::instruction::code;
 use crate::project::contract::vyper::expression::instruction::seq::Seq as SeqInstruction;
 use crate::project::contract::vyper::expression::instruction::Instruction;
 use crate::project::contract::vyper::expression::Expression;
@@ -22,14 +23,55 @@ impl Deploy {
     ///
     /// Extracts the runtime code expression from the deploy code.
     ///
+    /// The memory size and immutables size are left in place, as they are used by the EVM deploy
+    /// code to return the runtime code.
+    ///
     pub fn extract_runtime_code(&mut self) -> anyhow::Result<(SeqInstruction, Expression)> {
-        let _zero_1 = self.0.remove(0);
-        let expression = self.0.remove(0);
-        let immutables_size = self.0.remove(0);
+        let expression = self.0.remove(1);
+        let immutables_size = self
+            .0
+            .get(1)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("The immutables size is missing"))?;
 
         match expression {
             Expression::Instruction(Instruction::Seq(sequence)) => Ok((sequence, immutables_size)),
-            expression => anyhow::bail!("Expected `seq`, found `{expression:?}`"),
+            expression => anyhow::bail!("Expected `seq`, found `{expression}`"),
         }
     }
+
+    ///
+    /// Returns the memory size used by the deploy code.
+    ///
+    pub fn memory_size(&self) -> Option<&Expression> {
+        self.0.first()
+    }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    /// The runtime code is expected to be extracted already, leaving the memory and
+    /// immutables sizes.
+    ///
+    pub fn into_llvm_value_evm(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        let [memory_size, immutables_size]: [Expression; 2] =
+            self.0.try_into().map_err(|arguments: Vec<Expression>| {
+                anyhow::anyhow!(
+                    "Expected the memory and immutables sizes, found {} arguments",
+                    arguments.len()
+                )
+            })?;
+        let arguments = Instruction::translate_arguments_llvm_evm::<2>(
+            [Box::new(memory_size), Box::new(immutables_size)],
+            context,
+        )?;
+        code::deploy(
+            context,
+            arguments[0].into_int_value(),
+            arguments[1].into_int_value(),
+        )
+    }
 }
@@ -10,7 +10,7 @@ use crate::project::contract::vyper::expression::Expression;
 /// The Vyper LLL-specific `exit_to` instruction.
 ///
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
-pub struct ExitTo(Vec<Expression>);
+pub struct ExitTo(pub Vec<Expression>);
 
 impl ExitTo {
     ///
@@ -63,4 +63,40 @@ impl ExitTo {
         context.build_unconditional_branch(block)?;
         Ok(())
     }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    /// The label arguments are expected to be lowered into `set` instructions beforehand.
+    ///
+    pub fn into_llvm_value_evm(
+        mut self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        let label_name = self.0.remove(0).try_into_identifier()?;
+        if label_name.as_str() == crate::r#const::VARIABLE_IDENTIFIER_RETURN_PC {
+            context
+                .build_unconditional_branch(context.current_function().borrow().return_block())?;
+            return Ok(());
+        }
+        let label_name = label_name
+            .strip_prefix(crate::r#const::LABEL_DESTINATION_PREFIX)
+            .unwrap_or(label_name.as_str());
+
+        let block = context
+            .current_function()
+            .borrow()
+            .declaration()
+            .value
+            .get_basic_blocks()
+            .iter()
+            .find(|block| {
+                block.get_name().to_string_lossy() == Expression::safe_label(label_name).as_str()
+            })
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Block `{}` does not exist", label_name))?;
+
+        context.build_unconditional_branch(block)?;
+        Ok(())
+    }
 }
@@ -10,7 +10,7 @@ use crate::project::contract::vyper::expression::Expression;
 /// The Vyper LLL-specific `goto` instruction.
 ///
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
-pub struct Goto(Vec<Expression>);
+pub struct Goto(pub Vec<Expression>);
 
 impl Goto {
     ///
@@ -114,4 +114,88 @@ impl Goto {
 
         self.into_function_call(context, Expression::safe_label(label_name.as_str()))
     }
+
+    ///
+    /// Generates the function call code for EVM.
+    ///
+    pub fn into_function_call_evm<'ctx>(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+        label_name: String,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        let function = context
+            .get_function(label_name.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Function `{label_name}` does not exist"))?;
+
+        let mut arguments = Vec::new();
+        for expression in self.0.into_iter() {
+            if let Ok(identifier) = expression.try_into_identifier() {
+                if identifier.starts_with(crate::r#const::LABEL_DESTINATION_PREFIX) {
+                    continue;
+                }
+            }
+            if let Some(value) = expression.into_llvm_value_evm(context)? {
+                arguments.push(value);
+            }
+        }
+
+        context.build_call(
+            function.borrow().declaration(),
+            arguments.as_slice(),
+            label_name.as_str(),
+        )?;
+
+        Ok(None)
+    }
+
+    ///
+    /// Generates the block call code for EVM.
+    ///
+    /// The label arguments are expected to be lowered into `set` instructions beforehand.
+    ///
+    pub fn into_block_call_evm<'ctx>(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+        label_name: String,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        if !self.0.is_empty() {
+            anyhow::bail!("The block `{label_name}` arguments must be lowered before translation");
+        }
+
+        let block = context
+            .current_function()
+            .borrow()
+            .declaration()
+            .value
+            .get_basic_blocks()
+            .iter()
+            .find(|block| {
+                block.get_name().to_string_lossy()
+                    == Expression::safe_label(label_name.as_str()).as_str()
+            })
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Block `{}` does not exist", label_name))?;
+
+        context.build_unconditional_branch(block)?;
+
+        Ok(None)
+    }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    pub fn into_llvm_value_evm<'ctx>(
+        mut self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        let label_name = self.0.remove(0).try_into_identifier()?;
+
+        if label_name.ends_with(crate::r#const::LABEL_SUFFIX_CLEANUP)
+            || label_name == crate::r#const::FUNCTION_IDENTIFIER_FALLBACK
+        {
+            return self.into_block_call_evm(context, label_name);
+        }
+
+        self.into_function_call_evm(context, Expression::safe_label(label_name.as_str()))
+    }
 }
@@ -168,7 +168,8 @@ impl Label {
                 }
             }
             Some(Expression::Identifier(identifier)) if identifier.as_str() == "var_list" => {}
-            expression => anyhow::bail!("Expected a variable list, found `{expression:?}`"),
+            Some(expression) => anyhow::bail!("Expected a variable list, found `{expression}`"),
+            None => anyhow::bail!("Expected a variable list, found nothing"),
         };
 
         context
@@ -231,4 +232,104 @@ impl Label {
 
         Ok(())
     }
+
+    ///
+    /// Declares the label block for EVM.
+    ///
+    /// Unlike EraVM, the label arguments are not recorded, as they are lowered into `set`
+    /// instructions before translation.
+    ///
+    pub fn declare_evm(
+        &self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        if self.is_empty() || self.can_block_be_ignored() {
+            return Ok(());
+        }
+
+        let label_name = self.name()?;
+        context.append_basic_block(Expression::safe_label(label_name.as_str()).as_str());
+
+        context.set_basic_block(context.current_function().borrow().entry_block());
+        match self.0.get(1) {
+            Some(Expression::Instruction(Instruction::Var_List(ref arguments))) => {
+                for argument in arguments.iter() {
+                    let name = argument.try_into_identifier()?;
+                    if name.as_str() == crate::r#const::VARIABLE_IDENTIFIER_RETURN_PC {
+                        continue;
+                    }
+
+                    let pointer = context.build_alloca(context.field_type(), name.as_str())?;
+                    let value =
+                        if name.as_str() == crate::r#const::VARIABLE_IDENTIFIER_RETURN_BUFFER {
+                            context.current_function().borrow().get_nth_param(0)
+                        } else {
+                            context.field_const(0).as_basic_value_enum()
+                        };
+                    context.build_store(pointer, value)?;
+                    context
+                        .current_function()
+                        .borrow_mut()
+                        .insert_stack_pointer(name, pointer);
+                }
+            }
+            Some(Expression::Identifier(identifier)) if identifier.as_str() == "var_list" => {}
+            Some(expression) => anyhow::bail!("Expected a variable list, found `{expression}`"),
+            None => anyhow::bail!("Expected a variable list, found nothing"),
+        };
+
+        Ok(())
+    }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    pub fn into_llvm_value_evm(
+        mut self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        if self.is_empty() || self.can_block_be_ignored() {
+            return Ok(());
+        }
+        let is_block_empty_sequence = self.is_block_empty_sequence();
+
+        let label_name = self.0.remove(0);
+        let block = self.0.remove(1);
+
+        let current_block = context.basic_block();
+
+        let label_name = label_name.try_into_identifier()?;
+        let label_block = context
+            .current_function()
+            .borrow()
+            .declaration()
+            .value
+            .get_basic_blocks()
+            .iter()
+            .find(|block| {
+                block.get_name().to_string_lossy()
+                    == Expression::safe_label(label_name.as_str()).as_str()
+            })
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Block `{}` does not exist", label_name))?;
+
+        context.set_basic_block(label_block);
+        block.into_llvm_value_evm(context)?;
+
+        if label_name == crate::r#const::FUNCTION_IDENTIFIER_FALLBACK {
+            context
+                .build_unconditional_branch(context.current_function().borrow().return_block())?;
+        }
+
+        if Self::is_constructor_block(label_name.as_str())
+            && label_name.ends_with(crate::r#const::LABEL_SUFFIX_CLEANUP)
+            && is_block_empty_sequence
+        {
+            era_compiler_llvm_context::evm_return::stop(context)?;
+        }
+
+        context.set_basic_block(current_block);
+
+        Ok(())
+    }
 }
@@ -4,6 +4,7 @@
 
 pub mod assert;
 pub mod clamp;
+pub mod code;
 pub mod create;
 pub mod deploy;
 pub mod exit_to;
@@ -24,6 +25,10 @@ use std::collections::BTreeMap;
 use era_compiler_llvm_context::IContext;
 use inkwell::values::BasicValue;
 
+use crate::error::Error;
+use crate::project::contract::vyper::expression::lowering::binary_operation::BinaryOperation;
+use crate::project::contract::vyper::expression::lowering::modular_operation::ModularOperation;
+use crate::project::contract::vyper::expression::lowering::Lowering;
 use crate::project::contract::vyper::expression::Expression;
 
 use self::assert::Assert;
@@ -323,31 +328,22 @@ impl Instruction {
     ///
     /// Translates the specified number of arguments into LLVM values.
     ///
-    fn translate_arguments_llvm<'ctx, const N: usize>(
+    fn translate_arguments_llvm<'ctx, C, const N: usize>(
         arguments: [Box<Expression>; N],
-        context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
-    ) -> anyhow::Result<[inkwell::values::BasicValueEnum<'ctx>; N]> {
-        let debug_string = format!("`{arguments:?}`");
-
+        context: &mut C,
+    ) -> anyhow::Result<[inkwell::values::BasicValueEnum<'ctx>; N]>
+    where
+        C: Lowering<'ctx>,
+    {
         let mut values = Vec::with_capacity(N);
         for (index, expression) in arguments.into_iter().enumerate().rev() {
-            values.push(expression.into_llvm_value(context)?.ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Expression #{} of the instruction `{}` has zero valency",
-                    index,
-                    debug_string
-                )
+            let expression_string = expression.to_string();
+            values.push(context.lower_expression(*expression)?.ok_or_else(|| {
+                anyhow::anyhow!("Expression #{index} `{expression_string}` has zero valency")
             })?);
         }
         values.reverse();
 
-        if values.len() != N {
-            anyhow::bail!(
-                "Expected {N} arguments, found only {}: `{values:?}`",
-                values.len(),
-            );
-        }
-
         Ok(values.try_into().expect("Always valid"))
     }
 
@@ -359,21 +355,16 @@ impl Instruction {
         arguments: [Box<Expression>; N],
         context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
     ) -> anyhow::Result<[era_compiler_llvm_context::Value<'ctx>; N]> {
-        let debug_string = format!("`{arguments:?}`");
-
         let mut values = Vec::with_capacity(N);
         for (index, expression) in arguments.into_iter().enumerate().rev() {
+            let expression_string = expression.to_string();
             let original = match *expression {
                 Expression::Identifier(ref identifier) => Some(identifier.to_owned()),
                 Expression::IntegerLiteral(ref value) => Some(value.to_string()),
                 _ => None,
             };
             let value = expression.into_llvm_value(context)?.ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Expression #{} of the instruction `{}` has zero valency",
-                    index,
-                    debug_string
-                )
+                anyhow::anyhow!("Expression #{index} `{expression_string}` has zero valency")
             })?;
             values.push(match original {
                 Some(ref original) => {
@@ -424,7 +415,7 @@ impl Instruction {
     pub fn function_name(&self) -> anyhow::Result<String> {
         match self {
             Self::Seq(inner) => inner.function_name(),
-            expression => anyhow::bail!("Expected a function sequence, found `{expression:?}`"),
+            expression => anyhow::bail!("Expected a function sequence, found `{expression}`"),
         }
     }
 
@@ -444,466 +435,704 @@ impl Instruction {
 
             Self::GoTo(inner) => inner.into_llvm_value(context),
             Self::Exit_To(inner) => inner.into_llvm_value(context).map(|_| None),
-            Self::Jump(arguments) => {
-                let _arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                let block = context.current_function().borrow().return_block();
-                context.build_unconditional_branch(block)?;
-                Ok(None)
-            }
             Self::Label(inner) => inner.into_llvm_value(context).map(|_| None),
-            Self::Cleanup_Repeat => Ok(None),
-            Self::Break => {
-                let block = context.r#loop().join_block;
-                context.build_unconditional_branch(block)?;
-                Ok(None)
-            }
-            Self::Continue => {
-                let block = context.r#loop().continue_block;
-                context.build_unconditional_branch(block)?;
-                Ok(None)
-            }
-            Self::Pass => Ok(None),
             Self::Deploy(_inner) => Ok(None),
-            Self::Symbol(_inner) => Ok(None),
-            Self::Unique_Symbol(_inner) => Ok(None),
 
-            Self::UCLAMP(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-                clamp::ordinary(
-                    context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    arguments[2].into_int_value(),
-                    false,
-                )
-                .map(Some)
-            }
-            Self::CLAMP(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-                clamp::ordinary(
+            Self::SUB(arguments) => match Self::check_sub_code_offset(context, &arguments)? {
+                Some(result) => Ok(Some(result)),
+                None => Self::SUB(arguments).into_llvm_value_common(context),
+            },
+
+            Self::SHA3(arguments) | Self::KECCAK256(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_crypto::sha3(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
-                    arguments[2].into_int_value(),
-                    true,
                 )
                 .map(Some)
             }
-            Self::UCLAMPLT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                clamp::with_predicate(
+            Self::SHA3_32(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+
+                let pointer_one = era_compiler_llvm_context::Pointer::new_with_offset(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::ULT,
-                )
-                .map(Some)
-            }
-            Self::UCLAMPLE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                clamp::with_predicate(
+                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
+                    context.field_type(),
+                    context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
+                    "sha3_pointer_one",
+                )?;
+                context.build_store(pointer_one, arguments[0])?;
+
+                era_compiler_llvm_context::eravm_evm_crypto::sha3(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::ULE,
+                    context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
+                    context.field_const(era_compiler_common::BYTE_LENGTH_FIELD as u64),
                 )
                 .map(Some)
             }
-            Self::UCLAMPGT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                clamp::with_predicate(
+            Self::SHA3_64(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+
+                let pointer_one = era_compiler_llvm_context::Pointer::new_with_offset(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::UGT,
-                )
-                .map(Some)
-            }
-            Self::UCLAMPGE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                clamp::with_predicate(
+                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
+                    context.field_type(),
+                    context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
+                    "sha3_pointer_one",
+                )?;
+                context.build_store(pointer_one, arguments[0])?;
+                let pointer_two = era_compiler_llvm_context::Pointer::new_with_offset(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::UGE,
-                )
-                .map(Some)
-            }
-            Self::CLAMPLT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                clamp::with_predicate(
+                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
+                    context.field_type(),
+                    context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE2 as u64),
+                    "sha3_pointer_two",
+                )?;
+                context.build_store(pointer_two, arguments[1])?;
+
+                era_compiler_llvm_context::eravm_evm_crypto::sha3(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::SLT,
+                    context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
+                    context.field_const((era_compiler_common::BYTE_LENGTH_FIELD * 2) as u64),
                 )
                 .map(Some)
             }
-            Self::CLAMPLE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                clamp::with_predicate(
+
+            Self::MLOAD(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_memory::load(
                     context,
                     arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::SLE,
                 )
                 .map(Some)
             }
-            Self::CLAMPGT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                clamp::with_predicate(
+            Self::MSTORE(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_memory::store(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
-                    inkwell::IntPredicate::SGT,
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::CLAMPGE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                clamp::with_predicate(
+            Self::MSTORE8(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_memory::store_byte(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
-                    inkwell::IntPredicate::SGE,
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::CLAMP_NONZERO(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                clamp::with_predicate(
+            Self::MCOPY(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                let destination = era_compiler_llvm_context::Pointer::new_with_offset(
                     context,
+                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
+                    context.byte_type(),
                     arguments[0].into_int_value(),
-                    context.field_const(0),
-                    inkwell::IntPredicate::NE,
-                )
-                .map(Some)
-            }
-
-            Self::CEIL32(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                offset::ceil_32(context, arguments[0].into_int_value()).map(Some)
-            }
-            Self::SELECT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-                let condition = context.builder().build_int_compare(
-                    inkwell::IntPredicate::NE,
-                    arguments[0].into_int_value(),
-                    context.field_const(0),
-                    "select_condition",
+                    "mcopy_destination",
                 )?;
-                Ok(Some(context.builder().build_select(
-                    condition,
+                let source = era_compiler_llvm_context::Pointer::new_with_offset(
+                    context,
+                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
+                    context.byte_type(),
                     arguments[1].into_int_value(),
-                    arguments[2].into_int_value(),
-                    "select",
-                )?))
-            }
-
-            Self::Assert(inner) => inner.into_llvm_value(context, false).map(|_| None),
-            Self::Assert_Unreachable(inner) => inner.into_llvm_value(context, true).map(|_| None),
-
-            Self::Var_List(_inner) => Ok(None),
+                    "mcopy_source",
+                )?;
 
-            Self::POP(arguments) => {
-                let _arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
+                context.build_memcpy(
+                    context.intrinsics().memory_move_heap,
+                    destination,
+                    source,
+                    arguments[2].into_int_value(),
+                    "mcopy_size",
+                )?;
                 Ok(None)
             }
 
-            Self::ADD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_arithmetic::addition(
-                    context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                )
-                .map(Some)
-            }
-            Self::SUB(arguments) => {
-                if let Some(result) = Self::check_sub_code_offset(context, &arguments)? {
-                    return Ok(Some(result));
-                }
-
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_arithmetic::subtraction(
+            Self::SLOAD(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_storage::load(
                     context,
                     arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
                 )
                 .map(Some)
             }
-            Self::MUL(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_arithmetic::multiplication(
+            Self::SSTORE(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_storage::store(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::DIV(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_arithmetic::division(
+            Self::TLOAD(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_storage::transient_load(
                     context,
                     arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
                 )
                 .map(Some)
             }
-            Self::MOD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_arithmetic::remainder(
+            Self::TSTORE(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_storage::transient_store(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::SDIV(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_arithmetic::division_signed(
+
+            Self::ILOAD(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_immutable::load(
                     context,
                     arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
                 )
                 .map(Some)
             }
-            Self::SMOD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_arithmetic::remainder_signed(
+            Self::ISTORE(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_immutable::store(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
 
-            Self::LT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
-                    context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::ULT,
-                )
-                .map(Some)
+            Self::CALLDATALOAD(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+
+                match context
+                    .code_segment()
+                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
+                {
+                    era_compiler_common::CodeSegment::Deploy => {
+                        Ok(Some(context.field_const(0).as_basic_value_enum()))
+                    }
+                    era_compiler_common::CodeSegment::Runtime => {
+                        era_compiler_llvm_context::eravm_evm_calldata::load(
+                            context,
+                            arguments[0].into_int_value(),
+                        )
+                        .map(Some)
+                    }
+                }
+            }
+            Self::CALLDATASIZE => {
+                match context
+                    .code_segment()
+                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
+                {
+                    era_compiler_common::CodeSegment::Deploy => {
+                        Ok(Some(context.field_const(0).as_basic_value_enum()))
+                    }
+                    era_compiler_common::CodeSegment::Runtime => {
+                        era_compiler_llvm_context::eravm_evm_calldata::size(context).map(Some)
+                    }
+                }
             }
-            Self::LE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
+            Self::CALLDATACOPY(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+
+                let source_offset = match context
+                    .code_segment()
+                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
+                {
+                    era_compiler_common::CodeSegment::Deploy => {
+                        era_compiler_llvm_context::eravm_evm_calldata::size(context)?
+                    }
+                    era_compiler_common::CodeSegment::Runtime => {
+                        arguments[1].into_int_value().as_basic_value_enum()
+                    }
+                }
+                .into_int_value();
+
+                era_compiler_llvm_context::eravm_evm_calldata::copy(
                     context,
                     arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::ULE,
+                    source_offset,
+                    arguments[2].into_int_value(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::GT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
-                    context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::UGT,
-                )
+
+            Self::DLOAD(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+
+                match context.code_segment() {
+                    None => {
+                        panic!("code part is undefined");
+                    }
+                    Some(era_compiler_common::CodeSegment::Deploy) => {
+                        era_compiler_llvm_context::eravm_evm_calldata::load(
+                            context,
+                            arguments[0].into_int_value(),
+                        )
+                    }
+                    Some(era_compiler_common::CodeSegment::Runtime) => {
+                        era_compiler_llvm_context::eravm_evm_immutable::load(
+                            context,
+                            arguments[0].into_int_value(),
+                        )
+                    }
+                }
                 .map(Some)
             }
-            Self::GE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
-                    context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::UGE,
-                )
-                .map(Some)
+            Self::DLOADBYTES(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+
+                match context.code_segment() {
+                    None => {
+                        anyhow::bail!(
+                            "Immutables are not available if the contract part is undefined"
+                        );
+                    }
+                    Some(era_compiler_common::CodeSegment::Deploy) => {
+                        era_compiler_llvm_context::eravm_evm_calldata::copy(
+                            context,
+                            arguments[0].into_int_value(),
+                            arguments[1].into_int_value(),
+                            arguments[2].into_int_value(),
+                        )
+                    }
+                    Some(era_compiler_common::CodeSegment::Runtime) => immutable::load_bytes(
+                        context,
+                        arguments[0].into_int_value(),
+                        arguments[1].into_int_value(),
+                        arguments[2].into_int_value(),
+                    ),
+                }
+                .map(|_| None)
             }
-            Self::EQ(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
-                    context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::EQ,
-                )
-                .map(Some)
+
+            Self::CODESIZE => {
+                match context
+                    .code_segment()
+                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
+                {
+                    era_compiler_common::CodeSegment::Deploy => {
+                        era_compiler_llvm_context::eravm_evm_calldata::size(context).map(Some)
+                    }
+                    era_compiler_common::CodeSegment::Runtime => {
+                        let code_source =
+                            era_compiler_llvm_context::eravm_general::code_source(context)?;
+                        era_compiler_llvm_context::eravm_evm_ext_code::size(
+                            context,
+                            code_source.into_int_value(),
+                        )
+                        .map(Some)
+                    }
+                }
             }
-            Self::NE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
+            Self::CODECOPY(arguments) => {
+                if let era_compiler_common::CodeSegment::Runtime = context
+                    .code_segment()
+                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
+                {
+                    return Err(Error::unsupported(
+                        "The `CODECOPY` instruction is not supported in the runtime code"
+                            .to_owned(),
+                    )
+                    .into());
+                }
+
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_calldata::copy(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
-                    inkwell::IntPredicate::NE,
+                    arguments[2].into_int_value(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::ISZERO(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
-                    context,
-                    arguments[0].into_int_value(),
-                    context.field_const(0),
-                    inkwell::IntPredicate::EQ,
-                )
-                .map(Some)
+
+            Self::RETURNDATASIZE => {
+                era_compiler_llvm_context::eravm_evm_return_data::size(context).map(Some)
             }
-            Self::SLT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
+            Self::RETURNDATACOPY(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_return_data::copy(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
-                    inkwell::IntPredicate::SLT,
+                    arguments[2].into_int_value(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::SLE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
+
+            Self::EXTCODESIZE(arguments) => {
+                let arguments = Self::translate_arguments::<1>(arguments, context)?;
+                let mut requested_size = era_compiler_llvm_context::eravm_evm_ext_code::size(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::SLE,
-                )
-                .map(Some)
+                    arguments[0].value.into_int_value(),
+                )?;
+                if Some(crate::r#const::EXTCODESIZE_BLUEPRINT_ARGUMENT_NAME)
+                    == arguments[0].original.as_deref()
+                {
+                    let result_pointer = context.build_alloca(
+                        context.field_type(),
+                        "extcodesize_create_target_result_pointer",
+                    )?;
+                    context.build_store(
+                        result_pointer,
+                        context.field_const(
+                            era_compiler_llvm_context::eravm_const::DEPLOYER_CALL_HEADER_SIZE
+                                as u64,
+                        ),
+                    )?;
+
+                    let is_zero_block =
+                        context.append_basic_block("extcodesize_create_target_is_zero_block");
+                    let join_block =
+                        context.append_basic_block("extcodesize_create_target_join_block");
+                    let is_zero = context.builder().build_int_compare(
+                        inkwell::IntPredicate::EQ,
+                        requested_size.into_int_value(),
+                        context.field_const(0),
+                        "extcodesize_create_target_is_zero",
+                    )?;
+                    context.builder().build_conditional_branch(
+                        is_zero,
+                        is_zero_block,
+                        join_block,
+                    )?;
+
+                    context.set_basic_block(is_zero_block);
+                    context.build_store(result_pointer, context.field_const(0))?;
+                    context.build_unconditional_branch(join_block)?;
+
+                    context.set_basic_block(join_block);
+                    requested_size =
+                        context.build_load(result_pointer, "extcodesize_create_target_result")?;
+                }
+                Ok(Some(requested_size))
             }
-            Self::SGT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
+            Self::EXTCODEHASH(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_ext_code::hash(
                     context,
                     arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::SGT,
                 )
                 .map(Some)
             }
-            Self::SGE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_comparison::compare(
+            Self::EXTCODECOPY(arguments) => {
+                let arguments = Self::translate_arguments::<4>(arguments, context)?;
+
+                if Some(crate::r#const::EXTCODESIZE_BLUEPRINT_ARGUMENT_NAME)
+                    != arguments[0].original.as_deref()
+                {
+                    anyhow::bail!(
+                        "The `EXTCODECOPY` instruction is only supported for the `create_from_blueprint built-in."
+                    );
+                }
+
+                let hash_value = era_compiler_llvm_context::eravm_evm_ext_code::hash(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                    inkwell::IntPredicate::SGE,
-                )
-                .map(Some)
+                    arguments[0].value.into_int_value(),
+                )?;
+
+                let hash_heap_offset = context.builder().build_int_add(
+                    arguments[1].value.into_int_value(),
+                    context.field_const(
+                        (era_compiler_common::BYTE_LENGTH_X32
+                            + era_compiler_common::BYTE_LENGTH_FIELD)
+                            as u64,
+                    ),
+                    "extcodecopy_hash_offset",
+                )?;
+                let hash_heap_pointer = era_compiler_llvm_context::Pointer::new_with_offset(
+                    context,
+                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
+                    context.field_type(),
+                    hash_heap_offset,
+                    "extcodecopy_hash_destination",
+                )?;
+                context.build_store(hash_heap_pointer, hash_value)?;
+
+                let hash_aux_heap_offset = context.field_const(
+                    era_compiler_llvm_context::eravm_const::HEAP_AUX_OFFSET_EXTERNAL_CALL
+                        + (era_compiler_common::BYTE_LENGTH_X32
+                            + era_compiler_common::BYTE_LENGTH_FIELD)
+                            as u64,
+                );
+                let hash_aux_heap_pointer = era_compiler_llvm_context::Pointer::new_with_offset(
+                    context,
+                    era_compiler_llvm_context::EraVMAddressSpace::HeapAuxiliary,
+                    context.field_type(),
+                    hash_aux_heap_offset,
+                    "extcodecopy_hash_destination",
+                )?;
+                context.build_store(hash_aux_heap_pointer, hash_value)?;
+
+                Ok(None)
             }
 
-            Self::OR(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_bitwise::or(
+            Self::RETURN(inner) => inner.into_llvm_value(context).map(|_| None),
+            Self::REVERT(inner) => inner.into_llvm_value(context).map(|_| None),
+            Self::STOP => era_compiler_llvm_context::eravm_evm_return::stop(context).map(|_| None),
+            Self::INVALID => {
+                era_compiler_llvm_context::eravm_evm_return::invalid(context).map(|_| None)
+            }
+
+            Self::LOG0(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_event::log(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
+                    vec![],
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::XOR(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_bitwise::xor(
+            Self::LOG1(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_event::log(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
+                    arguments[2..]
+                        .iter()
+                        .map(|argument| argument.into_int_value())
+                        .collect(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::NOT(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_bitwise::xor(
+            Self::LOG2(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 4>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_event::log(
                     context,
                     arguments[0].into_int_value(),
-                    context.field_type().const_all_ones(),
+                    arguments[1].into_int_value(),
+                    arguments[2..]
+                        .iter()
+                        .map(|argument| argument.into_int_value())
+                        .collect(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::AND(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_bitwise::and(
+            Self::LOG3(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 5>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_event::log(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
+                    arguments[2..]
+                        .iter()
+                        .map(|argument| argument.into_int_value())
+                        .collect(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::SHL(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_bitwise::shift_left(
+            Self::LOG4(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 6>(arguments, context)?;
+                era_compiler_llvm_context::eravm_evm_event::log(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
+                    arguments[2..]
+                        .iter()
+                        .map(|argument| argument.into_int_value())
+                        .collect(),
                 )
-                .map(Some)
+                .map(|_| None)
             }
-            Self::SHR(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_bitwise::shift_right(
+
+            Self::CALL(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 7>(arguments, context)?;
+
+                let gas = arguments[0].into_int_value();
+                let address = arguments[1].into_int_value();
+                let value = arguments[2].into_int_value();
+                let input_offset = arguments[3].into_int_value();
+                let input_size = arguments[4].into_int_value();
+                let output_offset = arguments[5].into_int_value();
+                let output_size = arguments[6].into_int_value();
+
+                era_compiler_llvm_context::eravm_evm_call::default(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
+                    context.llvm_runtime().far_call,
+                    gas,
+                    address,
+                    Some(value),
+                    input_offset,
+                    input_size,
+                    output_offset,
+                    output_size,
+                    vec![],
                 )
                 .map(Some)
             }
-            Self::SAR(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_bitwise::shift_right_arithmetic(
+            Self::STATICCALL(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 6>(arguments, context)?;
+
+                let gas = arguments[0].into_int_value();
+                let address = arguments[1].into_int_value();
+                let input_offset = arguments[2].into_int_value();
+                let input_size = arguments[3].into_int_value();
+                let output_offset = arguments[4].into_int_value();
+                let output_size = arguments[5].into_int_value();
+
+                era_compiler_llvm_context::eravm_evm_call::default(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
+                    context.llvm_runtime().static_call,
+                    gas,
+                    address,
+                    None,
+                    input_offset,
+                    input_size,
+                    output_offset,
+                    output_size,
+                    vec![],
                 )
                 .map(Some)
             }
-            Self::BYTE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_bitwise::byte(
+            Self::DELEGATECALL(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 6>(arguments, context)?;
+
+                let gas = arguments[0].into_int_value();
+                let address = arguments[1].into_int_value();
+                let input_offset = arguments[2].into_int_value();
+                let input_size = arguments[3].into_int_value();
+                let output_offset = arguments[4].into_int_value();
+                let output_size = arguments[5].into_int_value();
+
+                era_compiler_llvm_context::eravm_evm_call::default(
                     context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
+                    context.llvm_runtime().delegate_call,
+                    gas,
+                    address,
+                    None,
+                    input_offset,
+                    input_size,
+                    output_offset,
+                    output_size,
+                    vec![],
                 )
                 .map(Some)
             }
 
-            Self::ADDMOD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_math::add_mod(
+            Self::CREATE(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+
+                create::create(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
                     arguments[2].into_int_value(),
+                    None,
                 )
                 .map(Some)
             }
-            Self::MULMOD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_math::mul_mod(
+            Self::CREATE2(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 4>(arguments, context)?;
+
+                create::create(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
                     arguments[2].into_int_value(),
+                    Some(arguments[3].into_int_value()),
                 )
                 .map(Some)
             }
-            Self::EXP(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_math::exponent(
-                    context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                )
-                .map(Some)
+
+            Self::ADDRESS => context.build_call(context.intrinsics().address, &[], "address"),
+            Self::CALLER => context.build_call(context.intrinsics().caller, &[], "caller"),
+
+            Self::CALLVALUE => {
+                era_compiler_llvm_context::eravm_evm_ether_gas::value(context).map(Some)
             }
-            Self::SIGNEXTEND(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_math::sign_extend(
-                    context,
-                    arguments[0].into_int_value(),
-                    arguments[1].into_int_value(),
-                )
-                .map(Some)
+            Self::GAS => era_compiler_llvm_context::eravm_evm_ether_gas::gas(context).map(Some),
+            Self::BALANCE(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+
+                let address = arguments[0].into_int_value();
+                era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address).map(Some)
+            }
+            Self::SELFBALANCE => {
+                let address = context
+                    .build_call(context.intrinsics().address, &[], "self_balance_address")?
+                    .expect("Always exists")
+                    .into_int_value();
+
+                era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address).map(Some)
+            }
+
+            Self::GASLIMIT => {
+                era_compiler_llvm_context::eravm_evm_contract_context::gas_limit(context).map(Some)
+            }
+            Self::GASPRICE => {
+                era_compiler_llvm_context::eravm_evm_contract_context::gas_price(context).map(Some)
+            }
+            Self::ORIGIN => {
+                era_compiler_llvm_context::eravm_evm_contract_context::origin(context).map(Some)
+            }
+            Self::CHAINID => {
+                era_compiler_llvm_context::eravm_evm_contract_context::chain_id(context).map(Some)
+            }
+            Self::NUMBER => {
+                era_compiler_llvm_context::eravm_evm_contract_context::block_number(context)
+                    .map(Some)
+            }
+            Self::TIMESTAMP => {
+                era_compiler_llvm_context::eravm_evm_contract_context::block_timestamp(context)
+                    .map(Some)
+            }
+            Self::BLOCKHASH(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                let index = arguments[0].into_int_value();
+
+                era_compiler_llvm_context::eravm_evm_contract_context::block_hash(context, index)
+                    .map(Some)
+            }
+            Self::DIFFICULTY => {
+                era_compiler_llvm_context::eravm_evm_contract_context::difficulty(context).map(Some)
+            }
+            Self::COINBASE => {
+                era_compiler_llvm_context::eravm_evm_contract_context::coinbase(context).map(Some)
+            }
+            Self::BASEFEE => {
+                era_compiler_llvm_context::eravm_evm_contract_context::basefee(context).map(Some)
+            }
+            Self::MSIZE => {
+                era_compiler_llvm_context::eravm_evm_contract_context::msize(context).map(Some)
             }
 
+            instruction => instruction.into_llvm_value_common(context),
+        }
+    }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    /// Unlike EraVM, the calldata, code, and contract creation instructions are native, so they
+    /// are translated without the code segment and blueprint special cases.
+    ///
+    pub fn into_llvm_value_evm<'ctx>(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        match self {
+            Self::With(inner) => inner.into_llvm_value_evm(context),
+            Self::Set(inner) => inner.into_llvm_value_evm(context).map(|_| None),
+            Self::Seq(inner) => inner.into_llvm_value_evm(context),
+            Self::If(inner) => inner.into_llvm_value_evm(context),
+            Self::Repeat(inner) => inner.into_llvm_value_evm(context).map(|_| None),
+
+            Self::GoTo(inner) => inner.into_llvm_value_evm(context),
+            Self::Exit_To(inner) => inner.into_llvm_value_evm(context).map(|_| None),
+            Self::Label(inner) => inner.into_llvm_value_evm(context).map(|_| None),
+            Self::Deploy(inner) => inner.into_llvm_value_evm(context).map(|_| None),
+
             Self::SHA3(arguments) | Self::KECCAK256(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_crypto::sha3(
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::evm_crypto::sha3(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -911,18 +1140,14 @@ impl Instruction {
                 .map(Some)
             }
             Self::SHA3_32(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-
-                let pointer_one = era_compiler_llvm_context::Pointer::new_with_offset(
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::evm_memory::store(
                     context,
-                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
-                    context.field_type(),
                     context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
-                    "sha3_pointer_one",
+                    arguments[0].into_int_value(),
                 )?;
-                context.build_store(pointer_one, arguments[0])?;
 
-                era_compiler_llvm_context::eravm_evm_crypto::sha3(
+                era_compiler_llvm_context::evm_crypto::sha3(
                     context,
                     context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
                     context.field_const(era_compiler_common::BYTE_LENGTH_FIELD as u64),
@@ -930,26 +1155,19 @@ impl Instruction {
                 .map(Some)
             }
             Self::SHA3_64(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-
-                let pointer_one = era_compiler_llvm_context::Pointer::new_with_offset(
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::evm_memory::store(
                     context,
-                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
-                    context.field_type(),
                     context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
-                    "sha3_pointer_one",
+                    arguments[0].into_int_value(),
                 )?;
-                context.build_store(pointer_one, arguments[0])?;
-                let pointer_two = era_compiler_llvm_context::Pointer::new_with_offset(
+                era_compiler_llvm_context::evm_memory::store(
                     context,
-                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
-                    context.field_type(),
                     context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE2 as u64),
-                    "sha3_pointer_two",
+                    arguments[1].into_int_value(),
                 )?;
-                context.build_store(pointer_two, arguments[1])?;
 
-                era_compiler_llvm_context::eravm_evm_crypto::sha3(
+                era_compiler_llvm_context::evm_crypto::sha3(
                     context,
                     context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
                     context.field_const((era_compiler_common::BYTE_LENGTH_FIELD * 2) as u64),
@@ -958,16 +1176,13 @@ impl Instruction {
             }
 
             Self::MLOAD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_memory::load(
-                    context,
-                    arguments[0].into_int_value(),
-                )
-                .map(Some)
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::evm_memory::load(context, arguments[0].into_int_value())
+                    .map(Some)
             }
             Self::MSTORE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_memory::store(
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::evm_memory::store(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -975,8 +1190,8 @@ impl Instruction {
                 .map(|_| None)
             }
             Self::MSTORE8(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_memory::store_byte(
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::evm_memory::store_byte(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -984,43 +1199,24 @@ impl Instruction {
                 .map(|_| None)
             }
             Self::MCOPY(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-                let destination = era_compiler_llvm_context::Pointer::new_with_offset(
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                era_compiler_llvm_context::evm_memory::copy(
                     context,
-                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
-                    context.byte_type(),
                     arguments[0].into_int_value(),
-                    "mcopy_destination",
-                )?;
-                let source = era_compiler_llvm_context::Pointer::new_with_offset(
-                    context,
-                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
-                    context.byte_type(),
                     arguments[1].into_int_value(),
-                    "mcopy_source",
-                )?;
-
-                context.build_memcpy(
-                    context.intrinsics().memory_move_heap,
-                    destination,
-                    source,
                     arguments[2].into_int_value(),
-                    "mcopy_size",
-                )?;
-                Ok(None)
+                )
+                .map(|_| None)
             }
 
             Self::SLOAD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_storage::load(
-                    context,
-                    arguments[0].into_int_value(),
-                )
-                .map(Some)
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::evm_storage::load(context, arguments[0].into_int_value())
+                    .map(Some)
             }
             Self::SSTORE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_storage::store(
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::evm_storage::store(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -1028,16 +1224,16 @@ impl Instruction {
                 .map(|_| None)
             }
             Self::TLOAD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_storage::transient_load(
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::evm_storage::transient_load(
                     context,
                     arguments[0].into_int_value(),
                 )
                 .map(Some)
             }
             Self::TSTORE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_storage::transient_store(
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::evm_storage::transient_store(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -1046,160 +1242,70 @@ impl Instruction {
             }
 
             Self::ILOAD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_immutable::load(
-                    context,
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                let immutables_offset = code::immutables_offset(context)?;
+                let offset = context.builder().build_int_add(
+                    immutables_offset,
                     arguments[0].into_int_value(),
-                )
-                .map(Some)
+                    "iload_offset",
+                )?;
+                era_compiler_llvm_context::evm_memory::load(context, offset).map(Some)
             }
             Self::ISTORE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_immutable::store(
-                    context,
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                let immutables_offset = code::immutables_offset(context)?;
+                let offset = context.builder().build_int_add(
+                    immutables_offset,
                     arguments[0].into_int_value(),
+                    "istore_offset",
+                )?;
+                era_compiler_llvm_context::evm_memory::store(
+                    context,
+                    offset,
                     arguments[1].into_int_value(),
                 )
                 .map(|_| None)
             }
 
             Self::CALLDATALOAD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-
-                match context
-                    .code_segment()
-                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
-                {
-                    era_compiler_common::CodeSegment::Deploy => {
-                        Ok(Some(context.field_const(0).as_basic_value_enum()))
-                    }
-                    era_compiler_common::CodeSegment::Runtime => {
-                        era_compiler_llvm_context::eravm_evm_calldata::load(
-                            context,
-                            arguments[0].into_int_value(),
-                        )
-                        .map(Some)
-                    }
-                }
-            }
-            Self::CALLDATASIZE => {
-                match context
-                    .code_segment()
-                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
-                {
-                    era_compiler_common::CodeSegment::Deploy => {
-                        Ok(Some(context.field_const(0).as_basic_value_enum()))
-                    }
-                    era_compiler_common::CodeSegment::Runtime => {
-                        era_compiler_llvm_context::eravm_evm_calldata::size(context).map(Some)
-                    }
-                }
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::evm_calldata::load(
+                    context,
+                    arguments[0].into_int_value(),
+                )
+                .map(Some)
             }
+            Self::CALLDATASIZE => era_compiler_llvm_context::evm_calldata::size(context).map(Some),
             Self::CALLDATACOPY(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-
-                let source_offset = match context
-                    .code_segment()
-                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
-                {
-                    era_compiler_common::CodeSegment::Deploy => {
-                        era_compiler_llvm_context::eravm_evm_calldata::size(context)?
-                    }
-                    era_compiler_common::CodeSegment::Runtime => {
-                        arguments[1].into_int_value().as_basic_value_enum()
-                    }
-                }
-                .into_int_value();
-
-                era_compiler_llvm_context::eravm_evm_calldata::copy(
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                era_compiler_llvm_context::evm_calldata::copy(
                     context,
                     arguments[0].into_int_value(),
-                    source_offset,
+                    arguments[1].into_int_value(),
                     arguments[2].into_int_value(),
                 )
                 .map(|_| None)
             }
 
             Self::DLOAD(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-
-                match context.code_segment() {
-                    None => {
-                        panic!("code part is undefined");
-                    }
-                    Some(era_compiler_common::CodeSegment::Deploy) => {
-                        era_compiler_llvm_context::eravm_evm_calldata::load(
-                            context,
-                            arguments[0].into_int_value(),
-                        )
-                    }
-                    Some(era_compiler_common::CodeSegment::Runtime) => {
-                        era_compiler_llvm_context::eravm_evm_immutable::load(
-                            context,
-                            arguments[0].into_int_value(),
-                        )
-                    }
-                }
-                .map(Some)
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                code::load(context, arguments[0].into_int_value()).map(Some)
             }
             Self::DLOADBYTES(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-
-                match context.code_segment() {
-                    None => {
-                        anyhow::bail!(
-                            "Immutables are not available if the contract part is undefined"
-                        );
-                    }
-                    Some(era_compiler_common::CodeSegment::Deploy) => {
-                        era_compiler_llvm_context::eravm_evm_calldata::copy(
-                            context,
-                            arguments[0].into_int_value(),
-                            arguments[1].into_int_value(),
-                            arguments[2].into_int_value(),
-                        )
-                    }
-                    Some(era_compiler_common::CodeSegment::Runtime) => immutable::load_bytes(
-                        context,
-                        arguments[0].into_int_value(),
-                        arguments[1].into_int_value(),
-                        arguments[2].into_int_value(),
-                    ),
-                }
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                code::load_bytes(
+                    context,
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                )
                 .map(|_| None)
             }
 
-            Self::CODESIZE => {
-                match context
-                    .code_segment()
-                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
-                {
-                    era_compiler_common::CodeSegment::Deploy => {
-                        era_compiler_llvm_context::eravm_evm_calldata::size(context).map(Some)
-                    }
-                    era_compiler_common::CodeSegment::Runtime => {
-                        let code_source =
-                            era_compiler_llvm_context::eravm_general::code_source(context)?;
-                        era_compiler_llvm_context::eravm_evm_ext_code::size(
-                            context,
-                            code_source.into_int_value(),
-                        )
-                        .map(Some)
-                    }
-                }
-            }
+            Self::CODESIZE => era_compiler_llvm_context::evm_code::size(context).map(Some),
             Self::CODECOPY(arguments) => {
-                if let era_compiler_common::CodeSegment::Runtime = context
-                    .code_segment()
-                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
-                {
-                    anyhow::bail!(
-                        "The `CODECOPY` instruction is not supported in the runtime code",
-                    );
-                }
-
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_calldata::copy(
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                era_compiler_llvm_context::evm_code::copy(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -1209,11 +1315,11 @@ impl Instruction {
             }
 
             Self::RETURNDATASIZE => {
-                era_compiler_llvm_context::eravm_evm_return_data::size(context).map(Some)
+                era_compiler_llvm_context::evm_return_data::size(context).map(Some)
             }
             Self::RETURNDATACOPY(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_return_data::copy(
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                era_compiler_llvm_context::evm_return_data::copy(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -1223,122 +1329,41 @@ impl Instruction {
             }
 
             Self::EXTCODESIZE(arguments) => {
-                let arguments = Self::translate_arguments::<1>(arguments, context)?;
-                let mut requested_size = era_compiler_llvm_context::eravm_evm_ext_code::size(
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::evm_ext_code::size(
                     context,
-                    arguments[0].value.into_int_value(),
-                )?;
-                if Some(crate::r#const::EXTCODESIZE_BLUEPRINT_ARGUMENT_NAME)
-                    == arguments[0].original.as_deref()
-                {
-                    let result_pointer = context.build_alloca(
-                        context.field_type(),
-                        "extcodesize_create_target_result_pointer",
-                    )?;
-                    context.build_store(
-                        result_pointer,
-                        context.field_const(
-                            era_compiler_llvm_context::eravm_const::DEPLOYER_CALL_HEADER_SIZE
-                                as u64,
-                        ),
-                    )?;
-
-                    let is_zero_block =
-                        context.append_basic_block("extcodesize_create_target_is_zero_block");
-                    let join_block =
-                        context.append_basic_block("extcodesize_create_target_join_block");
-                    let is_zero = context.builder().build_int_compare(
-                        inkwell::IntPredicate::EQ,
-                        requested_size.into_int_value(),
-                        context.field_const(0),
-                        "extcodesize_create_target_is_zero",
-                    )?;
-                    context.builder().build_conditional_branch(
-                        is_zero,
-                        is_zero_block,
-                        join_block,
-                    )?;
-
-                    context.set_basic_block(is_zero_block);
-                    context.build_store(result_pointer, context.field_const(0))?;
-                    context.build_unconditional_branch(join_block)?;
-
-                    context.set_basic_block(join_block);
-                    requested_size =
-                        context.build_load(result_pointer, "extcodesize_create_target_result")?;
-                }
-                Ok(Some(requested_size))
+                    arguments[0].into_int_value(),
+                )
+                .map(Some)
             }
             Self::EXTCODEHASH(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_ext_code::hash(
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                era_compiler_llvm_context::evm_ext_code::hash(
                     context,
                     arguments[0].into_int_value(),
                 )
                 .map(Some)
-            }
-            Self::EXTCODECOPY(arguments) => {
-                let arguments = Self::translate_arguments::<4>(arguments, context)?;
-
-                if Some(crate::r#const::EXTCODESIZE_BLUEPRINT_ARGUMENT_NAME)
-                    != arguments[0].original.as_deref()
-                {
-                    anyhow::bail!(
-                        "The `EXTCODECOPY` instruction is only supported for the `create_from_blueprint built-in."
-                    );
-                }
-
-                let hash_value = era_compiler_llvm_context::eravm_evm_ext_code::hash(
-                    context,
-                    arguments[0].value.into_int_value(),
-                )?;
-
-                let hash_heap_offset = context.builder().build_int_add(
-                    arguments[1].value.into_int_value(),
-                    context.field_const(
-                        (era_compiler_common::BYTE_LENGTH_X32
-                            + era_compiler_common::BYTE_LENGTH_FIELD)
-                            as u64,
-                    ),
-                    "extcodecopy_hash_offset",
-                )?;
-                let hash_heap_pointer = era_compiler_llvm_context::Pointer::new_with_offset(
-                    context,
-                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
-                    context.field_type(),
-                    hash_heap_offset,
-                    "extcodecopy_hash_destination",
-                )?;
-                context.build_store(hash_heap_pointer, hash_value)?;
-
-                let hash_aux_heap_offset = context.field_const(
-                    era_compiler_llvm_context::eravm_const::HEAP_AUX_OFFSET_EXTERNAL_CALL
-                        + (era_compiler_common::BYTE_LENGTH_X32
-                            + era_compiler_common::BYTE_LENGTH_FIELD)
-                            as u64,
-                );
-                let hash_aux_heap_pointer = era_compiler_llvm_context::Pointer::new_with_offset(
+            }
+            Self::EXTCODECOPY(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 4>(arguments, context)?;
+                era_compiler_llvm_context::evm_ext_code::copy(
                     context,
-                    era_compiler_llvm_context::EraVMAddressSpace::HeapAuxiliary,
-                    context.field_type(),
-                    hash_aux_heap_offset,
-                    "extcodecopy_hash_destination",
-                )?;
-                context.build_store(hash_aux_heap_pointer, hash_value)?;
-
-                Ok(None)
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                    arguments[3].into_int_value(),
+                )
+                .map(|_| None)
             }
 
-            Self::RETURN(inner) => inner.into_llvm_value(context).map(|_| None),
-            Self::REVERT(inner) => inner.into_llvm_value(context).map(|_| None),
-            Self::STOP => era_compiler_llvm_context::eravm_evm_return::stop(context).map(|_| None),
-            Self::INVALID => {
-                era_compiler_llvm_context::eravm_evm_return::invalid(context).map(|_| None)
-            }
+            Self::RETURN(inner) => inner.into_llvm_value_evm(context).map(|_| None),
+            Self::REVERT(inner) => inner.into_llvm_value_evm(context).map(|_| None),
+            Self::STOP => era_compiler_llvm_context::evm_return::stop(context).map(|_| None),
+            Self::INVALID => era_compiler_llvm_context::evm_return::invalid(context).map(|_| None),
 
             Self::LOG0(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<2>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_event::log(
+                let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+                era_compiler_llvm_context::evm_event::log(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -1347,8 +1372,8 @@ impl Instruction {
                 .map(|_| None)
             }
             Self::LOG1(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_event::log(
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                era_compiler_llvm_context::evm_event::log(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -1360,8 +1385,8 @@ impl Instruction {
                 .map(|_| None)
             }
             Self::LOG2(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<4>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_event::log(
+                let arguments = Self::translate_arguments_llvm::<_, 4>(arguments, context)?;
+                era_compiler_llvm_context::evm_event::log(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -1373,8 +1398,8 @@ impl Instruction {
                 .map(|_| None)
             }
             Self::LOG3(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<5>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_event::log(
+                let arguments = Self::translate_arguments_llvm::<_, 5>(arguments, context)?;
+                era_compiler_llvm_context::evm_event::log(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -1386,8 +1411,8 @@ impl Instruction {
                 .map(|_| None)
             }
             Self::LOG4(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<6>(arguments, context)?;
-                era_compiler_llvm_context::eravm_evm_event::log(
+                let arguments = Self::translate_arguments_llvm::<_, 6>(arguments, context)?;
+                era_compiler_llvm_context::evm_event::log(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
@@ -1400,182 +1425,427 @@ impl Instruction {
             }
 
             Self::CALL(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<7>(arguments, context)?;
-
-                let gas = arguments[0].into_int_value();
-                let address = arguments[1].into_int_value();
-                let value = arguments[2].into_int_value();
-                let input_offset = arguments[3].into_int_value();
-                let input_size = arguments[4].into_int_value();
-                let output_offset = arguments[5].into_int_value();
-                let output_size = arguments[6].into_int_value();
-
-                era_compiler_llvm_context::eravm_evm_call::default(
+                let arguments = Self::translate_arguments_llvm::<_, 7>(arguments, context)?;
+                era_compiler_llvm_context::evm_call::call(
                     context,
-                    context.llvm_runtime().far_call,
-                    gas,
-                    address,
-                    Some(value),
-                    input_offset,
-                    input_size,
-                    output_offset,
-                    output_size,
-                    vec![],
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                    arguments[3].into_int_value(),
+                    arguments[4].into_int_value(),
+                    arguments[5].into_int_value(),
+                    arguments[6].into_int_value(),
                 )
                 .map(Some)
             }
             Self::STATICCALL(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<6>(arguments, context)?;
-
-                let gas = arguments[0].into_int_value();
-                let address = arguments[1].into_int_value();
-                let input_offset = arguments[2].into_int_value();
-                let input_size = arguments[3].into_int_value();
-                let output_offset = arguments[4].into_int_value();
-                let output_size = arguments[5].into_int_value();
-
-                era_compiler_llvm_context::eravm_evm_call::default(
+                let arguments = Self::translate_arguments_llvm::<_, 6>(arguments, context)?;
+                era_compiler_llvm_context::evm_call::static_call(
                     context,
-                    context.llvm_runtime().static_call,
-                    gas,
-                    address,
-                    None,
-                    input_offset,
-                    input_size,
-                    output_offset,
-                    output_size,
-                    vec![],
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                    arguments[3].into_int_value(),
+                    arguments[4].into_int_value(),
+                    arguments[5].into_int_value(),
                 )
                 .map(Some)
             }
             Self::DELEGATECALL(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<6>(arguments, context)?;
-
-                let gas = arguments[0].into_int_value();
-                let address = arguments[1].into_int_value();
-                let input_offset = arguments[2].into_int_value();
-                let input_size = arguments[3].into_int_value();
-                let output_offset = arguments[4].into_int_value();
-                let output_size = arguments[5].into_int_value();
-
-                era_compiler_llvm_context::eravm_evm_call::default(
+                let arguments = Self::translate_arguments_llvm::<_, 6>(arguments, context)?;
+                era_compiler_llvm_context::evm_call::delegate_call(
                     context,
-                    context.llvm_runtime().delegate_call,
-                    gas,
-                    address,
-                    None,
-                    input_offset,
-                    input_size,
-                    output_offset,
-                    output_size,
-                    vec![],
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                    arguments[3].into_int_value(),
+                    arguments[4].into_int_value(),
+                    arguments[5].into_int_value(),
                 )
                 .map(Some)
             }
 
             Self::CREATE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<3>(arguments, context)?;
-
-                create::create(
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                era_compiler_llvm_context::evm_create::create(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
                     arguments[2].into_int_value(),
-                    None,
                 )
                 .map(Some)
             }
             Self::CREATE2(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<4>(arguments, context)?;
-
-                create::create(
+                let arguments = Self::translate_arguments_llvm::<_, 4>(arguments, context)?;
+                era_compiler_llvm_context::evm_create::create2(
                     context,
                     arguments[0].into_int_value(),
                     arguments[1].into_int_value(),
                     arguments[2].into_int_value(),
-                    Some(arguments[3].into_int_value()),
+                    arguments[3].into_int_value(),
                 )
                 .map(Some)
             }
 
-            Self::ADDRESS => context.build_call(context.intrinsics().address, &[], "address"),
-            Self::CALLER => context.build_call(context.intrinsics().caller, &[], "caller"),
-
-            Self::CALLVALUE => {
-                era_compiler_llvm_context::eravm_evm_ether_gas::value(context).map(Some)
+            Self::ADDRESS => {
+                era_compiler_llvm_context::evm_contract_context::address(context).map(Some)
             }
-            Self::GAS => era_compiler_llvm_context::eravm_evm_ether_gas::gas(context).map(Some),
+            Self::CALLER => {
+                era_compiler_llvm_context::evm_contract_context::caller(context).map(Some)
+            }
+
+            Self::CALLVALUE => era_compiler_llvm_context::evm_ether_gas::value(context).map(Some),
+            Self::GAS => era_compiler_llvm_context::evm_ether_gas::gas(context).map(Some),
             Self::BALANCE(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
 
                 let address = arguments[0].into_int_value();
-                era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address).map(Some)
+                era_compiler_llvm_context::evm_ether_gas::balance(context, address).map(Some)
             }
             Self::SELFBALANCE => {
-                let address = context
-                    .build_call(context.intrinsics().address, &[], "self_balance_address")?
-                    .expect("Always exists")
-                    .into_int_value();
-
-                era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address).map(Some)
+                era_compiler_llvm_context::evm_ether_gas::self_balance(context).map(Some)
             }
 
             Self::GASLIMIT => {
-                era_compiler_llvm_context::eravm_evm_contract_context::gas_limit(context).map(Some)
+                era_compiler_llvm_context::evm_contract_context::gas_limit(context).map(Some)
             }
             Self::GASPRICE => {
-                era_compiler_llvm_context::eravm_evm_contract_context::gas_price(context).map(Some)
+                era_compiler_llvm_context::evm_contract_context::gas_price(context).map(Some)
             }
             Self::ORIGIN => {
-                era_compiler_llvm_context::eravm_evm_contract_context::origin(context).map(Some)
+                era_compiler_llvm_context::evm_contract_context::origin(context).map(Some)
             }
             Self::CHAINID => {
-                era_compiler_llvm_context::eravm_evm_contract_context::chain_id(context).map(Some)
+                era_compiler_llvm_context::evm_contract_context::chain_id(context).map(Some)
             }
             Self::NUMBER => {
-                era_compiler_llvm_context::eravm_evm_contract_context::block_number(context)
-                    .map(Some)
+                era_compiler_llvm_context::evm_contract_context::block_number(context).map(Some)
             }
             Self::TIMESTAMP => {
-                era_compiler_llvm_context::eravm_evm_contract_context::block_timestamp(context)
-                    .map(Some)
+                era_compiler_llvm_context::evm_contract_context::block_timestamp(context).map(Some)
             }
             Self::BLOCKHASH(arguments) => {
-                let arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
                 let index = arguments[0].into_int_value();
 
-                era_compiler_llvm_context::eravm_evm_contract_context::block_hash(context, index)
+                era_compiler_llvm_context::evm_contract_context::block_hash(context, index)
                     .map(Some)
             }
             Self::DIFFICULTY => {
-                era_compiler_llvm_context::eravm_evm_contract_context::difficulty(context).map(Some)
+                era_compiler_llvm_context::evm_contract_context::difficulty(context).map(Some)
             }
             Self::COINBASE => {
-                era_compiler_llvm_context::eravm_evm_contract_context::coinbase(context).map(Some)
+                era_compiler_llvm_context::evm_contract_context::coinbase(context).map(Some)
             }
             Self::BASEFEE => {
-                era_compiler_llvm_context::eravm_evm_contract_context::basefee(context).map(Some)
+                era_compiler_llvm_context::evm_contract_context::basefee(context).map(Some)
             }
             Self::MSIZE => {
-                era_compiler_llvm_context::eravm_evm_contract_context::msize(context).map(Some)
+                era_compiler_llvm_context::evm_contract_context::msize(context).map(Some)
+            }
+
+            instruction => instruction.into_llvm_value_common(context),
+        }
+    }
+
+    ///
+    /// Converts the instruction whose translation does not depend on the target to an LLVM value.
+    ///
+    fn into_llvm_value_common<'ctx, C>(
+        self,
+        context: &mut C,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+    where
+        C: Lowering<'ctx>,
+    {
+        match self {
+            Self::Jump(arguments) => {
+                let _arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                let block = context.current_return_block();
+                context.build_unconditional_branch(block)?;
+                Ok(None)
+            }
+            Self::Cleanup_Repeat => Ok(None),
+            Self::Break => {
+                let block = context.current_loop_join_block();
+                context.build_unconditional_branch(block)?;
+                Ok(None)
+            }
+            Self::Continue => {
+                let block = context.current_loop_continue_block();
+                context.build_unconditional_branch(block)?;
+                Ok(None)
+            }
+            Self::Pass => Ok(None),
+            Self::Symbol(_inner) => Ok(None),
+            Self::Unique_Symbol(_inner) => Ok(None),
+
+            Self::UCLAMP(arguments) => Self::clamp(context, arguments, false),
+            Self::CLAMP(arguments) => Self::clamp(context, arguments, true),
+            Self::UCLAMPLT(arguments) => {
+                Self::clamp_with_predicate(context, arguments, inkwell::IntPredicate::ULT)
+            }
+            Self::UCLAMPLE(arguments) => {
+                Self::clamp_with_predicate(context, arguments, inkwell::IntPredicate::ULE)
+            }
+            Self::UCLAMPGT(arguments) => {
+                Self::clamp_with_predicate(context, arguments, inkwell::IntPredicate::UGT)
+            }
+            Self::UCLAMPGE(arguments) => {
+                Self::clamp_with_predicate(context, arguments, inkwell::IntPredicate::UGE)
+            }
+            Self::CLAMPLT(arguments) => {
+                Self::clamp_with_predicate(context, arguments, inkwell::IntPredicate::SLT)
+            }
+            Self::CLAMPLE(arguments) => {
+                Self::clamp_with_predicate(context, arguments, inkwell::IntPredicate::SLE)
+            }
+            Self::CLAMPGT(arguments) => {
+                Self::clamp_with_predicate(context, arguments, inkwell::IntPredicate::SGT)
+            }
+            Self::CLAMPGE(arguments) => {
+                Self::clamp_with_predicate(context, arguments, inkwell::IntPredicate::SGE)
+            }
+            Self::CLAMP_NONZERO(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                clamp::with_predicate(
+                    context,
+                    arguments[0].into_int_value(),
+                    context.field_const(0),
+                    inkwell::IntPredicate::NE,
+                )
+                .map(Some)
+            }
+
+            Self::CEIL32(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                offset::ceil_32(context, arguments[0].into_int_value()).map(Some)
+            }
+            Self::SELECT(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+                let condition = context.builder().build_int_compare(
+                    inkwell::IntPredicate::NE,
+                    arguments[0].into_int_value(),
+                    context.field_const(0),
+                    "select_condition",
+                )?;
+                Ok(Some(context.builder().build_select(
+                    condition,
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                    "select",
+                )?))
+            }
+
+            Self::Assert(inner) => inner.into_llvm_value(context, false).map(|_| None),
+            Self::Assert_Unreachable(inner) => inner.into_llvm_value(context, true).map(|_| None),
+
+            Self::Var_List(_inner) => Ok(None),
+
+            Self::POP(arguments) => {
+                let _arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                Ok(None)
+            }
+
+            Self::ADD(arguments) => Self::binary(context, arguments, BinaryOperation::Addition),
+            Self::SUB(arguments) => Self::binary(context, arguments, BinaryOperation::Subtraction),
+            Self::MUL(arguments) => {
+                Self::binary(context, arguments, BinaryOperation::Multiplication)
+            }
+            Self::DIV(arguments) => Self::binary(context, arguments, BinaryOperation::Division),
+            Self::MOD(arguments) => Self::binary(context, arguments, BinaryOperation::Remainder),
+            Self::SDIV(arguments) => {
+                Self::binary(context, arguments, BinaryOperation::DivisionSigned)
+            }
+            Self::SMOD(arguments) => {
+                Self::binary(context, arguments, BinaryOperation::RemainderSigned)
+            }
+
+            Self::LT(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::ULT),
+            Self::LE(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::ULE),
+            Self::GT(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::UGT),
+            Self::GE(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::UGE),
+            Self::EQ(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::EQ),
+            Self::NE(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::NE),
+            Self::ISZERO(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                context
+                    .lower_comparison(
+                        arguments[0].into_int_value(),
+                        context.field_const(0),
+                        inkwell::IntPredicate::EQ,
+                    )
+                    .map(Some)
+            }
+            Self::SLT(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::SLT),
+            Self::SLE(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::SLE),
+            Self::SGT(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::SGT),
+            Self::SGE(arguments) => Self::compare(context, arguments, inkwell::IntPredicate::SGE),
+
+            Self::OR(arguments) => Self::binary(context, arguments, BinaryOperation::Or),
+            Self::XOR(arguments) => Self::binary(context, arguments, BinaryOperation::Xor),
+            Self::NOT(arguments) => {
+                let arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                context
+                    .lower_binary(
+                        BinaryOperation::Xor,
+                        arguments[0].into_int_value(),
+                        context.field_type().const_all_ones(),
+                    )
+                    .map(Some)
+            }
+            Self::AND(arguments) => Self::binary(context, arguments, BinaryOperation::And),
+            Self::SHL(arguments) => Self::binary(context, arguments, BinaryOperation::ShiftLeft),
+            Self::SHR(arguments) => Self::binary(context, arguments, BinaryOperation::ShiftRight),
+            Self::SAR(arguments) => {
+                Self::binary(context, arguments, BinaryOperation::ShiftRightArithmetic)
+            }
+            Self::BYTE(arguments) => Self::binary(context, arguments, BinaryOperation::Byte),
+
+            Self::ADDMOD(arguments) => {
+                Self::modular(context, arguments, ModularOperation::Addition)
+            }
+            Self::MULMOD(arguments) => {
+                Self::modular(context, arguments, ModularOperation::Multiplication)
+            }
+            Self::EXP(arguments) => Self::binary(context, arguments, BinaryOperation::Exponent),
+            Self::SIGNEXTEND(arguments) => {
+                Self::binary(context, arguments, BinaryOperation::SignExtend)
             }
 
             Self::CALLCODE(arguments) => {
-                let _arguments = Self::translate_arguments_llvm::<7>(arguments, context)?;
-                anyhow::bail!("The `CALLCODE` instruction is not supported")
+                let _arguments = Self::translate_arguments_llvm::<_, 7>(arguments, context)?;
+                Err(
+                    Error::unsupported("The `CALLCODE` instruction is not supported".to_owned())
+                        .into(),
+                )
+            }
+            Self::PC => {
+                Err(Error::unsupported("The `PC` instruction is not supported".to_owned()).into())
             }
-            Self::PC => anyhow::bail!("The `PC` instruction is not supported"),
             Self::SELFDESTRUCT(arguments) => {
-                let _arguments = Self::translate_arguments_llvm::<1>(arguments, context)?;
-                anyhow::bail!("The `SELFDESTRUCT` instruction is not supported")
+                let _arguments = Self::translate_arguments_llvm::<_, 1>(arguments, context)?;
+                Err(Error::unsupported(
+                    "The `SELFDESTRUCT` instruction is not supported".to_owned(),
+                )
+                .into())
             }
 
             Self::Unknown(value) => {
                 anyhow::bail!("Unknown LLL instruction: {value}");
             }
+
+            instruction => anyhow::bail!("The instruction `{instruction}` is target-specific"),
         }
     }
 
+    ///
+    /// Translates the two-sides bounded clamp.
+    ///
+    fn clamp<'ctx, C>(
+        context: &mut C,
+        arguments: [Box<Expression>; 3],
+        is_signed: bool,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+    where
+        C: Lowering<'ctx>,
+    {
+        let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+        clamp::ordinary(
+            context,
+            arguments[0].into_int_value(),
+            arguments[1].into_int_value(),
+            arguments[2].into_int_value(),
+            is_signed,
+        )
+        .map(Some)
+    }
+
+    ///
+    /// Translates the one-side bounded clamp with predicate.
+    ///
+    fn clamp_with_predicate<'ctx, C>(
+        context: &mut C,
+        arguments: [Box<Expression>; 2],
+        predicate: inkwell::IntPredicate,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+    where
+        C: Lowering<'ctx>,
+    {
+        let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+        clamp::with_predicate(
+            context,
+            arguments[0].into_int_value(),
+            arguments[1].into_int_value(),
+            predicate,
+        )
+        .map(Some)
+    }
+
+    ///
+    /// Translates the arithmetic, bitwise, or math binary operation.
+    ///
+    fn binary<'ctx, C>(
+        context: &mut C,
+        arguments: [Box<Expression>; 2],
+        operation: BinaryOperation,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+    where
+        C: Lowering<'ctx>,
+    {
+        let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+        context
+            .lower_binary(
+                operation,
+                arguments[0].into_int_value(),
+                arguments[1].into_int_value(),
+            )
+            .map(Some)
+    }
+
+    ///
+    /// Translates the comparison with the predicate.
+    ///
+    fn compare<'ctx, C>(
+        context: &mut C,
+        arguments: [Box<Expression>; 2],
+        predicate: inkwell::IntPredicate,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+    where
+        C: Lowering<'ctx>,
+    {
+        let arguments = Self::translate_arguments_llvm::<_, 2>(arguments, context)?;
+        context
+            .lower_comparison(
+                arguments[0].into_int_value(),
+                arguments[1].into_int_value(),
+                predicate,
+            )
+            .map(Some)
+    }
+
+    ///
+    /// Translates the modular arithmetic operation.
+    ///
+    fn modular<'ctx, C>(
+        context: &mut C,
+        arguments: [Box<Expression>; 3],
+        operation: ModularOperation,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+    where
+        C: Lowering<'ctx>,
+    {
+        let arguments = Self::translate_arguments_llvm::<_, 3>(arguments, context)?;
+        context
+            .lower_modular(
+                operation,
+                arguments[0].into_int_value(),
+                arguments[1].into_int_value(),
+                arguments[2].into_int_value(),
+            )
+            .map(Some)
+    }
+
     ///
     /// Checks if it is code offset is subtracted from `EXTCODESIZE`.
     ///
@@ -1599,3 +1869,14 @@ impl Instruction {
         Ok(None)
     }
 }
+
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = serde_json::to_value(self).map_err(|_| std::fmt::Error)?;
+        write!(
+            f,
+            "{}",
+            crate::project::contract::vyper::expression::printer::print(&value)
+        )
+    }
+}
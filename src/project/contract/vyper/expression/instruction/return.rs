@@ -9,7 +9,7 @@ use crate::project::contract::vyper::expression::Expression;
 /// The `return` instruction.
 ///
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
-pub struct Return([Box<Expression>; 2]);
+pub struct Return(pub [Box<Expression>; 2]);
 
 impl Default for Return {
     fn default() -> Self {
@@ -34,4 +34,19 @@ impl Return {
             arguments[1].into_int_value(),
         )
     }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    pub fn into_llvm_value_evm(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        let arguments = Instruction::translate_arguments_llvm_evm::<2>(self.0, context)?;
+        era_compiler_llvm_context::evm_return::r#return(
+            context,
+            arguments[0].into_int_value(),
+            arguments[1].into_int_value(),
+        )
+    }
 }
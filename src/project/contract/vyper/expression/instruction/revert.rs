@@ -9,7 +9,7 @@ use crate::project::contract::vyper::expression::Expression;
 /// The `revert` instruction.
 ///
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
-pub struct Revert([Box<Expression>; 2]);
+pub struct Revert(pub [Box<Expression>; 2]);
 
 impl Default for Revert {
     fn default() -> Self {
@@ -34,4 +34,19 @@ impl Revert {
             arguments[1].into_int_value(),
         )
     }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    pub fn into_llvm_value_evm(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        let arguments = Instruction::translate_arguments_llvm_evm::<2>(self.0, context)?;
+        era_compiler_llvm_context::evm_return::revert(
+            context,
+            arguments[0].into_int_value(),
+            arguments[1].into_int_value(),
+        )
+    }
 }
@@ -137,7 +137,10 @@ impl Seq {
     pub fn function_name(&self) -> anyhow::Result<String> {
         match self.0.first() {
             Some(Expression::Instruction(Instruction::Label(label))) => label.name(),
-            expression => anyhow::bail!("Expected a function sequence, found `{expression:?}`"),
+            Some(expression) => {
+                anyhow::bail!("Expected a function sequence, found `{expression}`")
+            }
+            None => anyhow::bail!("Expected a function sequence, found an empty sequence"),
         }
     }
 
@@ -168,6 +171,34 @@ impl Seq {
 
         Ok(result)
     }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    pub fn into_llvm_value_evm<'ctx>(
+        mut self,
+        context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        let (mut labels, expressions) = self.drain_and_split();
+
+        for label in labels.iter_mut() {
+            label.declare_evm(context)?;
+        }
+        for label in labels.into_iter() {
+            label.into_llvm_value_evm(context)?;
+        }
+
+        let mut result = None;
+        for expression in expressions.into_iter() {
+            if context.basic_block().get_terminator().is_some() {
+                break;
+            }
+
+            result = expression.into_llvm_value_evm(context)?;
+        }
+
+        Ok(result)
+    }
 }
 
 impl era_compiler_llvm_context::EraVMWriteLLVM for Seq {
@@ -194,3 +225,32 @@ impl era_compiler_llvm_context::EraVMWriteLLVM for Seq {
         Ok(())
     }
 }
+
+impl era_compiler_llvm_context::EVMWriteLLVM for Seq {
+    fn into_llvm(
+        mut self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        let current_block = context.basic_block();
+
+        let (mut labels, expressions) = self.drain_and_split();
+
+        for label in labels.iter_mut() {
+            label.declare_evm(context)?;
+        }
+        for label in labels.into_iter() {
+            label.into_llvm_value_evm(context)?;
+        }
+
+        context.set_basic_block(current_block);
+        for expression in expressions.into_iter() {
+            if context.basic_block().get_terminator().is_some() {
+                break;
+            }
+
+            expression.into_llvm_value_evm(context)?;
+        }
+
+        Ok(())
+    }
+}
@@ -10,7 +10,7 @@ use crate::project::contract::vyper::expression::Expression;
 /// The Vyper LLL-specific `set` instruction.
 ///
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
-pub struct Set([Box<Expression>; 2]);
+pub struct Set(pub [Box<Expression>; 2]);
 
 impl Set {
     ///
@@ -36,4 +36,28 @@ impl Set {
 
         Ok(())
     }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    pub fn into_llvm_value_evm(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        let [identifier, value] = self.0;
+        let identifier = identifier.try_into_identifier()?;
+
+        let pointer = context
+            .current_function()
+            .borrow()
+            .get_stack_pointer(identifier.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Variable `{}` not found", identifier))?;
+
+        let value = value
+            .into_llvm_value_evm(context)?
+            .ok_or_else(|| anyhow::anyhow!("Expected a value"))?;
+        context.build_store(pointer, value)?;
+
+        Ok(())
+    }
 }
@@ -12,7 +12,7 @@ use crate::project::contract::vyper::expression::Expression;
 /// The Vyper LLL-specific `with` instruction.
 ///
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
-pub struct With([Box<Expression>; 3]);
+pub struct With(pub [Box<Expression>; 3]);
 
 impl With {
     ///
@@ -64,4 +64,44 @@ impl With {
 
         Ok(result)
     }
+
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    pub fn into_llvm_value_evm<'ctx>(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        let [identifier, value, block] = self.0;
+        let identifier = identifier.try_into_identifier()?;
+
+        let pointer = context.build_alloca(context.field_type(), identifier.as_str())?;
+        let value = value
+            .into_llvm_value_evm(context)?
+            .ok_or_else(|| anyhow::anyhow!("Expected a value"))?;
+        context.build_store(pointer, value)?;
+        let shadowed_pointer = context
+            .current_function()
+            .borrow_mut()
+            .insert_stack_pointer(identifier.clone(), pointer);
+
+        let result = block.into_llvm_value_evm(context)?;
+
+        match shadowed_pointer {
+            Some(old_pointer) => {
+                context
+                    .current_function()
+                    .borrow_mut()
+                    .insert_stack_pointer(identifier, old_pointer);
+            }
+            None => {
+                context
+                    .current_function()
+                    .borrow_mut()
+                    .remove_stack_pointer(identifier.as_str());
+            }
+        }
+
+        Ok(result)
+    }
 }
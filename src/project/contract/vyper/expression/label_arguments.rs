@@ -0,0 +1,120 @@
+//!
+//! The LLL IR label arguments lowering.
+//!
+//! On EraVM, the arguments of block jumps are bound to the label variables using the function
+//! Vyper data. The EVM context does not have such data, so the arguments are moved into `set`
+//! instructions placed before the jump.
+//!
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+///
+/// Moves the arguments of `goto` block jumps and `exit_to` into `set` instructions.
+///
+/// Function calls and `exit_to return_pc` are left as is, as their arguments are passed to
+/// the LLVM function or ignored.
+///
+pub fn lower(mut value: Value) -> Value {
+    let mut labels = BTreeMap::new();
+    collect(&value, &mut labels);
+    rewrite(&mut value, &labels);
+    value
+}
+
+///
+/// Collects the label variable lists, excluding the return address.
+///
+fn collect(value: &Value, labels: &mut BTreeMap<String, Vec<Value>>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::Array(arguments)) = object.get("label") {
+                if let (Some(Value::String(name)), Some(Value::Object(variable_list))) =
+                    (arguments.first(), arguments.get(1))
+                {
+                    if let Some(Value::Array(variables)) = variable_list.get("var_list") {
+                        let variables = variables
+                            .iter()
+                            .filter(|variable| {
+                                variable.as_str()
+                                    != Some(crate::r#const::VARIABLE_IDENTIFIER_RETURN_PC)
+                            })
+                            .cloned()
+                            .collect();
+                        labels.insert(name.to_owned(), variables);
+                    }
+                }
+            }
+            for value in object.values() {
+                collect(value, labels);
+            }
+        }
+        Value::Array(array) => {
+            for value in array.iter() {
+                collect(value, labels);
+            }
+        }
+        _ => {}
+    }
+}
+
+///
+/// Rewrites the jumps with arguments in post-order.
+///
+fn rewrite(value: &mut Value, labels: &BTreeMap<String, Vec<Value>>) {
+    match value {
+        Value::Object(object) => {
+            for value in object.values_mut() {
+                rewrite(value, labels);
+            }
+        }
+        Value::Array(array) => {
+            for value in array.iter_mut() {
+                rewrite(value, labels);
+            }
+            return;
+        }
+        _ => return,
+    }
+
+    if let Some(replacement) = lower_jump(value, labels) {
+        *value = replacement;
+    }
+}
+
+///
+/// Returns the `seq` of `set` instructions followed by the jump, if the value is a block jump
+/// with arguments.
+///
+fn lower_jump(value: &Value, labels: &BTreeMap<String, Vec<Value>>) -> Option<Value> {
+    let object = value.as_object().filter(|object| object.len() == 1)?;
+    let (instruction, arguments) = object.iter().next()?;
+    let arguments = arguments.as_array()?;
+    let label_name = arguments.first()?.as_str()?;
+    if arguments.len() < 2 {
+        return None;
+    }
+
+    let label = match instruction.as_str() {
+        "goto"
+            if label_name.ends_with(crate::r#const::LABEL_SUFFIX_CLEANUP)
+                || label_name == crate::r#const::FUNCTION_IDENTIFIER_FALLBACK =>
+        {
+            label_name
+        }
+        "exit_to" if label_name != crate::r#const::VARIABLE_IDENTIFIER_RETURN_PC => label_name
+            .strip_prefix(crate::r#const::LABEL_DESTINATION_PREFIX)
+            .unwrap_or(label_name),
+        _ => return None,
+    };
+    let variables = labels.get(label)?;
+
+    let mut sequence: Vec<Value> = variables
+        .iter()
+        .zip(arguments.iter().skip(1))
+        .map(|(variable, argument)| serde_json::json!({ "set": [variable, argument] }))
+        .collect();
+    sequence.push(serde_json::json!({ instruction.as_str(): [label_name] }));
+    Some(serde_json::json!({ "seq": sequence }))
+}
@@ -0,0 +1,42 @@
+//!
+//! The binary operation.
+//!
+
+///
+/// The binary operation whose semantics do not depend on the target.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperation {
+    /// The `add` instruction.
+    Addition,
+    /// The `sub` instruction.
+    Subtraction,
+    /// The `mul` instruction.
+    Multiplication,
+    /// The `div` instruction.
+    Division,
+    /// The `mod` instruction.
+    Remainder,
+    /// The `sdiv` instruction.
+    DivisionSigned,
+    /// The `smod` instruction.
+    RemainderSigned,
+    /// The `or` instruction.
+    Or,
+    /// The `xor` instruction.
+    Xor,
+    /// The `and` instruction.
+    And,
+    /// The `shl` instruction.
+    ShiftLeft,
+    /// The `shr` instruction.
+    ShiftRight,
+    /// The `sar` instruction.
+    ShiftRightArithmetic,
+    /// The `byte` instruction.
+    Byte,
+    /// The `exp` instruction.
+    Exponent,
+    /// The `signextend` instruction.
+    SignExtend,
+}
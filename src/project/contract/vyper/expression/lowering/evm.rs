@@ -0,0 +1,138 @@
+//!
+//! The EVM lowering target.
+//!
+
+use era_compiler_llvm_context::IContext;
+
+use crate::project::contract::vyper::expression::Expression;
+
+use super::binary_operation::BinaryOperation;
+use super::modular_operation::ModularOperation;
+use super::Lowering;
+
+impl<'ctx> Lowering<'ctx> for era_compiler_llvm_context::EVMContext<'ctx> {
+    fn lower_expression(
+        &mut self,
+        expression: Expression,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        expression.into_llvm_value_evm(self)
+    }
+
+    fn current_return_block(&self) -> inkwell::basic_block::BasicBlock<'ctx> {
+        self.current_function().borrow().return_block()
+    }
+
+    fn current_loop_join_block(&self) -> inkwell::basic_block::BasicBlock<'ctx> {
+        self.r#loop().join_block
+    }
+
+    fn current_loop_continue_block(&self) -> inkwell::basic_block::BasicBlock<'ctx> {
+        self.r#loop().continue_block
+    }
+
+    fn lower_revert(
+        &mut self,
+        offset: inkwell::values::IntValue<'ctx>,
+        length: inkwell::values::IntValue<'ctx>,
+    ) -> anyhow::Result<()> {
+        era_compiler_llvm_context::evm_return::revert(self, offset, length)?;
+        Ok(())
+    }
+
+    fn lower_invalid(&mut self) -> anyhow::Result<()> {
+        era_compiler_llvm_context::evm_return::invalid(self)?;
+        Ok(())
+    }
+
+    fn lower_binary(
+        &mut self,
+        operation: BinaryOperation,
+        operand_1: inkwell::values::IntValue<'ctx>,
+        operand_2: inkwell::values::IntValue<'ctx>,
+    ) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+        match operation {
+            BinaryOperation::Addition => {
+                era_compiler_llvm_context::evm_arithmetic::addition(self, operand_1, operand_2)
+            }
+            BinaryOperation::Subtraction => {
+                era_compiler_llvm_context::evm_arithmetic::subtraction(self, operand_1, operand_2)
+            }
+            BinaryOperation::Multiplication => {
+                era_compiler_llvm_context::evm_arithmetic::multiplication(
+                    self, operand_1, operand_2,
+                )
+            }
+            BinaryOperation::Division => {
+                era_compiler_llvm_context::evm_arithmetic::division(self, operand_1, operand_2)
+            }
+            BinaryOperation::Remainder => {
+                era_compiler_llvm_context::evm_arithmetic::remainder(self, operand_1, operand_2)
+            }
+            BinaryOperation::DivisionSigned => {
+                era_compiler_llvm_context::evm_arithmetic::division_signed(
+                    self, operand_1, operand_2,
+                )
+            }
+            BinaryOperation::RemainderSigned => {
+                era_compiler_llvm_context::evm_arithmetic::remainder_signed(
+                    self, operand_1, operand_2,
+                )
+            }
+            BinaryOperation::Or => {
+                era_compiler_llvm_context::evm_bitwise::or(self, operand_1, operand_2)
+            }
+            BinaryOperation::Xor => {
+                era_compiler_llvm_context::evm_bitwise::xor(self, operand_1, operand_2)
+            }
+            BinaryOperation::And => {
+                era_compiler_llvm_context::evm_bitwise::and(self, operand_1, operand_2)
+            }
+            BinaryOperation::ShiftLeft => {
+                era_compiler_llvm_context::evm_bitwise::shift_left(self, operand_1, operand_2)
+            }
+            BinaryOperation::ShiftRight => {
+                era_compiler_llvm_context::evm_bitwise::shift_right(self, operand_1, operand_2)
+            }
+            BinaryOperation::ShiftRightArithmetic => {
+                era_compiler_llvm_context::evm_bitwise::shift_right_arithmetic(
+                    self, operand_1, operand_2,
+                )
+            }
+            BinaryOperation::Byte => {
+                era_compiler_llvm_context::evm_bitwise::byte(self, operand_1, operand_2)
+            }
+            BinaryOperation::Exponent => {
+                era_compiler_llvm_context::evm_math::exponent(self, operand_1, operand_2)
+            }
+            BinaryOperation::SignExtend => {
+                era_compiler_llvm_context::evm_math::sign_extend(self, operand_1, operand_2)
+            }
+        }
+    }
+
+    fn lower_comparison(
+        &mut self,
+        operand_1: inkwell::values::IntValue<'ctx>,
+        operand_2: inkwell::values::IntValue<'ctx>,
+        predicate: inkwell::IntPredicate,
+    ) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+        era_compiler_llvm_context::evm_comparison::compare(self, operand_1, operand_2, predicate)
+    }
+
+    fn lower_modular(
+        &mut self,
+        operation: ModularOperation,
+        operand_1: inkwell::values::IntValue<'ctx>,
+        operand_2: inkwell::values::IntValue<'ctx>,
+        modulo: inkwell::values::IntValue<'ctx>,
+    ) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+        match operation {
+            ModularOperation::Addition => {
+                era_compiler_llvm_context::evm_math::add_mod(self, operand_1, operand_2, modulo)
+            }
+            ModularOperation::Multiplication => {
+                era_compiler_llvm_context::evm_math::mul_mod(self, operand_1, operand_2, modulo)
+            }
+        }
+    }
+}
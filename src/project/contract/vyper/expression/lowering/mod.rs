@@ -0,0 +1,91 @@
+//!
+//! The LLL IR lowering target.
+//!
+
+pub mod binary_operation;
+pub mod eravm;
+pub mod evm;
+pub mod modular_operation;
+
+use era_compiler_llvm_context::IContext;
+
+use crate::project::contract::vyper::expression::Expression;
+
+use self::binary_operation::BinaryOperation;
+use self::modular_operation::ModularOperation;
+
+///
+/// The LLVM context the LLL IR is lowered with.
+///
+/// Provides the target-specific parts of the lowering, so the instructions whose semantics
+/// do not depend on the target are translated by the same code for EraVM and EVM.
+///
+pub trait Lowering<'ctx>: IContext<'ctx> {
+    ///
+    /// Translates the expression into an LLVM value.
+    ///
+    fn lower_expression(
+        &mut self,
+        expression: Expression,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>;
+
+    ///
+    /// Returns the return block of the current function.
+    ///
+    fn current_return_block(&self) -> inkwell::basic_block::BasicBlock<'ctx>;
+
+    ///
+    /// Returns the join block of the current loop.
+    ///
+    fn current_loop_join_block(&self) -> inkwell::basic_block::BasicBlock<'ctx>;
+
+    ///
+    /// Returns the continue block of the current loop.
+    ///
+    fn current_loop_continue_block(&self) -> inkwell::basic_block::BasicBlock<'ctx>;
+
+    ///
+    /// Translates the `revert` instruction.
+    ///
+    fn lower_revert(
+        &mut self,
+        offset: inkwell::values::IntValue<'ctx>,
+        length: inkwell::values::IntValue<'ctx>,
+    ) -> anyhow::Result<()>;
+
+    ///
+    /// Translates the `invalid` instruction.
+    ///
+    fn lower_invalid(&mut self) -> anyhow::Result<()>;
+
+    ///
+    /// Translates the arithmetic, bitwise, or math binary operation.
+    ///
+    fn lower_binary(
+        &mut self,
+        operation: BinaryOperation,
+        operand_1: inkwell::values::IntValue<'ctx>,
+        operand_2: inkwell::values::IntValue<'ctx>,
+    ) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>>;
+
+    ///
+    /// Translates the comparison with the predicate.
+    ///
+    fn lower_comparison(
+        &mut self,
+        operand_1: inkwell::values::IntValue<'ctx>,
+        operand_2: inkwell::values::IntValue<'ctx>,
+        predicate: inkwell::IntPredicate,
+    ) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>>;
+
+    ///
+    /// Translates the modular arithmetic operation.
+    ///
+    fn lower_modular(
+        &mut self,
+        operation: ModularOperation,
+        operand_1: inkwell::values::IntValue<'ctx>,
+        operand_2: inkwell::values::IntValue<'ctx>,
+        modulo: inkwell::values::IntValue<'ctx>,
+    ) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>>;
+}
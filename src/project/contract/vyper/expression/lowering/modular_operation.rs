@@ -0,0 +1,14 @@
+//!
+//! The modular arithmetic operation.
+//!
+
+///
+/// The modular arithmetic operation.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModularOperation {
+    /// The `addmod` instruction.
+    Addition,
+    /// The `mulmod` instruction.
+    Multiplication,
+}
@@ -3,6 +3,10 @@
 //!
 
 pub mod instruction;
+pub mod label_arguments;
+pub mod lowering;
+pub mod parser;
+pub mod printer;
 
 use std::collections::BTreeMap;
 
@@ -38,6 +42,21 @@ impl Default for Expression {
 }
 
 impl Expression {
+    ///
+    /// Parses the LLL IR, given either as `vyper -f ir_json` JSON or as `vyper -f ir` s-expressions.
+    ///
+    pub fn try_from_lll(input: &str) -> anyhow::Result<Self> {
+        if input.trim_start().starts_with('{') {
+            return era_compiler_common::deserialize_from_str(input)
+                .map_err(|error| anyhow::anyhow!("LLL IR JSON parsing error: {error}"));
+        }
+
+        let value = self::parser::parse(input)
+            .map_err(|error| anyhow::anyhow!("LLL IR parsing error: {error}"))?;
+        serde_json::from_value(value)
+            .map_err(|error| anyhow::anyhow!("LLL IR parsing error: {error}"))
+    }
+
     ///
     /// Extracts the deploy code expression.
     ///
@@ -47,12 +66,13 @@ impl Expression {
                 sequence.normalize_deploy_code();
                 Ok(sequence)
             }
-            Self::Instruction(Instruction::Deploy(_deploy)) => {
-                let mut sequence = SeqInstruction::default();
+            Self::Instruction(Instruction::Deploy(deploy)) => {
+                let mut sequence =
+                    SeqInstruction(vec![Self::Instruction(Instruction::Deploy(deploy))]);
                 sequence.normalize_deploy_code();
                 Ok(sequence)
             }
-            instruction => anyhow::bail!("Expected [`seq`, `deploy`], found `{instruction:?}`"),
+            instruction => anyhow::bail!("Expected [`seq`, `deploy`], found `{instruction}`"),
         }
     }
 
@@ -75,17 +95,42 @@ impl Expression {
                 runtime_code.normalize_runtime_code();
                 Ok(Some((runtime_code, immutables_size)))
             }
-            instruction => anyhow::bail!("Expected [`seq`, `deploy`], found `{instruction:?}`"),
+            instruction => anyhow::bail!("Expected [`seq`, `deploy`], found `{instruction}`"),
+        }
+    }
+
+    ///
+    /// Returns the memory size used by the deploy code, which is the first `deploy` argument.
+    ///
+    pub fn deploy_memory_size(&self) -> Option<&Self> {
+        match self {
+            Self::Instruction(Instruction::Seq(sequence)) => sequence
+                .0
+                .iter()
+                .find_map(|expression| expression.deploy_memory_size()),
+            Self::Instruction(Instruction::Deploy(deploy)) => deploy.memory_size(),
+            _ => None,
         }
     }
 
+    ///
+    /// Moves the arguments of block jumps into `set` instructions before the jump.
+    ///
+    /// Used for EVM, where there is no function Vyper data to bind the label arguments.
+    ///
+    pub fn lower_label_arguments(self) -> anyhow::Result<Self> {
+        let value = serde_json::to_value(self).expect("Always valid");
+        serde_json::from_value(self::label_arguments::lower(value))
+            .map_err(|error| anyhow::anyhow!("LLL IR label arguments lowering error: {error}"))
+    }
+
     ///
     /// Converts the entity to an identifier.
     ///
     pub fn try_into_identifier(&self) -> anyhow::Result<String> {
         match self {
             Self::Identifier(identifier) => Ok(identifier.to_owned()),
-            expression => anyhow::bail!("Expected identifier, found `{expression:?}`"),
+            expression => anyhow::bail!("Expected identifier, found `{expression}`"),
         }
     }
 
@@ -128,7 +173,7 @@ impl Expression {
     pub fn function_name(&self) -> anyhow::Result<String> {
         match self {
             Expression::Instruction(inner) => inner.function_name(),
-            expression => anyhow::bail!("Expected a function sequence, found `{expression:?}`"),
+            expression => anyhow::bail!("Expected a function sequence, found `{expression}`"),
         }
     }
 
@@ -193,3 +238,77 @@ impl Expression {
         }
     }
 }
+
+impl Expression {
+    ///
+    /// Converts the entity to an LLVM value for EVM.
+    ///
+    pub fn into_llvm_value_evm<'ctx>(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext<'ctx>,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        match self {
+            Self::Instruction(inner) => inner.into_llvm_value_evm(context),
+            Self::IntegerLiteral(number) => {
+                let string = number.to_string();
+
+                let value = if let Some(string) = string.strip_prefix('-') {
+                    let modulo = context.field_const_str_dec(string);
+                    let max_value_diff = context.builder().build_int_sub(
+                        modulo,
+                        context.field_const(1),
+                        "max_value_diff",
+                    )?;
+                    let max_value = context.field_type().const_all_ones();
+                    let value = context.builder().build_int_sub(
+                        max_value,
+                        max_value_diff,
+                        "negative_value",
+                    )?;
+                    value.as_basic_value_enum()
+                } else {
+                    context
+                        .field_const_str_dec(string.as_str())
+                        .as_basic_value_enum()
+                };
+
+                Ok(Some(value))
+            }
+            Self::Identifier(identifier) => {
+                if identifier.as_str() == crate::r#const::DEFAULT_SEQUENCE_IDENTIFIER {
+                    era_compiler_llvm_context::evm_return::revert(
+                        context,
+                        context.field_const(0),
+                        context.field_const(0),
+                    )?;
+                }
+                if identifier.as_str() == crate::r#const::CODE_LENGTH_IDENTIFIER {
+                    let code_end = self::instruction::code::end(context)?;
+                    return Ok(Some(code_end.as_basic_value_enum()));
+                }
+
+                let value = match context
+                    .current_function()
+                    .borrow()
+                    .get_stack_pointer(identifier.as_str())
+                {
+                    Some(pointer) => context.build_load(pointer, identifier.as_str())?,
+                    None => context.field_const(0).as_basic_value_enum(),
+                };
+
+                Ok(Some(value))
+            }
+
+            Self::Unknown(value) => {
+                anyhow::bail!("Unknown LLL expression: {value}");
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = serde_json::to_value(self).map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", self::printer::print(&value))
+    }
+}
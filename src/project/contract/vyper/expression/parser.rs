@@ -0,0 +1,254 @@
+//!
+//! The LLL IR s-expression parser.
+//!
+
+///
+/// Parses the LLL IR s-expression text into the `vyper -f ir_json` layout.
+///
+/// Lists are delimited with either square or round brackets, and their elements are
+/// separated with commas or whitespace. A list is converted into an object whose only key
+/// is the list head, and whose value is the array of the remaining elements.
+/// Integer literals, including hexadecimal ones, are converted into numbers, and other atoms
+/// are converted into strings.
+///
+/// Line comments starting with `#` and block comments `/* ... */` are skipped, so the
+/// `vyper -f ir` output and the `--debug-output-dir` dumps can be parsed as is.
+///
+pub fn parse(input: &str) -> anyhow::Result<serde_json::Value> {
+    let tokens = tokenize(input)?;
+    let mut tokens = tokens.into_iter();
+
+    let value = match tokens.next() {
+        Some(token) => parse_token(token, &mut tokens)?,
+        None => anyhow::bail!("The LLL IR input is empty"),
+    };
+    if let Some(token) = tokens.next() {
+        anyhow::bail!(
+            "{}:{}: Expected the end of input, found `{}`",
+            token.line,
+            token.column,
+            token.kind
+        );
+    }
+    Ok(value)
+}
+
+///
+/// The s-expression token.
+///
+#[derive(Debug)]
+struct Token {
+    /// The token kind.
+    kind: TokenKind,
+    /// The line number, starting from 1.
+    line: usize,
+    /// The column number, starting from 1.
+    column: usize,
+}
+
+///
+/// The s-expression token kind.
+///
+#[derive(Debug, PartialEq, Eq)]
+enum TokenKind {
+    /// The `[` or `(` bracket.
+    Open,
+    /// The `]` or `)` bracket.
+    Close,
+    /// The list element, that is, either an identifier or a literal.
+    Atom(String),
+}
+
+impl std::fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => write!(f, "["),
+            Self::Close => write!(f, "]"),
+            Self::Atom(atom) => write!(f, "{atom}"),
+        }
+    }
+}
+
+///
+/// Splits the input into tokens, skipping separators and comments.
+///
+fn tokenize(input: &str) -> anyhow::Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut characters = input.chars().peekable();
+    let mut line = 1;
+    let mut column = 0;
+
+    while let Some(character) = characters.next() {
+        column += 1;
+        match character {
+            '\n' => {
+                line += 1;
+                column = 0;
+            }
+            character if character.is_whitespace() || character == ',' => {}
+            '#' => while characters.next_if(|character| *character != '\n').is_some() {},
+            '/' if characters.peek() == Some(&'*') => {
+                let (start_line, start_column) = (line, column);
+                characters.next();
+                column += 1;
+                let mut previous = None;
+                loop {
+                    let character = match characters.next() {
+                        Some(character) => character,
+                        None => {
+                            anyhow::bail!("{start_line}:{start_column}: Unterminated block comment")
+                        }
+                    };
+                    if character == '\n' {
+                        line += 1;
+                        column = 0;
+                    } else {
+                        column += 1;
+                    }
+                    if character == '/' && previous == Some('*') {
+                        break;
+                    }
+                    previous = Some(character);
+                }
+            }
+            '[' | '(' => tokens.push(Token {
+                kind: TokenKind::Open,
+                line,
+                column,
+            }),
+            ']' | ')' => tokens.push(Token {
+                kind: TokenKind::Close,
+                line,
+                column,
+            }),
+            character => {
+                let start_column = column;
+                let mut atom = character.to_string();
+                while let Some(character) = characters.next_if(|character| {
+                    !character.is_whitespace() && !matches!(character, ',' | '[' | ']' | '(' | ')')
+                }) {
+                    atom.push(character);
+                    column += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Atom(atom),
+                    line,
+                    column: start_column,
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+///
+/// Parses the expression starting with `token`.
+///
+fn parse_token(
+    token: Token,
+    tokens: &mut std::vec::IntoIter<Token>,
+) -> anyhow::Result<serde_json::Value> {
+    match token.kind {
+        TokenKind::Open => {
+            let name = match tokens.next() {
+                Some(Token {
+                    kind: TokenKind::Atom(name),
+                    ..
+                }) => name,
+                Some(token) => anyhow::bail!(
+                    "{}:{}: Expected the list head, found `{}`",
+                    token.line,
+                    token.column,
+                    token.kind
+                ),
+                None => anyhow::bail!("{}:{}: Unterminated list", token.line, token.column),
+            };
+
+            let mut arguments = Vec::new();
+            loop {
+                match tokens.next() {
+                    Some(Token {
+                        kind: TokenKind::Close,
+                        ..
+                    }) => break,
+                    Some(argument) => arguments.push(parse_token(argument, tokens)?),
+                    None => anyhow::bail!("{}:{}: Unterminated list", token.line, token.column),
+                }
+            }
+
+            let mut object = serde_json::Map::with_capacity(1);
+            object.insert(name, serde_json::Value::Array(arguments));
+            Ok(serde_json::Value::Object(object))
+        }
+        TokenKind::Close => anyhow::bail!(
+            "{}:{}: Unexpected `{}`",
+            token.line,
+            token.column,
+            token.kind
+        ),
+        TokenKind::Atom(atom) => Ok(parse_atom(atom)),
+    }
+}
+
+///
+/// Converts the atom into either a number or a string.
+///
+fn parse_atom(atom: String) -> serde_json::Value {
+    let (sign, digits) = match atom.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", atom.as_str()),
+    };
+
+    let decimal = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hexadecimal) => hexadecimal_to_decimal(hexadecimal),
+        None if !digits.is_empty() && digits.chars().all(|digit| digit.is_ascii_digit()) => {
+            let digits = digits.trim_start_matches('0');
+            Some(if digits.is_empty() { "0" } else { digits }.to_owned())
+        }
+        None => None,
+    };
+
+    match decimal.and_then(|decimal| {
+        format!("{sign}{decimal}")
+            .parse::<serde_json::Number>()
+            .ok()
+    }) {
+        Some(number) => serde_json::Value::Number(number),
+        None => serde_json::Value::String(atom),
+    }
+}
+
+///
+/// Converts the arbitrary length hexadecimal string into a decimal one.
+///
+fn hexadecimal_to_decimal(hexadecimal: &str) -> Option<String> {
+    if hexadecimal.is_empty() {
+        return None;
+    }
+
+    let mut decimal: Vec<u32> = vec![0];
+    for character in hexadecimal.chars() {
+        let mut carry = character.to_digit(era_compiler_common::BASE_HEXADECIMAL)?;
+        for digit in decimal.iter_mut() {
+            let value = *digit * era_compiler_common::BASE_HEXADECIMAL + carry;
+            *digit = value % 10;
+            carry = value / 10;
+        }
+        while carry > 0 {
+            decimal.push(carry % 10);
+            carry /= 10;
+        }
+    }
+
+    Some(
+        decimal
+            .into_iter()
+            .rev()
+            .map(|digit| char::from_digit(digit, 10).expect("Always valid"))
+            .collect(),
+    )
+}
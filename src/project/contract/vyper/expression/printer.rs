@@ -0,0 +1,65 @@
+//!
+//! The LLL IR s-expression printer.
+//!
+
+/// The maximum length of a list printed on a single line, unless it has a single argument.
+pub const SINGLE_LINE_LENGTH_LIMIT: usize = 80;
+
+///
+/// Prints the serialized LLL IR expression in the `vyper -f ir` layout.
+///
+/// A list is printed on a single line if none of its arguments span multiple lines, and
+/// it is either shorter than the limit or has a single argument.
+/// Otherwise, each argument is printed on a separate line with a two-space indentation.
+/// Lists without arguments are kept in brackets, so they can be told apart from identifiers.
+///
+pub fn print(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Object(map) if map.len() == 1 => {
+            let (name, arguments) = map.iter().next().expect("Always exists");
+            let arguments: Vec<String> = match arguments {
+                serde_json::Value::Array(arguments) if arguments.is_empty() => {
+                    return format!("[{name}]");
+                }
+                serde_json::Value::Array(arguments) => arguments.iter().map(print).collect(),
+                serde_json::Value::Null => vec![],
+                argument => vec![print(argument)],
+            };
+            print_list(name.as_str(), arguments)
+        }
+        serde_json::Value::Array(values) => {
+            let mut values = values.iter().map(print);
+            match values.next() {
+                Some(name) => print_list(name.as_str(), values.collect()),
+                None => "[]".to_owned(),
+            }
+        }
+        serde_json::Value::String(string) => string.to_owned(),
+        value => value.to_string(),
+    }
+}
+
+///
+/// Prints a list with the specified head and already printed arguments.
+///
+fn print_list(name: &str, arguments: Vec<String>) -> String {
+    if arguments.is_empty() {
+        return name.to_owned();
+    }
+
+    let has_inner_newlines = arguments.iter().any(|argument| argument.contains('\n'));
+    if !has_inner_newlines {
+        let single_line = format!("[{name}, {}]", arguments.join(", "));
+        if single_line.len() < SINGLE_LINE_LENGTH_LIMIT || arguments.len() == 1 {
+            return single_line;
+        }
+    }
+
+    let mut output = format!("[{name}");
+    for argument in arguments.iter() {
+        output.push_str(",\n  ");
+        output.push_str(argument.replace('\n', "\n  ").as_str());
+    }
+    output.push(']');
+    output
+}
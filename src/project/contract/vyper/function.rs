@@ -4,9 +4,11 @@
 
 use inkwell::types::BasicType;
 
+use era_compiler_llvm_context::EVMWriteLLVM;
 use era_compiler_llvm_context::EraVMWriteLLVM;
 use era_compiler_llvm_context::IContext;
 
+use crate::error::Error;
 use crate::project::contract::vyper::expression::instruction::seq::Seq;
 use crate::project::contract::vyper::expression::instruction::Instruction;
 use crate::project::contract::vyper::expression::Expression;
@@ -85,7 +87,9 @@ impl EraVMWriteLLVM for Function {
         let llvm_entry_block = context.current_function().borrow().entry_block();
         let llvm_value = context.current_function().borrow().declaration().value;
 
-        self.expression.into_llvm_value(context)?;
+        self.expression
+            .into_llvm_value(context)
+            .map_err(|error| Error::in_lll_function(error, self.name.as_str()))?;
 
         context.set_basic_block(llvm_entry_block);
         let ir_entry_block = llvm_value
@@ -113,3 +117,60 @@ impl EraVMWriteLLVM for Function {
         Ok(())
     }
 }
+
+impl EVMWriteLLVM for Function {
+    fn declare(
+        &mut self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        let mut argument_types = Vec::with_capacity(1);
+        if self.has_return_value() {
+            argument_types.push(context.field_type().as_basic_type_enum());
+        }
+
+        context.add_function(
+            self.name.as_str(),
+            context.function_type(argument_types, 0, false),
+            0,
+            Some(inkwell::module::Linkage::Private),
+        )?;
+
+        Ok(())
+    }
+
+    fn into_llvm(self, context: &mut era_compiler_llvm_context::EVMContext) -> anyhow::Result<()> {
+        context.set_current_function(self.name.as_str())?;
+
+        let llvm_entry_block = context.current_function().borrow().entry_block();
+        let llvm_value = context.current_function().borrow().declaration().value;
+
+        self.expression
+            .into_llvm_value_evm(context)
+            .map_err(|error| Error::in_lll_function(error, self.name.as_str()))?;
+
+        context.set_basic_block(llvm_entry_block);
+        let ir_entry_block = llvm_value
+            .get_basic_blocks()
+            .iter()
+            .find(|block| block.get_name().to_string_lossy() == self.name)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Block `{}` does not exist", self.name))?;
+        context.build_unconditional_branch(ir_entry_block)?;
+
+        context.set_basic_block(context.current_function().borrow().return_block());
+        context.build_return(None)?;
+
+        for block in llvm_value.get_basic_blocks() {
+            if block.get_terminator().is_none() {
+                context.set_basic_block(block);
+                era_compiler_llvm_context::evm_return::revert(
+                    context,
+                    context.field_const(0),
+                    context.field_const(0),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
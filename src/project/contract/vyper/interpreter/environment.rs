@@ -0,0 +1,46 @@
+//!
+//! The interpreter execution environment.
+//!
+
+use num::BigUint;
+
+///
+/// The interpreter execution environment.
+///
+/// The values are returned by the corresponding EVM opcodes.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Environment {
+    /// The call data.
+    pub calldata: Vec<u8>,
+    /// The `ADDRESS` value.
+    pub address: BigUint,
+    /// The `CALLER` value.
+    pub caller: BigUint,
+    /// The `ORIGIN` value.
+    pub origin: BigUint,
+    /// The `CALLVALUE` value.
+    pub callvalue: BigUint,
+    /// The `SELFBALANCE` value.
+    pub self_balance: BigUint,
+    /// The `CHAINID` value.
+    pub chain_id: BigUint,
+    /// The `NUMBER` value.
+    pub block_number: BigUint,
+    /// The `TIMESTAMP` value.
+    pub block_timestamp: BigUint,
+    /// The `GAS` value.
+    pub gas: BigUint,
+}
+
+impl Environment {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(calldata: Vec<u8>) -> Self {
+        Self {
+            calldata,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//!
+//! The interpreter event log.
+//!
+
+use num::BigUint;
+
+///
+/// The event log emitted with one of the `LOG*` opcodes.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Log {
+    /// The log topics.
+    pub topics: Vec<BigUint>,
+    /// The log data.
+    pub data: Vec<u8>,
+}
+
+impl Log {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(topics: Vec<BigUint>, data: Vec<u8>) -> Self {
+        Self { topics, data }
+    }
+}
@@ -0,0 +1,115 @@
+//!
+//! The interpreter linear memory.
+//!
+
+use num::BigUint;
+use num::ToPrimitive;
+use num::Zero;
+
+use super::word;
+
+///
+/// The interpreter linear memory.
+///
+/// The memory is expanded by 32-byte words on each access, as in the EVM.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Memory {
+    /// The memory bytes.
+    pub bytes: Vec<u8>,
+}
+
+impl Memory {
+    /// The memory size limit, which prevents runaway allocations on invalid offsets.
+    pub const SIZE_LIMIT: usize = 1 << 26;
+
+    ///
+    /// Loads a word from the memory.
+    ///
+    pub fn load(&mut self, offset: &BigUint) -> anyhow::Result<BigUint> {
+        let bytes = self.read(offset, &BigUint::from(word::BYTE_LENGTH))?;
+        Ok(BigUint::from_bytes_be(bytes.as_slice()))
+    }
+
+    ///
+    /// Stores a word to the memory.
+    ///
+    pub fn store(&mut self, offset: &BigUint, value: &BigUint) -> anyhow::Result<()> {
+        self.write(offset, &word::to_bytes(value))
+    }
+
+    ///
+    /// Stores the least significant byte of `value` to the memory.
+    ///
+    pub fn store_byte(&mut self, offset: &BigUint, value: &BigUint) -> anyhow::Result<()> {
+        let byte = (value & BigUint::from(u8::MAX))
+            .to_u8()
+            .expect("Always valid");
+        self.write(offset, &[byte])
+    }
+
+    ///
+    /// Copies `size` bytes within the memory, allowing the ranges to overlap.
+    ///
+    pub fn copy(
+        &mut self,
+        destination: &BigUint,
+        source: &BigUint,
+        size: &BigUint,
+    ) -> anyhow::Result<()> {
+        let bytes = self.read(source, size)?;
+        self.write(destination, bytes.as_slice())
+    }
+
+    ///
+    /// Reads `size` bytes from the memory, expanding it if necessary.
+    ///
+    pub fn read(&mut self, offset: &BigUint, size: &BigUint) -> anyhow::Result<Vec<u8>> {
+        if size.is_zero() {
+            return Ok(vec![]);
+        }
+
+        let (offset, size) = self.expand(offset, size)?;
+        Ok(self.bytes[offset..offset + size].to_vec())
+    }
+
+    ///
+    /// Writes `bytes` to the memory, expanding it if necessary.
+    ///
+    pub fn write(&mut self, offset: &BigUint, bytes: &[u8]) -> anyhow::Result<()> {
+        if bytes.is_empty() {
+            return Ok(());
+        }
+
+        let (offset, size) = self.expand(offset, &BigUint::from(bytes.len()))?;
+        self.bytes[offset..offset + size].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    ///
+    /// Returns the memory size in bytes, which is always a multiple of 32.
+    ///
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    ///
+    /// Expands the memory to fit the range, returning the range as native integers.
+    ///
+    fn expand(&mut self, offset: &BigUint, size: &BigUint) -> anyhow::Result<(usize, usize)> {
+        let end = offset + size;
+        let (offset, size) = match (offset.to_usize(), size.to_usize(), end.to_usize()) {
+            (Some(offset), Some(size), Some(end)) if end <= Self::SIZE_LIMIT => (offset, size),
+            _ => anyhow::bail!(
+                "Memory range {offset}..{end} exceeds the limit of {} bytes",
+                Self::SIZE_LIMIT
+            ),
+        };
+
+        let end = (offset + size).div_ceil(word::BYTE_LENGTH) * word::BYTE_LENGTH;
+        if end > self.bytes.len() {
+            self.bytes.resize(end, 0);
+        }
+        Ok((offset, size))
+    }
+}
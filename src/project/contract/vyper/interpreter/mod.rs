@@ -0,0 +1,895 @@
+//!
+//! The reference LLL IR interpreter.
+//!
+
+pub mod environment;
+pub mod log;
+pub mod memory;
+pub mod outcome;
+pub mod word;
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use num::BigInt;
+use num::BigUint;
+use num::ToPrimitive;
+use num::Zero;
+
+use crate::project::contract::vyper::expression::instruction::exit_to::ExitTo;
+use crate::project::contract::vyper::expression::instruction::goto::Goto;
+use crate::project::contract::vyper::expression::instruction::label::Label;
+use crate::project::contract::vyper::expression::instruction::Instruction;
+use crate::project::contract::vyper::expression::Expression;
+
+use self::environment::Environment;
+use self::log::Log;
+use self::memory::Memory;
+use self::outcome::Outcome;
+
+///
+/// The reference LLL IR interpreter.
+///
+/// Evaluates LLL IR with the EVM semantics over 256-bit words, memory, storage, and call data.
+/// It is used to check the instruction lowering and constant folding against the semantics of
+/// `vyper`'s LLL IR without running the compiled code on a chain.
+///
+/// Control flow follows the translator: labels are blocks declared by their enclosing sequences,
+/// `goto` to a `cleanup` or `fallback` label is a jump, and `goto` to any other label is
+/// a function call returning with `exit_to` to `return_pc`.
+///
+/// External calls, contract creation, and code access are not supported.
+///
+#[derive(Debug, Default)]
+pub struct Interpreter {
+    /// The execution environment.
+    pub environment: Environment,
+    /// The linear memory.
+    pub memory: Memory,
+    /// The persistent storage.
+    pub storage: BTreeMap<BigUint, BigUint>,
+    /// The transient storage.
+    pub transient_storage: BTreeMap<BigUint, BigUint>,
+    /// The immutables, keyed by their offsets.
+    pub immutables: BTreeMap<BigUint, BigUint>,
+    /// The emitted logs.
+    pub logs: Vec<Log>,
+
+    /// The labels declared in the code.
+    labels: BTreeMap<String, Label>,
+    /// The variable frames, one per function call.
+    frames: Vec<BTreeMap<String, BigUint>>,
+    /// The number of evaluated expressions.
+    steps: usize,
+    /// Whether only pure expressions are allowed, which is used for constant folding.
+    is_pure: bool,
+}
+
+///
+/// The non-sequential control flow.
+///
+#[derive(Debug)]
+enum Interrupt {
+    /// The `break` instruction.
+    Break,
+    /// The `continue` instruction.
+    Continue,
+    /// The exit from the current function.
+    Exit,
+    /// The end of execution.
+    Halt(Outcome),
+    /// The interpretation error.
+    Error(anyhow::Error),
+}
+
+impl From<anyhow::Error> for Interrupt {
+    fn from(error: anyhow::Error) -> Self {
+        Self::Error(error)
+    }
+}
+
+///
+/// The expression evaluation result.
+///
+type Evaluation = Result<Option<BigUint>, Interrupt>;
+
+impl Interpreter {
+    /// The maximum number of evaluated expressions.
+    pub const STEP_LIMIT: usize = 10_000_000;
+
+    /// The maximum function call depth.
+    pub const CALL_DEPTH_LIMIT: usize = 1024;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(environment: Environment) -> Self {
+        Self {
+            environment,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Runs the code until it halts or reaches the end.
+    ///
+    pub fn run(&mut self, code: &Expression) -> anyhow::Result<Outcome> {
+        self.labels = Self::collect_labels(code)?;
+        self.frames = vec![BTreeMap::new()];
+        self.steps = 0;
+
+        match self.evaluate(code) {
+            Ok(_) | Err(Interrupt::Exit) => Ok(Outcome::Stop),
+            Err(Interrupt::Halt(outcome)) => Ok(outcome),
+            Err(Interrupt::Break) => anyhow::bail!("`break` outside of a loop"),
+            Err(Interrupt::Continue) => anyhow::bail!("`continue` outside of a loop"),
+            Err(Interrupt::Error(error)) => Err(error),
+        }
+    }
+
+    ///
+    /// Folds the expression into a constant.
+    ///
+    /// Returns `None` if the expression has no value, halts, or depends on the environment
+    /// or storage.
+    ///
+    pub fn fold(expression: &Expression) -> Option<BigUint> {
+        let mut interpreter = Self {
+            frames: vec![BTreeMap::new()],
+            is_pure: true,
+            ..Default::default()
+        };
+        interpreter.labels = Self::collect_labels(expression).ok()?;
+        interpreter.evaluate(expression).ok().flatten()
+    }
+
+    ///
+    /// Evaluates the expression.
+    ///
+    fn evaluate(&mut self, expression: &Expression) -> Evaluation {
+        self.steps += 1;
+        if self.steps > Self::STEP_LIMIT {
+            return Err(
+                anyhow::anyhow!("The step limit of {} is exceeded", Self::STEP_LIMIT).into(),
+            );
+        }
+
+        match expression {
+            Expression::Instruction(instruction) => self.instruction(instruction),
+            Expression::IntegerLiteral(number) => {
+                let value = BigInt::from_str(number.to_string().as_str()).map_err(|error| {
+                    anyhow::anyhow!("Invalid integer literal `{number}`: {error}")
+                })?;
+                Ok(Some(word::from_signed(value)))
+            }
+            Expression::Identifier(identifier) => {
+                if identifier.as_str() == crate::r#const::DEFAULT_SEQUENCE_IDENTIFIER {
+                    return Err(Interrupt::Halt(Outcome::Revert(vec![])));
+                }
+
+                Ok(Some(
+                    self.frame().get(identifier).cloned().unwrap_or_default(),
+                ))
+            }
+            Expression::Unknown(value) => {
+                Err(anyhow::anyhow!("Unknown LLL expression: {value}").into())
+            }
+        }
+    }
+
+    ///
+    /// Evaluates the instruction.
+    ///
+    fn instruction(&mut self, instruction: &Instruction) -> Evaluation {
+        if self.is_pure && Self::is_impure(instruction) {
+            return Err(anyhow::anyhow!(
+                "The `{}` instruction cannot be folded",
+                Self::instruction_name(instruction)
+            )
+            .into());
+        }
+
+        match instruction {
+            Instruction::With(inner) => {
+                let [identifier, value, body] = &inner.0;
+                let identifier = identifier.try_into_identifier()?;
+                let value = self.value(value)?;
+
+                let shadowed = self.frame_mut().insert(identifier.clone(), value);
+                let result = self.evaluate(body);
+                match shadowed {
+                    Some(value) => self.frame_mut().insert(identifier, value),
+                    None => self.frame_mut().remove(identifier.as_str()),
+                };
+                result
+            }
+            Instruction::Set(inner) => {
+                let [identifier, value] = &inner.0;
+                let identifier = identifier.try_into_identifier()?;
+                if !self.frame().contains_key(identifier.as_str()) {
+                    return Err(anyhow::anyhow!("Variable `{identifier}` not found").into());
+                }
+
+                let value = self.value(value)?;
+                self.frame_mut().insert(identifier, value);
+                Ok(None)
+            }
+            Instruction::Seq(inner) => {
+                let mut result = None;
+                for expression in inner.0.iter() {
+                    if let Expression::Instruction(Instruction::Label(_)) = expression {
+                        continue;
+                    }
+                    result = self.evaluate(expression)?;
+                }
+                Ok(result)
+            }
+            Instruction::If(inner) => {
+                let (condition, main, r#else) = match inner.0.as_slice() {
+                    [condition, main] => (condition, main, None),
+                    [condition, main, r#else] => (condition, main, Some(r#else)),
+                    _ => {
+                        return Err(anyhow::anyhow!(
+                            "Expected 2 or 3 `if` arguments, found {}",
+                            inner.0.len()
+                        )
+                        .into())
+                    }
+                };
+
+                if !self.value(condition)?.is_zero() {
+                    self.evaluate(main)
+                } else if let Some(r#else) = r#else {
+                    self.evaluate(r#else)
+                } else {
+                    Ok(None)
+                }
+            }
+            Instruction::Repeat(inner) => {
+                let [index, start, rounds, rounds_bound, body] = inner.0.as_slice() else {
+                    return Err(anyhow::anyhow!(
+                        "Expected 5 `repeat` arguments, found {}",
+                        inner.0.len()
+                    )
+                    .into());
+                };
+                let index = index.try_into_identifier()?;
+                let start = self.value(start)?;
+                let rounds = self.value(rounds)?;
+                let rounds_bound = self.value(rounds_bound)?;
+
+                self.frame_mut().insert(index.clone(), start);
+                let mut round = BigUint::zero();
+                while round < rounds && round < rounds_bound {
+                    match self.evaluate(body) {
+                        Ok(_) | Err(Interrupt::Continue) => {}
+                        Err(Interrupt::Break) => break,
+                        Err(interrupt) => return Err(interrupt),
+                    }
+
+                    let value = self
+                        .frame()
+                        .get(index.as_str())
+                        .cloned()
+                        .unwrap_or_default();
+                    self.frame_mut()
+                        .insert(index.clone(), word::add(&value, &BigUint::from(1u8)));
+                    round += 1u8;
+                }
+                self.frame_mut().remove(index.as_str());
+                Ok(None)
+            }
+
+            Instruction::GoTo(inner) => self.goto(inner),
+            Instruction::Exit_To(inner) => self.exit_to(inner),
+            Instruction::Jump(arguments) => {
+                let _arguments = self.arguments(arguments)?;
+                Err(Interrupt::Exit)
+            }
+            Instruction::Break => Err(Interrupt::Break),
+            Instruction::Continue => Err(Interrupt::Continue),
+            Instruction::Label(_)
+            | Instruction::Cleanup_Repeat
+            | Instruction::Pass
+            | Instruction::Symbol(_)
+            | Instruction::Unique_Symbol(_)
+            | Instruction::Var_List(_) => Ok(None),
+
+            Instruction::UCLAMP(arguments) => {
+                let [lower, value, upper] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), lower <= value && value <= upper)
+            }
+            Instruction::CLAMP(arguments) => {
+                let [lower, value, upper] = self.arguments(arguments)?;
+                Self::clamp(
+                    value.clone(),
+                    !word::slt(&value, &lower) && !word::slt(&upper, &value),
+                )
+            }
+            Instruction::UCLAMPLT(arguments) => {
+                let [value, bound] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), value < bound)
+            }
+            Instruction::UCLAMPLE(arguments) => {
+                let [value, bound] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), value <= bound)
+            }
+            Instruction::UCLAMPGT(arguments) => {
+                let [value, bound] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), value > bound)
+            }
+            Instruction::UCLAMPGE(arguments) => {
+                let [value, bound] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), value >= bound)
+            }
+            Instruction::CLAMPLT(arguments) => {
+                let [value, bound] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), word::slt(&value, &bound))
+            }
+            Instruction::CLAMPLE(arguments) => {
+                let [value, bound] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), !word::slt(&bound, &value))
+            }
+            Instruction::CLAMPGT(arguments) => {
+                let [value, bound] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), word::slt(&bound, &value))
+            }
+            Instruction::CLAMPGE(arguments) => {
+                let [value, bound] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), !word::slt(&value, &bound))
+            }
+            Instruction::CLAMP_NONZERO(arguments) => {
+                let [value] = self.arguments(arguments)?;
+                Self::clamp(value.clone(), !value.is_zero())
+            }
+
+            Instruction::CEIL32(arguments) => self.unary(arguments, |value| {
+                let length = BigUint::from(word::BYTE_LENGTH);
+                let padding = (&length - value % &length) % &length;
+                word::add(value, &padding)
+            }),
+            Instruction::SELECT(arguments) => {
+                let [condition, value_1, value_2] = self.arguments(arguments)?;
+                Ok(Some(if condition.is_zero() {
+                    value_2
+                } else {
+                    value_1
+                }))
+            }
+
+            Instruction::Assert(inner) => {
+                let [condition] = self.arguments(&inner.0)?;
+                if condition.is_zero() {
+                    return Err(Interrupt::Halt(Outcome::Revert(vec![])));
+                }
+                Ok(None)
+            }
+            Instruction::Assert_Unreachable(inner) => {
+                let [condition] = self.arguments(&inner.0)?;
+                if condition.is_zero() {
+                    return Err(Interrupt::Halt(Outcome::Invalid));
+                }
+                Ok(None)
+            }
+
+            Instruction::POP(arguments) => {
+                let _arguments = self.arguments(arguments)?;
+                Ok(None)
+            }
+
+            Instruction::ADD(arguments) => self.binary(arguments, word::add),
+            Instruction::SUB(arguments) => self.binary(arguments, word::sub),
+            Instruction::MUL(arguments) => self.binary(arguments, word::mul),
+            Instruction::DIV(arguments) => self.binary(arguments, word::div),
+            Instruction::MOD(arguments) => self.binary(arguments, word::r#mod),
+            Instruction::SDIV(arguments) => self.binary(arguments, word::sdiv),
+            Instruction::SMOD(arguments) => self.binary(arguments, word::smod),
+
+            Instruction::LT(arguments) => self.compare(arguments, |a, b| a < b),
+            Instruction::LE(arguments) => self.compare(arguments, |a, b| a <= b),
+            Instruction::GT(arguments) => self.compare(arguments, |a, b| a > b),
+            Instruction::GE(arguments) => self.compare(arguments, |a, b| a >= b),
+            Instruction::EQ(arguments) => self.compare(arguments, |a, b| a == b),
+            Instruction::NE(arguments) => self.compare(arguments, |a, b| a != b),
+            Instruction::ISZERO(arguments) => {
+                self.unary(arguments, |a| word::from_bool(a.is_zero()))
+            }
+            Instruction::SLT(arguments) => self.compare(arguments, word::slt),
+            Instruction::SLE(arguments) => self.compare(arguments, |a, b| !word::slt(b, a)),
+            Instruction::SGT(arguments) => self.compare(arguments, |a, b| word::slt(b, a)),
+            Instruction::SGE(arguments) => self.compare(arguments, |a, b| !word::slt(a, b)),
+
+            Instruction::OR(arguments) => self.binary(arguments, |a, b| a | b),
+            Instruction::XOR(arguments) => self.binary(arguments, |a, b| a ^ b),
+            Instruction::NOT(arguments) => self.unary(arguments, word::not),
+            Instruction::AND(arguments) => self.binary(arguments, |a, b| a & b),
+            Instruction::SHL(arguments) => self.binary(arguments, word::shl),
+            Instruction::SHR(arguments) => self.binary(arguments, word::shr),
+            Instruction::SAR(arguments) => self.binary(arguments, word::sar),
+            Instruction::BYTE(arguments) => self.binary(arguments, word::byte),
+
+            Instruction::ADDMOD(arguments) => {
+                let [operand_1, operand_2, modulo] = self.arguments(arguments)?;
+                Ok(Some(word::addmod(&operand_1, &operand_2, &modulo)))
+            }
+            Instruction::MULMOD(arguments) => {
+                let [operand_1, operand_2, modulo] = self.arguments(arguments)?;
+                Ok(Some(word::mulmod(&operand_1, &operand_2, &modulo)))
+            }
+            Instruction::EXP(arguments) => self.binary(arguments, word::exp),
+            Instruction::SIGNEXTEND(arguments) => self.binary(arguments, word::signextend),
+
+            Instruction::SHA3(arguments) | Instruction::KECCAK256(arguments) => {
+                let [offset, size] = self.arguments(arguments)?;
+                let data = self.memory.read(&offset, &size)?;
+                Ok(Some(Self::keccak256(data.as_slice())))
+            }
+            Instruction::SHA3_32(arguments) => {
+                let [value] = self.arguments(arguments)?;
+                let offset = BigUint::from(crate::r#const::OFFSET_FREE_VAR_SPACE);
+                self.memory.store(&offset, &value)?;
+                let data = self
+                    .memory
+                    .read(&offset, &BigUint::from(word::BYTE_LENGTH))?;
+                Ok(Some(Self::keccak256(data.as_slice())))
+            }
+            Instruction::SHA3_64(arguments) => {
+                let [value_1, value_2] = self.arguments(arguments)?;
+                let offset = BigUint::from(crate::r#const::OFFSET_FREE_VAR_SPACE);
+                self.memory.store(&offset, &value_1)?;
+                self.memory.store(
+                    &BigUint::from(crate::r#const::OFFSET_FREE_VAR_SPACE2),
+                    &value_2,
+                )?;
+                let data = self
+                    .memory
+                    .read(&offset, &BigUint::from(word::BYTE_LENGTH * 2))?;
+                Ok(Some(Self::keccak256(data.as_slice())))
+            }
+
+            Instruction::MLOAD(arguments) => {
+                let [offset] = self.arguments(arguments)?;
+                Ok(Some(self.memory.load(&offset)?))
+            }
+            Instruction::MSTORE(arguments) => {
+                let [offset, value] = self.arguments(arguments)?;
+                self.memory.store(&offset, &value)?;
+                Ok(None)
+            }
+            Instruction::MSTORE8(arguments) => {
+                let [offset, value] = self.arguments(arguments)?;
+                self.memory.store_byte(&offset, &value)?;
+                Ok(None)
+            }
+            Instruction::MCOPY(arguments) => {
+                let [destination, source, size] = self.arguments(arguments)?;
+                self.memory.copy(&destination, &source, &size)?;
+                Ok(None)
+            }
+            Instruction::MSIZE => Ok(Some(BigUint::from(self.memory.size()))),
+
+            Instruction::SLOAD(arguments) => {
+                let [key] = self.arguments(arguments)?;
+                Ok(Some(self.storage.get(&key).cloned().unwrap_or_default()))
+            }
+            Instruction::SSTORE(arguments) => {
+                let [key, value] = self.arguments(arguments)?;
+                self.storage.insert(key, value);
+                Ok(None)
+            }
+            Instruction::TLOAD(arguments) => {
+                let [key] = self.arguments(arguments)?;
+                Ok(Some(
+                    self.transient_storage
+                        .get(&key)
+                        .cloned()
+                        .unwrap_or_default(),
+                ))
+            }
+            Instruction::TSTORE(arguments) => {
+                let [key, value] = self.arguments(arguments)?;
+                self.transient_storage.insert(key, value);
+                Ok(None)
+            }
+            Instruction::ILOAD(arguments) => {
+                let [offset] = self.arguments(arguments)?;
+                Ok(Some(
+                    self.immutables.get(&offset).cloned().unwrap_or_default(),
+                ))
+            }
+            Instruction::ISTORE(arguments) => {
+                let [offset, value] = self.arguments(arguments)?;
+                self.immutables.insert(offset, value);
+                Ok(None)
+            }
+
+            Instruction::CALLDATALOAD(arguments) => {
+                let [offset] = self.arguments(arguments)?;
+                let data = self.calldata(&offset, word::BYTE_LENGTH);
+                Ok(Some(BigUint::from_bytes_be(data.as_slice())))
+            }
+            Instruction::CALLDATASIZE => Ok(Some(BigUint::from(self.environment.calldata.len()))),
+            Instruction::CALLDATACOPY(arguments) => {
+                let [destination, offset, size] = self.arguments(arguments)?;
+                let size = size
+                    .to_usize()
+                    .filter(|size| *size <= Memory::SIZE_LIMIT)
+                    .ok_or_else(|| anyhow::anyhow!("Call data copy size {size} is too large"))?;
+                let data = self.calldata(&offset, size);
+                self.memory.write(&destination, data.as_slice())?;
+                Ok(None)
+            }
+            Instruction::RETURNDATASIZE => Ok(Some(BigUint::zero())),
+            Instruction::RETURNDATACOPY(arguments) => {
+                let [_destination, offset, size] = self.arguments(arguments)?;
+                if !(offset + size).is_zero() {
+                    return Err(Interrupt::Halt(Outcome::Invalid));
+                }
+                Ok(None)
+            }
+
+            Instruction::RETURN(inner) => {
+                let [offset, size] = self.arguments(&inner.0)?;
+                let data = self.memory.read(&offset, &size)?;
+                Err(Interrupt::Halt(Outcome::Return(data)))
+            }
+            Instruction::REVERT(inner) => {
+                let [offset, size] = self.arguments(&inner.0)?;
+                let data = self.memory.read(&offset, &size)?;
+                Err(Interrupt::Halt(Outcome::Revert(data)))
+            }
+            Instruction::STOP => Err(Interrupt::Halt(Outcome::Stop)),
+            Instruction::INVALID => Err(Interrupt::Halt(Outcome::Invalid)),
+
+            Instruction::LOG0(arguments) => self.log(arguments),
+            Instruction::LOG1(arguments) => self.log(arguments),
+            Instruction::LOG2(arguments) => self.log(arguments),
+            Instruction::LOG3(arguments) => self.log(arguments),
+            Instruction::LOG4(arguments) => self.log(arguments),
+
+            Instruction::ADDRESS => Ok(Some(self.environment.address.clone())),
+            Instruction::CALLER => Ok(Some(self.environment.caller.clone())),
+            Instruction::ORIGIN => Ok(Some(self.environment.origin.clone())),
+            Instruction::CALLVALUE => Ok(Some(self.environment.callvalue.clone())),
+            Instruction::SELFBALANCE => Ok(Some(self.environment.self_balance.clone())),
+            Instruction::CHAINID => Ok(Some(self.environment.chain_id.clone())),
+            Instruction::NUMBER => Ok(Some(self.environment.block_number.clone())),
+            Instruction::TIMESTAMP => Ok(Some(self.environment.block_timestamp.clone())),
+            Instruction::GAS => Ok(Some(self.environment.gas.clone())),
+
+            instruction => Err(anyhow::anyhow!(
+                "The `{}` instruction is not supported by the LLL interpreter",
+                Self::instruction_name(instruction)
+            )
+            .into()),
+        }
+    }
+
+    ///
+    /// Evaluates the `goto` instruction.
+    ///
+    fn goto(&mut self, goto: &Goto) -> Evaluation {
+        let (label_name, arguments) = match goto.0.split_first() {
+            Some((label_name, arguments)) => (label_name.try_into_identifier()?, arguments),
+            None => return Err(anyhow::anyhow!("Expected a `goto` label").into()),
+        };
+
+        if label_name.ends_with(crate::r#const::LABEL_SUFFIX_CLEANUP)
+            || label_name == crate::r#const::FUNCTION_IDENTIFIER_FALLBACK
+        {
+            return self.jump(label_name.as_str(), arguments);
+        }
+
+        self.call(label_name.as_str(), arguments)
+    }
+
+    ///
+    /// Evaluates the `exit_to` instruction.
+    ///
+    fn exit_to(&mut self, exit_to: &ExitTo) -> Evaluation {
+        let (label_name, arguments) = match exit_to.0.split_first() {
+            Some((label_name, arguments)) => (label_name.try_into_identifier()?, arguments),
+            None => return Err(anyhow::anyhow!("Expected an `exit_to` label").into()),
+        };
+
+        if label_name.as_str() == crate::r#const::VARIABLE_IDENTIFIER_RETURN_PC {
+            return Err(Interrupt::Exit);
+        }
+        let label_name = label_name
+            .strip_prefix(crate::r#const::LABEL_DESTINATION_PREFIX)
+            .unwrap_or(label_name.as_str());
+
+        self.jump(label_name, arguments)
+    }
+
+    ///
+    /// Jumps to the block, assigning its variables and leaving the current function afterwards.
+    ///
+    fn jump(&mut self, label_name: &str, arguments: &[Expression]) -> Evaluation {
+        let label = self
+            .labels
+            .get(label_name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Block `{label_name}` does not exist"))?;
+
+        for (name, argument) in Self::label_variables(&label)?.into_iter().zip(arguments) {
+            let value = self.value(argument)?;
+            self.frame_mut().insert(name, value);
+        }
+
+        if let Some(body) = label.0.get(2) {
+            self.evaluate(body)?;
+        }
+        Err(Interrupt::Exit)
+    }
+
+    ///
+    /// Calls the function, passing the return buffer as the only argument.
+    ///
+    fn call(&mut self, label_name: &str, arguments: &[Expression]) -> Evaluation {
+        let label = self
+            .labels
+            .get(label_name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Function `{label_name}` does not exist"))?;
+        if self.frames.len() >= Self::CALL_DEPTH_LIMIT {
+            return Err(anyhow::anyhow!(
+                "The call depth limit of {} is exceeded",
+                Self::CALL_DEPTH_LIMIT
+            )
+            .into());
+        }
+
+        let mut values = Vec::with_capacity(arguments.len());
+        for argument in arguments.iter() {
+            if let Expression::Identifier(identifier) = argument {
+                if identifier.starts_with(crate::r#const::LABEL_DESTINATION_PREFIX) {
+                    continue;
+                }
+            }
+            values.extend(self.evaluate(argument)?);
+        }
+
+        let frame = Self::label_variables(&label)?
+            .into_iter()
+            .map(|name| {
+                let value = if name.as_str() == crate::r#const::VARIABLE_IDENTIFIER_RETURN_BUFFER {
+                    values.first().cloned().unwrap_or_default()
+                } else {
+                    BigUint::zero()
+                };
+                (name, value)
+            })
+            .collect();
+
+        self.frames.push(frame);
+        let result = match label.0.get(2) {
+            Some(body) => self.evaluate(body),
+            None => Ok(None),
+        };
+        self.frames.pop();
+
+        match result {
+            Ok(_) | Err(Interrupt::Exit) => Ok(None),
+            Err(Interrupt::Break | Interrupt::Continue) => Err(anyhow::anyhow!(
+                "`break` or `continue` escapes the function `{label_name}`"
+            )
+            .into()),
+            Err(interrupt) => Err(interrupt),
+        }
+    }
+
+    ///
+    /// Appends a log.
+    ///
+    fn log<const N: usize>(&mut self, arguments: &[Box<Expression>; N]) -> Evaluation {
+        let values = self.arguments(arguments)?;
+        let data = self.memory.read(&values[0], &values[1])?;
+        self.logs.push(Log::new(values[2..].to_vec(), data));
+        Ok(None)
+    }
+
+    ///
+    /// Evaluates the arguments from right to left, as they are pushed onto the EVM stack.
+    ///
+    fn arguments<const N: usize>(
+        &mut self,
+        arguments: &[Box<Expression>; N],
+    ) -> Result<[BigUint; N], Interrupt> {
+        let mut values = Vec::with_capacity(N);
+        for (index, expression) in arguments.iter().enumerate().rev() {
+            let value = self.evaluate(expression)?.ok_or_else(|| {
+                anyhow::anyhow!("Expression #{index} `{expression}` has zero valency")
+            })?;
+            values.push(value);
+        }
+        values.reverse();
+        Ok(values.try_into().expect("Always valid"))
+    }
+
+    ///
+    /// Evaluates the expression that must have a value.
+    ///
+    fn value(&mut self, expression: &Expression) -> Result<BigUint, Interrupt> {
+        Ok(self
+            .evaluate(expression)?
+            .ok_or_else(|| anyhow::anyhow!("Expected a value, found `{expression}`"))?)
+    }
+
+    ///
+    /// Evaluates the unary operation.
+    ///
+    fn unary(
+        &mut self,
+        arguments: &[Box<Expression>; 1],
+        operation: impl Fn(&BigUint) -> BigUint,
+    ) -> Evaluation {
+        let [operand] = self.arguments(arguments)?;
+        Ok(Some(operation(&operand)))
+    }
+
+    ///
+    /// Evaluates the binary operation.
+    ///
+    fn binary(
+        &mut self,
+        arguments: &[Box<Expression>; 2],
+        operation: impl Fn(&BigUint, &BigUint) -> BigUint,
+    ) -> Evaluation {
+        let [operand_1, operand_2] = self.arguments(arguments)?;
+        Ok(Some(operation(&operand_1, &operand_2)))
+    }
+
+    ///
+    /// Evaluates the comparison.
+    ///
+    fn compare(
+        &mut self,
+        arguments: &[Box<Expression>; 2],
+        predicate: impl Fn(&BigUint, &BigUint) -> bool,
+    ) -> Evaluation {
+        let [operand_1, operand_2] = self.arguments(arguments)?;
+        Ok(Some(word::from_bool(predicate(&operand_1, &operand_2))))
+    }
+
+    ///
+    /// Returns the clamped value, or reverts if the condition does not hold.
+    ///
+    fn clamp(value: BigUint, condition: bool) -> Evaluation {
+        if !condition {
+            return Err(Interrupt::Halt(Outcome::Revert(vec![])));
+        }
+        Ok(Some(value))
+    }
+
+    ///
+    /// Reads `size` bytes of the call data, padding them with zeros.
+    ///
+    fn calldata(&self, offset: &BigUint, size: usize) -> Vec<u8> {
+        let mut data = vec![0u8; size];
+        if let Some(offset) = offset.to_usize() {
+            let calldata = self.environment.calldata.as_slice();
+            if offset < calldata.len() {
+                let available = (calldata.len() - offset).min(size);
+                data[..available].copy_from_slice(&calldata[offset..offset + available]);
+            }
+        }
+        data
+    }
+
+    ///
+    /// Returns the current variable frame.
+    ///
+    fn frame(&self) -> &BTreeMap<String, BigUint> {
+        self.frames.last().expect("Always exists")
+    }
+
+    ///
+    /// Returns the current variable frame.
+    ///
+    fn frame_mut(&mut self) -> &mut BTreeMap<String, BigUint> {
+        self.frames.last_mut().expect("Always exists")
+    }
+
+    ///
+    /// Returns the label variables, except for `return_pc`.
+    ///
+    fn label_variables(label: &Label) -> anyhow::Result<Vec<String>> {
+        let arguments = match label.0.get(1) {
+            Some(Expression::Instruction(Instruction::Var_List(arguments))) => arguments,
+            Some(_) | None => return Ok(vec![]),
+        };
+
+        let mut variables = Vec::with_capacity(arguments.len());
+        for argument in arguments.iter() {
+            let name = argument.try_into_identifier()?;
+            if name.as_str() != crate::r#const::VARIABLE_IDENTIFIER_RETURN_PC {
+                variables.push(name);
+            }
+        }
+        Ok(variables)
+    }
+
+    ///
+    /// Collects the labels declared anywhere in the code.
+    ///
+    fn collect_labels(code: &Expression) -> anyhow::Result<BTreeMap<String, Label>> {
+        fn collect(
+            value: &serde_json::Value,
+            labels: &mut BTreeMap<String, Label>,
+        ) -> anyhow::Result<()> {
+            match value {
+                serde_json::Value::Object(map) => {
+                    for (name, arguments) in map.iter() {
+                        if name.as_str() == "label" && arguments.is_array() {
+                            let label: Label = serde_json::from_value(arguments.to_owned())?;
+                            labels.insert(label.name()?, label);
+                        }
+                        collect(arguments, labels)?;
+                    }
+                }
+                serde_json::Value::Array(values) => {
+                    for value in values.iter() {
+                        collect(value, labels)?;
+                    }
+                }
+                _ => {}
+            }
+            Ok(())
+        }
+
+        let mut labels = BTreeMap::new();
+        collect(&serde_json::to_value(code)?, &mut labels)?;
+        Ok(labels)
+    }
+
+    ///
+    /// Whether the instruction depends on or changes the state outside of memory.
+    ///
+    fn is_impure(instruction: &Instruction) -> bool {
+        matches!(
+            instruction,
+            Instruction::SLOAD(_)
+                | Instruction::SSTORE(_)
+                | Instruction::TLOAD(_)
+                | Instruction::TSTORE(_)
+                | Instruction::ILOAD(_)
+                | Instruction::ISTORE(_)
+                | Instruction::CALLDATALOAD(_)
+                | Instruction::CALLDATASIZE
+                | Instruction::CALLDATACOPY(_)
+                | Instruction::LOG0(_)
+                | Instruction::LOG1(_)
+                | Instruction::LOG2(_)
+                | Instruction::LOG3(_)
+                | Instruction::LOG4(_)
+                | Instruction::ADDRESS
+                | Instruction::CALLER
+                | Instruction::ORIGIN
+                | Instruction::CALLVALUE
+                | Instruction::SELFBALANCE
+                | Instruction::CHAINID
+                | Instruction::NUMBER
+                | Instruction::TIMESTAMP
+                | Instruction::GAS
+        )
+    }
+
+    ///
+    /// Returns the LLL name of the instruction.
+    ///
+    fn instruction_name(instruction: &Instruction) -> String {
+        match serde_json::to_value(instruction) {
+            Ok(serde_json::Value::String(name)) => name,
+            Ok(serde_json::Value::Object(map)) => map.keys().next().cloned().unwrap_or_default(),
+            Ok(_) | Err(_) => "unknown".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the Keccak-256 hash of the data.
+    ///
+    fn keccak256(data: &[u8]) -> BigUint {
+        BigUint::from_bytes_be(era_compiler_common::Keccak256Hash::from_slice(data).as_bytes())
+    }
+}
@@ -0,0 +1,18 @@
+//!
+//! The interpreter execution outcome.
+//!
+
+///
+/// The interpreter execution outcome.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The execution returned the data.
+    Return(Vec<u8>),
+    /// The execution reverted with the data.
+    Revert(Vec<u8>),
+    /// The execution stopped, or reached the end of the code.
+    Stop,
+    /// The execution hit the `INVALID` opcode or an unreachable assertion.
+    Invalid,
+}
@@ -0,0 +1,266 @@
+//!
+//! The EVM 256-bit word arithmetic.
+//!
+
+use num::BigInt;
+use num::BigUint;
+use num::One;
+use num::Signed;
+use num::ToPrimitive;
+use num::Zero;
+
+/// The word bit length.
+pub const BIT_LENGTH: usize = era_compiler_common::BIT_LENGTH_FIELD;
+
+/// The word byte length.
+pub const BYTE_LENGTH: usize = era_compiler_common::BYTE_LENGTH_FIELD;
+
+///
+/// Returns `2^256`.
+///
+pub fn modulus() -> BigUint {
+    BigUint::one() << BIT_LENGTH
+}
+
+///
+/// Returns `2^256 - 1`.
+///
+pub fn max() -> BigUint {
+    modulus() - BigUint::one()
+}
+
+///
+/// Truncates the value to 256 bits.
+///
+pub fn wrap(value: BigUint) -> BigUint {
+    value & max()
+}
+
+///
+/// Converts the boolean into a word.
+///
+pub fn from_bool(value: bool) -> BigUint {
+    if value {
+        BigUint::one()
+    } else {
+        BigUint::zero()
+    }
+}
+
+///
+/// Converts the arbitrary precision integer into a word using the two's complement.
+///
+pub fn from_signed(value: BigInt) -> BigUint {
+    let modulus = BigInt::from(modulus());
+    let value = ((value % &modulus) + &modulus) % modulus;
+    value.to_biguint().expect("Always non-negative")
+}
+
+///
+/// Interprets the word as a two's complement signed integer.
+///
+pub fn to_signed(value: &BigUint) -> BigInt {
+    if value.bit((BIT_LENGTH - 1) as u64) {
+        BigInt::from(value.to_owned()) - BigInt::from(modulus())
+    } else {
+        BigInt::from(value.to_owned())
+    }
+}
+
+///
+/// Converts the word into a shift or index amount, saturating at `usize::MAX`.
+///
+pub fn to_usize_saturating(value: &BigUint) -> usize {
+    value.to_usize().unwrap_or(usize::MAX)
+}
+
+///
+/// The `ADD` opcode.
+///
+pub fn add(operand_1: &BigUint, operand_2: &BigUint) -> BigUint {
+    wrap(operand_1 + operand_2)
+}
+
+///
+/// The `SUB` opcode.
+///
+pub fn sub(operand_1: &BigUint, operand_2: &BigUint) -> BigUint {
+    wrap(operand_1 + modulus() - operand_2)
+}
+
+///
+/// The `MUL` opcode.
+///
+pub fn mul(operand_1: &BigUint, operand_2: &BigUint) -> BigUint {
+    wrap(operand_1 * operand_2)
+}
+
+///
+/// The `DIV` opcode.
+///
+pub fn div(operand_1: &BigUint, operand_2: &BigUint) -> BigUint {
+    if operand_2.is_zero() {
+        return BigUint::zero();
+    }
+    operand_1 / operand_2
+}
+
+///
+/// The `MOD` opcode.
+///
+pub fn r#mod(operand_1: &BigUint, operand_2: &BigUint) -> BigUint {
+    if operand_2.is_zero() {
+        return BigUint::zero();
+    }
+    operand_1 % operand_2
+}
+
+///
+/// The `SDIV` opcode.
+///
+/// The quotient is rounded towards zero, and `-2^255 / -1` overflows back to `-2^255`.
+///
+pub fn sdiv(operand_1: &BigUint, operand_2: &BigUint) -> BigUint {
+    if operand_2.is_zero() {
+        return BigUint::zero();
+    }
+    from_signed(to_signed(operand_1) / to_signed(operand_2))
+}
+
+///
+/// The `SMOD` opcode.
+///
+/// The remainder has the sign of the dividend.
+///
+pub fn smod(operand_1: &BigUint, operand_2: &BigUint) -> BigUint {
+    if operand_2.is_zero() {
+        return BigUint::zero();
+    }
+    from_signed(to_signed(operand_1) % to_signed(operand_2))
+}
+
+///
+/// The `ADDMOD` opcode.
+///
+pub fn addmod(operand_1: &BigUint, operand_2: &BigUint, modulo: &BigUint) -> BigUint {
+    if modulo.is_zero() {
+        return BigUint::zero();
+    }
+    (operand_1 + operand_2) % modulo
+}
+
+///
+/// The `MULMOD` opcode.
+///
+pub fn mulmod(operand_1: &BigUint, operand_2: &BigUint, modulo: &BigUint) -> BigUint {
+    if modulo.is_zero() {
+        return BigUint::zero();
+    }
+    (operand_1 * operand_2) % modulo
+}
+
+///
+/// The `EXP` opcode.
+///
+pub fn exp(base: &BigUint, exponent: &BigUint) -> BigUint {
+    base.modpow(exponent, &modulus())
+}
+
+///
+/// The `SIGNEXTEND` opcode.
+///
+/// Extends the sign bit of the byte at index `bytes` counting from the least significant one.
+///
+pub fn signextend(bytes: &BigUint, value: &BigUint) -> BigUint {
+    let bytes = to_usize_saturating(bytes);
+    if bytes >= BYTE_LENGTH - 1 {
+        return value.to_owned();
+    }
+
+    let sign_bit = bytes * era_compiler_common::BIT_LENGTH_BYTE + 7;
+    let mask = (BigUint::one() << (sign_bit + 1)) - BigUint::one();
+    if value.bit(sign_bit as u64) {
+        value | (max() ^ mask)
+    } else {
+        value & mask
+    }
+}
+
+///
+/// The `BYTE` opcode.
+///
+/// Returns the byte at index `index` counting from the most significant one.
+///
+pub fn byte(index: &BigUint, value: &BigUint) -> BigUint {
+    let index = to_usize_saturating(index);
+    if index >= BYTE_LENGTH {
+        return BigUint::zero();
+    }
+
+    let shift = (BYTE_LENGTH - 1 - index) * era_compiler_common::BIT_LENGTH_BYTE;
+    (value >> shift) & BigUint::from(u8::MAX)
+}
+
+///
+/// The `SHL` opcode.
+///
+pub fn shl(shift: &BigUint, value: &BigUint) -> BigUint {
+    let shift = to_usize_saturating(shift);
+    if shift >= BIT_LENGTH {
+        return BigUint::zero();
+    }
+    wrap(value << shift)
+}
+
+///
+/// The `SHR` opcode.
+///
+pub fn shr(shift: &BigUint, value: &BigUint) -> BigUint {
+    let shift = to_usize_saturating(shift);
+    if shift >= BIT_LENGTH {
+        return BigUint::zero();
+    }
+    value >> shift
+}
+
+///
+/// The `SAR` opcode.
+///
+/// The shifted value is rounded towards negative infinity.
+///
+pub fn sar(shift: &BigUint, value: &BigUint) -> BigUint {
+    let value = to_signed(value);
+    let shift = to_usize_saturating(shift);
+    if shift >= BIT_LENGTH {
+        return if value.is_negative() {
+            max()
+        } else {
+            BigUint::zero()
+        };
+    }
+    from_signed(value >> shift)
+}
+
+///
+/// The `SLT` opcode.
+///
+pub fn slt(operand_1: &BigUint, operand_2: &BigUint) -> bool {
+    to_signed(operand_1) < to_signed(operand_2)
+}
+
+///
+/// The `NOT` opcode.
+///
+pub fn not(value: &BigUint) -> BigUint {
+    value ^ max()
+}
+
+///
+/// Converts the word into 32 big-endian bytes.
+///
+pub fn to_bytes(value: &BigUint) -> [u8; BYTE_LENGTH] {
+    let bytes = value.to_bytes_be();
+    let mut result = [0u8; BYTE_LENGTH];
+    result[BYTE_LENGTH - bytes.len()..].copy_from_slice(bytes.as_slice());
+    result
+}
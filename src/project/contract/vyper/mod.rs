@@ -3,21 +3,29 @@
 //!
 
 pub mod ast;
+pub mod call_graph;
 pub mod expression;
 pub mod function;
+pub mod interpreter;
+pub mod venom;
 
 use std::collections::BTreeMap;
 
+use era_compiler_llvm_context::EVMWriteLLVM;
 use era_compiler_llvm_context::EraVMWriteLLVM;
 use era_compiler_llvm_context::IContext;
 
 use crate::build::contract::Contract as ContractBuild;
+use crate::build_evm::contract::Contract as EVMContractBuild;
 use crate::vyper::selector::Selector as VyperSelector;
 use crate::warning_type::WarningType;
 
 use self::ast::AST;
+use self::call_graph::CallGraph;
+use self::expression::instruction::code;
 use self::expression::Expression;
 use self::function::Function;
+use self::venom::Venom;
 
 ///
 /// The Vyper contract.
@@ -42,6 +50,14 @@ pub struct Contract {
     pub userdoc: Option<serde_json::Value>,
     /// The contract developer documentation.
     pub devdoc: Option<serde_json::Value>,
+    /// The Venom IR, which is translated instead of LLL IR if present.
+    pub venom: Option<Venom>,
+    /// The upstream EVM deploy bytecode.
+    pub evm_bytecode: Option<Vec<u8>>,
+    /// The upstream EVM runtime bytecode.
+    pub evm_bytecode_runtime: Option<Vec<u8>>,
+    /// The chain-independent output forwarded from `vyper` as is.
+    pub forwarded_outputs: BTreeMap<VyperSelector, String>,
 }
 
 impl Contract {
@@ -69,6 +85,10 @@ impl Contract {
             layout,
             userdoc,
             devdoc,
+            venom: None,
+            evm_bytecode: None,
+            evm_bytecode_runtime: None,
+            forwarded_outputs: BTreeMap::new(),
         }
     }
 
@@ -113,20 +133,48 @@ impl Contract {
                 VyperSelector::DeveloperDocumentation => {
                     devdoc = Some(era_compiler_common::deserialize_from_str(line)?);
                 }
-
                 VyperSelector::CombinedJson => {
                     panic!("Combined JSON cannot be requested with other types of output");
                 }
+                VyperSelector::IR => {
+                    panic!("LLL IR s-expressions are printed by `zkvyper` from JSON");
+                }
+                VyperSelector::Interface
+                | VyperSelector::ExternalInterface
+                | VyperSelector::AnnotatedAST
+                | VyperSelector::Metadata
+                | VyperSelector::IRRuntime
+                | VyperSelector::BasicBlocks
+                | VyperSelector::BasicBlocksRuntime
+                | VyperSelector::ControlFlowGraph
+                | VyperSelector::ControlFlowGraphRuntime
+                | VyperSelector::Integrity
+                | VyperSelector::SolcJson
+                | VyperSelector::Archive => {
+                    panic!("Output `{selection}` is forwarded from a separate `vyper` call");
+                }
+                VyperSelector::EVMBytecode | VyperSelector::EVMBytecodeRuntime => {
+                    panic!("EVM bytecode is requested with a separate `vyper` call");
+                }
+                VyperSelector::Bytecode | VyperSelector::BytecodeRuntime => {
+                    panic!("EraVM bytecode cannot be requested from `vyper` executable");
+                }
                 VyperSelector::EraVMAssembly => {
                     panic!("EraVM assembly cannot be requested from `vyper` executable");
                 }
+                VyperSelector::ErgsEstimates => {
+                    panic!("Ergs estimates cannot be requested from `vyper` executable");
+                }
+                VyperSelector::CallGraph => {
+                    panic!("Call graph cannot be requested from `vyper` executable");
+                }
                 VyperSelector::ProjectMetadata => {
                     panic!("Project metadata cannot be requested from `vyper` executable");
                 }
             }
         }
 
-        Ok(Self::new(
+        let contract = Self::new(
             version,
             source_code,
             ir.expect("Always exists"),
@@ -136,7 +184,8 @@ impl Contract {
             layout,
             userdoc,
             devdoc,
-        ))
+        );
+        Ok(contract)
     }
 
     ///
@@ -176,6 +225,11 @@ impl Contract {
         } else {
             None
         };
+        let ir_string = if output_selection.contains(&VyperSelector::IR) {
+            Some(self.ir.to_string())
+        } else {
+            None
+        };
         let ast = if output_selection.contains(&VyperSelector::AST) {
             Some(self.ast.clone())
         } else {
@@ -206,6 +260,20 @@ impl Contract {
         } else {
             None
         };
+        let call_graph = if output_selection.contains(&VyperSelector::CallGraph) {
+            Some(CallGraph::try_from_ir(&self.ir)?)
+        } else {
+            None
+        };
+        let estimated_method_identifiers =
+            if output_selection.contains(&VyperSelector::ErgsEstimates) {
+                Some(self.method_identifiers.clone())
+            } else {
+                None
+            };
+        let evm_bytecode = self.evm_bytecode.take();
+        let evm_bytecode_runtime = self.evm_bytecode_runtime.take();
+        let forwarded_outputs = std::mem::take(&mut self.forwarded_outputs);
 
         self.declare(&mut context).map_err(|error| {
             anyhow::anyhow!(
@@ -245,10 +313,19 @@ impl Contract {
             metadata_hash,
             cbor_data,
             output_selection.contains(&VyperSelector::EraVMAssembly)
+                || output_selection.contains(&VyperSelector::ErgsEstimates)
                 || output_selection.contains(&VyperSelector::CombinedJson),
             false,
         )?;
 
+        let ergs_estimates = match estimated_method_identifiers {
+            Some(method_identifiers) => Some(crate::estimator::estimate(
+                build.assembly.as_deref().expect("Always exists"),
+                &method_identifiers,
+            )?),
+            None => None,
+        };
+
         if is_minimal_proxy_used {
             build.factory_dependencies.insert(
                 hex::encode(
@@ -263,16 +340,208 @@ impl Contract {
 
         Ok(ContractBuild::new(
             build,
+            evm_bytecode,
+            evm_bytecode_runtime,
+            forwarded_outputs,
             ir,
+            ir_string,
             ast,
             abi,
             method_identifiers,
             layout,
             userdoc,
             devdoc,
+            ergs_estimates,
+            call_graph,
             warnings,
         ))
     }
+
+    ///
+    /// Compiles the contract to EVM, returning the build.
+    ///
+    /// The deploy and runtime code are translated into separate LLVM modules, where the runtime
+    /// module is named after the deploy one with the runtime suffix appended.
+    ///
+    pub fn compile_to_evm(
+        mut self,
+        contract_path: &str,
+        metadata_hash: Option<era_compiler_common::Hash>,
+        optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+        llvm_options: Vec<String>,
+        output_selection: Vec<VyperSelector>,
+        suppressed_warnings: Vec<WarningType>,
+        debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    ) -> anyhow::Result<EVMContractBuild> {
+        if self.venom.is_some() {
+            anyhow::bail!("The experimental code generator is not supported for the EVM target");
+        }
+
+        let warnings = self
+            .ast
+            .get_warnings(&self.ast.ast, suppressed_warnings.as_slice());
+
+        let ir = if output_selection.contains(&VyperSelector::IRJson) {
+            Some(self.ir.clone())
+        } else {
+            None
+        };
+        let ir_string = if output_selection.contains(&VyperSelector::IR) {
+            Some(self.ir.to_string())
+        } else {
+            None
+        };
+        let ast = if output_selection.contains(&VyperSelector::AST) {
+            Some(self.ast.clone())
+        } else {
+            None
+        };
+        let abi = if output_selection.contains(&VyperSelector::ABI) {
+            Some(self.abi.clone())
+        } else {
+            None
+        };
+        let method_identifiers = if output_selection.contains(&VyperSelector::MethodIdentifiers) {
+            Some(self.method_identifiers.clone())
+        } else {
+            None
+        };
+        let layout = if output_selection.contains(&VyperSelector::Layout) {
+            self.layout.take()
+        } else {
+            None
+        };
+        let userdoc = if output_selection.contains(&VyperSelector::UserDocumentation) {
+            self.userdoc.take()
+        } else {
+            None
+        };
+        let devdoc = if output_selection.contains(&VyperSelector::DeveloperDocumentation) {
+            self.devdoc.take()
+        } else {
+            None
+        };
+        let call_graph = if output_selection.contains(&VyperSelector::CallGraph) {
+            Some(CallGraph::try_from_ir(&self.ir)?)
+        } else {
+            None
+        };
+
+        let mut ir_evm = self.ir.lower_label_arguments()?;
+        let memory_size = match ir_evm.deploy_memory_size() {
+            Some(Expression::IntegerLiteral(number)) => number
+                .as_u64()
+                .ok_or_else(|| anyhow::anyhow!("Memory size `{number}` parsing error"))?,
+            Some(expression) => anyhow::bail!("Invalid memory size format: `{expression}`"),
+            None => 0,
+        };
+        let (runtime_code, immutables_size) = ir_evm.extract_runtime_code()?.unwrap_or_default();
+        let immutables_size = match immutables_size {
+            Expression::IntegerLiteral(number) => number
+                .as_u64()
+                .ok_or_else(|| anyhow::anyhow!("Immutable size `{number}` parsing error"))?,
+            expression => anyhow::bail!("Invalid immutables size format: `{expression}`"),
+        };
+        let deploy_code = ir_evm.try_into_deploy_code()?;
+
+        let runtime_code_identifier =
+            format!("{contract_path}{}", crate::r#const::EVM_RUNTIME_CODE_SUFFIX);
+
+        let llvm = inkwell::context::Context::create();
+        let mut bytecodes = Vec::with_capacity(2);
+        for (identifier, code_segment, code, constant) in [
+            (
+                contract_path.to_owned(),
+                era_compiler_common::CodeSegment::Deploy,
+                deploy_code,
+                (code::GLOBAL_DEPLOY_MEMORY_SIZE, memory_size),
+            ),
+            (
+                runtime_code_identifier.clone(),
+                era_compiler_common::CodeSegment::Runtime,
+                runtime_code,
+                (code::GLOBAL_IMMUTABLES_SIZE, immutables_size),
+            ),
+        ] {
+            let optimizer = era_compiler_llvm_context::Optimizer::new(optimizer_settings.clone());
+            let mut context = era_compiler_llvm_context::EVMContext::new(
+                &llvm,
+                llvm.create_module(identifier.as_str()),
+                llvm_options.clone(),
+                optimizer,
+                debug_config.clone(),
+            );
+            context.set_code_segment(code_segment);
+            code::declare_constant(&mut context, constant.0, constant.1);
+
+            Self::into_llvm_evm(code, &mut context).map_err(|error| {
+                anyhow::anyhow!(
+                    "The contract `{identifier}` {code_segment} code LLVM IR generator error: {error}",
+                )
+            })?;
+
+            let build = context.build(
+                identifier.as_str(),
+                match code_segment {
+                    era_compiler_common::CodeSegment::Deploy => None,
+                    era_compiler_common::CodeSegment::Runtime => metadata_hash.clone(),
+                },
+            )?;
+            bytecodes.push(build.bytecode);
+        }
+        let runtime_bytecode = bytecodes.pop().expect("Always exists");
+        let deploy_bytecode = era_compiler_llvm_context::evm_link(
+            bytecodes.pop().expect("Always exists"),
+            &[(
+                runtime_code_identifier.as_str(),
+                runtime_bytecode.as_slice(),
+            )],
+        )?;
+
+        Ok(EVMContractBuild::new(
+            deploy_bytecode,
+            runtime_bytecode,
+            std::mem::take(&mut self.forwarded_outputs),
+            ir,
+            ir_string,
+            ast,
+            abi,
+            method_identifiers,
+            layout,
+            userdoc,
+            devdoc,
+            call_graph,
+            warnings,
+        ))
+    }
+
+    ///
+    /// Translates the deploy or runtime code with its functions into the EVM LLVM module.
+    ///
+    fn into_llvm_evm(
+        mut code: expression::instruction::seq::Seq,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        let mut functions = code
+            .extract_functions()?
+            .into_iter()
+            .map(|(label, expression)| {
+                Function::new(Expression::safe_label(label.as_str()), expression)
+            })
+            .collect::<Vec<Function>>();
+        for function in functions.iter_mut() {
+            EVMWriteLLVM::declare(function, context)?;
+        }
+        for function in functions.into_iter() {
+            EVMWriteLLVM::into_llvm(function, context)?;
+        }
+
+        let mut entry = era_compiler_llvm_context::EVMEntryFunction::new(code);
+        entry.declare(context)?;
+        entry.into_llvm(context)?;
+
+        Ok(())
+    }
 }
 
 impl EraVMWriteLLVM for Contract {
@@ -335,7 +604,11 @@ impl EraVMWriteLLVM for Contract {
                 );
                 context.set_vyper_data(vyper_data);
             }
-            expression => anyhow::bail!("Invalid immutables size format: {expression:?}"),
+            expression => anyhow::bail!("Invalid immutables size format: `{expression}`"),
+        }
+
+        if let Some(venom) = self.venom.take() {
+            return venom.into_llvm(context);
         }
 
         let mut function_expressions = deploy_code
@@ -363,11 +636,11 @@ impl EraVMWriteLLVM for Contract {
             ));
         }
         for (function, _code_segment) in functions.iter_mut() {
-            function.declare(context)?;
+            EraVMWriteLLVM::declare(function, context)?;
         }
         for (function, code_segment) in functions.into_iter() {
             context.set_code_segment(code_segment);
-            function.into_llvm(context)?;
+            EraVMWriteLLVM::into_llvm(function, context)?;
         }
 
         era_compiler_llvm_context::EraVMDeployCodeFunction::new(deploy_code).into_llvm(context)?;
@@ -0,0 +1,65 @@
+//!
+//! The Venom IR basic block.
+//!
+
+use era_compiler_llvm_context::IContext;
+
+use crate::project::contract::vyper::venom::instruction::Instruction;
+use crate::project::contract::vyper::venom::scope::Scope;
+
+///
+/// The Venom IR basic block.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Block {
+    /// The block label.
+    pub label: String,
+    /// The block instructions, the last of which is the terminator.
+    pub instructions: Vec<Instruction>,
+}
+
+impl Block {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(label: String) -> Self {
+        Self {
+            label,
+            instructions: Vec::new(),
+        }
+    }
+
+    ///
+    /// Returns the labels of the successor blocks.
+    ///
+    pub fn successors(&self) -> Vec<&str> {
+        self.instructions
+            .last()
+            .map(|instruction| instruction.successors())
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Translates the block into the LLVM block predeclared in `scope`.
+    ///
+    pub fn into_llvm<'ctx>(
+        self,
+        context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
+        scope: &mut Scope<'ctx>,
+    ) -> anyhow::Result<()> {
+        context.set_basic_block(scope.block(self.label.as_str())?);
+
+        for instruction in self.instructions.into_iter() {
+            let instruction_string = instruction.to_string();
+            instruction.into_llvm(context, scope).map_err(|error| {
+                anyhow::anyhow!(
+                    "Block `@{}`, instruction `{instruction_string}`: {error}",
+                    self.label
+                )
+            })?;
+        }
+
+        scope.exits.insert(self.label, context.basic_block());
+        Ok(())
+    }
+}
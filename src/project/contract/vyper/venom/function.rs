@@ -0,0 +1,278 @@
+//!
+//! The Venom IR function.
+//!
+
+use std::collections::BTreeMap;
+
+use inkwell::types::BasicType;
+use inkwell::values::BasicValue;
+
+use era_compiler_llvm_context::EraVMWriteLLVM;
+use era_compiler_llvm_context::IContext;
+
+use crate::project::contract::vyper::expression::Expression;
+use crate::project::contract::vyper::venom::block::Block;
+use crate::project::contract::vyper::venom::instruction::Instruction;
+use crate::project::contract::vyper::venom::operand::Operand;
+use crate::project::contract::vyper::venom::scope::Scope;
+
+///
+/// The Venom IR function.
+///
+/// The internal function parameters are declared with `param` instructions. The last one or
+/// the one passed to `ret` holds the return address, which is not used on EraVM, as internal
+/// functions are translated into LLVM functions.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Function {
+    /// The function name.
+    pub name: String,
+    /// The basic blocks, the first of which is the function entry.
+    pub blocks: Vec<Block>,
+}
+
+impl Function {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            blocks: Vec::new(),
+        }
+    }
+
+    ///
+    /// Returns the LLVM function name.
+    ///
+    /// The code segment is appended, as the deploy and runtime code may contain functions with
+    /// the same name.
+    ///
+    pub fn llvm_name(name: &str, code_segment: era_compiler_common::CodeSegment) -> String {
+        let code_segment = match code_segment {
+            era_compiler_common::CodeSegment::Deploy => "deploy",
+            era_compiler_common::CodeSegment::Runtime => "runtime",
+        };
+        Expression::safe_label(format!("{name}_{code_segment}").as_str())
+    }
+
+    ///
+    /// Returns the variables declared with `param` instructions, in the declaration order.
+    ///
+    pub fn parameters(&self) -> Vec<&str> {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.instructions.iter())
+            .filter(|instruction| instruction.opcode == "param")
+            .filter_map(|instruction| instruction.output.as_deref())
+            .collect()
+    }
+
+    ///
+    /// Returns the parameter holding the return address.
+    ///
+    pub fn return_address(&self) -> Option<&str> {
+        let parameters = self.parameters();
+        self.returns()
+            .flat_map(|instruction| instruction.operands.iter())
+            .find_map(|operand| match operand {
+                Operand::Variable(name) if parameters.contains(&name.as_str()) => {
+                    Some(name.as_str())
+                }
+                _ => None,
+            })
+            .or_else(|| parameters.last().copied())
+    }
+
+    ///
+    /// Returns the parameters passed by the caller, that is, all except the return address.
+    ///
+    pub fn arguments(&self) -> Vec<&str> {
+        let return_address = self.return_address();
+        self.parameters()
+            .into_iter()
+            .filter(|name| Some(*name) != return_address)
+            .collect()
+    }
+
+    ///
+    /// Whether the function returns a value along with the return address.
+    ///
+    pub fn has_return_value(&self) -> bool {
+        self.returns()
+            .any(|instruction| instruction.operands.len() > 1)
+    }
+
+    ///
+    /// Returns the block indexes in the reverse post-order of the control flow graph.
+    ///
+    /// In this order, the definition of each SSA variable is translated before its uses,
+    /// except for the phi node operands. Unreachable blocks are omitted.
+    ///
+    pub fn reverse_post_order(&self) -> anyhow::Result<Vec<usize>> {
+        let indexes: BTreeMap<&str, usize> = self
+            .blocks
+            .iter()
+            .enumerate()
+            .map(|(index, block)| (block.label.as_str(), index))
+            .collect();
+
+        let mut order = Vec::with_capacity(self.blocks.len());
+        if self.blocks.is_empty() {
+            return Ok(order);
+        }
+
+        let mut is_visited = vec![false; self.blocks.len()];
+        let mut stack = vec![(0, 0)];
+        is_visited[0] = true;
+        while let Some((index, successor)) = stack.pop() {
+            let successors = self.blocks[index].successors();
+            match successors.get(successor) {
+                Some(label) => {
+                    stack.push((index, successor + 1));
+                    let next = *indexes
+                        .get(label)
+                        .ok_or_else(|| anyhow::anyhow!("Block `@{label}` does not exist"))?;
+                    if !is_visited[next] {
+                        is_visited[next] = true;
+                        stack.push((next, 0));
+                    }
+                }
+                None => order.push(index),
+            }
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+
+    ///
+    /// Translates the blocks into the current LLVM function, branching to them from the
+    /// current LLVM block.
+    ///
+    /// The blocks left without a terminator, including the unreachable ones, revert.
+    ///
+    pub fn into_llvm_body<'ctx>(
+        self,
+        context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
+        scope: &mut Scope<'ctx>,
+    ) -> anyhow::Result<()> {
+        let order = self.reverse_post_order()?;
+
+        for block in self.blocks.iter() {
+            if scope.blocks.contains_key(block.label.as_str()) {
+                anyhow::bail!("Block `@{}` is declared more than once", block.label);
+            }
+            let llvm_block = context.append_basic_block(block.label.as_str());
+            scope.blocks.insert(block.label.clone(), llvm_block);
+        }
+
+        let entry_block = match self.blocks.first() {
+            Some(block) => scope.block(block.label.as_str())?,
+            None => anyhow::bail!("Function `{}` has no blocks", self.name),
+        };
+        context.build_unconditional_branch(entry_block)?;
+
+        let mut blocks: Vec<Option<Block>> = self.blocks.into_iter().map(Some).collect();
+        for index in order.into_iter() {
+            let block = blocks[index].take().expect("Always exists");
+            block.into_llvm(context, scope)?;
+        }
+        scope.resolve_phis(context)?;
+
+        for block in scope.blocks.values() {
+            if block.get_terminator().is_none() {
+                context.set_basic_block(*block);
+                context.build_exit(
+                    context.llvm_runtime().revert,
+                    context.field_const(0),
+                    context.field_const(0),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the `ret` instructions.
+    ///
+    fn returns(&self) -> impl Iterator<Item = &Instruction> {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.instructions.iter())
+            .filter(|instruction| instruction.opcode == "ret")
+    }
+}
+
+impl EraVMWriteLLVM for Function {
+    fn declare(
+        &mut self,
+        context: &mut era_compiler_llvm_context::EraVMContext,
+    ) -> anyhow::Result<()> {
+        let code_segment = context
+            .code_segment()
+            .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?;
+
+        let argument_types =
+            vec![context.field_type().as_basic_type_enum(); self.arguments().len()];
+        let return_values_length = if self.has_return_value() { 1 } else { 0 };
+
+        let function = context.add_function(
+            Self::llvm_name(self.name.as_str(), code_segment).as_str(),
+            context.function_type(argument_types, return_values_length, false),
+            return_values_length,
+            Some(inkwell::module::Linkage::Private),
+        )?;
+        function
+            .borrow_mut()
+            .set_vyper_data(era_compiler_llvm_context::EraVMFunctionVyperData::default());
+
+        Ok(())
+    }
+
+    fn into_llvm(
+        self,
+        context: &mut era_compiler_llvm_context::EraVMContext,
+    ) -> anyhow::Result<()> {
+        let code_segment = context
+            .code_segment()
+            .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?;
+        context.set_current_function(Self::llvm_name(self.name.as_str(), code_segment).as_str())?;
+        context.set_basic_block(context.current_function().borrow().entry_block());
+
+        let mut scope = Scope::default();
+        for (index, name) in self.arguments().into_iter().enumerate() {
+            let value = context.current_function().borrow().get_nth_param(index);
+            scope.variables.insert(name.to_owned(), value);
+        }
+        if let Some(return_address) = self.return_address() {
+            scope.variables.insert(
+                return_address.to_owned(),
+                context.field_const(0).as_basic_value_enum(),
+            );
+            scope.return_address = Some(return_address.to_owned());
+        }
+        if self.has_return_value() {
+            let pointer = context.build_alloca(context.field_type(), "return_pointer")?;
+            context.build_store(pointer, context.field_const(0))?;
+            scope.return_pointer = Some(pointer);
+        }
+        let return_pointer = scope.return_pointer;
+
+        self.into_llvm_body(context, &mut scope)?;
+
+        context.set_basic_block(context.current_function().borrow().return_block());
+        match return_pointer {
+            Some(pointer) => {
+                let value = context.build_load(pointer, "return_value")?;
+                context.build_return(Some(&value))?;
+            }
+            None => {
+                context.build_return(None)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1020 @@
+//!
+//! The Venom IR instruction.
+//!
+
+use era_compiler_llvm_context::IContext;
+use inkwell::values::BasicValue;
+
+use crate::error::Error;
+use crate::project::contract::vyper::expression::instruction::create;
+use crate::project::contract::vyper::expression::instruction::immutable;
+use crate::project::contract::vyper::venom::function::Function;
+use crate::project::contract::vyper::venom::operand::Operand;
+use crate::project::contract::vyper::venom::scope::Scope;
+
+///
+/// The Venom IR instruction.
+///
+/// The operands are stored in the order they are printed by `vyper`, which is the order of
+/// the EVM opcode arguments, with the first argument being the top of the stack.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Instruction {
+    /// The output variable, stored without the `%` sigil.
+    pub output: Option<String>,
+    /// The opcode.
+    pub opcode: String,
+    /// The operands.
+    pub operands: Vec<Operand>,
+}
+
+impl Instruction {
+    /// The opcode of the variable assignment, which is printed without the opcode.
+    pub const OPCODE_STORE: &'static str = "store";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(output: Option<String>, opcode: String, operands: Vec<Operand>) -> Self {
+        Self {
+            output,
+            opcode,
+            operands,
+        }
+    }
+
+    ///
+    /// Whether the instruction transfers control to another block or function.
+    ///
+    pub fn is_branch(&self) -> bool {
+        matches!(self.opcode.as_str(), "jmp" | "jnz" | "djmp")
+    }
+
+    ///
+    /// Returns the labels of the blocks the instruction may branch to.
+    ///
+    pub fn successors(&self) -> Vec<&str> {
+        if !self.is_branch() {
+            return vec![];
+        }
+
+        self.operands
+            .iter()
+            .filter_map(|operand| operand.try_as_label().ok())
+            .collect()
+    }
+
+    ///
+    /// Translates the instruction, binding the output variable in `scope`.
+    ///
+    pub fn into_llvm<'ctx>(
+        self,
+        context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
+        scope: &mut Scope<'ctx>,
+    ) -> anyhow::Result<()> {
+        let value = match self.opcode.as_str() {
+            "param" => {
+                let output = self.output()?;
+                if !scope.variables.contains_key(output) {
+                    anyhow::bail!("Parameters are only allowed in internal functions");
+                }
+                return Ok(());
+            }
+            "phi" => {
+                let output = self.output()?.to_owned();
+                return self.phi(context, scope, output);
+            }
+            "nop" => None,
+            Self::OPCODE_STORE => {
+                let [value] = self.values::<1>(context, scope)?;
+                Some(value.as_basic_value_enum())
+            }
+            "alloca" | "palloca" | "calloca" => {
+                let offset = self
+                    .operands
+                    .last()
+                    .ok_or_else(|| anyhow::anyhow!("The memory offset is missing"))?;
+                Some(scope.value(context, offset)?.as_basic_value_enum())
+            }
+
+            "jmp" => {
+                let [label] = self.labels::<1>()?;
+                context.build_unconditional_branch(scope.block(label)?)?;
+                None
+            }
+            "jnz" => {
+                let condition = match self.operands.first() {
+                    Some(operand) => scope.value(context, operand)?,
+                    None => anyhow::bail!("The condition is missing"),
+                };
+                let labels = self.operands[1..]
+                    .iter()
+                    .map(|operand| operand.try_as_label())
+                    .collect::<anyhow::Result<Vec<&str>>>()?;
+                let [then_label, else_label] = <[&str; 2]>::try_from(labels)
+                    .map_err(|labels| anyhow::anyhow!("Expected 2 labels, found {labels:?}"))?;
+
+                let condition = context.builder().build_int_compare(
+                    inkwell::IntPredicate::NE,
+                    condition,
+                    context.field_const(0),
+                    "jnz_condition",
+                )?;
+                context.build_conditional_branch(
+                    condition,
+                    scope.block(then_label)?,
+                    scope.block(else_label)?,
+                )?;
+                None
+            }
+            "djmp" => {
+                return Err(Error::unsupported(
+                    "Dynamic jumps are not supported, as EraVM has no code addresses. Disable the `vyper` optimizer to avoid jump tables".to_owned(),
+                )
+                .into())
+            }
+            "invoke" => self.invoke(context, scope)?,
+            "ret" => {
+                self.r#return(context, scope)?;
+                None
+            }
+
+            "add" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_arithmetic::addition(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "sub" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_arithmetic::subtraction(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "mul" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(
+                    era_compiler_llvm_context::eravm_evm_arithmetic::multiplication(
+                        context,
+                        arguments[0],
+                        arguments[1],
+                    )?,
+                )
+            }
+            "div" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_arithmetic::division(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "mod" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_arithmetic::remainder(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "sdiv" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(
+                    era_compiler_llvm_context::eravm_evm_arithmetic::division_signed(
+                        context,
+                        arguments[0],
+                        arguments[1],
+                    )?,
+                )
+            }
+            "smod" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(
+                    era_compiler_llvm_context::eravm_evm_arithmetic::remainder_signed(
+                        context,
+                        arguments[0],
+                        arguments[1],
+                    )?,
+                )
+            }
+
+            "lt" => self.compare(context, scope, inkwell::IntPredicate::ULT)?,
+            "gt" => self.compare(context, scope, inkwell::IntPredicate::UGT)?,
+            "slt" => self.compare(context, scope, inkwell::IntPredicate::SLT)?,
+            "sgt" => self.compare(context, scope, inkwell::IntPredicate::SGT)?,
+            "eq" => self.compare(context, scope, inkwell::IntPredicate::EQ)?,
+            "iszero" => {
+                let [value] = self.values::<1>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_comparison::compare(
+                    context,
+                    value,
+                    context.field_const(0),
+                    inkwell::IntPredicate::EQ,
+                )?)
+            }
+
+            "and" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_bitwise::and(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "or" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_bitwise::or(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "xor" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_bitwise::xor(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "not" => {
+                let [value] = self.values::<1>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_bitwise::xor(
+                    context,
+                    value,
+                    context.field_type().const_all_ones(),
+                )?)
+            }
+            "shl" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_bitwise::shift_left(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "shr" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_bitwise::shift_right(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "sar" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(
+                    era_compiler_llvm_context::eravm_evm_bitwise::shift_right_arithmetic(
+                        context,
+                        arguments[0],
+                        arguments[1],
+                    )?,
+                )
+            }
+            "byte" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_bitwise::byte(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+
+            "addmod" => {
+                let arguments = self.values::<3>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_math::add_mod(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                    arguments[2],
+                )?)
+            }
+            "mulmod" => {
+                let arguments = self.values::<3>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_math::mul_mod(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                    arguments[2],
+                )?)
+            }
+            "exp" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_math::exponent(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "signextend" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_math::sign_extend(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+
+            "sha3" | "keccak256" => {
+                let arguments = self.values::<2>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_crypto::sha3(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?)
+            }
+            "sha3_64" => {
+                let arguments = self.values::<2>(context, scope)?;
+                for (index, (value, offset)) in arguments
+                    .into_iter()
+                    .zip([
+                        crate::r#const::OFFSET_FREE_VAR_SPACE,
+                        crate::r#const::OFFSET_FREE_VAR_SPACE2,
+                    ])
+                    .enumerate()
+                {
+                    let pointer = era_compiler_llvm_context::Pointer::new_with_offset(
+                        context,
+                        era_compiler_llvm_context::EraVMAddressSpace::Heap,
+                        context.field_type(),
+                        context.field_const(offset as u64),
+                        format!("sha3_64_pointer_{index}").as_str(),
+                    )?;
+                    context.build_store(pointer, value)?;
+                }
+                Some(era_compiler_llvm_context::eravm_evm_crypto::sha3(
+                    context,
+                    context.field_const(crate::r#const::OFFSET_FREE_VAR_SPACE as u64),
+                    context.field_const((era_compiler_common::BYTE_LENGTH_FIELD * 2) as u64),
+                )?)
+            }
+
+            "mload" => {
+                let [offset] = self.values::<1>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_memory::load(
+                    context, offset,
+                )?)
+            }
+            "mstore" => {
+                let arguments = self.values::<2>(context, scope)?;
+                era_compiler_llvm_context::eravm_evm_memory::store(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?;
+                None
+            }
+            "mstore8" => {
+                let arguments = self.values::<2>(context, scope)?;
+                era_compiler_llvm_context::eravm_evm_memory::store_byte(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?;
+                None
+            }
+            "mcopy" => {
+                let arguments = self.values::<3>(context, scope)?;
+                let destination = era_compiler_llvm_context::Pointer::new_with_offset(
+                    context,
+                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
+                    context.byte_type(),
+                    arguments[0],
+                    "mcopy_destination",
+                )?;
+                let source = era_compiler_llvm_context::Pointer::new_with_offset(
+                    context,
+                    era_compiler_llvm_context::EraVMAddressSpace::Heap,
+                    context.byte_type(),
+                    arguments[1],
+                    "mcopy_source",
+                )?;
+                context.build_memcpy(
+                    context.intrinsics().memory_move_heap,
+                    destination,
+                    source,
+                    arguments[2],
+                    "mcopy_size",
+                )?;
+                None
+            }
+
+            "sload" => {
+                let [key] = self.values::<1>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_storage::load(
+                    context, key,
+                )?)
+            }
+            "sstore" => {
+                let arguments = self.values::<2>(context, scope)?;
+                era_compiler_llvm_context::eravm_evm_storage::store(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?;
+                None
+            }
+            "tload" => {
+                let [key] = self.values::<1>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_storage::transient_load(
+                    context, key,
+                )?)
+            }
+            "tstore" => {
+                let arguments = self.values::<2>(context, scope)?;
+                era_compiler_llvm_context::eravm_evm_storage::transient_store(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?;
+                None
+            }
+            "iload" => {
+                let [offset] = self.values::<1>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_immutable::load(
+                    context, offset,
+                )?)
+            }
+            "istore" => {
+                let arguments = self.values::<2>(context, scope)?;
+                era_compiler_llvm_context::eravm_evm_immutable::store(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?;
+                None
+            }
+
+            "calldataload" => {
+                let [offset] = self.values::<1>(context, scope)?;
+                match Self::code_segment(context)? {
+                    era_compiler_common::CodeSegment::Deploy => {
+                        Some(context.field_const(0).as_basic_value_enum())
+                    }
+                    era_compiler_common::CodeSegment::Runtime => Some(
+                        era_compiler_llvm_context::eravm_evm_calldata::load(context, offset)?,
+                    ),
+                }
+            }
+            "calldatasize" => match Self::code_segment(context)? {
+                era_compiler_common::CodeSegment::Deploy => {
+                    Some(context.field_const(0).as_basic_value_enum())
+                }
+                era_compiler_common::CodeSegment::Runtime => {
+                    Some(era_compiler_llvm_context::eravm_evm_calldata::size(context)?)
+                }
+            },
+            "calldatacopy" => {
+                let arguments = self.values::<3>(context, scope)?;
+                let source_offset = match Self::code_segment(context)? {
+                    era_compiler_common::CodeSegment::Deploy => {
+                        era_compiler_llvm_context::eravm_evm_calldata::size(context)?
+                            .into_int_value()
+                    }
+                    era_compiler_common::CodeSegment::Runtime => arguments[1],
+                };
+                era_compiler_llvm_context::eravm_evm_calldata::copy(
+                    context,
+                    arguments[0],
+                    source_offset,
+                    arguments[2],
+                )?;
+                None
+            }
+            "dload" => {
+                let [offset] = self.values::<1>(context, scope)?;
+                Some(match Self::code_segment(context)? {
+                    era_compiler_common::CodeSegment::Deploy => {
+                        era_compiler_llvm_context::eravm_evm_calldata::load(context, offset)?
+                    }
+                    era_compiler_common::CodeSegment::Runtime => {
+                        era_compiler_llvm_context::eravm_evm_immutable::load(context, offset)?
+                    }
+                })
+            }
+            "dloadbytes" => {
+                let arguments = self.values::<3>(context, scope)?;
+                match Self::code_segment(context)? {
+                    era_compiler_common::CodeSegment::Deploy => {
+                        era_compiler_llvm_context::eravm_evm_calldata::copy(
+                            context,
+                            arguments[0],
+                            arguments[1],
+                            arguments[2],
+                        )?
+                    }
+                    era_compiler_common::CodeSegment::Runtime => immutable::load_bytes(
+                        context,
+                        arguments[0],
+                        arguments[1],
+                        arguments[2],
+                    )?,
+                }
+                None
+            }
+            "codesize" => Some(match Self::code_segment(context)? {
+                era_compiler_common::CodeSegment::Deploy => {
+                    era_compiler_llvm_context::eravm_evm_calldata::size(context)?
+                }
+                era_compiler_common::CodeSegment::Runtime => {
+                    let code_source =
+                        era_compiler_llvm_context::eravm_general::code_source(context)?;
+                    era_compiler_llvm_context::eravm_evm_ext_code::size(
+                        context,
+                        code_source.into_int_value(),
+                    )?
+                }
+            }),
+            "codecopy" => {
+                if let era_compiler_common::CodeSegment::Runtime = Self::code_segment(context)? {
+                    return Err(Error::unsupported(
+                        "The `codecopy` instruction is not supported in the runtime code"
+                            .to_owned(),
+                    )
+                    .into());
+                }
+
+                let arguments = self.values::<3>(context, scope)?;
+                era_compiler_llvm_context::eravm_evm_calldata::copy(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                    arguments[2],
+                )?;
+                None
+            }
+            "returndatasize" => Some(era_compiler_llvm_context::eravm_evm_return_data::size(
+                context,
+            )?),
+            "returndatacopy" => {
+                let arguments = self.values::<3>(context, scope)?;
+                era_compiler_llvm_context::eravm_evm_return_data::copy(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                    arguments[2],
+                )?;
+                None
+            }
+            "extcodesize" => {
+                let [address] = self.values::<1>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_ext_code::size(
+                    context, address,
+                )?)
+            }
+            "extcodehash" => {
+                let [address] = self.values::<1>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_ext_code::hash(
+                    context, address,
+                )?)
+            }
+
+            "return" => {
+                let arguments = self.values::<2>(context, scope)?;
+                era_compiler_llvm_context::eravm_evm_return::r#return(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?;
+                None
+            }
+            "revert" => {
+                let arguments = self.values::<2>(context, scope)?;
+                era_compiler_llvm_context::eravm_evm_return::revert(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                )?;
+                None
+            }
+            "stop" => {
+                era_compiler_llvm_context::eravm_evm_return::stop(context)?;
+                None
+            }
+            "invalid" => {
+                era_compiler_llvm_context::eravm_evm_return::invalid(context)?;
+                None
+            }
+            "exit" => {
+                if let era_compiler_common::CodeSegment::Runtime = Self::code_segment(context)? {
+                    anyhow::bail!("The `exit` instruction is only allowed in the deploy code");
+                }
+
+                era_compiler_llvm_context::eravm_evm_return::r#return(
+                    context,
+                    context.field_const(0),
+                    context.field_const(0),
+                )?;
+                None
+            }
+            "assert" | "assert_unreachable" => {
+                let [condition] = self.values::<1>(context, scope)?;
+
+                let error_block = context.append_basic_block("assert_error");
+                let join_block = context.append_basic_block("assert_join");
+                let condition = context.builder().build_int_compare(
+                    inkwell::IntPredicate::NE,
+                    condition,
+                    context.field_const(0),
+                    "assert_condition",
+                )?;
+                context.build_conditional_branch(condition, join_block, error_block)?;
+
+                context.set_basic_block(error_block);
+                if self.opcode == "assert_unreachable" {
+                    era_compiler_llvm_context::eravm_evm_return::invalid(context)?;
+                } else {
+                    era_compiler_llvm_context::eravm_evm_return::revert(
+                        context,
+                        context.field_const(0),
+                        context.field_const(0),
+                    )?;
+                }
+
+                context.set_basic_block(join_block);
+                None
+            }
+
+            "log" => {
+                let topics_count = match self.operands.last() {
+                    Some(Operand::Literal(count)) => count.parse::<usize>()?,
+                    _ => anyhow::bail!("The topic count is missing"),
+                };
+                if self.operands.len() != topics_count + 3 {
+                    anyhow::bail!(
+                        "Expected {} operands for {topics_count} topics, found {}",
+                        topics_count + 3,
+                        self.operands.len()
+                    );
+                }
+
+                let mut arguments = Vec::with_capacity(self.operands.len() - 1);
+                for operand in self.operands[..self.operands.len() - 1].iter() {
+                    arguments.push(scope.value(context, operand)?);
+                }
+                era_compiler_llvm_context::eravm_evm_event::log(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                    arguments[2..].to_vec(),
+                )?;
+                None
+            }
+
+            "call" => {
+                let arguments = self.values::<7>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_call::default(
+                    context,
+                    context.llvm_runtime().far_call,
+                    arguments[0],
+                    arguments[1],
+                    Some(arguments[2]),
+                    arguments[3],
+                    arguments[4],
+                    arguments[5],
+                    arguments[6],
+                    vec![],
+                )?)
+            }
+            "staticcall" | "delegatecall" => {
+                let arguments = self.values::<6>(context, scope)?;
+                let function = if self.opcode == "staticcall" {
+                    context.llvm_runtime().static_call
+                } else {
+                    context.llvm_runtime().delegate_call
+                };
+                Some(era_compiler_llvm_context::eravm_evm_call::default(
+                    context,
+                    function,
+                    arguments[0],
+                    arguments[1],
+                    None,
+                    arguments[2],
+                    arguments[3],
+                    arguments[4],
+                    arguments[5],
+                    vec![],
+                )?)
+            }
+            "create" => {
+                let arguments = self.values::<3>(context, scope)?;
+                Some(create::create(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                    arguments[2],
+                    None,
+                )?)
+            }
+            "create2" => {
+                let arguments = self.values::<4>(context, scope)?;
+                Some(create::create(
+                    context,
+                    arguments[0],
+                    arguments[1],
+                    arguments[2],
+                    Some(arguments[3]),
+                )?)
+            }
+
+            "address" => context.build_call(context.intrinsics().address, &[], "address")?,
+            "caller" => context.build_call(context.intrinsics().caller, &[], "caller")?,
+            "callvalue" => Some(era_compiler_llvm_context::eravm_evm_ether_gas::value(
+                context,
+            )?),
+            "gas" => Some(era_compiler_llvm_context::eravm_evm_ether_gas::gas(
+                context,
+            )?),
+            "balance" => {
+                let [address] = self.values::<1>(context, scope)?;
+                Some(era_compiler_llvm_context::eravm_evm_ether_gas::balance(
+                    context, address,
+                )?)
+            }
+            "selfbalance" => {
+                let address = context
+                    .build_call(context.intrinsics().address, &[], "self_balance_address")?
+                    .expect("Always exists")
+                    .into_int_value();
+                Some(era_compiler_llvm_context::eravm_evm_ether_gas::balance(
+                    context, address,
+                )?)
+            }
+            "gaslimit" => Some(
+                era_compiler_llvm_context::eravm_evm_contract_context::gas_limit(context)?,
+            ),
+            "gasprice" => Some(
+                era_compiler_llvm_context::eravm_evm_contract_context::gas_price(context)?,
+            ),
+            "origin" => Some(era_compiler_llvm_context::eravm_evm_contract_context::origin(
+                context,
+            )?),
+            "chainid" => Some(
+                era_compiler_llvm_context::eravm_evm_contract_context::chain_id(context)?,
+            ),
+            "number" => Some(
+                era_compiler_llvm_context::eravm_evm_contract_context::block_number(context)?,
+            ),
+            "timestamp" => Some(
+                era_compiler_llvm_context::eravm_evm_contract_context::block_timestamp(context)?,
+            ),
+            "blockhash" => {
+                let [index] = self.values::<1>(context, scope)?;
+                Some(
+                    era_compiler_llvm_context::eravm_evm_contract_context::block_hash(
+                        context, index,
+                    )?,
+                )
+            }
+            "prevrandao" | "difficulty" => Some(
+                era_compiler_llvm_context::eravm_evm_contract_context::difficulty(context)?,
+            ),
+            "coinbase" => Some(
+                era_compiler_llvm_context::eravm_evm_contract_context::coinbase(context)?,
+            ),
+            "basefee" => Some(
+                era_compiler_llvm_context::eravm_evm_contract_context::basefee(context)?,
+            ),
+            "msize" => Some(era_compiler_llvm_context::eravm_evm_contract_context::msize(
+                context,
+            )?),
+
+            "offset" => {
+                return Err(Error::unsupported(
+                    "Code offsets are not supported, as EraVM has no code addresses".to_owned(),
+                )
+                .into())
+            }
+            opcode @ ("callcode" | "selfdestruct" | "extcodecopy" | "pc") => {
+                return Err(
+                    Error::unsupported(format!("The `{opcode}` instruction is not supported"))
+                        .into(),
+                )
+            }
+            opcode => anyhow::bail!("Unknown Venom IR instruction `{opcode}`"),
+        };
+
+        match (self.output, value) {
+            (Some(output), Some(value)) => {
+                scope.variables.insert(output, value);
+            }
+            (Some(output), None) => {
+                anyhow::bail!("The instruction has no value to assign to `%{output}`")
+            }
+            (None, _) => {}
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the output variable name.
+    ///
+    fn output(&self) -> anyhow::Result<&str> {
+        self.output
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("The output variable is missing"))
+    }
+
+    ///
+    /// Translates the specified number of operands into LLVM values.
+    ///
+    fn values<'ctx, const N: usize>(
+        &self,
+        context: &era_compiler_llvm_context::EraVMContext<'ctx>,
+        scope: &Scope<'ctx>,
+    ) -> anyhow::Result<[inkwell::values::IntValue<'ctx>; N]> {
+        if self.operands.len() != N {
+            anyhow::bail!("Expected {N} operands, found {}", self.operands.len());
+        }
+
+        let mut values = Vec::with_capacity(N);
+        for operand in self.operands.iter() {
+            values.push(scope.value(context, operand)?);
+        }
+        Ok(values.try_into().expect("Always valid"))
+    }
+
+    ///
+    /// Returns the specified number of label operands.
+    ///
+    fn labels<const N: usize>(&self) -> anyhow::Result<[&str; N]> {
+        if self.operands.len() != N {
+            anyhow::bail!("Expected {N} operands, found {}", self.operands.len());
+        }
+
+        let labels = self
+            .operands
+            .iter()
+            .map(|operand| operand.try_as_label())
+            .collect::<anyhow::Result<Vec<&str>>>()?;
+        Ok(labels.try_into().expect("Always valid"))
+    }
+
+    ///
+    /// Returns the code segment being translated.
+    ///
+    fn code_segment(
+        context: &era_compiler_llvm_context::EraVMContext,
+    ) -> anyhow::Result<era_compiler_common::CodeSegment> {
+        context
+            .code_segment()
+            .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))
+    }
+
+    ///
+    /// Translates a comparison instruction.
+    ///
+    fn compare<'ctx>(
+        &self,
+        context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
+        scope: &Scope<'ctx>,
+        predicate: inkwell::IntPredicate,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        let arguments = self.values::<2>(context, scope)?;
+        era_compiler_llvm_context::eravm_evm_comparison::compare(
+            context,
+            arguments[0],
+            arguments[1],
+            predicate,
+        )
+        .map(Some)
+    }
+
+    ///
+    /// Translates the `phi` instruction.
+    ///
+    /// The operands are pairs of the incoming block label and value, e.g.
+    /// `%3 = phi @then, %1, @else, %2`.
+    ///
+    fn phi<'ctx>(
+        self,
+        context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
+        scope: &mut Scope<'ctx>,
+        output: String,
+    ) -> anyhow::Result<()> {
+        if self.operands.is_empty() || self.operands.len() % 2 != 0 {
+            anyhow::bail!(
+                "Expected pairs of labels and values, found {} operands",
+                self.operands.len()
+            );
+        }
+
+        let mut incoming = Vec::with_capacity(self.operands.len() / 2);
+        let mut operands = self.operands.into_iter();
+        while let (Some(label), Some(value)) = (operands.next(), operands.next()) {
+            incoming.push((label.try_as_label()?.to_owned(), value));
+        }
+
+        let phi = context
+            .builder()
+            .build_phi(context.field_type(), output.as_str())?;
+        scope.variables.insert(output, phi.as_basic_value());
+        scope.phis.push((phi, incoming));
+        Ok(())
+    }
+
+    ///
+    /// Translates the `invoke` instruction, which is an internal function call.
+    ///
+    /// The first operand is the function label, and the rest are the arguments. The return
+    /// address is passed implicitly.
+    ///
+    fn invoke<'ctx>(
+        &self,
+        context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
+        scope: &Scope<'ctx>,
+    ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
+        let label = match self.operands.first() {
+            Some(operand) => operand.try_as_label()?,
+            None => anyhow::bail!("The function label is missing"),
+        };
+        let name = Function::llvm_name(label, Self::code_segment(context)?);
+        let function = context
+            .get_function(name.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Function `@{label}` does not exist"))?;
+
+        let mut arguments = Vec::with_capacity(self.operands.len() - 1);
+        for operand in self.operands[1..].iter() {
+            arguments.push(scope.value(context, operand)?.as_basic_value_enum());
+        }
+        let parameters_count = function.borrow().declaration().value.count_params() as usize;
+        if arguments.len() != parameters_count {
+            anyhow::bail!(
+                "Function `@{label}` expects {parameters_count} arguments, found {}",
+                arguments.len()
+            );
+        }
+
+        let result = context.build_call(
+            function.borrow().declaration(),
+            arguments.as_slice(),
+            name.as_str(),
+        )?;
+        Ok(result)
+    }
+
+    ///
+    /// Translates the `ret` instruction, which is an internal function return.
+    ///
+    /// The operand holding the return address is skipped, and the remaining one, if any,
+    /// is the return value.
+    ///
+    fn r#return<'ctx>(
+        &self,
+        context: &mut era_compiler_llvm_context::EraVMContext<'ctx>,
+        scope: &Scope<'ctx>,
+    ) -> anyhow::Result<()> {
+        let return_address = scope
+            .return_address
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Returns are only allowed in internal functions"))?;
+
+        let values = self
+            .operands
+            .iter()
+            .filter(|operand| !matches!(operand, Operand::Variable(name) if name == return_address))
+            .collect::<Vec<&Operand>>();
+        match (values.as_slice(), scope.return_pointer) {
+            ([], _) => {}
+            ([value], Some(pointer)) => {
+                let value = scope.value(context, value)?;
+                context.build_store(pointer, value)?;
+            }
+            ([_], None) => anyhow::bail!("The function has no return value"),
+            (values, _) => {
+                anyhow::bail!("Expected at most 1 return value, found {}", values.len())
+            }
+        }
+
+        context.build_unconditional_branch(context.current_function().borrow().return_block())?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(ref output) = self.output {
+            write!(f, "%{output} = ")?;
+        }
+        if self.opcode != Self::OPCODE_STORE {
+            write!(f, "{}", self.opcode)?;
+            if !self.operands.is_empty() {
+                write!(f, " ")?;
+            }
+        }
+        write!(
+            f,
+            "{}",
+            self.operands
+                .iter()
+                .map(|operand| operand.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
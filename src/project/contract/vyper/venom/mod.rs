@@ -0,0 +1,78 @@
+//!
+//! The Venom IR.
+//!
+
+pub mod block;
+pub mod function;
+pub mod instruction;
+pub mod operand;
+pub mod parser;
+pub mod program;
+pub mod scope;
+
+use era_compiler_llvm_context::EraVMWriteLLVM;
+use era_compiler_llvm_context::IContext;
+
+use self::program::Program;
+
+///
+/// The Venom IR produced by the `vyper` experimental code generator.
+///
+/// Venom IR is an SSA IR with explicit basic blocks and phi nodes, so it is translated to LLVM
+/// directly, without the label and variable bookkeeping required by LLL IR.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Venom {
+    /// The deploy code.
+    pub deploy_code: Program,
+    /// The runtime code.
+    pub runtime_code: Program,
+}
+
+impl Venom {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(deploy_code: Program, runtime_code: Program) -> Self {
+        Self {
+            deploy_code,
+            runtime_code,
+        }
+    }
+}
+
+impl EraVMWriteLLVM for Venom {
+    fn into_llvm(
+        mut self,
+        context: &mut era_compiler_llvm_context::EraVMContext,
+    ) -> anyhow::Result<()> {
+        let mut functions = self
+            .deploy_code
+            .extract_functions()?
+            .into_iter()
+            .map(|function| (function, era_compiler_common::CodeSegment::Deploy))
+            .collect::<Vec<_>>();
+        functions.extend(
+            self.runtime_code
+                .extract_functions()?
+                .into_iter()
+                .map(|function| (function, era_compiler_common::CodeSegment::Runtime)),
+        );
+
+        for (function, code_segment) in functions.iter_mut() {
+            context.set_code_segment(*code_segment);
+            function.declare(context)?;
+        }
+        for (function, code_segment) in functions.into_iter() {
+            context.set_code_segment(code_segment);
+            function.into_llvm(context)?;
+        }
+
+        era_compiler_llvm_context::EraVMDeployCodeFunction::new(self.deploy_code)
+            .into_llvm(context)?;
+        era_compiler_llvm_context::EraVMRuntimeCodeFunction::new(self.runtime_code)
+            .into_llvm(context)?;
+
+        Ok(())
+    }
+}
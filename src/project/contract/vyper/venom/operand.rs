@@ -0,0 +1,137 @@
+//!
+//! The Venom IR instruction operand.
+//!
+
+use std::str::FromStr;
+
+use num::BigInt;
+use num::BigUint;
+use num::Num;
+use num::One;
+use num::Signed;
+
+///
+/// The Venom IR instruction operand.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Operand {
+    /// The SSA variable, stored without the `%` sigil.
+    Variable(String),
+    /// The basic block or function label, stored without the `@` sigil and quotes.
+    Label(String),
+    /// The integer literal, wrapped to 256 bits and stored as a decimal string.
+    Literal(String),
+}
+
+impl Operand {
+    ///
+    /// Returns the label name, if the operand is a label.
+    ///
+    pub fn try_as_label(&self) -> anyhow::Result<&str> {
+        match self {
+            Self::Label(label) => Ok(label.as_str()),
+            operand => anyhow::bail!("Expected a label, found `{operand}`"),
+        }
+    }
+
+    ///
+    /// Returns the variable name, if the operand is a variable.
+    ///
+    pub fn try_as_variable(&self) -> anyhow::Result<&str> {
+        match self {
+            Self::Variable(name) => Ok(name.as_str()),
+            operand => anyhow::bail!("Expected a variable, found `{operand}`"),
+        }
+    }
+
+    ///
+    /// Quotes the label or function name, unless it is a plain identifier.
+    ///
+    pub fn quote(name: &str) -> String {
+        if !name.is_empty()
+            && name
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == '_')
+        {
+            name.to_owned()
+        } else {
+            serde_json::to_string(name).expect("Always valid")
+        }
+    }
+
+    ///
+    /// Parses the integer literal, wrapping negative values to 256 bits.
+    ///
+    fn parse_literal(string: &str) -> Option<BigUint> {
+        let (is_negative, digits) = match string.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, string),
+        };
+
+        let value = match digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"))
+        {
+            Some(hexadecimal) => {
+                BigInt::from_str_radix(hexadecimal, era_compiler_common::BASE_HEXADECIMAL).ok()?
+            }
+            None if !digits.is_empty() && digits.chars().all(|digit| digit.is_ascii_digit()) => {
+                BigInt::from_str(digits).ok()?
+            }
+            None => return None,
+        };
+        let value = if is_negative { -value } else { value };
+
+        let modulus = BigInt::one() << era_compiler_common::BIT_LENGTH_FIELD;
+        if value.abs() >= modulus {
+            return None;
+        }
+        let value = if value.is_negative() {
+            value + modulus
+        } else {
+            value
+        };
+        value.to_biguint()
+    }
+}
+
+impl FromStr for Operand {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> anyhow::Result<Self> {
+        if let Some(name) = string.strip_prefix('%') {
+            if name.is_empty() {
+                anyhow::bail!("Empty variable name");
+            }
+            return Ok(Self::Variable(name.to_owned()));
+        }
+
+        if let Some(label) = string.strip_prefix('@') {
+            let label = if label.starts_with('"') {
+                serde_json::from_str::<String>(label)
+                    .map_err(|error| anyhow::anyhow!("Invalid quoted label `{label}`: {error}"))?
+            } else {
+                label.to_owned()
+            };
+            if label.is_empty() {
+                anyhow::bail!("Empty label name");
+            }
+            return Ok(Self::Label(label));
+        }
+
+        match Self::parse_literal(string) {
+            Some(value) => Ok(Self::Literal(value.to_string())),
+            None => anyhow::bail!("Invalid operand `{string}`"),
+        }
+    }
+}
+
+impl std::fmt::Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Variable(name) => write!(f, "%{name}"),
+            Self::Label(label) => write!(f, "@{}", Self::quote(label)),
+            Self::Literal(value) => write!(f, "{value}"),
+        }
+    }
+}
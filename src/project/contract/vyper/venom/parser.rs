@@ -0,0 +1,224 @@
+//!
+//! The Venom IR text parser.
+//!
+
+use crate::project::contract::vyper::venom::block::Block;
+use crate::project::contract::vyper::venom::function::Function;
+use crate::project::contract::vyper::venom::instruction::Instruction;
+use crate::project::contract::vyper::venom::operand::Operand;
+use crate::project::contract::vyper::venom::program::Program;
+
+///
+/// Parses the Venom IR text printed by `vyper -f bb` or `vyper -f bb_runtime`.
+///
+/// Both the `function <name> {` layout of `vyper` v0.4.1 and later, and the `IRFunction: <name>`
+/// layout of `vyper` v0.4.0 are accepted. Anything after the colon of a block label, such as the
+/// control flow annotations, and `;` comments are skipped.
+///
+/// Data sections are skipped, as they only contain jump tables and the runtime code reference,
+/// which are not used on EraVM.
+///
+pub fn parse(input: &str) -> anyhow::Result<Program> {
+    let mut program = Program::default();
+    let mut data_section = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let line_number = index + 1;
+        let line = match find_unquoted(line, ';') {
+            Some(position) => &line[..position],
+            None => line,
+        }
+        .trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        match data_section {
+            Some(DataSection::Braced) => {
+                if line == "}" {
+                    data_section = None;
+                }
+                continue;
+            }
+            Some(DataSection::Trailing)
+                if !line.starts_with("function ") && !line.starts_with("IRFunction:") =>
+            {
+                continue;
+            }
+            Some(DataSection::Trailing) => data_section = None,
+            None => {}
+        }
+
+        if line == "IRContext:" || line == "}" {
+            continue;
+        }
+        if line == "[data]" || line == "Data segment:" {
+            data_section = Some(DataSection::Trailing);
+            continue;
+        }
+        if line.starts_with("data ") && line.ends_with('{') {
+            data_section = Some(DataSection::Braced);
+            continue;
+        }
+
+        let function_name = line
+            .strip_prefix("function ")
+            .and_then(|name| name.strip_suffix('{'))
+            .or_else(|| line.strip_prefix("IRFunction:"));
+        if let Some(name) = function_name {
+            let name =
+                unquote(name.trim()).map_err(|error| anyhow::anyhow!("{line_number}: {error}"))?;
+            program.functions.push(Function::new(name));
+            continue;
+        }
+
+        let function = program.functions.last_mut().ok_or_else(|| {
+            anyhow::anyhow!("{line_number}: Expected a function declaration, found `{line}`")
+        })?;
+
+        if let Some(label) = block_label(line) {
+            let label =
+                unquote(label).map_err(|error| anyhow::anyhow!("{line_number}: {error}"))?;
+            function.blocks.push(Block::new(label));
+            continue;
+        }
+
+        let block = function.blocks.last_mut().ok_or_else(|| {
+            anyhow::anyhow!("{line_number}: Expected a block label, found `{line}`")
+        })?;
+        let instruction =
+            parse_instruction(line).map_err(|error| anyhow::anyhow!("{line_number}: {error}"))?;
+        block.instructions.push(instruction);
+    }
+
+    if program.functions.is_empty() {
+        anyhow::bail!("The Venom IR input has no functions");
+    }
+    Ok(program)
+}
+
+///
+/// The data section kind.
+///
+#[derive(Debug, Clone, Copy)]
+enum DataSection {
+    /// The `data readonly { ... }` section.
+    Braced,
+    /// The `[data]` or `Data segment:` section, which lasts until the next function.
+    Trailing,
+}
+
+///
+/// Parses the instruction, e.g. `%3 = add %1, %2`, `mstore 64, %3`, or `%4 = %3`.
+///
+fn parse_instruction(line: &str) -> anyhow::Result<Instruction> {
+    let (output, expression) = match find_unquoted(line, '=') {
+        Some(position) => {
+            let output = line[..position].trim();
+            let output = match output.parse::<Operand>() {
+                Ok(Operand::Variable(name)) => name,
+                _ => anyhow::bail!("Expected an output variable, found `{output}`"),
+            };
+            (Some(output), line[position + 1..].trim())
+        }
+        None => (None, line),
+    };
+
+    let is_store = expression.starts_with(['%', '@', '-'])
+        || expression.starts_with(|character: char| character.is_ascii_digit());
+    let (opcode, operands) = if is_store {
+        (Instruction::OPCODE_STORE, expression)
+    } else {
+        match expression.split_once(char::is_whitespace) {
+            Some((opcode, operands)) => (opcode, operands.trim()),
+            None => (expression, ""),
+        }
+    };
+    if opcode.is_empty() {
+        anyhow::bail!("Expected an opcode, found `{line}`");
+    }
+
+    let operands = if operands.is_empty() {
+        vec![]
+    } else {
+        split_unquoted(operands, ',')
+            .into_iter()
+            .map(|operand| operand.trim().parse::<Operand>())
+            .collect::<anyhow::Result<Vec<Operand>>>()?
+    };
+
+    Ok(Instruction::new(output, opcode.to_owned(), operands))
+}
+
+///
+/// Returns the block label, if the line is a block header, e.g. `label:` or `"label":`.
+///
+fn block_label(line: &str) -> Option<&str> {
+    let position = find_unquoted(line, ':')?;
+    let label = line[..position].trim();
+    if label.is_empty() || label.contains(['%', '@', '=']) {
+        return None;
+    }
+    Some(label)
+}
+
+///
+/// Removes the quotes from a function name or label, if it is quoted.
+///
+fn unquote(name: &str) -> anyhow::Result<String> {
+    if name.starts_with('"') {
+        serde_json::from_str::<String>(name)
+            .map_err(|error| anyhow::anyhow!("Invalid quoted name `{name}`: {error}"))
+    } else if name.is_empty() {
+        anyhow::bail!("Empty name")
+    } else {
+        Ok(name.to_owned())
+    }
+}
+
+///
+/// Finds the first `delimiter` outside of quotes and brackets.
+///
+/// Brackets are tracked, as `vyper` v0.4.0 prints labels without quotes, which may contain
+/// function signatures, e.g. `@internal 0 transfer(address,uint256)`.
+///
+fn find_unquoted(line: &str, delimiter: char) -> Option<usize> {
+    let mut is_quoted = false;
+    let mut is_escaped = false;
+    let mut depth = 0usize;
+
+    for (position, character) in line.char_indices() {
+        if is_quoted {
+            match character {
+                _ if is_escaped => is_escaped = false,
+                '\\' => is_escaped = true,
+                '"' => is_quoted = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match character {
+            '"' => is_quoted = true,
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            character if character == delimiter && depth == 0 => return Some(position),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+///
+/// Splits the line by `delimiter` outside of quotes and brackets.
+///
+fn split_unquoted(mut line: &str, delimiter: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    while let Some(position) = find_unquoted(line, delimiter) {
+        parts.push(&line[..position]);
+        line = &line[position + delimiter.len_utf8()..];
+    }
+    parts.push(line);
+    parts
+}
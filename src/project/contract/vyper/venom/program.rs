@@ -0,0 +1,80 @@
+//!
+//! The Venom IR program.
+//!
+
+use std::str::FromStr;
+
+use era_compiler_llvm_context::EraVMWriteLLVM;
+
+use crate::project::contract::vyper::venom::function::Function;
+use crate::project::contract::vyper::venom::operand::Operand;
+use crate::project::contract::vyper::venom::scope::Scope;
+
+///
+/// The Venom IR program, which is either the deploy or runtime code.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Program {
+    /// The functions, the first of which is the code entry.
+    pub functions: Vec<Function>,
+}
+
+impl Program {
+    ///
+    /// Extracts the internal functions, leaving only the code entry.
+    ///
+    pub fn extract_functions(&mut self) -> anyhow::Result<Vec<Function>> {
+        if self.functions.is_empty() {
+            anyhow::bail!("The Venom IR program has no functions");
+        }
+
+        Ok(self.functions.split_off(1))
+    }
+}
+
+impl FromStr for Program {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> anyhow::Result<Self> {
+        crate::project::contract::vyper::venom::parser::parse(string)
+            .map_err(|error| anyhow::anyhow!("Venom IR parsing error: {error}"))
+    }
+}
+
+impl EraVMWriteLLVM for Program {
+    fn into_llvm(
+        mut self,
+        context: &mut era_compiler_llvm_context::EraVMContext,
+    ) -> anyhow::Result<()> {
+        let entry = self
+            .functions
+            .drain(..)
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("The Venom IR program has no functions"))?;
+
+        let mut scope = Scope::default();
+        entry.into_llvm_body(context, &mut scope)?;
+
+        // The enclosing deploy or runtime code function terminates the current block itself,
+        // so the builder is left at an empty block without predecessors.
+        let join_block = context.append_basic_block("venom_join");
+        context.set_basic_block(join_block);
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Program {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for function in self.functions.iter() {
+            writeln!(f, "function {} {{", Operand::quote(function.name.as_str()))?;
+            for block in function.blocks.iter() {
+                writeln!(f, "  {}:", Operand::quote(block.label.as_str()))?;
+                for instruction in block.instructions.iter() {
+                    writeln!(f, "    {instruction}")?;
+                }
+            }
+            writeln!(f, "}}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+//!
+//! The Venom IR function translation scope.
+//!
+
+use std::collections::BTreeMap;
+
+use era_compiler_llvm_context::IContext;
+use inkwell::values::BasicValue;
+
+use crate::project::contract::vyper::venom::operand::Operand;
+
+///
+/// The Venom IR function translation scope.
+///
+/// Since Venom IR is in SSA form, variables are mapped directly to LLVM values, and phi nodes
+/// are mapped to LLVM phi nodes. The incoming values of phi nodes are added after all blocks
+/// are translated, as they may be defined after the phi node itself.
+///
+#[derive(Debug, Default)]
+pub struct Scope<'ctx> {
+    /// The LLVM values of SSA variables.
+    pub variables: BTreeMap<String, inkwell::values::BasicValueEnum<'ctx>>,
+    /// The LLVM blocks the basic blocks start with.
+    pub blocks: BTreeMap<String, inkwell::basic_block::BasicBlock<'ctx>>,
+    /// The LLVM blocks the basic blocks end with, which are the incoming blocks of phi nodes.
+    pub exits: BTreeMap<String, inkwell::basic_block::BasicBlock<'ctx>>,
+    /// The phi nodes with their incoming labels and values.
+    pub phis: Vec<(inkwell::values::PhiValue<'ctx>, Vec<(String, Operand)>)>,
+    /// The variable holding the return address, if the scope is an internal function.
+    pub return_address: Option<String>,
+    /// The return value pointer, if the scope is an internal function with a return value.
+    pub return_pointer: Option<era_compiler_llvm_context::Pointer<'ctx>>,
+}
+
+impl<'ctx> Scope<'ctx> {
+    ///
+    /// Returns the LLVM value of the operand.
+    ///
+    pub fn value(
+        &self,
+        context: &era_compiler_llvm_context::EraVMContext<'ctx>,
+        operand: &Operand,
+    ) -> anyhow::Result<inkwell::values::IntValue<'ctx>> {
+        match operand {
+            Operand::Variable(name) => self
+                .variables
+                .get(name.as_str())
+                .map(|value| value.into_int_value())
+                .ok_or_else(|| anyhow::anyhow!("Variable `%{name}` is used before definition")),
+            Operand::Literal(value) => Ok(context.field_const_str_dec(value.as_str())),
+            Operand::Label(label) => {
+                anyhow::bail!("Label `@{label}` cannot be used as a value")
+            }
+        }
+    }
+
+    ///
+    /// Returns the LLVM block the basic block starts with.
+    ///
+    pub fn block(&self, label: &str) -> anyhow::Result<inkwell::basic_block::BasicBlock<'ctx>> {
+        self.blocks
+            .get(label)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Block `@{label}` does not exist"))
+    }
+
+    ///
+    /// Adds the incoming values to the phi nodes.
+    ///
+    /// Incoming values from blocks that have not been translated are skipped, as such blocks
+    /// are unreachable and do not branch to the phi node block.
+    ///
+    pub fn resolve_phis(
+        &mut self,
+        context: &era_compiler_llvm_context::EraVMContext<'ctx>,
+    ) -> anyhow::Result<()> {
+        for (phi, incoming) in std::mem::take(&mut self.phis).into_iter() {
+            for (label, operand) in incoming.into_iter() {
+                let block = match self.exits.get(label.as_str()) {
+                    Some(block) => *block,
+                    None => continue,
+                };
+                let value = self.value(context, &operand)?;
+                phi.add_incoming(&[(&value.as_basic_value_enum(), block)]);
+            }
+        }
+        Ok(())
+    }
+}
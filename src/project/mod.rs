@@ -8,17 +8,28 @@ use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::path::Path;
 
-use rayon::iter::IntoParallelRefIterator;
+use rayon::iter::IntoParallelIterator;
+use rayon::iter::ParallelBridge;
 use rayon::iter::ParallelIterator;
 
 use crate::build::contract::Contract as ContractBuild;
 use crate::build::Build;
+use crate::build_evm::contract::Contract as EVMContractBuild;
+use crate::build_evm::Build as EVMBuild;
+use crate::error::Error;
+use crate::optimization_override::OptimizationOverride;
 use crate::process::input::Input as ProcessInput;
 use crate::process::output::Output as ProcessOutput;
+use crate::process::output_evm::Output as EVMProcessOutput;
+use crate::process::strategy::Strategy as ProcessStrategy;
 use crate::project::contract::vyper::ast::AST as VyperAST;
+use crate::project::contract::vyper::expression::Expression as VyperExpression;
 use crate::project::contract::vyper::Contract as VyperContract;
 use crate::project::contract::Contract as ProjectContract;
+use crate::tuning::objective::Objective as TuningObjective;
+use crate::tuning::Tuning;
 use crate::vyper::selector::Selector as VyperSelector;
+use crate::vyper::settings::Settings as VyperSettings;
 use crate::vyper::standard_json::output::Output as VyperStandardJsonOutput;
 use crate::warning_type::WarningType;
 
@@ -27,6 +38,12 @@ use self::contract::llvm_ir::Contract as LLVMIRContract;
 use self::contract::metadata::Metadata as ContractMetadata;
 use self::contract::Contract;
 
+///
+/// The optimizer settings and LLVM options of each contract.
+///
+type ContractSettings =
+    BTreeMap<String, (era_compiler_llvm_context::OptimizerSettings, Vec<String>)>;
+
 ///
 /// The Vyper project.
 ///
@@ -40,6 +57,8 @@ pub struct Project {
     pub output_selection: Vec<VyperSelector>,
     /// The project source code hash.
     pub project_hash: era_compiler_common::Keccak256Hash,
+    /// The `vyper` settings the sources have been compiled with, if `vyper` has been used.
+    pub vyper_settings: Option<VyperSettings>,
 }
 
 impl Project {
@@ -62,6 +81,7 @@ impl Project {
             contracts,
             output_selection,
             project_hash,
+            vyper_settings: None,
         }
     }
 
@@ -115,6 +135,50 @@ impl Project {
         Ok(Self::new(version.to_owned(), project_contracts, vec![]))
     }
 
+    ///
+    /// Reads LLL IR source code files and returns the project.
+    ///
+    /// The files may contain either `vyper -f ir_json` JSON or `vyper -f ir` s-expressions.
+    /// As there is no `vyper` invocation, the latest supported `vyper` version is assumed.
+    ///
+    pub fn try_from_lll_paths(
+        paths: &[&Path],
+        output_selection: &[VyperSelector],
+    ) -> anyhow::Result<Self> {
+        let version = crate::vyper::Compiler::SUPPORTED_VERSIONS
+            .last()
+            .expect("Always exists")
+            .to_owned();
+
+        let contracts = paths
+            .iter()
+            .map(|path| {
+                let source_code = std::fs::read_to_string(path)
+                    .map_err(|error| Error::io(path, "reading", error))?;
+                let path = path.to_string_lossy().to_string();
+
+                let ir = VyperExpression::try_from_lll(source_code.as_str())
+                    .map_err(|error| anyhow::anyhow!("LLL IR file `{path}`: {error}"))?;
+                let contract = VyperContract::new(
+                    version.clone(),
+                    source_code,
+                    ir,
+                    VyperAST::new(path.clone(), serde_json::Value::Null),
+                    serde_json::Value::Null,
+                    BTreeMap::new(),
+                    None,
+                    None,
+                    None,
+                )
+                .into();
+
+                Ok((path, contract))
+            })
+            .collect::<anyhow::Result<BTreeMap<String, Contract>>>()?;
+
+        Ok(Self::new(version, contracts, output_selection.to_owned()))
+    }
+
     ///
     /// Reads LLVM IR source code files and returns the project.
     ///
@@ -125,9 +189,8 @@ impl Project {
         let contracts = paths
             .iter()
             .map(|path| {
-                let source_code = std::fs::read_to_string(path).map_err(|error| {
-                    anyhow::anyhow!("LLVM IR file {path:?} reading error: {error}")
-                })?;
+                let source_code = std::fs::read_to_string(path)
+                    .map_err(|error| Error::io(path, "reading", error))?;
                 let path = path.to_string_lossy().to_string();
 
                 let contract =
@@ -155,9 +218,8 @@ impl Project {
         let contracts = paths
             .iter()
             .map(|path| {
-                let source_code = std::fs::read_to_string(path).map_err(|error| {
-                    anyhow::anyhow!("EraVM assembly file {path:?} reading error: {error}")
-                })?;
+                let source_code = std::fs::read_to_string(path)
+                    .map_err(|error| Error::io(path, "reading", error))?;
                 let path = path.to_string_lossy().to_string();
 
                 let contract = EraVMAssemblyContract::new(source_code).into();
@@ -176,6 +238,8 @@ impl Project {
     ///
     /// Compiles all contracts, returning the build.
     ///
+    /// The contracts are compiled in parallel, each one with the specified execution strategy.
+    ///
     pub fn compile(
         self,
         evm_version: Option<era_compiler_common::EVMVersion>,
@@ -183,45 +247,73 @@ impl Project {
         append_bytecode_metadata: bool,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
+        optimization_overrides: Vec<OptimizationOverride>,
+        tuning: Option<Tuning>,
         suppressed_warnings: Vec<WarningType>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+        strategy: ProcessStrategy,
     ) -> anyhow::Result<Build> {
-        let metadata = ContractMetadata::new(
-            self.project_hash.as_bytes(),
-            &self.version,
+        let mut contract_settings = self.contract_settings(
+            &optimizer_settings,
+            llvm_options.as_slice(),
+            optimization_overrides.as_slice(),
+        )?;
+        let (mut metadata_json, metadata_hashes) = self.metadata(
             evm_version,
-            semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid"),
-            optimizer_settings.clone(),
+            metadata_hash_type,
+            &optimizer_settings,
             llvm_options.as_slice(),
+            optimization_overrides.as_slice(),
+            tuning.as_ref(),
+            suppressed_warnings.as_slice(),
+            &contract_settings,
         );
-        let metadata_json = serde_json::to_value(&metadata).expect("Always valid");
 
-        let metadata_bytes = metadata_json.to_string().into_bytes();
-        let metadata_hash = match metadata_hash_type {
-            era_compiler_common::MetadataHashType::None => None,
-            era_compiler_common::MetadataHashType::Keccak256 => Some(
-                era_compiler_common::Keccak256Hash::from_slice(metadata_bytes.as_slice()).into(),
-            ),
-            era_compiler_common::MetadataHashType::IPFS => {
-                Some(era_compiler_common::IPFSHash::from_slice(metadata_bytes.as_slice()).into())
-            }
-        };
-
-        let mut build = Build::new(metadata_json);
-        let results: BTreeMap<String, anyhow::Result<ContractBuild>> = self
-            .contracts
-            .par_iter()
+        let results: BTreeMap<
+            String,
+            anyhow::Result<(era_compiler_llvm_context::OptimizerSettings, ContractBuild)>,
+        > = self
+            .contracts_by_size()
+            .into_iter()
+            .par_bridge()
             .map(|(full_path, contract)| {
-                let process_output: anyhow::Result<ProcessOutput> = crate::process::call(
+                let (optimizer_settings, llvm_options) = contract_settings
+                    .get(full_path)
+                    .cloned()
+                    .expect("Always exists");
+
+                if let Some(tuning) = tuning.as_ref() {
+                    let result = self.tune(
+                        full_path,
+                        contract,
+                        tuning,
+                        evm_version,
+                        metadata_hash_type,
+                        append_bytecode_metadata,
+                        &optimizer_settings,
+                        llvm_options.as_slice(),
+                        suppressed_warnings.as_slice(),
+                        debug_config.as_ref(),
+                        strategy,
+                    );
+                    return (full_path.to_owned(), result);
+                }
+
+                let metadata_hash = metadata_hashes
+                    .get(full_path)
+                    .cloned()
+                    .expect("Always exists");
+                let process_output: anyhow::Result<ProcessOutput> = strategy.call(
                     full_path.as_str(),
                     ProcessInput::new(
                         Cow::Borrowed(full_path),
                         Cow::Borrowed(contract),
-                        metadata_hash.clone(),
+                        era_compiler_common::Target::EraVM,
+                        metadata_hash,
                         append_bytecode_metadata,
                         self.output_selection.clone(),
                         optimizer_settings.clone(),
-                        llvm_options.clone(),
+                        llvm_options,
                         suppressed_warnings.clone(),
                         debug_config.clone(),
                     ),
@@ -229,15 +321,39 @@ impl Project {
 
                 (
                     full_path.to_owned(),
-                    process_output.map(|output| output.build),
+                    process_output.map(|output| (optimizer_settings, output.build)),
                 )
             })
             .collect();
 
+        if tuning.is_some() {
+            for (path, result) in results.iter() {
+                if let Ok((optimizer_settings, _build)) = result {
+                    if let Some(settings) = contract_settings.get_mut(path) {
+                        settings.0 = optimizer_settings.to_owned();
+                    }
+                }
+            }
+            metadata_json = self
+                .metadata(
+                    evm_version,
+                    metadata_hash_type,
+                    &optimizer_settings,
+                    llvm_options.as_slice(),
+                    optimization_overrides.as_slice(),
+                    tuning.as_ref(),
+                    suppressed_warnings.as_slice(),
+                    &contract_settings,
+                )
+                .0;
+        }
+
+        let mut build = Build::new(metadata_json);
+
         let is_minimal_proxy_used = results.iter().any(|(_path, result)| {
             result
                 .as_ref()
-                .map(|contract| {
+                .map(|(_optimizer_settings, contract)| {
                     contract.build.factory_dependencies.contains_key(
                         hex::encode(
                             crate::r#const::MINIMAL_PROXY_BUILD
@@ -260,29 +376,348 @@ impl Project {
             );
         }
 
-        let mut errors = Vec::with_capacity(results.len());
+        let mut errors = BTreeMap::new();
+        for (path, result) in results.into_iter() {
+            match result {
+                Ok((_optimizer_settings, contract)) => {
+                    build.contracts.insert(path, contract);
+                }
+                Err(error) => {
+                    errors.insert(path, Error::from_anyhow(error));
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::Contracts(errors).into());
+        }
+
+        Ok(build)
+    }
+
+    ///
+    /// Compiles all contracts to EVM, returning the build.
+    ///
+    pub fn compile_to_evm(
+        self,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+        llvm_options: Vec<String>,
+        optimization_overrides: Vec<OptimizationOverride>,
+        suppressed_warnings: Vec<WarningType>,
+        debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+        strategy: ProcessStrategy,
+    ) -> anyhow::Result<EVMBuild> {
+        let contract_settings = self.contract_settings(
+            &optimizer_settings,
+            llvm_options.as_slice(),
+            optimization_overrides.as_slice(),
+        )?;
+        let (metadata_json, metadata_hashes) = self.metadata(
+            evm_version,
+            metadata_hash_type,
+            &optimizer_settings,
+            llvm_options.as_slice(),
+            optimization_overrides.as_slice(),
+            None,
+            suppressed_warnings.as_slice(),
+            &contract_settings,
+        );
+
+        let mut build = EVMBuild::new(metadata_json);
+        let results: BTreeMap<String, anyhow::Result<EVMContractBuild>> = self
+            .contracts_by_size()
+            .into_iter()
+            .par_bridge()
+            .map(|(full_path, contract)| {
+                let (optimizer_settings, llvm_options) = contract_settings
+                    .get(full_path)
+                    .cloned()
+                    .expect("Always exists");
+                let metadata_hash = metadata_hashes
+                    .get(full_path)
+                    .cloned()
+                    .expect("Always exists");
+                let process_output: anyhow::Result<EVMProcessOutput> = strategy.call(
+                    full_path.as_str(),
+                    ProcessInput::new(
+                        Cow::Borrowed(full_path),
+                        Cow::Borrowed(contract),
+                        era_compiler_common::Target::EVM,
+                        metadata_hash,
+                        false,
+                        self.output_selection.clone(),
+                        optimizer_settings,
+                        llvm_options,
+                        suppressed_warnings.clone(),
+                        debug_config.clone(),
+                    ),
+                );
+
+                (
+                    full_path.to_owned(),
+                    process_output.map(|output| output.build),
+                )
+            })
+            .collect();
+
+        let mut errors = BTreeMap::new();
         for (path, result) in results.into_iter() {
             match result {
                 Ok(contract) => {
                     build.contracts.insert(path, contract);
                 }
                 Err(error) => {
-                    errors.push((path, error));
+                    errors.insert(path, Error::from_anyhow(error));
                 }
             }
         }
 
         if !errors.is_empty() {
-            anyhow::bail!(
-                "{}",
-                errors
-                    .into_iter()
-                    .map(|(path, error)| format!("Contract `{path}`: {error}"))
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            );
+            return Err(Error::Contracts(errors).into());
         }
 
         Ok(build)
     }
+
+    ///
+    /// Returns the contracts ordered by source code size, largest first.
+    ///
+    /// The largest contracts take the longest to compile, so starting them first shortens the
+    /// tail of the parallel compilation.
+    ///
+    fn contracts_by_size(&self) -> Vec<(&String, &Contract)> {
+        let mut contracts: Vec<(&String, &Contract)> = self.contracts.iter().collect();
+        contracts.sort_by_key(|(_path, contract)| std::cmp::Reverse(contract.source_code().len()));
+        contracts
+    }
+
+    ///
+    /// Returns the optimizer settings and LLVM options of each contract with overrides applied.
+    ///
+    fn contract_settings(
+        &self,
+        optimizer_settings: &era_compiler_llvm_context::OptimizerSettings,
+        llvm_options: &[String],
+        optimization_overrides: &[OptimizationOverride],
+    ) -> anyhow::Result<ContractSettings> {
+        self.contracts
+            .keys()
+            .map(|path| {
+                let settings = OptimizationOverride::resolve(
+                    optimization_overrides,
+                    path.as_str(),
+                    optimizer_settings,
+                    llvm_options,
+                )
+                .map_err(|error| Error::contract(path.as_str(), error))?;
+                Ok((path.to_owned(), settings))
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the project metadata and the metadata hash of the requested type for each contract.
+    ///
+    /// Contracts compiled with overridden or auto-tuned settings get their own metadata recording
+    /// the settings actually used, which is also listed in the project metadata. Other contracts
+    /// are identified by the project metadata hash.
+    ///
+    fn metadata(
+        &self,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        optimizer_settings: &era_compiler_llvm_context::OptimizerSettings,
+        llvm_options: &[String],
+        optimization_overrides: &[OptimizationOverride],
+        tuning: Option<&Tuning>,
+        suppressed_warnings: &[WarningType],
+        contract_settings: &ContractSettings,
+    ) -> (
+        serde_json::Value,
+        BTreeMap<String, Option<era_compiler_common::Hash>>,
+    ) {
+        let mut contracts = BTreeMap::new();
+        for (path, (contract_optimizer_settings, contract_llvm_options)) in contract_settings.iter()
+        {
+            if tuning.is_none()
+                && contract_optimizer_settings.to_string() == optimizer_settings.to_string()
+                && contract_llvm_options.as_slice() == llvm_options
+            {
+                continue;
+            }
+
+            contracts.insert(
+                path.to_owned(),
+                self.contract_metadata(
+                    evm_version,
+                    metadata_hash_type,
+                    contract_optimizer_settings,
+                    contract_llvm_options.as_slice(),
+                    suppressed_warnings,
+                ),
+            );
+        }
+
+        let metadata = ContractMetadata::new(
+            self.project_hash.as_bytes(),
+            &self.version,
+            evm_version,
+            semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid"),
+            optimizer_settings.to_owned(),
+            llvm_options,
+            self.vyper_settings.as_ref(),
+            metadata_hash_type,
+            suppressed_warnings,
+            optimization_overrides,
+            tuning,
+            &contracts,
+        );
+        let metadata_json = serde_json::to_value(&metadata).expect("Always valid");
+        let metadata_hash = Self::metadata_hash(metadata_hash_type, &metadata_json);
+
+        let metadata_hashes = contract_settings
+            .keys()
+            .map(|path| {
+                let hash = match contracts.get(path) {
+                    Some(contract_metadata_json) => {
+                        Self::metadata_hash(metadata_hash_type, contract_metadata_json)
+                    }
+                    None => metadata_hash.clone(),
+                };
+                (path.to_owned(), hash)
+            })
+            .collect();
+
+        (metadata_json, metadata_hashes)
+    }
+
+    ///
+    /// Returns the metadata of a contract compiled with its own settings.
+    ///
+    fn contract_metadata(
+        &self,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        optimizer_settings: &era_compiler_llvm_context::OptimizerSettings,
+        llvm_options: &[String],
+        suppressed_warnings: &[WarningType],
+    ) -> serde_json::Value {
+        let metadata = ContractMetadata::new(
+            self.project_hash.as_bytes(),
+            &self.version,
+            evm_version,
+            semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid"),
+            optimizer_settings.to_owned(),
+            llvm_options,
+            self.vyper_settings.as_ref(),
+            metadata_hash_type,
+            suppressed_warnings,
+            &[],
+            None,
+            &BTreeMap::new(),
+        );
+        serde_json::to_value(&metadata).expect("Always valid")
+    }
+
+    ///
+    /// Compiles the contract with each auto-tuning candidate in parallel and selects the best build.
+    ///
+    /// Each candidate is compiled with the hash of its own contract metadata, so the selected build
+    /// already carries the metadata of the settings it was compiled with.
+    ///
+    fn tune(
+        &self,
+        full_path: &String,
+        contract: &Contract,
+        tuning: &Tuning,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        append_bytecode_metadata: bool,
+        optimizer_settings: &era_compiler_llvm_context::OptimizerSettings,
+        llvm_options: &[String],
+        suppressed_warnings: &[WarningType],
+        debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
+        strategy: ProcessStrategy,
+    ) -> anyhow::Result<(era_compiler_llvm_context::OptimizerSettings, ContractBuild)> {
+        let mut output_selection = self.output_selection.clone();
+        if tuning.objective == TuningObjective::Cycles
+            && !output_selection.contains(&VyperSelector::ErgsEstimates)
+        {
+            output_selection.push(VyperSelector::ErgsEstimates);
+        }
+
+        let results = tuning
+            .candidates(optimizer_settings)
+            .into_par_iter()
+            .map(|optimizer_settings| {
+                let metadata_hash = Self::metadata_hash(
+                    metadata_hash_type,
+                    &self.contract_metadata(
+                        evm_version,
+                        metadata_hash_type,
+                        &optimizer_settings,
+                        llvm_options,
+                        suppressed_warnings,
+                    ),
+                );
+                let process_output: anyhow::Result<ProcessOutput> = strategy.call(
+                    full_path.as_str(),
+                    ProcessInput::new(
+                        Cow::Borrowed(full_path),
+                        Cow::Borrowed(contract),
+                        era_compiler_common::Target::EraVM,
+                        metadata_hash,
+                        append_bytecode_metadata,
+                        output_selection.clone(),
+                        optimizer_settings.clone(),
+                        llvm_options.to_owned(),
+                        suppressed_warnings.to_owned(),
+                        debug_config.cloned(),
+                    ),
+                );
+                (
+                    optimizer_settings,
+                    process_output.map(|output| output.build),
+                )
+            })
+            .collect();
+
+        let (optimizer_settings, mut build) = tuning.select(results)?;
+        if !self
+            .output_selection
+            .contains(&VyperSelector::ErgsEstimates)
+        {
+            build.ergs_estimates = None;
+            if !self.output_selection.iter().any(|selector| {
+                matches!(
+                    selector,
+                    VyperSelector::EraVMAssembly | VyperSelector::CombinedJson
+                )
+            }) {
+                build.build.assembly = None;
+            }
+        }
+        Ok((optimizer_settings, build))
+    }
+
+    ///
+    /// Returns the metadata hash of the requested type.
+    ///
+    fn metadata_hash(
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        metadata_json: &serde_json::Value,
+    ) -> Option<era_compiler_common::Hash> {
+        let metadata_bytes = metadata_json.to_string().into_bytes();
+        match metadata_hash_type {
+            era_compiler_common::MetadataHashType::None => None,
+            era_compiler_common::MetadataHashType::Keccak256 => Some(
+                era_compiler_common::Keccak256Hash::from_slice(metadata_bytes.as_slice()).into(),
+            ),
+            era_compiler_common::MetadataHashType::IPFS => {
+                Some(era_compiler_common::IPFSHash::from_slice(metadata_bytes.as_slice()).into())
+            }
+        }
+    }
 }
@@ -0,0 +1,120 @@
+//!
+//! The crash reproducer bundle.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::process::encoding::Encoding;
+use crate::process::handshake::Handshake;
+
+///
+/// The crash reproducer bundle.
+///
+/// Records the exact input of a failed contract compilation together with the compiler build,
+/// so that the compilation can be re-run with `--replay` without `vyper` or the project sources.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Reproducer {
+    /// The version of the compiler that has failed.
+    pub compiler_version: String,
+    /// The LLVM build of the compiler that has failed.
+    pub llvm_commit: String,
+    /// The version of the recursive process input schema.
+    pub protocol_version: u32,
+    /// The contract path.
+    pub contract_path: String,
+    /// The error reported by the failed compilation.
+    pub error: String,
+    /// The recursive process input with the contract, settings and LLVM options.
+    pub input: serde_json::Value,
+}
+
+impl Reproducer {
+    /// The bundle file extension.
+    pub const EXTENSION: &'static str = "reproducer.json";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(contract_path: String, error: String, input: serde_json::Value) -> Self {
+        Self {
+            compiler_version: env!("CARGO_PKG_VERSION").to_owned(),
+            llvm_commit: inkwell::support::get_commit_id().to_string(),
+            protocol_version: Handshake::PROTOCOL_VERSION,
+            contract_path,
+            error,
+            input,
+        }
+    }
+
+    ///
+    /// Reads the bundle from the file.
+    ///
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let bundle = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("Reproducer {path:?} reading error: {error}"))?;
+        era_compiler_common::deserialize_from_slice(bundle.as_slice())
+            .map_err(|error| anyhow::anyhow!("Reproducer {path:?} parsing error: {error}"))
+    }
+
+    ///
+    /// Writes the bundle to the directory, returning the file path.
+    ///
+    /// The file name consists of the contract file name and the input hash, so failures of
+    /// different contracts and settings do not overwrite each other.
+    ///
+    pub fn write(&self, directory: &Path) -> anyhow::Result<PathBuf> {
+        let bundle = serde_json::to_vec_pretty(self).expect("Always valid");
+        let hash = hex::encode(
+            era_compiler_common::Keccak256Hash::from_slice(
+                serde_json::to_vec(&self.input)
+                    .expect("Always valid")
+                    .as_slice(),
+            )
+            .as_bytes(),
+        );
+        let contract_name = Path::new(self.contract_path.as_str())
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        std::fs::create_dir_all(directory)?;
+        let path = directory.join(format!(
+            "{contract_name}.{}.{}",
+            &hash[..16],
+            Self::EXTENSION
+        ));
+        std::fs::write(path.as_path(), bundle)
+            .map_err(|error| anyhow::anyhow!("Reproducer {path:?} writing error: {error}"))?;
+        Ok(path)
+    }
+
+    ///
+    /// Re-runs the recorded compilation in this process, returning the output JSON.
+    ///
+    pub fn replay(&self) -> anyhow::Result<Vec<u8>> {
+        if self.protocol_version != Handshake::PROTOCOL_VERSION {
+            anyhow::bail!(
+                "The reproducer has been recorded by zkvyper v{} with the input schema v{}, which this zkvyper v{} cannot read. Replay it with the matching compiler version.",
+                self.compiler_version,
+                self.protocol_version,
+                env!("CARGO_PKG_VERSION"),
+            );
+        }
+        let llvm_commit = inkwell::support::get_commit_id().to_string();
+        if self.compiler_version != env!("CARGO_PKG_VERSION") || self.llvm_commit != llvm_commit {
+            eprintln!(
+                "Warning: The reproducer has been recorded by zkvyper v{} (LLVM build {}), but is replayed by zkvyper v{} (LLVM build {llvm_commit}).",
+                self.compiler_version,
+                self.llvm_commit,
+                env!("CARGO_PKG_VERSION"),
+            );
+        }
+
+        crate::process::compile(
+            serde_json::to_vec(&self.input).expect("Always valid"),
+            Encoding::Json,
+        )
+    }
+}
@@ -0,0 +1,192 @@
+//!
+//! The persistent compile server.
+//!
+//! Answers newline-delimited JSON requests on stdio or a Unix socket, keeping the `vyper`
+//! executables, LLVM target, and compilation cache warm between requests.
+//!
+
+pub mod request;
+pub mod response;
+
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+
+use crate::session::config::Config as CompilationConfig;
+use crate::session::Session as CompilerSession;
+use crate::vyper::Compiler as VyperCompiler;
+
+use self::request::CompileParams;
+use self::request::Request;
+use self::response::Response;
+
+///
+/// Runs the server until the `shutdown` request or the end of input.
+///
+/// Listens on the Unix socket at `socket_path`, or on stdin and stdout if it is not given.
+///
+pub fn run(socket_path: Option<&Path>) -> anyhow::Result<()> {
+    match socket_path {
+        Some(socket_path) => listen(socket_path),
+        None => {
+            serve(std::io::stdin().lock(), std::io::stdout().lock())?;
+            Ok(())
+        }
+    }
+}
+
+///
+/// Accepts connections on the Unix socket and serves them one by one.
+///
+/// A failed connection is reported to stderr and does not stop the server. Only the `shutdown`
+/// request and listener errors do.
+///
+#[cfg(unix)]
+fn listen(socket_path: &Path) -> anyhow::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+    use std::os::unix::net::UnixListener;
+
+    if let Ok(metadata) = std::fs::symlink_metadata(socket_path) {
+        if !metadata.file_type().is_socket() {
+            anyhow::bail!("Server socket path {socket_path:?} already exists and is not a socket.");
+        }
+        std::fs::remove_file(socket_path)?;
+    }
+
+    let listener = UnixListener::bind(socket_path)
+        .map_err(|error| anyhow::anyhow!("Server socket {socket_path:?} binding error: {error}"))?;
+    let mut result = Ok(());
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(error) => {
+                result = Err(anyhow::anyhow!(
+                    "Server socket {socket_path:?} accepting error: {error}"
+                ));
+                break;
+            }
+        };
+
+        let is_shutdown = stream
+            .try_clone()
+            .map_err(anyhow::Error::from)
+            .and_then(|reader| serve(std::io::BufReader::new(reader), stream));
+        match is_shutdown {
+            Ok(true) => break,
+            Ok(false) => {}
+            Err(error) => eprintln!("Server connection error: {error}"),
+        }
+    }
+    std::fs::remove_file(socket_path)?;
+    result
+}
+
+///
+/// Accepts connections on the Unix socket and serves them one by one.
+///
+#[cfg(not(unix))]
+fn listen(_socket_path: &Path) -> anyhow::Result<()> {
+    anyhow::bail!(
+        "Unix sockets are not supported on this platform. Use the stdio transport instead."
+    )
+}
+
+///
+/// Answers requests read from `reader` until the `shutdown` request or the end of input.
+///
+/// Returns whether the `shutdown` request was received.
+///
+fn serve<R, W>(reader: R, mut writer: W) -> anyhow::Result<bool>
+where
+    R: BufRead,
+    W: Write,
+{
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (response, is_shutdown) = match serde_json::from_str::<Request>(line.as_str()) {
+            Ok(request) => {
+                let is_shutdown = request.method == "shutdown";
+                (handle(request), is_shutdown)
+            }
+            Err(error) => (
+                Response::new_error(
+                    serde_json::Value::Null,
+                    anyhow::anyhow!("Request parsing error: {error}"),
+                ),
+                false,
+            ),
+        };
+
+        serde_json::to_writer(&mut writer, &response)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+
+        if is_shutdown {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+///
+/// Handles the request.
+///
+fn handle(request: Request) -> Response {
+    let result = match request.method.as_str() {
+        "compile" => request
+            .params
+            .ok_or_else(|| anyhow::anyhow!("The `compile` method requires parameters."))
+            .and_then(|params| {
+                serde_json::from_value::<CompileParams>(params)
+                    .map_err(|error| anyhow::anyhow!("Parameters parsing error: {error}"))
+            })
+            .and_then(compile),
+        "version" => Ok(serde_json::json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "llvm": inkwell::support::get_commit_id().to_string(),
+        })),
+        "shutdown" => Ok(serde_json::Value::Null),
+        method => Err(anyhow::anyhow!(
+            "Unknown method `{method}`. Available methods: `compile`, `version`, `shutdown`."
+        )),
+    };
+
+    match result {
+        Ok(result) => Response::new_result(request.id, result),
+        Err(error) => Response::new_error(request.id, error),
+    }
+}
+
+///
+/// Compiles the project in combined JSON mode.
+///
+fn compile(params: CompileParams) -> anyhow::Result<serde_json::Value> {
+    let (optimizer_settings, tuning) = params.optimizer_settings()?;
+    let config = CompilationConfig {
+        vyper: params
+            .vyper
+            .clone()
+            .unwrap_or_else(|| VyperCompiler::DEFAULT_EXECUTABLE_NAME.to_owned()),
+        evm_version: params.evm_version()?,
+        enable_decimals: params.enable_decimals,
+        search_paths: params.search_paths.clone(),
+        vyper_optimizer_enabled: !params.disable_vyper_optimizer,
+        experimental_codegen: params.experimental_codegen,
+        evm_bytecode: params.evm_bytecode,
+        metadata_hash_type: params.metadata_hash_type()?,
+        append_bytecode_metadata: !params.no_bytecode_metadata,
+        optimizer_settings,
+        llvm_options: params.llvm_options.clone(),
+        optimization_overrides: params.optimization_overrides()?,
+        tuning,
+        suppressed_warnings: params.suppressed_warnings()?,
+        ..CompilationConfig::default()
+    };
+
+    let combined_json = CompilerSession::new(config).combined_json(params.input_paths)?;
+    Ok(serde_json::to_value(&combined_json).expect("Always valid"))
+}
@@ -0,0 +1,192 @@
+//!
+//! The compile server request.
+//!
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::optimization_level::OptimizationLevel;
+use crate::optimization_override::OptimizationOverride;
+use crate::tuning::objective::Objective as TuningObjective;
+use crate::tuning::Tuning;
+use crate::warning_type::WarningType;
+
+///
+/// The compile server request.
+///
+/// Is written as a single line of JSON.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Request {
+    /// The request identifier echoed in the response.
+    #[serde(default)]
+    pub id: serde_json::Value,
+    /// The method name: `compile`, `version`, or `shutdown`.
+    pub method: String,
+    /// The method parameters.
+    #[serde(default)]
+    pub params: Option<serde_json::Value>,
+}
+
+///
+/// The `compile` method parameters.
+///
+/// Mirror the command-line options of the combined JSON mode.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CompileParams {
+    /// The input file paths.
+    pub input_paths: Vec<PathBuf>,
+    /// The `vyper` executable path.
+    #[serde(default)]
+    pub vyper: Option<String>,
+    /// The EVM version.
+    #[serde(default)]
+    pub evm_version: Option<String>,
+    /// Whether to enable decimals in `vyper`.
+    #[serde(default)]
+    pub enable_decimals: bool,
+    /// The `vyper` search paths.
+    #[serde(default)]
+    pub search_paths: Option<Vec<String>>,
+    /// Whether to disable the `vyper` LLL IR optimizer.
+    #[serde(default)]
+    pub disable_vyper_optimizer: bool,
+    /// Whether to translate the Venom IR of the `vyper` experimental code generator.
+    #[serde(default)]
+    pub experimental_codegen: bool,
+    /// Whether to emit the upstream `vyper` EVM bytecode.
+    #[serde(default)]
+    pub evm_bytecode: bool,
+    /// The metadata hash type.
+    #[serde(default)]
+    pub metadata_hash: Option<String>,
+    /// Whether to turn off CBOR metadata at the end of bytecode.
+    #[serde(default)]
+    pub no_bytecode_metadata: bool,
+    /// The optimization level, including `auto`.
+    #[serde(default)]
+    pub optimization: Option<String>,
+    /// The objective of the optimization auto-tuning.
+    #[serde(default)]
+    pub optimization_objective: Option<String>,
+    /// The maximum bytecode size of the optimization auto-tuning.
+    #[serde(default)]
+    pub optimization_size_budget: Option<usize>,
+    /// The per-contract optimization levels in the `<pattern>=<level>` form.
+    #[serde(default)]
+    pub optimization_overrides: Vec<String>,
+    /// Whether to try to recompile with -Oz if the bytecode is too large.
+    #[serde(default)]
+    pub fallback_oz: bool,
+    /// The extra LLVM options.
+    #[serde(default)]
+    pub llvm_options: Vec<String>,
+    /// The suppressed warnings.
+    #[serde(default)]
+    pub suppress_warnings: Vec<String>,
+}
+
+impl CompileParams {
+    ///
+    /// Returns the EVM version.
+    ///
+    pub fn evm_version(&self) -> anyhow::Result<Option<era_compiler_common::EVMVersion>> {
+        self.evm_version
+            .as_deref()
+            .map(|evm_version| Self::parse("evm_version", evm_version))
+            .transpose()
+    }
+
+    ///
+    /// Returns the metadata hash type.
+    ///
+    pub fn metadata_hash_type(&self) -> anyhow::Result<era_compiler_common::MetadataHashType> {
+        Ok(self
+            .metadata_hash
+            .as_deref()
+            .map(|metadata_hash| Self::parse("metadata_hash", metadata_hash))
+            .transpose()?
+            .unwrap_or(era_compiler_common::MetadataHashType::None))
+    }
+
+    ///
+    /// Returns the optimizer settings and the auto-tuning settings, if requested.
+    ///
+    pub fn optimizer_settings(
+        &self,
+    ) -> anyhow::Result<(era_compiler_llvm_context::OptimizerSettings, Option<Tuning>)> {
+        let level = self
+            .optimization
+            .as_deref()
+            .map(OptimizationLevel::from_str)
+            .transpose()?;
+        let objective = self
+            .optimization_objective
+            .as_deref()
+            .map(TuningObjective::from_str)
+            .transpose()?;
+
+        let (mut optimizer_settings, tuning) = match level {
+            Some(OptimizationLevel::Fixed(level)) => (
+                era_compiler_llvm_context::OptimizerSettings::try_from_cli(level)?,
+                None,
+            ),
+            Some(OptimizationLevel::Auto) => {
+                if self.fallback_oz || !self.optimization_overrides.is_empty() {
+                    anyhow::bail!(
+                        "Falling back to -Oz and optimization overrides cannot be used together with the `auto` optimization level."
+                    );
+                }
+                (
+                    era_compiler_llvm_context::OptimizerSettings::cycles(),
+                    Some(Tuning::new(
+                        objective.unwrap_or_default(),
+                        self.optimization_size_budget,
+                    )),
+                )
+            }
+            None => (era_compiler_llvm_context::OptimizerSettings::cycles(), None),
+        };
+        if tuning.is_none() && (objective.is_some() || self.optimization_size_budget.is_some()) {
+            anyhow::bail!(
+                "The optimization objective and size budget can only be used together with the `auto` optimization level."
+            );
+        }
+        if self.fallback_oz {
+            optimizer_settings.enable_fallback_to_size();
+        }
+        Ok((optimizer_settings, tuning))
+    }
+
+    ///
+    /// Returns the per-contract optimization overrides.
+    ///
+    pub fn optimization_overrides(&self) -> anyhow::Result<Vec<OptimizationOverride>> {
+        self.optimization_overrides
+            .iter()
+            .map(|value| OptimizationOverride::from_str(value.as_str()))
+            .collect()
+    }
+
+    ///
+    /// Returns the suppressed warnings.
+    ///
+    pub fn suppressed_warnings(&self) -> anyhow::Result<Vec<WarningType>> {
+        WarningType::try_from_strings(self.suppress_warnings.as_slice())
+    }
+
+    ///
+    /// Parses the parameter value with the same parser as the command-line option.
+    ///
+    fn parse<T>(key: &str, value: &str) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        T::from_str(value).map_err(|error| {
+            anyhow::anyhow!("Parameter `{key}` value {value:?} is invalid: {error}")
+        })
+    }
+}
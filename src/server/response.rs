@@ -0,0 +1,55 @@
+//!
+//! The compile server response.
+//!
+
+///
+/// The compile server response.
+///
+/// Is written as a single line of JSON, with either `result` or `error` set.
+///
+#[derive(Debug, serde::Serialize)]
+pub struct Response {
+    /// The identifier of the request.
+    pub id: serde_json::Value,
+    /// The method result.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    /// The method error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+///
+/// The compile server response error.
+///
+#[derive(Debug, serde::Serialize)]
+pub struct Error {
+    /// The error message.
+    pub message: String,
+}
+
+impl Response {
+    ///
+    /// A shortcut constructor for a successful response.
+    ///
+    pub fn new_result(id: serde_json::Value, result: serde_json::Value) -> Self {
+        Self {
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor for an error response.
+    ///
+    pub fn new_error(id: serde_json::Value, error: anyhow::Error) -> Self {
+        Self {
+            id,
+            result: None,
+            error: Some(Error {
+                message: error.to_string(),
+            }),
+        }
+    }
+}
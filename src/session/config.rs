@@ -0,0 +1,210 @@
+//!
+//! The compilation config.
+//!
+
+use crate::optimization_override::OptimizationOverride;
+use crate::process::strategy::Strategy as ProcessStrategy;
+use crate::tuning::Tuning;
+use crate::vyper::Compiler as VyperCompiler;
+use crate::warning_type::WarningType;
+
+///
+/// The compilation config.
+///
+/// Holds all settings of a compiler session. Starts from the defaults of the `zkvyper` executable,
+/// which are overridden with the `with_*` methods.
+///
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The `vyper` executable path.
+    pub vyper: String,
+    /// The EVM version of `vyper`.
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
+    /// Whether to enable decimals in `vyper`.
+    pub enable_decimals: bool,
+    /// The `vyper` search paths.
+    pub search_paths: Option<Vec<String>>,
+    /// Whether to enable the `vyper` optimizer.
+    pub vyper_optimizer_enabled: bool,
+    /// Whether to use the `vyper` experimental code generator.
+    pub experimental_codegen: bool,
+    /// Whether to add the upstream `vyper` EVM bytecode to combined JSON.
+    pub evm_bytecode: bool,
+    /// The metadata hash type.
+    pub metadata_hash_type: era_compiler_common::MetadataHashType,
+    /// Whether to append the metadata hash to the bytecode.
+    pub append_bytecode_metadata: bool,
+    /// The optimizer settings.
+    pub optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    /// The extra LLVM options.
+    pub llvm_options: Vec<String>,
+    /// The per-contract optimization overrides.
+    pub optimization_overrides: Vec<OptimizationOverride>,
+    /// The auto-tuning settings.
+    pub tuning: Option<Tuning>,
+    /// The suppressed warnings.
+    pub suppressed_warnings: Vec<WarningType>,
+    /// The debug output config.
+    pub debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    /// The execution strategy of contract compilations.
+    pub strategy: ProcessStrategy,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            vyper: VyperCompiler::DEFAULT_EXECUTABLE_NAME.to_owned(),
+            evm_version: None,
+            enable_decimals: false,
+            search_paths: None,
+            vyper_optimizer_enabled: true,
+            experimental_codegen: false,
+            evm_bytecode: false,
+            metadata_hash_type: era_compiler_common::MetadataHashType::None,
+            append_bytecode_metadata: true,
+            optimizer_settings: era_compiler_llvm_context::OptimizerSettings::cycles(),
+            llvm_options: vec![],
+            optimization_overrides: vec![],
+            tuning: None,
+            suppressed_warnings: vec![],
+            debug_config: None,
+            strategy: ProcessStrategy::default(),
+        }
+    }
+}
+
+impl Config {
+    ///
+    /// Sets the `vyper` executable path.
+    ///
+    pub fn with_vyper(mut self, executable: &str) -> Self {
+        self.vyper = executable.to_owned();
+        self
+    }
+
+    ///
+    /// Sets the EVM version of `vyper`.
+    ///
+    pub fn with_evm_version(mut self, evm_version: era_compiler_common::EVMVersion) -> Self {
+        self.evm_version = Some(evm_version);
+        self
+    }
+
+    ///
+    /// Enables decimals in `vyper`.
+    ///
+    pub fn with_decimals(mut self, enable_decimals: bool) -> Self {
+        self.enable_decimals = enable_decimals;
+        self
+    }
+
+    ///
+    /// Sets the `vyper` search paths.
+    ///
+    pub fn with_search_paths(mut self, search_paths: Vec<String>) -> Self {
+        self.search_paths = Some(search_paths);
+        self
+    }
+
+    ///
+    /// Enables or disables the `vyper` optimizer.
+    ///
+    pub fn with_vyper_optimizer(mut self, vyper_optimizer_enabled: bool) -> Self {
+        self.vyper_optimizer_enabled = vyper_optimizer_enabled;
+        self
+    }
+
+    ///
+    /// Enables the `vyper` experimental code generator.
+    ///
+    pub fn with_experimental_codegen(mut self, experimental_codegen: bool) -> Self {
+        self.experimental_codegen = experimental_codegen;
+        self
+    }
+
+    ///
+    /// Adds the upstream `vyper` EVM bytecode to combined JSON.
+    ///
+    pub fn with_evm_bytecode(mut self, evm_bytecode: bool) -> Self {
+        self.evm_bytecode = evm_bytecode;
+        self
+    }
+
+    ///
+    /// Sets the metadata hash type and whether to append the hash to the bytecode.
+    ///
+    pub fn with_metadata(
+        mut self,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        append_bytecode_metadata: bool,
+    ) -> Self {
+        self.metadata_hash_type = metadata_hash_type;
+        self.append_bytecode_metadata = append_bytecode_metadata;
+        self
+    }
+
+    ///
+    /// Sets the optimizer settings.
+    ///
+    pub fn with_optimizer_settings(
+        mut self,
+        optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    ) -> Self {
+        self.optimizer_settings = optimizer_settings;
+        self
+    }
+
+    ///
+    /// Sets the extra LLVM options.
+    ///
+    pub fn with_llvm_options(mut self, llvm_options: Vec<String>) -> Self {
+        self.llvm_options = llvm_options;
+        self
+    }
+
+    ///
+    /// Sets the per-contract optimization overrides.
+    ///
+    pub fn with_optimization_overrides(
+        mut self,
+        optimization_overrides: Vec<OptimizationOverride>,
+    ) -> Self {
+        self.optimization_overrides = optimization_overrides;
+        self
+    }
+
+    ///
+    /// Enables auto-tuning of the optimization level of each contract.
+    ///
+    pub fn with_tuning(mut self, tuning: Tuning) -> Self {
+        self.tuning = Some(tuning);
+        self
+    }
+
+    ///
+    /// Sets the suppressed warnings.
+    ///
+    pub fn with_suppressed_warnings(mut self, suppressed_warnings: Vec<WarningType>) -> Self {
+        self.suppressed_warnings = suppressed_warnings;
+        self
+    }
+
+    ///
+    /// Sets the debug output config.
+    ///
+    pub fn with_debug_config(
+        mut self,
+        debug_config: era_compiler_llvm_context::DebugConfig,
+    ) -> Self {
+        self.debug_config = Some(debug_config);
+        self
+    }
+
+    ///
+    /// Sets the execution strategy of contract compilations.
+    ///
+    pub fn with_strategy(mut self, strategy: ProcessStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+}
@@ -0,0 +1,404 @@
+//!
+//! The compiler session.
+//!
+
+pub mod config;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::build::Build;
+use crate::build_evm::Build as EVMBuild;
+use crate::cache::source_key::SourceKey;
+use crate::project::Project;
+use crate::tuning::Tuning;
+use crate::vyper::combined_json::CombinedJson;
+use crate::vyper::selector::Selector as VyperSelector;
+use crate::vyper::Compiler as VyperCompiler;
+
+use self::config::Config;
+
+///
+/// The compiler session.
+///
+/// Runs each compilation mode with the settings of the config, returning the build instead of
+/// writing it to `stdout`.
+///
+#[derive(Debug, Clone)]
+pub struct Session {
+    /// The compilation config.
+    pub config: Config,
+}
+
+impl Session {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    ///
+    /// Returns the `vyper` compiler of the session.
+    ///
+    pub fn vyper(&self) -> anyhow::Result<VyperCompiler> {
+        VyperCompiler::new(self.config.vyper.as_str())
+    }
+
+    ///
+    /// Compiles LLL IR files.
+    ///
+    pub fn lll(
+        &self,
+        input_paths: Vec<PathBuf>,
+        output_selection: &[VyperSelector],
+    ) -> anyhow::Result<Build> {
+        let paths: Vec<&Path> = input_paths.iter().map(|path| path.as_path()).collect();
+        let project = Project::try_from_lll_paths(paths.as_slice(), output_selection)?;
+        self.dump_lll(&project)?;
+        self.compile(project, None, None)
+    }
+
+    ///
+    /// Compiles LLVM IR files.
+    ///
+    pub fn llvm_ir(
+        &self,
+        input_paths: Vec<PathBuf>,
+        output_selection: &[VyperSelector],
+    ) -> anyhow::Result<Build> {
+        let paths: Vec<&Path> = input_paths.iter().map(|path| path.as_path()).collect();
+        let project = Project::try_from_llvm_ir_paths(paths.as_slice(), output_selection)?;
+        self.compile(project, None, None)
+    }
+
+    ///
+    /// Assembles EraVM assembly files.
+    ///
+    /// The optimizer settings, overrides and auto-tuning do not apply to assembly.
+    ///
+    pub fn eravm_assembly(
+        &self,
+        input_paths: Vec<PathBuf>,
+        output_selection: &[VyperSelector],
+    ) -> anyhow::Result<Build> {
+        let paths: Vec<&Path> = input_paths.iter().map(|path| path.as_path()).collect();
+        let project = Project::try_from_eravm_assembly_paths(paths.as_slice(), output_selection)?;
+
+        let mut build = project.compile(
+            None,
+            self.config.metadata_hash_type,
+            self.config.append_bytecode_metadata,
+            era_compiler_llvm_context::OptimizerSettings::cycles(),
+            self.config.llvm_options.clone(),
+            vec![],
+            None,
+            self.config.suppressed_warnings.clone(),
+            self.config.debug_config.clone(),
+            self.config.strategy,
+        )?;
+        build.link(BTreeMap::new())?;
+        Ok(build)
+    }
+
+    ///
+    /// Compiles Vyper source files with the selected output.
+    ///
+    pub fn standard_output(
+        &self,
+        input_paths: Vec<PathBuf>,
+        output_selection: &[VyperSelector],
+    ) -> anyhow::Result<Build> {
+        let vyper = self.vyper()?;
+        self.cached(
+            &vyper,
+            input_paths,
+            output_selection,
+            era_compiler_common::Target::EraVM,
+            |input_paths| {
+                let project = self.batch(
+                    &vyper,
+                    input_paths,
+                    output_selection,
+                    self.config.experimental_codegen,
+                )?;
+                self.compile(project, self.config.evm_version, self.config.tuning.clone())
+            },
+        )
+    }
+
+    ///
+    /// Compiles Vyper source files to combined JSON.
+    ///
+    pub fn combined_json(&self, input_paths: Vec<PathBuf>) -> anyhow::Result<CombinedJson> {
+        let zkvyper_version =
+            semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid");
+
+        let mut output_selection: Vec<VyperSelector> = vec![
+            VyperSelector::IRJson,
+            VyperSelector::AST,
+            VyperSelector::ABI,
+            VyperSelector::MethodIdentifiers,
+            VyperSelector::Layout,
+            VyperSelector::UserDocumentation,
+            VyperSelector::DeveloperDocumentation,
+            VyperSelector::EraVMAssembly,
+            VyperSelector::ProjectMetadata,
+        ];
+        if self.config.evm_bytecode {
+            output_selection.extend([
+                VyperSelector::EVMBytecode,
+                VyperSelector::EVMBytecodeRuntime,
+            ]);
+        }
+
+        let vyper = self.vyper()?;
+        let build = self.cached(
+            &vyper,
+            input_paths,
+            output_selection.as_slice(),
+            era_compiler_common::Target::EraVM,
+            |input_paths| {
+                let project = self.batch(
+                    &vyper,
+                    input_paths,
+                    output_selection.as_slice(),
+                    self.config.experimental_codegen,
+                )?;
+                self.compile(project, self.config.evm_version, self.config.tuning.clone())
+            },
+        )?;
+
+        Ok(build.into_combined_json(Some(&vyper.version.default), &zkvyper_version))
+    }
+
+    ///
+    /// Compiles Vyper source files to EVM with the selected output.
+    ///
+    pub fn standard_output_evm(
+        &self,
+        input_paths: Vec<PathBuf>,
+        output_selection: &[VyperSelector],
+    ) -> anyhow::Result<EVMBuild> {
+        if self.config.evm_bytecode || output_selection.iter().any(VyperSelector::is_evm_bytecode) {
+            anyhow::bail!("EVM bytecode is already emitted for the EVM target.");
+        }
+
+        let vyper = self.vyper()?;
+        self.cached(
+            &vyper,
+            input_paths,
+            output_selection,
+            era_compiler_common::Target::EVM,
+            |input_paths| {
+                let project = self.batch(&vyper, input_paths, output_selection, false)?;
+                project.compile_to_evm(
+                    self.config.evm_version,
+                    self.config.metadata_hash_type,
+                    self.config.optimizer_settings.clone(),
+                    self.config.llvm_options.clone(),
+                    self.config.optimization_overrides.clone(),
+                    self.config.suppressed_warnings.clone(),
+                    self.config.debug_config.clone(),
+                    self.config.strategy,
+                )
+            },
+        )
+    }
+
+    ///
+    /// Compiles Vyper source files to EVM combined JSON.
+    ///
+    pub fn combined_json_evm(&self, input_paths: Vec<PathBuf>) -> anyhow::Result<CombinedJson> {
+        let zkvyper_version =
+            semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid");
+
+        let output_selection: Vec<VyperSelector> = vec![
+            VyperSelector::IRJson,
+            VyperSelector::AST,
+            VyperSelector::ABI,
+            VyperSelector::MethodIdentifiers,
+            VyperSelector::Layout,
+            VyperSelector::UserDocumentation,
+            VyperSelector::DeveloperDocumentation,
+            VyperSelector::ProjectMetadata,
+        ];
+
+        let vyper = self.vyper()?;
+        let build = self.standard_output_evm(input_paths, output_selection.as_slice())?;
+
+        Ok(build.into_combined_json(Some(&vyper.version.default), &zkvyper_version))
+    }
+
+    ///
+    /// Returns the cached build of the Vyper sources, or builds them with `compile` and caches
+    /// the successful build.
+    ///
+    /// The build is keyed by the `SourceKey` of the sources and the settings, so a hit skips both
+    /// `vyper` and LLVM. The cache is bypassed if it is disabled, if the debug output is
+    /// requested, as it is only produced by actual compilation, or if a source cannot be read,
+    /// so the error is reported by `vyper`.
+    ///
+    fn cached<B, F>(
+        &self,
+        vyper: &VyperCompiler,
+        input_paths: Vec<PathBuf>,
+        output_selection: &[VyperSelector],
+        target: era_compiler_common::Target,
+        compile: F,
+    ) -> anyhow::Result<B>
+    where
+        B: serde::Serialize + serde::de::DeserializeOwned + Send,
+        F: FnOnce(Vec<PathBuf>) -> anyhow::Result<B>,
+    {
+        let cache = match crate::process::CACHE.get() {
+            Some(cache) if self.config.debug_config.is_none() => cache,
+            _ => return compile(input_paths),
+        };
+        let source_key = match SourceKey::new(
+            input_paths.as_slice(),
+            self.config.search_paths.as_deref().unwrap_or_default(),
+        ) {
+            Ok(source_key) => source_key,
+            Err(_) => return compile(input_paths),
+        };
+        let key = source_key.hash(&self.cache_settings(vyper, output_selection, target));
+
+        let encoding = crate::process::IPC_ENCODING
+            .get()
+            .copied()
+            .unwrap_or_default();
+        if let Some(build) = cache
+            .get(key.as_str())
+            .and_then(|entry| Self::on_large_stack(|| encoding.decode::<B>(entry.as_slice())).ok())
+        {
+            return Ok(build);
+        }
+
+        let build = compile(input_paths)?;
+        let (build, entry) = Self::on_large_stack(move || {
+            let entry = encoding.encode(&build);
+            Ok((build, entry))
+        })?;
+        if let Err(error) = cache.put(key.as_str(), entry.as_slice()) {
+            eprintln!("Warning: {error}");
+        }
+        Ok(build)
+    }
+
+    ///
+    /// Returns the settings the build of Vyper sources depends on, which are a part of its
+    /// cache key.
+    ///
+    fn cache_settings(
+        &self,
+        vyper: &VyperCompiler,
+        output_selection: &[VyperSelector],
+        target: era_compiler_common::Target,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "target": target,
+            "vyper_version": vyper.version.long,
+            "evm_version": self.config.evm_version,
+            "enable_decimals": self.config.enable_decimals,
+            "search_paths": self.config.search_paths,
+            "vyper_optimizer_enabled": self.config.vyper_optimizer_enabled,
+            "experimental_codegen": self.config.experimental_codegen,
+            "evm_bytecode": self.config.evm_bytecode,
+            "metadata_hash_type": self.config.metadata_hash_type,
+            "append_bytecode_metadata": self.config.append_bytecode_metadata,
+            "optimizer_settings": self.config.optimizer_settings,
+            "llvm_options": self.config.llvm_options,
+            "optimization_overrides": self.config.optimization_overrides,
+            "tuning": self.config.tuning,
+            "suppressed_warnings": self.config.suppressed_warnings,
+            "output_selection": output_selection,
+        })
+    }
+
+    ///
+    /// Runs `function` on a thread with a large stack, as both encoding and decoding of deep LLL
+    /// IR trees are recursive.
+    ///
+    fn on_large_stack<T, F>(function: F) -> anyhow::Result<T>
+    where
+        T: Send,
+        F: FnOnce() -> anyhow::Result<T> + Send,
+    {
+        std::thread::scope(|scope| {
+            std::thread::Builder::new()
+                .stack_size(crate::WORKER_THREAD_STACK_SIZE)
+                .spawn_scoped(scope, function)
+                .expect("Threading error")
+                .join()
+                .expect("Threading error")
+        })
+    }
+
+    ///
+    /// Runs `vyper` on the source files and dumps LLL IR if the debug output is enabled.
+    ///
+    fn batch(
+        &self,
+        vyper: &VyperCompiler,
+        input_paths: Vec<PathBuf>,
+        output_selection: &[VyperSelector],
+        experimental_codegen: bool,
+    ) -> anyhow::Result<Project> {
+        let project = vyper.batch(
+            &vyper.version.default,
+            input_paths,
+            output_selection,
+            self.config.evm_version,
+            self.config.enable_decimals,
+            self.config.search_paths.clone(),
+            self.config.vyper_optimizer_enabled,
+            experimental_codegen,
+        )?;
+        self.dump_lll(&project)?;
+        Ok(project)
+    }
+
+    ///
+    /// Dumps LLL IR of the project contracts if the debug output is enabled.
+    ///
+    fn dump_lll(&self, project: &Project) -> anyhow::Result<()> {
+        if let Some(ref debug_config) = self.config.debug_config {
+            for (path, contract) in project.contracts.iter() {
+                debug_config.dump_lll(
+                    path.as_str(),
+                    contract.ir_string().unwrap_or_default().as_str(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Compiles the project to EraVM with the session settings and links the build.
+    ///
+    /// The EVM version and auto-tuning only apply to Vyper sources.
+    ///
+    fn compile(
+        &self,
+        project: Project,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        tuning: Option<Tuning>,
+    ) -> anyhow::Result<Build> {
+        let mut build = project.compile(
+            evm_version,
+            self.config.metadata_hash_type,
+            self.config.append_bytecode_metadata,
+            self.config.optimizer_settings.clone(),
+            self.config.llvm_options.clone(),
+            self.config.optimization_overrides.clone(),
+            tuning,
+            self.config.suppressed_warnings.clone(),
+            self.config.debug_config.clone(),
+            self.config.strategy,
+        )?;
+        build.link(BTreeMap::new())?;
+        Ok(build)
+    }
+}
@@ -0,0 +1,137 @@
+//!
+//! The subprocess failure.
+//!
+
+use std::time::Duration;
+
+///
+/// The kind of subprocess failure.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ErrorKind {
+    /// The subprocess could not be started.
+    Spawn(String),
+    /// Communication with the subprocess failed.
+    Io(String),
+    /// The subprocess exceeded the time limit and was killed.
+    Timeout(Duration),
+    /// The subprocess was terminated by a signal, for example on an LLVM abort.
+    Signal(i32),
+    /// The subprocess exited with a failure code without reporting an error.
+    Exit(Option<i32>),
+    /// The subprocess output could not be decoded.
+    Protocol(String),
+}
+
+///
+/// The subprocess failure.
+///
+/// Carries the `stderr` captured from the subprocess, which includes the LLVM pretty stack trace
+/// if LLVM has crashed.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Error {
+    /// The subprocess executable.
+    pub executable: String,
+    /// The kind of failure.
+    pub kind: ErrorKind,
+    /// The captured `stderr`.
+    pub stderr: String,
+    /// The memory limit in effect, if any.
+    pub memory_limit: Option<u64>,
+}
+
+impl Error {
+    /// The header LLVM prints before the pretty stack trace.
+    pub const STACK_TRACE_HEADER: &'static str = "Stack dump:";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(executable: String, kind: ErrorKind, stderr: String) -> Self {
+        Self {
+            executable,
+            kind,
+            stderr,
+            memory_limit: super::limits::Limits::global().memory_limit,
+        }
+    }
+
+    ///
+    /// Returns the LLVM pretty stack trace from the captured `stderr`, if LLVM has crashed.
+    ///
+    pub fn stack_trace(&self) -> Option<&str> {
+        self.stderr
+            .find(Self::STACK_TRACE_HEADER)
+            .map(|position| &self.stderr[position..])
+    }
+
+    ///
+    /// Returns the conventional name of the signal.
+    ///
+    fn signal_name(signal: i32) -> &'static str {
+        match signal {
+            4 => "SIGILL",
+            6 => "SIGABRT",
+            7 => "SIGBUS",
+            9 => "SIGKILL",
+            11 => "SIGSEGV",
+            15 => "SIGTERM",
+            _ => "unknown signal",
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ErrorKind::Spawn(ref message) => write!(
+                f,
+                "{} subprocess spawning error: {message}",
+                self.executable
+            )?,
+            ErrorKind::Io(ref message) => write!(
+                f,
+                "{} subprocess communication error: {message}",
+                self.executable
+            )?,
+            ErrorKind::Timeout(timeout) => write!(
+                f,
+                "{} subprocess timed out after {}s and was killed",
+                self.executable,
+                timeout.as_secs_f64()
+            )?,
+            ErrorKind::Signal(signal) => {
+                write!(
+                    f,
+                    "{} subprocess was terminated by signal {signal} ({})",
+                    self.executable,
+                    Self::signal_name(signal)
+                )?;
+                if let Some(memory_limit) = self.memory_limit {
+                    write!(
+                        f,
+                        ". The memory limit of {} MiB may have been exceeded",
+                        memory_limit / (1024 * 1024)
+                    )?;
+                }
+            }
+            ErrorKind::Exit(code) => write!(
+                f,
+                "{} subprocess failed with exit code {code:?}",
+                self.executable
+            )?,
+            ErrorKind::Protocol(ref message) => write!(
+                f,
+                "{} subprocess output decoding error: {message}",
+                self.executable
+            )?,
+        }
+        if !self.stderr.trim().is_empty() {
+            write!(f, ":\n{}", self.stderr.trim_end())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+//!
+//! The resource limits of subprocesses.
+//!
+
+use std::process::Command;
+use std::time::Duration;
+
+///
+/// The resource limits of subprocesses.
+///
+/// Apply to each `vyper` invocation and each compilation on a worker process separately.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The wall-clock time limit, after which the subprocess is killed.
+    pub timeout: Option<Duration>,
+    /// The address space limit in bytes.
+    pub memory_limit: Option<u64>,
+}
+
+impl Limits {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(timeout: Option<Duration>, memory_limit: Option<u64>) -> Self {
+        Self {
+            timeout,
+            memory_limit,
+        }
+    }
+
+    ///
+    /// Returns the process-wide limits, or no limits if they have not been set.
+    ///
+    pub fn global() -> Self {
+        super::PROCESS_LIMITS.get().copied().unwrap_or_default()
+    }
+
+    ///
+    /// Sets the memory limit of the command to be spawned.
+    ///
+    /// The limit restricts the address space, so it must leave room for the thread stacks the
+    /// compiler reserves in addition to the memory actually used.
+    ///
+    #[cfg(unix)]
+    pub fn apply(&self, command: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        if let Some(memory_limit) = self.memory_limit {
+            let limit = libc::rlimit {
+                rlim_cur: memory_limit as libc::rlim_t,
+                rlim_max: memory_limit as libc::rlim_t,
+            };
+            unsafe {
+                command.pre_exec(move || {
+                    if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                        return Err(std::io::Error::last_os_error());
+                    }
+                    Ok(())
+                });
+            }
+        }
+    }
+
+    ///
+    /// Sets the memory limit of the command to be spawned.
+    ///
+    /// Memory limits are only supported on Unix platforms and rejected by the CLI elsewhere.
+    ///
+    #[cfg(not(unix))]
+    pub fn apply(&self, _command: &mut Command) {}
+}
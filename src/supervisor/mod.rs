@@ -0,0 +1,154 @@
+//!
+//! The supervision of subprocesses.
+//!
+//! Runs `vyper` and the recursive workers under the configured time and memory limits, and turns
+//! their crashes into errors instead of panics of the parent process.
+//!
+
+pub mod error;
+pub mod limits;
+pub mod watchdog;
+
+use std::io::Read;
+use std::io::Write;
+use std::process::Command;
+use std::process::ExitStatus;
+use std::process::Output;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::thread::JoinHandle;
+
+use self::error::Error;
+use self::error::ErrorKind;
+use self::limits::Limits;
+use self::watchdog::Watchdog;
+
+/// The resource limits of subprocesses, if set.
+pub static PROCESS_LIMITS: OnceLock<Limits> = OnceLock::new();
+
+///
+/// Runs the command to completion under the process-wide limits, returning its output.
+///
+/// A non-zero exit code is returned as a part of the output, as `vyper` uses it to report errors
+/// in the source code. Timeouts and terminations by a signal are returned as errors.
+///
+pub fn output(mut command: Command, stdin: Option<Vec<u8>>) -> Result<Output, Error> {
+    let executable = command.get_program().to_string_lossy().to_string();
+    let limits = Limits::global();
+    limits.apply(&mut command);
+    command.stdin(if stdin.is_some() {
+        std::process::Stdio::piped()
+    } else {
+        std::process::Stdio::null()
+    });
+    command.stdout(std::process::Stdio::piped());
+    command.stderr(std::process::Stdio::piped());
+
+    let mut process = command.spawn().map_err(|error| {
+        Error::new(
+            executable.clone(),
+            ErrorKind::Spawn(error.to_string()),
+            String::new(),
+        )
+    })?;
+    let stdin_writer = match (stdin, process.stdin.take()) {
+        (Some(input), Some(mut pipe)) => {
+            Some(std::thread::spawn(move || pipe.write_all(input.as_slice())))
+        }
+        _ => None,
+    };
+    let stdout_reader = drain(process.stdout.take());
+    let stderr_reader = drain(process.stderr.take());
+
+    let process = Arc::new(Mutex::new(process));
+    let watchdog = limits
+        .timeout
+        .map(|timeout| Watchdog::start(process.clone(), timeout));
+
+    let stdout = stdout_reader.join().unwrap_or_default();
+    let stderr = stderr_reader.join().unwrap_or_default();
+    let is_timed_out = watchdog.map(Watchdog::stop).unwrap_or_default();
+    let status = process.lock().expect("Sync").wait();
+    let stdin_result = stdin_writer.map(|writer| writer.join());
+
+    let status = status.map_err(|error| {
+        Error::new(
+            executable.clone(),
+            ErrorKind::Io(error.to_string()),
+            String::from_utf8_lossy(stderr.as_slice()).to_string(),
+        )
+    })?;
+    if let Some(error) = failure(
+        executable.as_str(),
+        status,
+        String::from_utf8_lossy(stderr.as_slice()).to_string(),
+        is_timed_out.then_some(limits.timeout).flatten(),
+    ) {
+        return Err(error);
+    }
+    if let Some(Ok(Err(error))) = stdin_result {
+        return Err(Error::new(
+            executable,
+            ErrorKind::Io(format!("stdin writing: {error}")),
+            String::from_utf8_lossy(stderr.as_slice()).to_string(),
+        ));
+    }
+
+    Ok(Output {
+        status,
+        stdout,
+        stderr,
+    })
+}
+
+///
+/// Returns the error if the subprocess has timed out or has been terminated by a signal.
+///
+pub fn failure(
+    executable: &str,
+    status: ExitStatus,
+    stderr: String,
+    timeout: Option<std::time::Duration>,
+) -> Option<Error> {
+    let kind = match (timeout, signal(status)) {
+        (Some(timeout), _) => ErrorKind::Timeout(timeout),
+        (None, Some(signal)) => ErrorKind::Signal(signal),
+        (None, None) => return None,
+    };
+    Some(Error::new(executable.to_owned(), kind, stderr))
+}
+
+///
+/// Returns the signal that has terminated the subprocess.
+///
+#[cfg(unix)]
+fn signal(status: ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+
+    status.signal()
+}
+
+///
+/// Returns the signal that has terminated the subprocess.
+///
+#[cfg(not(unix))]
+fn signal(_status: ExitStatus) -> Option<i32> {
+    None
+}
+
+///
+/// Reads the stream to the end on a separate thread.
+///
+fn drain<R>(stream: Option<R>) -> JoinHandle<Vec<u8>>
+where
+    R: Read + Send + 'static,
+{
+    std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut stream) = stream {
+            let _ = stream.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
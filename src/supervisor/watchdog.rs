@@ -0,0 +1,48 @@
+//!
+//! The subprocess watchdog.
+//!
+
+use std::process::Child;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+///
+/// The subprocess watchdog.
+///
+/// Kills the subprocess if it is not stopped before the timeout.
+///
+#[derive(Debug)]
+pub struct Watchdog {
+    /// The channel stopping the watchdog.
+    sender: mpsc::Sender<()>,
+    /// The watchdog thread, returning whether the subprocess has been killed.
+    handle: JoinHandle<bool>,
+}
+
+impl Watchdog {
+    ///
+    /// Starts watching the subprocess.
+    ///
+    pub fn start(process: Arc<Mutex<Child>>, timeout: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel::<()>();
+        let handle = std::thread::spawn(move || match receiver.recv_timeout(timeout) {
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                let _ = process.lock().expect("Sync").kill();
+                true
+            }
+            _ => false,
+        });
+        Self { sender, handle }
+    }
+
+    ///
+    /// Stops watching the subprocess, returning whether it has been killed on timeout.
+    ///
+    pub fn stop(self) -> bool {
+        let _ = self.sender.send(());
+        self.handle.join().unwrap_or_default()
+    }
+}
@@ -0,0 +1,61 @@
+//!
+//! The optimization auto-tuning candidate.
+//!
+
+///
+/// The optimization auto-tuning candidate.
+///
+/// Describes the outcome of compiling a contract with one of the candidate settings.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Candidate {
+    /// The stringified optimizer settings.
+    pub optimizer_settings: String,
+    /// The bytecode size in bytes, if the compilation succeeded.
+    pub bytecode_size: Option<usize>,
+    /// The estimated cycles, if requested by the objective and the compilation succeeded.
+    pub estimated_cycles: Option<u64>,
+    /// The reason the candidate was rejected.
+    pub rejection: Option<String>,
+    /// Whether the candidate was selected.
+    pub is_selected: bool,
+}
+
+impl Candidate {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        optimizer_settings: String,
+        bytecode_size: Option<usize>,
+        estimated_cycles: Option<u64>,
+        rejection: Option<String>,
+    ) -> Self {
+        Self {
+            optimizer_settings,
+            bytecode_size,
+            estimated_cycles,
+            rejection,
+            is_selected: false,
+        }
+    }
+}
+
+impl std::fmt::Display for Candidate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.optimizer_settings)?;
+        if let Some(bytecode_size) = self.bytecode_size {
+            write!(f, ": {bytecode_size} bytes")?;
+        }
+        if let Some(estimated_cycles) = self.estimated_cycles {
+            write!(f, ", ~{estimated_cycles} cycles")?;
+        }
+        if let Some(rejection) = self.rejection.as_ref() {
+            write!(f, " (rejected: {rejection})")?;
+        }
+        if self.is_selected {
+            write!(f, " (selected)")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+//!
+//! The optimization auto-tuning.
+//!
+
+pub mod candidate;
+pub mod objective;
+
+use crate::build::contract::Contract as ContractBuild;
+use crate::error::Error;
+
+use self::candidate::Candidate;
+use self::objective::Objective;
+
+///
+/// The optimization auto-tuning.
+///
+/// Each contract is compiled with every candidate optimization level, and the best build
+/// according to the objective is selected.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Tuning {
+    /// The objective the candidates are compared by.
+    pub objective: Objective,
+    /// The maximum bytecode size in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_budget: Option<usize>,
+}
+
+impl Tuning {
+    /// The candidate optimization levels, in the order of preference among equal builds.
+    pub const LEVELS: [char; 5] = ['3', '2', '1', 's', 'z'];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(objective: Objective, size_budget: Option<usize>) -> Self {
+        Self {
+            objective,
+            size_budget,
+        }
+    }
+
+    ///
+    /// Returns the candidate optimizer settings.
+    ///
+    /// The LLVM debugging flags are taken from the global `optimizer_settings`.
+    ///
+    pub fn candidates(
+        &self,
+        optimizer_settings: &era_compiler_llvm_context::OptimizerSettings,
+    ) -> Vec<era_compiler_llvm_context::OptimizerSettings> {
+        Self::LEVELS
+            .iter()
+            .map(|level| {
+                let mut settings =
+                    era_compiler_llvm_context::OptimizerSettings::try_from_cli(*level)
+                        .expect("Always valid");
+                settings.is_verify_each_enabled = optimizer_settings.is_verify_each_enabled;
+                settings.is_debug_logging_enabled = optimizer_settings.is_debug_logging_enabled;
+                settings
+            })
+            .collect()
+    }
+
+    ///
+    /// Selects the best build among the candidate results.
+    ///
+    /// The tried candidates are attached to the selected build.
+    ///
+    pub fn select(
+        &self,
+        results: Vec<(
+            era_compiler_llvm_context::OptimizerSettings,
+            anyhow::Result<ContractBuild>,
+        )>,
+    ) -> anyhow::Result<(era_compiler_llvm_context::OptimizerSettings, ContractBuild)> {
+        let mut candidates = Vec::with_capacity(results.len());
+        let mut selected = None;
+        for (optimizer_settings, result) in results.into_iter() {
+            let build = match result {
+                Ok(build) => build,
+                Err(error) => {
+                    let error = error.to_string();
+                    let reason = error
+                        .lines()
+                        .rev()
+                        .find(|line| !line.trim().is_empty())
+                        .unwrap_or("compilation failed")
+                        .trim()
+                        .to_owned();
+                    candidates.push(Candidate::new(
+                        optimizer_settings.to_string(),
+                        None,
+                        None,
+                        Some(reason),
+                    ));
+                    continue;
+                }
+            };
+
+            let bytecode_size = build.build.bytecode.len();
+            let estimated_cycles = match self.objective {
+                Objective::Size => None,
+                Objective::Cycles => Some(Self::estimated_cycles(&build)),
+            };
+            let rejection = match self.size_budget {
+                Some(size_budget) if bytecode_size > size_budget => {
+                    Some(format!("exceeds the size budget of {size_budget} bytes"))
+                }
+                _ => None,
+            };
+            let is_rejected = rejection.is_some();
+            candidates.push(Candidate::new(
+                optimizer_settings.to_string(),
+                Some(bytecode_size),
+                estimated_cycles,
+                rejection,
+            ));
+            if is_rejected {
+                continue;
+            }
+
+            let key = (estimated_cycles.unwrap_or_default(), bytecode_size);
+            if selected
+                .as_ref()
+                .map(|(_, selected_key, _, _)| key < *selected_key)
+                .unwrap_or(true)
+            {
+                selected = Some((candidates.len() - 1, key, optimizer_settings, build));
+            }
+        }
+
+        let (index, _, optimizer_settings, mut build) = match selected {
+            Some(selected) => selected,
+            None => {
+                let message = format!(
+                    "None of the optimization candidates is suitable:\n{}",
+                    candidates
+                        .iter()
+                        .map(|candidate| format!("    {candidate}"))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                );
+                let smallest_size = candidates
+                    .iter()
+                    .filter_map(|candidate| candidate.bytecode_size)
+                    .min();
+                return Err(match (self.size_budget, smallest_size) {
+                    (Some(limit), Some(size)) => Error::SizeLimit {
+                        size,
+                        limit,
+                        message,
+                    },
+                    _ => Error::Compilation { message },
+                }
+                .into());
+            }
+        };
+        candidates[index].is_selected = true;
+        build.optimization_candidates = Some(candidates);
+        Ok((optimizer_settings, build))
+    }
+
+    ///
+    /// Returns the estimated cycles of the build, summed over its external functions.
+    ///
+    /// The worst case is used for functions where it is bounded, and the best case otherwise.
+    ///
+    fn estimated_cycles(build: &ContractBuild) -> u64 {
+        build
+            .ergs_estimates
+            .as_ref()
+            .map(|estimates| {
+                estimates
+                    .values()
+                    .map(|estimate| {
+                        estimate
+                            .worst_case
+                            .as_ref()
+                            .unwrap_or(&estimate.best_case)
+                            .cycles
+                    })
+                    .fold(0, u64::saturating_add)
+            })
+            .unwrap_or_default()
+    }
+}
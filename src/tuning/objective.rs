@@ -0,0 +1,41 @@
+//!
+//! The optimization auto-tuning objective.
+//!
+
+use std::str::FromStr;
+
+///
+/// The optimization auto-tuning objective.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Objective {
+    /// The smallest bytecode.
+    #[default]
+    Size,
+    /// The smallest estimated cycles within the size budget.
+    Cycles,
+}
+
+impl FromStr for Objective {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> anyhow::Result<Self> {
+        Ok(match string {
+            "size" => Self::Size,
+            "cycles" => Self::Cycles,
+            string => anyhow::bail!(
+                "Unknown optimization objective `{string}`. Available objectives: `size`, `cycles`."
+            ),
+        })
+    }
+}
+
+impl std::fmt::Display for Objective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Size => write!(f, "size"),
+            Self::Cycles => write!(f, "cycles"),
+        }
+    }
+}
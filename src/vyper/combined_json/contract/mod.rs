@@ -6,6 +6,8 @@ pub mod warning;
 
 use std::collections::BTreeMap;
 
+use crate::tuning::candidate::Candidate as TuningCandidate;
+
 use self::warning::Warning;
 
 ///
@@ -15,8 +17,15 @@ use self::warning::Warning;
 pub struct Contract {
     /// The bytecode hexadecimal string.
     pub bytecode: String,
-    /// The same as above. Kept for legacy reasons.
+    /// The runtime bytecode hexadecimal string.
+    /// On EraVM, it is the same as above, as the deploy and runtime code are not separated.
     pub bytecode_runtime: String,
+    /// The upstream EVM deploy bytecode hexadecimal string built by `vyper`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_bytecode: Option<String>,
+    /// The upstream EVM runtime bytecode hexadecimal string built by `vyper`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_bytecode_runtime: Option<String>,
 
     /// The `vyper` LLL IR JSON output.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,6 +58,9 @@ pub struct Contract {
     /// The compilation warnings.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub warnings: Option<Vec<Warning>>,
+    /// The optimization candidates tried by auto-tuning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimization_candidates: Option<Vec<TuningCandidate>>,
 }
 
 impl Contract {
@@ -4,11 +4,13 @@
 
 pub mod combined_json;
 pub mod selector;
+pub mod settings;
 pub mod standard_json;
 pub mod version;
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::OnceLock;
 use std::sync::RwLock;
@@ -17,11 +19,16 @@ use rayon::iter::IndexedParallelIterator;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
+use crate::error::source_location::SourceLocation;
+use crate::error::Error;
+use crate::project::contract::vyper::venom::program::Program as VenomProgram;
+use crate::project::contract::vyper::venom::Venom;
 use crate::project::contract::vyper::Contract as VyperContract;
 use crate::project::contract::Contract;
 use crate::project::Project;
 
 use self::selector::Selector;
+use self::settings::Settings;
 use self::standard_json::input::settings::optimize::Optimize as StandardJsonInputSettingsOptimize;
 use self::standard_json::input::Input as StandardJsonInput;
 use self::standard_json::output::Output as StandardJsonOutput;
@@ -60,6 +67,10 @@ impl Compiler {
     pub const FIRST_VERSION_ENABLE_DECIMALS_SUPPORT: semver::Version =
         semver::Version::new(0, 4, 0);
 
+    /// The first version supporting `--experimental-codegen`.
+    pub const FIRST_VERSION_EXPERIMENTAL_CODEGEN_SUPPORT: semver::Version =
+        semver::Version::new(0, 4, 0);
+
     /// The first version returning absolute paths.
     pub const FIRST_VERSION_ABSOLUTE_PATHS: semver::Version = semver::Version::new(0, 4, 0);
 
@@ -104,34 +115,14 @@ impl Compiler {
         mut input: StandardJsonInput,
     ) -> anyhow::Result<StandardJsonOutput> {
         let mut command = std::process::Command::new(self.executable.as_str());
-        command.stdin(std::process::Stdio::piped());
-        command.stdout(std::process::Stdio::piped());
-        command.stderr(std::process::Stdio::piped());
         command.arg("--standard-json");
 
         if self.version.default >= Self::FIRST_VERSION_OPTIMIZER_UNUSABLE {
             input.settings.optimize = StandardJsonInputSettingsOptimize::None;
         }
 
-        let mut process = command.spawn().map_err(|error| {
-            anyhow::anyhow!("{} subprocess spawning error: {error:?}", self.executable)
-        })?;
-        let stdin = process.stdin.take().ok_or_else(|| {
-            anyhow::anyhow!("{:?} subprocess stdin getting error", self.executable)
-        })?;
-        serde_json::to_writer(stdin, &input).map_err(|error| {
-            anyhow::anyhow!(
-                "{} subprocess stdin writing error: {error:?}",
-                self.executable
-            )
-        })?;
-
-        let result = process.wait_with_output().map_err(|error| {
-            anyhow::anyhow!(
-                "{} subprocess output reading error: {error:?}",
-                self.executable
-            )
-        })?;
+        let input_json = serde_json::to_vec(&input).expect("Always valid");
+        let result = crate::supervisor::output(command, Some(input_json))?;
         let mut output = match era_compiler_common::deserialize_from_slice::<StandardJsonOutput>(
             result.stdout.as_slice(),
         ) {
@@ -145,11 +136,11 @@ impl Compiler {
             }
         };
         if !result.status.success() {
-            anyhow::bail!(
-                "{} error: {}",
-                self.executable,
-                String::from_utf8_lossy(result.stderr.as_slice())
-            );
+            return Err(Error::vyper(
+                self.executable.as_str(),
+                String::from_utf8_lossy(result.stderr.as_slice()).to_string(),
+            )
+            .into());
         }
 
         for (full_path, source) in input.sources.into_iter() {
@@ -159,7 +150,7 @@ impl Compiler {
                 ..last_dot_position.unwrap_or(full_path.len())];
 
             Self::check_unsupported(source.content.as_str())
-                .map_err(|error| anyhow::anyhow!("Contract `{full_path}`: {error}"))?;
+                .map_err(|error| Error::contract(full_path.as_str(), error))?;
 
             output
                 .contracts
@@ -195,9 +186,34 @@ impl Compiler {
         enable_decimals: bool,
         search_paths: Option<Vec<String>>,
         optimize: bool,
+        experimental_codegen: bool,
     ) -> anyhow::Result<Project> {
         paths.sort();
 
+        if experimental_codegen
+            && self.version.default < Self::FIRST_VERSION_EXPERIMENTAL_CODEGEN_SUPPORT
+        {
+            anyhow::bail!(
+                "The experimental code generator is only available in `vyper` v{} and later",
+                Self::FIRST_VERSION_EXPERIMENTAL_CODEGEN_SUPPORT
+            );
+        }
+
+        for selector in selection.iter() {
+            if let Some(first_version) = selector.first_vyper_version() {
+                if self.version.default < first_version {
+                    anyhow::bail!(
+                        "Selection flag `{selector}` is only available in `vyper` v{first_version} and later"
+                    );
+                }
+            }
+        }
+        let forwarded_selection: Vec<Selector> = selection
+            .iter()
+            .filter(|selector| selector.is_forwarded_from_vyper())
+            .copied()
+            .collect();
+
         let mut vyper_selection = selection.to_owned();
         vyper_selection.retain(|flag| flag.is_requested_from_vyper());
         vyper_selection.extend(
@@ -212,18 +228,12 @@ impl Compiler {
             .collect::<Vec<&Selector>>(),
         );
 
-        let mut command = std::process::Command::new(self.executable.as_str());
-        if let Some(evm_version) = evm_version {
-            command.arg("--evm-version");
-            command.arg(evm_version.to_string());
-        }
-        if enable_decimals && self.version.default >= Self::FIRST_VERSION_ENABLE_DECIMALS_SUPPORT {
-            command.arg("--enable-decimals");
-        }
-        for search_path in search_paths.unwrap_or_default() {
-            command.arg("-p");
-            command.arg(search_path);
-        }
+        let mut command = self.command(
+            evm_version,
+            enable_decimals,
+            search_paths.as_deref().unwrap_or_default(),
+            optimize,
+        );
         command.arg("-f");
         command.arg(
             vyper_selection
@@ -232,23 +242,15 @@ impl Compiler {
                 .collect::<Vec<String>>()
                 .join(","),
         );
-        if self.version.default >= Self::FIRST_VERSION_OPTIMIZER_UNUSABLE {
-            command.arg("--optimize");
-            command.arg("none");
-        } else if !optimize {
-            command.arg("--no-optimize");
-        }
         command.args(paths.as_slice());
 
-        let output = command.output().map_err(|error| {
-            anyhow::anyhow!("{} subprocess error: {:?}", self.executable, error)
-        })?;
+        let output = crate::supervisor::output(command, None)?;
         if !output.status.success() {
-            anyhow::bail!(
-                "{} error: {}",
-                self.executable,
-                String::from_utf8_lossy(output.stderr.as_slice()).to_string()
-            );
+            return Err(Error::vyper(
+                self.executable.as_str(),
+                String::from_utf8_lossy(output.stderr.as_slice()).to_string(),
+            )
+            .into());
         }
 
         let stdout = String::from_utf8_lossy(output.stdout.as_slice()).to_string();
@@ -258,15 +260,15 @@ impl Compiler {
             .zip(lines.into_par_iter().chunks(vyper_selection.len()))
             .map(|(path, group)| {
                 let path_str = path.to_string_lossy().to_string();
-                let source_code = match std::fs::read_to_string(path).map_err(|error| {
-                    anyhow::anyhow!("Source code file `{path_str}` reading error: {error}")
+                let source_code = match std::fs::read_to_string(path.as_path()).map_err(|error| {
+                    anyhow::Error::from(Error::io(path.as_path(), "reading", error))
                 }) {
                     Ok(source_code) => source_code,
                     Err(error) => return (path_str, Err(error)),
                 };
 
                 if let Err(error) = Self::check_unsupported(source_code.as_str()) {
-                    let error = anyhow::anyhow!("Contract `{path_str}`: {error}");
+                    let error = anyhow::Error::from(Error::contract(path_str.as_str(), error));
                     return (path_str, Err(error));
                 }
 
@@ -278,6 +280,47 @@ impl Compiler {
                 )
                 .map_err(|error| {
                     anyhow::anyhow!("Contract `{path_str}` JSON output parsing: {error}")
+                })
+                .and_then(|mut contract| {
+                    if experimental_codegen {
+                        let venom = self
+                            .venom(
+                                path.as_path(),
+                                evm_version,
+                                enable_decimals,
+                                search_paths.as_deref().unwrap_or_default(),
+                                optimize,
+                            )
+                            .map_err(|error| Error::contract(path_str.as_str(), error))?;
+                        contract.venom = Some(venom);
+                    }
+                    for selector in forwarded_selection.iter() {
+                        let output = self
+                            .forward(
+                                path.as_path(),
+                                *selector,
+                                evm_version,
+                                enable_decimals,
+                                search_paths.as_deref().unwrap_or_default(),
+                                optimize,
+                            )
+                            .map_err(|error| Error::contract(path_str.as_str(), error))?;
+                        contract.forwarded_outputs.insert(*selector, output);
+                    }
+                    if selection.iter().any(Selector::is_evm_bytecode) {
+                        let (deploy_code, runtime_code) = self
+                            .evm_bytecode(
+                                path.as_path(),
+                                evm_version,
+                                enable_decimals,
+                                search_paths.as_deref().unwrap_or_default(),
+                                optimize,
+                            )
+                            .map_err(|error| Error::contract(path_str.as_str(), error))?;
+                        contract.evm_bytecode = Some(deploy_code);
+                        contract.evm_bytecode_runtime = Some(runtime_code);
+                    }
+                    Ok(contract)
                 });
 
                 (path_str, contract_result)
@@ -291,11 +334,144 @@ impl Compiler {
                     Ok::<BTreeMap<String, Contract>, anyhow::Error>(accumulator)
                 })?;
 
-        let project = Project::new(version.to_owned(), contracts, selection.to_owned());
+        let mut project = Project::new(version.to_owned(), contracts, selection.to_owned());
+        project.vyper_settings = Some(Settings::new(
+            enable_decimals,
+            search_paths.unwrap_or_default(),
+            optimize,
+            experimental_codegen,
+        ));
 
         Ok(project)
     }
 
+    ///
+    /// Returns the Venom IR of the contract at `path` produced by the experimental code generator.
+    ///
+    /// The deploy and runtime code are requested separately, as Venom IR is printed
+    /// as multiple lines.
+    ///
+    pub fn venom(
+        &self,
+        path: &Path,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        enable_decimals: bool,
+        search_paths: &[String],
+        optimize: bool,
+    ) -> anyhow::Result<Venom> {
+        let mut programs = Vec::with_capacity(2);
+        for format in ["bb", "bb_runtime"] {
+            let mut command = self.command(evm_version, enable_decimals, search_paths, optimize);
+            command.arg("--experimental-codegen");
+            command.arg("-f");
+            command.arg(format);
+            command.arg(path);
+
+            let output = crate::supervisor::output(command, None)?;
+            if !output.status.success() {
+                return Err(Error::vyper(
+                    self.executable.as_str(),
+                    String::from_utf8_lossy(output.stderr.as_slice()).to_string(),
+                )
+                .into());
+            }
+
+            let program = String::from_utf8_lossy(output.stdout.as_slice())
+                .parse::<VenomProgram>()
+                .map_err(|error| anyhow::anyhow!("`{format}` output: {error}"))?;
+            programs.push(program);
+        }
+
+        let runtime_code = programs.pop().expect("Always exists");
+        let deploy_code = programs.pop().expect("Always exists");
+        Ok(Venom::new(deploy_code, runtime_code))
+    }
+
+    ///
+    /// Returns the chain-independent output of the contract at `path` as printed by `vyper`.
+    ///
+    /// The output is requested separately, as it may span multiple lines.
+    ///
+    pub fn forward(
+        &self,
+        path: &Path,
+        selector: Selector,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        enable_decimals: bool,
+        search_paths: &[String],
+        optimize: bool,
+    ) -> anyhow::Result<String> {
+        let mut command = self.command(evm_version, enable_decimals, search_paths, optimize);
+        if selector.is_experimental_codegen() {
+            command.arg("--experimental-codegen");
+        }
+        command.arg("-f");
+        command.arg(selector.to_string());
+        command.arg(path);
+
+        let output = crate::supervisor::output(command, None)?;
+        if !output.status.success() {
+            return Err(Error::vyper(
+                self.executable.as_str(),
+                String::from_utf8_lossy(output.stderr.as_slice()).to_string(),
+            )
+            .into());
+        }
+
+        Ok(String::from_utf8_lossy(output.stdout.as_slice()).to_string())
+    }
+
+    ///
+    /// Returns the upstream EVM deploy and runtime bytecode of the contract at `path`.
+    ///
+    /// Unlike the other calls, the `vyper` optimizer is not forced off here, so the bytecode
+    /// is the same as the one built by `vyper` itself with the same settings.
+    ///
+    pub fn evm_bytecode(
+        &self,
+        path: &Path,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        enable_decimals: bool,
+        search_paths: &[String],
+        optimize: bool,
+    ) -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
+        let mut command = self.upstream_command(evm_version, enable_decimals, search_paths);
+        if !optimize {
+            if self.version.default >= Self::FIRST_VERSION_OPTIMIZER_UNUSABLE {
+                command.arg("--optimize");
+                command.arg("none");
+            } else {
+                command.arg("--no-optimize");
+            }
+        }
+        command.arg("-f");
+        command.arg("bytecode,bytecode_runtime");
+        command.arg(path);
+
+        let output = crate::supervisor::output(command, None)?;
+        if !output.status.success() {
+            return Err(Error::vyper(
+                self.executable.as_str(),
+                String::from_utf8_lossy(output.stderr.as_slice()).to_string(),
+            )
+            .into());
+        }
+
+        let stdout = String::from_utf8_lossy(output.stdout.as_slice()).to_string();
+        let mut bytecodes = stdout.lines().map(|line| {
+            let line = line.trim();
+            hex::decode(line.strip_prefix("0x").unwrap_or(line))
+                .map_err(|error| anyhow::anyhow!("Bytecode `{line}` parsing error: {error}"))
+        });
+        let deploy_code = bytecodes
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("EVM deploy bytecode not found in `vyper` output"))??;
+        let runtime_code = bytecodes
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("EVM runtime bytecode not found in `vyper` output"))??;
+        Ok((deploy_code, runtime_code))
+    }
+
     ///
     /// Checks for unsupported code is a Vyper source code file.
     ///
@@ -304,16 +480,64 @@ impl Compiler {
             crate::r#const::FORBIDDEN_FUNCTION_NAME_CREATE_COPY_OF,
             crate::r#const::FORBIDDEN_FUNCTION_NAME_RAW_CREATE,
         ] {
-            if source_code.contains(function) {
-                return Err(anyhow::anyhow!(
-                    "Built-in function `{function}` is not supported"
-                ));
+            if let Some(offset) = source_code.find(function) {
+                return Err(Error::Unsupported {
+                    message: format!("Built-in function `{function}` is not supported"),
+                    location: Some(SourceLocation::from_offset(source_code, offset)),
+                    lll_function: None,
+                }
+                .into());
             }
         }
 
         Ok(())
     }
 
+    ///
+    /// Creates the `vyper` command with the options shared by all output formats.
+    ///
+    fn command(
+        &self,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        enable_decimals: bool,
+        search_paths: &[String],
+        optimize: bool,
+    ) -> std::process::Command {
+        let mut command = self.upstream_command(evm_version, enable_decimals, search_paths);
+        if self.version.default >= Self::FIRST_VERSION_OPTIMIZER_UNUSABLE {
+            command.arg("--optimize");
+            command.arg("none");
+        } else if !optimize {
+            command.arg("--no-optimize");
+        }
+        command
+    }
+
+    ///
+    /// Creates the `vyper` command with the options shared by all output formats, except for the
+    /// optimizer ones.
+    ///
+    fn upstream_command(
+        &self,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        enable_decimals: bool,
+        search_paths: &[String],
+    ) -> std::process::Command {
+        let mut command = std::process::Command::new(self.executable.as_str());
+        if let Some(evm_version) = evm_version {
+            command.arg("--evm-version");
+            command.arg(evm_version.to_string());
+        }
+        if enable_decimals && self.version.default >= Self::FIRST_VERSION_ENABLE_DECIMALS_SUPPORT {
+            command.arg("--enable-decimals");
+        }
+        for search_path in search_paths.iter() {
+            command.arg("-p");
+            command.arg(search_path);
+        }
+        command
+    }
+
     ///
     /// Returns the global shared array of `vyper` executables.
     ///
@@ -328,15 +552,13 @@ impl Compiler {
     fn parse_version(executable: &str) -> anyhow::Result<Version> {
         let mut command = std::process::Command::new(executable);
         command.arg("--version");
-        let output = command
-            .output()
-            .map_err(|error| anyhow::anyhow!("{executable} subprocess error: {error:?}"))?;
+        let output = crate::supervisor::output(command, None)?;
         if !output.status.success() {
-            anyhow::bail!(
-                "{} error: {}",
+            return Err(Error::vyper(
                 executable,
-                String::from_utf8_lossy(output.stderr.as_slice()).to_string()
-            );
+                String::from_utf8_lossy(output.stderr.as_slice()).to_string(),
+            )
+            .into());
         }
 
         let stdout = String::from_utf8_lossy(output.stdout.as_slice());
@@ -7,12 +7,16 @@ use std::str::FromStr;
 ///
 /// The `vyper` output selector flag.
 ///
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
 pub enum Selector {
     /// The combined JSON.
     CombinedJson,
     /// The JSON LLL IR.
     IRJson,
+    /// The LLL IR s-expressions.
+    IR,
     /// The AST.
     AST,
     /// The ABI data.
@@ -25,9 +29,47 @@ pub enum Selector {
     UserDocumentation,
     /// The developer documentation.
     DeveloperDocumentation,
+    /// The upstream EVM deploy bytecode.
+    EVMBytecode,
+    /// The upstream EVM runtime bytecode.
+    EVMBytecodeRuntime,
+
+    /// The contract interface in Vyper syntax.
+    Interface,
+    /// The external interface in Vyper syntax.
+    ExternalInterface,
+    /// The AST annotated with types.
+    AnnotatedAST,
+    /// The contract metadata with function info.
+    Metadata,
+    /// The runtime LLL IR s-expressions.
+    IRRuntime,
+    /// The Venom IR basic blocks.
+    BasicBlocks,
+    /// The runtime Venom IR basic blocks.
+    BasicBlocksRuntime,
+    /// The Venom IR control flow graph.
+    ControlFlowGraph,
+    /// The runtime Venom IR control flow graph.
+    ControlFlowGraphRuntime,
+    /// The integrity hash of the sources.
+    Integrity,
+    /// The `solc`-style standard JSON input.
+    SolcJson,
+    /// The archive with all sources and settings.
+    Archive,
+
+    /// The EraVM bytecode requested as the `vyper` deploy bytecode.
+    Bytecode,
+    /// The EraVM bytecode requested as the `vyper` runtime bytecode.
+    BytecodeRuntime,
 
     /// The EraVM assembly.
     EraVMAssembly,
+    /// The static ergs estimates of external functions.
+    ErgsEstimates,
+    /// The call graph of functions.
+    CallGraph,
     /// The project metadata JSON.
     ProjectMetadata,
 }
@@ -35,14 +77,85 @@ pub enum Selector {
 impl Selector {
     ///
     /// Whether the data can be retrieved from the `vyper` output.
-    /// If it is not, it must be generated by `zkvyper`.
+    /// If it is not, it must be generated by `zkvyper` or requested with a separate `vyper` call.
     ///
     pub fn is_requested_from_vyper(&self) -> bool {
         !matches!(
             self,
-            Self::CombinedJson | Self::EraVMAssembly | Self::ProjectMetadata
+            Self::CombinedJson
+                | Self::IR
+                | Self::EVMBytecode
+                | Self::EVMBytecodeRuntime
+                | Self::Bytecode
+                | Self::BytecodeRuntime
+                | Self::EraVMAssembly
+                | Self::ErgsEstimates
+                | Self::CallGraph
+                | Self::ProjectMetadata
+        ) && !self.is_forwarded_from_vyper()
+    }
+
+    ///
+    /// Whether the chain-independent output is forwarded from `vyper` as is.
+    ///
+    /// Such output may span multiple lines, so it is requested with a separate `vyper` call.
+    ///
+    pub fn is_forwarded_from_vyper(&self) -> bool {
+        matches!(
+            self,
+            Self::Interface
+                | Self::ExternalInterface
+                | Self::AnnotatedAST
+                | Self::Metadata
+                | Self::IRRuntime
+                | Self::BasicBlocks
+                | Self::BasicBlocksRuntime
+                | Self::ControlFlowGraph
+                | Self::ControlFlowGraphRuntime
+                | Self::Integrity
+                | Self::SolcJson
+                | Self::Archive
+        )
+    }
+
+    ///
+    /// Whether the output is the upstream EVM bytecode.
+    ///
+    /// It is requested with a separate `vyper` call with the user's optimizer settings, as the
+    /// LLL IR for `zkvyper` is always requested with the `vyper` optimizer disabled.
+    ///
+    pub fn is_evm_bytecode(&self) -> bool {
+        matches!(self, Self::EVMBytecode | Self::EVMBytecodeRuntime)
+    }
+
+    ///
+    /// Whether the output is produced by the `vyper` experimental code generator.
+    ///
+    pub fn is_experimental_codegen(&self) -> bool {
+        matches!(
+            self,
+            Self::BasicBlocks
+                | Self::BasicBlocksRuntime
+                | Self::ControlFlowGraph
+                | Self::ControlFlowGraphRuntime
         )
     }
+
+    ///
+    /// Returns the first `vyper` version supporting the output, if it is not supported by all.
+    ///
+    pub fn first_vyper_version(&self) -> Option<semver::Version> {
+        match self {
+            Self::BasicBlocks
+            | Self::BasicBlocksRuntime
+            | Self::ControlFlowGraph
+            | Self::ControlFlowGraphRuntime
+            | Self::Integrity
+            | Self::SolcJson
+            | Self::Archive => Some(semver::Version::new(0, 4, 0)),
+            _ => None,
+        }
+    }
 }
 
 impl FromStr for Selector {
@@ -52,14 +165,43 @@ impl FromStr for Selector {
         Ok(match string {
             "combined_json" => Self::CombinedJson,
             "ir_json" => Self::IRJson,
+            "ir" => Self::IR,
             "ast" => Self::AST,
             "abi" => Self::ABI,
             "method_identifiers" => Self::MethodIdentifiers,
             "layout" => Self::Layout,
             "userdoc" => Self::UserDocumentation,
             "devdoc" => Self::DeveloperDocumentation,
+            "evm_bytecode" => Self::EVMBytecode,
+            "evm_bytecode_runtime" => Self::EVMBytecodeRuntime,
+            "interface" => Self::Interface,
+            "external_interface" => Self::ExternalInterface,
+            "annotated_ast" => Self::AnnotatedAST,
+            "metadata" => Self::Metadata,
+            "ir_runtime" => Self::IRRuntime,
+            "bb" => Self::BasicBlocks,
+            "bb_runtime" => Self::BasicBlocksRuntime,
+            "cfg" => Self::ControlFlowGraph,
+            "cfg_runtime" => Self::ControlFlowGraphRuntime,
+            "integrity" => Self::Integrity,
+            "solc_json" => Self::SolcJson,
+            "archive" => Self::Archive,
+            "bytecode" => Self::Bytecode,
+            "bytecode_runtime" => Self::BytecodeRuntime,
             "eravm_assembly" => Self::EraVMAssembly,
+            "ergs_estimates" => Self::ErgsEstimates,
+            "call_graph" => Self::CallGraph,
             "project_metadata" => Self::ProjectMetadata,
+
+            "opcodes" | "opcodes_runtime" | "asm" => anyhow::bail!(
+                "Selection flag `{string}` is EVM-specific. Use `eravm_assembly` to get the EraVM assembly."
+            ),
+            "source_map" | "source_map_runtime" => anyhow::bail!(
+                "Selection flag `{string}` is EVM-specific, and EraVM source maps are not supported."
+            ),
+            "blueprint_bytecode" => anyhow::bail!(
+                "Selection flag `{string}` is EVM-specific, as EIP-5202 blueprints are not supported on EraVM."
+            ),
             string => anyhow::bail!("Unknown selection flag `{string}`"),
         })
     }
@@ -70,13 +212,32 @@ impl std::fmt::Display for Selector {
         match self {
             Self::CombinedJson => write!(f, "combined_json"),
             Self::IRJson => write!(f, "ir_json"),
+            Self::IR => write!(f, "ir"),
             Self::AST => write!(f, "ast"),
             Self::ABI => write!(f, "abi"),
             Self::MethodIdentifiers => write!(f, "method_identifiers"),
             Self::Layout => write!(f, "layout"),
             Self::UserDocumentation => write!(f, "userdoc"),
             Self::DeveloperDocumentation => write!(f, "devdoc"),
+            Self::EVMBytecode => write!(f, "evm_bytecode"),
+            Self::EVMBytecodeRuntime => write!(f, "evm_bytecode_runtime"),
+            Self::Interface => write!(f, "interface"),
+            Self::ExternalInterface => write!(f, "external_interface"),
+            Self::AnnotatedAST => write!(f, "annotated_ast"),
+            Self::Metadata => write!(f, "metadata"),
+            Self::IRRuntime => write!(f, "ir_runtime"),
+            Self::BasicBlocks => write!(f, "bb"),
+            Self::BasicBlocksRuntime => write!(f, "bb_runtime"),
+            Self::ControlFlowGraph => write!(f, "cfg"),
+            Self::ControlFlowGraphRuntime => write!(f, "cfg_runtime"),
+            Self::Integrity => write!(f, "integrity"),
+            Self::SolcJson => write!(f, "solc_json"),
+            Self::Archive => write!(f, "archive"),
+            Self::Bytecode => write!(f, "bytecode"),
+            Self::BytecodeRuntime => write!(f, "bytecode_runtime"),
             Self::EraVMAssembly => write!(f, "eravm_assembly"),
+            Self::ErgsEstimates => write!(f, "ergs_estimates"),
+            Self::CallGraph => write!(f, "call_graph"),
             Self::ProjectMetadata => write!(f, "project_metadata"),
         }
     }
@@ -0,0 +1,40 @@
+//!
+//! The `vyper` settings.
+//!
+
+///
+/// The `vyper` settings the sources are compiled with.
+///
+/// They are recorded in the project metadata, so the build can be reproduced from it.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Settings {
+    /// Whether decimals are enabled.
+    pub enable_decimals: bool,
+    /// The `vyper` search paths.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub search_paths: Vec<String>,
+    /// Whether the `vyper` optimizer is enabled.
+    pub optimize: bool,
+    /// Whether the experimental code generator is used.
+    pub experimental_codegen: bool,
+}
+
+impl Settings {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        enable_decimals: bool,
+        search_paths: Vec<String>,
+        optimize: bool,
+        experimental_codegen: bool,
+    ) -> Self {
+        Self {
+            enable_decimals,
+            search_paths,
+            optimize,
+            experimental_codegen,
+        }
+    }
+}
@@ -23,6 +23,12 @@ pub struct Arguments {
     /// LLVM IR mode currently supports only a single file.
     pub input_paths: Vec<PathBuf>,
 
+    /// Read the project configuration from the specified file.
+    /// By default, `zkvyper.toml` is looked up in the current directory and its ancestors.
+    /// Options passed on the command line take precedence over the configuration file.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
     /// Create one file per component and contract/file at the specified directory, if given.
     #[arg(short, long)]
     pub output_dir: Option<PathBuf>,
@@ -31,15 +37,50 @@ pub struct Arguments {
     #[structopt(long)]
     pub overwrite: bool,
 
-    /// Set the optimization parameter -O[0 | 1 | 2 | 3 | s | z].
+    /// Mirror the source directory structure relative to this path in `--output-dir`.
+    /// By default, the deepest directory containing all sources is used.
+    #[arg(long)]
+    pub base_path: Option<PathBuf>,
+
+    /// Concatenate the JSON output into a single extension-less file named after the source,
+    /// one JSON per line, instead of writing one file per output with a manifest.
+    /// Used together with `--output-dir`, for compatibility with the legacy layout.
+    #[structopt(long)]
+    pub legacy_output_layout: bool,
+
+    /// Write one artifact JSON per contract in the layout of the ZKsync tooling.
+    /// Available layouts: `foundry`, `hardhat`.
+    /// Used together with `--output-dir`, instead of one file per selected output.
+    #[arg(long)]
+    pub artifacts: Option<era_compiler_vyper::ArtifactFormat>,
+
+    /// Set the optimization parameter -O[0 | 1 | 2 | 3 | s | z | auto].
     /// Use `3` for best performance and `z` for minimal size.
+    /// Use `auto` to try several levels for each contract and select the best one by
+    /// `--optimization-objective`.
     #[arg(short = 'O', long)]
-    pub optimization: Option<char>,
+    pub optimization: Option<era_compiler_vyper::OptimizationLevel>,
+
+    /// Set the objective of `--optimization auto`.
+    /// Available objectives: `size`, `cycles`.
+    /// The default is `size`.
+    #[arg(long)]
+    pub optimization_objective: Option<era_compiler_vyper::TuningObjective>,
+
+    /// Set the maximum bytecode size in bytes for the `cycles` objective of `--optimization auto`.
+    #[arg(long)]
+    pub optimization_size_budget: Option<usize>,
 
     /// Try to recompile with -Oz if the bytecode is too large.
     #[arg(long = "fallback-Oz")]
     pub fallback_to_optimizing_for_size: bool,
 
+    /// Override the optimization level of contracts matching a glob pattern, e.g. `Pool.vy=z`.
+    /// Patterns containing `/` match the contract path, other patterns match the file name.
+    /// Overrides are applied in order, so later ones take precedence.
+    #[arg(long, num_args = 1..)]
+    pub optimization_override: Option<Vec<era_compiler_vyper::OptimizationOverride>>,
+
     /// Pass arbitrary space-separated options to LLVM.
     /// The argument must be a single quoted string following a `=` separator.
     /// Example: `--llvm-options='-eravm-jump-table-density-threshold=10'`.
@@ -47,10 +88,16 @@ pub struct Arguments {
     pub llvm_options: Option<String>,
 
     /// Specify the path to the `vyper` executable. By default, the one in `${PATH}` is used.
-    /// In LLVM IR and EraVM assembly modes, `vyper` executable is unused.
+    /// In LLL IR, LLVM IR and EraVM assembly modes, `vyper` executable is unused.
     #[arg(long)]
     pub vyper: Option<String>,
 
+    /// Set the target machine.
+    /// Available targets: `eravm`, `evm`.
+    /// The default is `eravm`.
+    #[arg(long)]
+    pub target: Option<era_compiler_common::Target>,
+
     /// The EVM version to generate IR for.
     #[arg(long)]
     pub evm_version: Option<era_compiler_common::EVMVersion>,
@@ -64,12 +111,25 @@ pub struct Arguments {
     #[arg(long)]
     pub enable_decimals: bool,
 
+    /// Translates the Venom IR of the `vyper` experimental code generator instead of LLL IR.
+    /// Only available in `vyper` v0.4.0 and later.
+    #[arg(long)]
+    pub experimental_codegen: bool,
+
+    /// Also request the EVM bytecode from `vyper` and emit it alongside the EraVM bytecode.
+    /// Both artifacts are built from the same sources and settings.
+    #[arg(long)]
+    pub evm_bytecode: bool,
+
     /// Adds more paths to the list of `vyper` search paths.
     #[arg(long, num_args = 1..)]
     pub search_paths: Option<Vec<String>>,
 
     /// Set the output format selection.
-    /// Available options: combined_json | ir_json, ast, abi, method_identifiers, layout, userdoc, devdoc, eravm_assembly, project_metadata
+    /// Available options: combined_json | ir_json, ir, ast, abi, method_identifiers, layout, userdoc, devdoc,
+    /// evm_bytecode, evm_bytecode_runtime, interface, external_interface, annotated_ast, metadata, ir_runtime,
+    /// bb, bb_runtime, cfg, cfg_runtime, integrity, solc_json, archive, bytecode, bytecode_runtime,
+    /// eravm_assembly, ergs_estimates, call_graph, project_metadata
     #[arg(short, long)]
     pub format: Option<String>,
 
@@ -77,6 +137,32 @@ pub struct Arguments {
     #[arg(short, long)]
     pub threads: Option<usize>,
 
+    /// Set the compilation cache directory.
+    /// By default, `zkvyper` in the user cache directory is used.
+    #[arg(long)]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Disable the compilation cache.
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Run a persistent compile server answering newline-delimited JSON requests.
+    /// Listens on the Unix socket at the specified path, or on stdin and stdout if no path is given.
+    #[arg(long, value_name = "SOCKET")]
+    pub server: Option<Option<PathBuf>>,
+
+    /// Remove all entries from the compilation cache.
+    /// Can be used without input files.
+    #[arg(long)]
+    pub prune_cache: bool,
+
+    /// Switch to LLL IR mode.
+    /// The input files may contain either `vyper -f ir_json` JSON or `vyper -f ir` s-expressions.
+    /// Cannot be used with combined JSON mode.
+    /// Use this mode at your own risk, as LLL IR input validation is not implemented.
+    #[arg(long)]
+    pub lll: bool,
+
     /// Switch to LLVM IR mode.
     /// Only one input LLVM IR file is allowed.
     /// Cannot be used with combined JSON mode.
@@ -127,10 +213,40 @@ pub struct Arguments {
     #[arg(long)]
     pub llvm_debug_logging: bool,
 
+    /// Set the time limit in seconds of each `vyper` call and each contract compilation.
+    /// The subprocess is killed if it runs longer.
+    #[arg(long)]
+    pub process_timeout: Option<u64>,
+
+    /// Set the memory limit in MiB of each `vyper` call and each compiler worker process.
+    /// Only supported on Unix platforms.
+    #[arg(long)]
+    pub process_memory_limit: Option<u64>,
+
+    /// Write a reproducer bundle for each contract whose compilation fails to the specified directory.
+    /// Bundles can be re-run with `--replay` without `vyper` or the project sources.
+    #[arg(long)]
+    pub reproducer_dir: Option<PathBuf>,
+
+    /// Re-run the compilation recorded in a reproducer bundle and print the output JSON.
+    #[arg(long)]
+    pub replay: Option<PathBuf>,
+
+    /// Set the encoding of inputs and outputs of the recursive worker processes.
+    /// Available arguments: `binary`, `json`. The default is `binary`.
+    /// Only for testing and debugging.
+    #[arg(long)]
+    pub ipc_encoding: Option<era_compiler_vyper::ProcessEncoding>,
+
     /// Run this process recursively and provide JSON input to compile a single contract.
     /// Only for usage from within the compiler.
     #[arg(long)]
     pub recursive_process: bool,
+
+    /// Run this process as a long-lived worker compiling framed JSON inputs until stdin is closed.
+    /// Only for usage from within the compiler.
+    #[arg(long)]
+    pub recursive_worker: bool,
 }
 
 impl Arguments {
@@ -138,7 +254,7 @@ impl Arguments {
     /// Validates the arguments.
     ///
     pub fn validate(&self) -> anyhow::Result<()> {
-        if self.recursive_process {
+        if self.recursive_process || self.recursive_worker {
             if std::env::args().count() > 2 {
                 anyhow::bail!("No other options are allowed in recursive mode.");
             } else {
@@ -154,10 +270,102 @@ impl Arguments {
             }
         }
 
+        if self.replay.is_some() {
+            if !self.input_paths.is_empty() {
+                anyhow::bail!("Input files cannot be passed together with `--replay`.");
+            }
+            if self.server.is_some() {
+                anyhow::bail!("Server mode cannot be used together with `--replay`.");
+            }
+            return Ok(());
+        }
+
+        if self.process_timeout == Some(0) {
+            anyhow::bail!("The process timeout must be positive.");
+        }
+        if self.process_memory_limit == Some(0) {
+            anyhow::bail!("The process memory limit must be positive.");
+        }
+        if self.process_memory_limit.is_some() && cfg!(not(unix)) {
+            anyhow::bail!("Process memory limits are only supported on Unix platforms.");
+        }
+
+        if self.no_cache && self.cache_dir.is_some() {
+            anyhow::bail!("The cache directory cannot be set together with `--no-cache`.");
+        }
+
+        if self.server.is_some() {
+            if !self.input_paths.is_empty() {
+                anyhow::bail!(
+                    "Input files cannot be passed in server mode. Send them in `compile` requests instead."
+                );
+            }
+            return Ok(());
+        }
+
         if self.input_paths.is_empty() {
+            if self.prune_cache {
+                return Ok(());
+            }
             anyhow::bail!("No input files provided.");
         }
 
+        if self.legacy_output_layout && self.output_dir.is_none() {
+            anyhow::bail!(
+                "The legacy output layout can only be used together with `--output-dir`."
+            );
+        }
+
+        if self.base_path.is_some() && self.output_dir.is_none() {
+            anyhow::bail!("The base path can only be used together with `--output-dir`.");
+        }
+
+        if self.artifacts.is_some() {
+            if self.output_dir.is_none() {
+                anyhow::bail!("Artifacts can only be written together with `--output-dir`.");
+            }
+
+            if self.format.is_some() {
+                anyhow::bail!("Artifacts cannot be requested together with `--format`.");
+            }
+
+            if self.legacy_output_layout {
+                anyhow::bail!("Artifacts cannot be written in the legacy output layout.");
+            }
+
+            if self.lll || self.llvm_ir || self.eravm_assembly || self.disassemble {
+                anyhow::bail!(
+                    "Artifacts are not written in LLL IR, LLVM IR, EraVM assembly and disassembler modes."
+                );
+            }
+        }
+
+        if let Some(era_compiler_vyper::OptimizationLevel::Auto) = self.optimization {
+            if self.lll || self.llvm_ir || self.eravm_assembly || self.disassemble {
+                anyhow::bail!(
+                    "Optimization auto-tuning is not supported in LLL IR, LLVM IR, EraVM assembly and disassembler modes."
+                );
+            }
+
+            if self.fallback_to_optimizing_for_size {
+                anyhow::bail!(
+                    "Falling back to -Oz cannot be used together with `--optimization auto`."
+                );
+            }
+
+            if self.optimization_size_budget.is_some()
+                && self.optimization_objective != Some(era_compiler_vyper::TuningObjective::Cycles)
+            {
+                anyhow::bail!(
+                    "The size budget can only be used together with `--optimization-objective cycles`."
+                );
+            }
+        } else if self.optimization_objective.is_some() || self.optimization_size_budget.is_some() {
+            anyhow::bail!(
+                "The optimization objective and size budget can only be used together with `--optimization auto`."
+            );
+        }
+
         if let Some(era_compiler_common::MetadataHashType::Keccak256) = self.metadata_hash {
             eprintln!(
                 "Warning: `keccak256` metadata hash type is deprecated. Please use `ipfs` instead."
@@ -166,6 +374,7 @@ impl Arguments {
 
         let modes_count = [
             self.format.is_some(),
+            self.lll,
             self.llvm_ir,
             self.eravm_assembly,
             self.disassemble,
@@ -175,7 +384,7 @@ impl Arguments {
         .count();
         if modes_count > 1 {
             anyhow::bail!(
-                "Only one mode is allowed at the same time: format, LLVM IR, EraVM assembly, disassembler."
+                "Only one mode is allowed at the same time: format, LLL IR, LLVM IR, EraVM assembly, disassembler."
             );
         }
 
@@ -183,13 +392,61 @@ impl Arguments {
             anyhow::bail!("No other options are allowed in disassembler mode.");
         }
 
-        if self.llvm_ir || self.eravm_assembly {
+        if self.lll || self.llvm_ir || self.eravm_assembly {
             if self.vyper.is_some() {
-                anyhow::bail!("`vyper` is not used in LLVM IR and EraVM assembly modes.");
+                anyhow::bail!("`vyper` is not used in LLL IR, LLVM IR and EraVM assembly modes.");
             }
 
             if self.evm_version.is_some() {
-                anyhow::bail!("EVM version is not used in LLVM IR and EraVM assembly modes.");
+                anyhow::bail!(
+                    "EVM version is not used in LLL IR, LLVM IR and EraVM assembly modes."
+                );
+            }
+
+            if self.experimental_codegen {
+                anyhow::bail!(
+                    "The experimental code generator is not used in LLL IR, LLVM IR and EraVM assembly modes."
+                );
+            }
+
+            if self.evm_bytecode {
+                anyhow::bail!(
+                    "EVM bytecode is not built in LLL IR, LLVM IR and EraVM assembly modes."
+                );
+            }
+        }
+
+        if let Some(era_compiler_common::Target::EVM) = self.target {
+            if self.lll || self.llvm_ir || self.eravm_assembly || self.disassemble {
+                anyhow::bail!(
+                    "LLL IR, LLVM IR, EraVM assembly and disassembler modes are not supported for the EVM target."
+                );
+            }
+
+            if self.experimental_codegen {
+                anyhow::bail!(
+                    "The experimental code generator is not supported for the EVM target."
+                );
+            }
+
+            if self.no_bytecode_metadata {
+                anyhow::bail!("CBOR metadata is not appended for the EVM target.");
+            }
+
+            if self.evm_bytecode {
+                anyhow::bail!("EVM bytecode is already emitted for the EVM target.");
+            }
+
+            if self.fallback_to_optimizing_for_size {
+                anyhow::bail!("Falling back to -Oz is not supported for the EVM target.");
+            }
+
+            if self.artifacts.is_some() {
+                anyhow::bail!("Artifacts are not supported for the EVM target.");
+            }
+
+            if let Some(era_compiler_vyper::OptimizationLevel::Auto) = self.optimization {
+                anyhow::bail!("Optimization auto-tuning is not supported for the EVM target.");
             }
         }
 
@@ -201,6 +458,10 @@ impl Arguments {
             if self.fallback_to_optimizing_for_size {
                 anyhow::bail!("Falling back to -Oz is not supported in EraVM assembly mode.");
             }
+
+            if self.optimization_override.is_some() {
+                anyhow::bail!("Optimization overrides are not supported in EraVM assembly mode.");
+            }
         }
 
         Ok(())
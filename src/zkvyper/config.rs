@@ -0,0 +1,382 @@
+//!
+//! Vyper compiler project configuration file.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::arguments::Arguments;
+
+///
+/// Vyper compiler project configuration file.
+///
+/// The keys are the long names of the command-line options. Options passed on the command line
+/// take precedence, and relative paths are resolved against the configuration file directory.
+///
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    /// The input file paths.
+    pub input_paths: Option<Vec<PathBuf>>,
+    /// The output directory.
+    pub output_dir: Option<PathBuf>,
+    /// Whether to overwrite existing files.
+    pub overwrite: Option<bool>,
+    /// The base path of sources in the output directory.
+    pub base_path: Option<PathBuf>,
+    /// Whether to use the legacy output layout.
+    pub legacy_output_layout: Option<bool>,
+    /// The artifact layout.
+    pub artifacts: Option<String>,
+    /// The optimization level.
+    pub optimization: Option<Optimization>,
+    /// The objective of the optimization auto-tuning.
+    pub optimization_objective: Option<String>,
+    /// The maximum bytecode size of the optimization auto-tuning.
+    pub optimization_size_budget: Option<usize>,
+    /// Whether to try to recompile with -Oz if the bytecode is too large.
+    #[serde(rename = "fallback-Oz")]
+    pub fallback_to_optimizing_for_size: Option<bool>,
+    /// The per-contract optimization levels in the `<pattern>=<level>` form.
+    pub optimization_override: Option<Vec<String>>,
+    /// The space-separated extra LLVM options.
+    pub llvm_options: Option<String>,
+    /// The `vyper` executable path.
+    pub vyper: Option<String>,
+    /// The target machine.
+    pub target: Option<String>,
+    /// The EVM version.
+    pub evm_version: Option<String>,
+    /// Whether to disable the `vyper` LLL IR optimizer.
+    pub disable_vyper_optimizer: Option<bool>,
+    /// Whether to enable decimals in `vyper`.
+    pub enable_decimals: Option<bool>,
+    /// Whether to translate the Venom IR of the `vyper` experimental code generator.
+    pub experimental_codegen: Option<bool>,
+    /// Whether to emit the upstream `vyper` EVM bytecode.
+    pub evm_bytecode: Option<bool>,
+    /// The `vyper` search paths.
+    pub search_paths: Option<Vec<String>>,
+    /// The output format selection.
+    pub format: Option<String>,
+    /// The number of threads.
+    pub threads: Option<usize>,
+    /// The compilation cache directory.
+    pub cache_dir: Option<PathBuf>,
+    /// Whether to disable the compilation cache.
+    pub no_cache: Option<bool>,
+    /// The time limit in seconds of each subprocess.
+    pub process_timeout: Option<u64>,
+    /// The memory limit in MiB of each subprocess.
+    pub process_memory_limit: Option<u64>,
+    /// The crash reproducer bundle directory.
+    pub reproducer_dir: Option<PathBuf>,
+    /// Whether to switch to LLL IR mode.
+    pub lll: Option<bool>,
+    /// Whether to switch to LLVM IR mode.
+    pub llvm_ir: Option<bool>,
+    /// Whether to switch to EraVM assembly mode.
+    pub eravm_assembly: Option<bool>,
+    /// Whether to switch to disassembler mode.
+    pub disassemble: Option<bool>,
+    /// The metadata hash type.
+    pub metadata_hash: Option<String>,
+    /// Whether to turn off CBOR metadata at the end of bytecode.
+    pub no_bytecode_metadata: Option<bool>,
+    /// The debug output directory.
+    pub debug_output_dir: Option<PathBuf>,
+    /// The suppressed warnings.
+    pub suppress_warnings: Option<Vec<String>>,
+    /// Whether to set the `verify-each` option in LLVM.
+    pub llvm_verify_each: Option<bool>,
+    /// Whether to set the `debug-logging` option in LLVM.
+    pub llvm_debug_logging: Option<bool>,
+
+    /// The per-contract optimization overrides.
+    #[serde(default)]
+    pub overrides: Vec<Override>,
+
+    /// The configuration file directory relative to the current directory.
+    #[serde(skip)]
+    pub directory: PathBuf,
+}
+
+///
+/// The per-contract optimization override.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Override {
+    /// The glob patterns of contracts the override applies to.
+    pub contracts: Vec<String>,
+    /// The optimization level.
+    pub optimization: Option<Optimization>,
+    /// Whether to try to recompile with -Oz if the bytecode is too large.
+    #[serde(default, rename = "fallback-Oz")]
+    pub fallback_to_optimizing_for_size: bool,
+    /// The space-separated extra LLVM options replacing the global ones.
+    pub llvm_options: Option<String>,
+}
+
+///
+/// The optimization level, written either as a number or as a string.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Optimization {
+    /// The numeric level, such as `3`.
+    Number(u32),
+    /// The string level, such as `"z"` or `"auto"`.
+    String(String),
+}
+
+impl Config {
+    /// The configuration file name discovered upward from the current directory.
+    pub const FILE_NAME: &'static str = "zkvyper.toml";
+
+    ///
+    /// Reads the configuration file at `path`, or discovers it upward from the current directory.
+    ///
+    /// Returns `None` if no path is given and no file is found.
+    ///
+    pub fn read_or_discover(path: Option<&Path>) -> anyhow::Result<Option<Self>> {
+        let path = match path {
+            Some(path) => path.to_owned(),
+            None => match Self::discover()? {
+                Some(path) => path,
+                None => return Ok(None),
+            },
+        };
+
+        let contents = std::fs::read_to_string(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Config file {path:?} reading error: {error}"))?;
+        let mut config: Self = toml::from_str(contents.as_str())
+            .map_err(|error| anyhow::anyhow!("Config file {path:?} parsing error: {error}"))?;
+        config.directory = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Ok(Some(config))
+    }
+
+    ///
+    /// Sets the arguments not passed on the command line.
+    ///
+    /// Returns the per-contract optimization overrides.
+    ///
+    pub fn apply(
+        self,
+        arguments: &mut Arguments,
+    ) -> anyhow::Result<Vec<era_compiler_vyper::OptimizationOverride>> {
+        if arguments.input_paths.is_empty() {
+            arguments.input_paths = self
+                .input_paths
+                .unwrap_or_default()
+                .into_iter()
+                .map(|path| self.directory.join(path))
+                .collect();
+        }
+        if arguments.output_dir.is_none() {
+            arguments.output_dir = self.output_dir.map(|path| self.directory.join(path));
+        }
+        arguments.overwrite |= self.overwrite.unwrap_or_default();
+        if arguments.base_path.is_none() {
+            arguments.base_path = self.base_path.map(|path| self.directory.join(path));
+        }
+        arguments.legacy_output_layout |= self.legacy_output_layout.unwrap_or_default();
+        if arguments.artifacts.is_none() {
+            arguments.artifacts = self
+                .artifacts
+                .as_deref()
+                .map(era_compiler_vyper::ArtifactFormat::from_str)
+                .transpose()?;
+        }
+        if arguments.optimization.is_none() {
+            arguments.optimization = self
+                .optimization
+                .map(Optimization::try_into_level)
+                .transpose()?;
+        }
+        if arguments.optimization_objective.is_none() {
+            arguments.optimization_objective = self
+                .optimization_objective
+                .as_deref()
+                .map(|objective| Self::parse("optimization-objective", objective))
+                .transpose()?;
+        }
+        if arguments.optimization_size_budget.is_none() {
+            arguments.optimization_size_budget = self.optimization_size_budget;
+        }
+        arguments.fallback_to_optimizing_for_size |=
+            self.fallback_to_optimizing_for_size.unwrap_or_default();
+        if arguments.optimization_override.is_none() {
+            arguments.optimization_override = self
+                .optimization_override
+                .map(|optimization_overrides| {
+                    optimization_overrides
+                        .iter()
+                        .map(|value| Self::parse("optimization-override", value.as_str()))
+                        .collect::<anyhow::Result<Vec<_>>>()
+                })
+                .transpose()?;
+        }
+        if arguments.llvm_options.is_none() {
+            arguments.llvm_options = self.llvm_options;
+        }
+        if arguments.vyper.is_none() {
+            arguments.vyper = self.vyper.map(|vyper| {
+                if Path::new(vyper.as_str()).components().count() > 1 {
+                    self.directory.join(vyper).to_string_lossy().to_string()
+                } else {
+                    vyper
+                }
+            });
+        }
+        if arguments.target.is_none() {
+            arguments.target = self
+                .target
+                .as_deref()
+                .map(|target| Self::parse("target", target))
+                .transpose()?;
+        }
+        if arguments.evm_version.is_none() {
+            arguments.evm_version = self
+                .evm_version
+                .as_deref()
+                .map(|evm_version| Self::parse("evm-version", evm_version))
+                .transpose()?;
+        }
+        arguments.disable_vyper_optimizer |= self.disable_vyper_optimizer.unwrap_or_default();
+        arguments.enable_decimals |= self.enable_decimals.unwrap_or_default();
+        arguments.experimental_codegen |= self.experimental_codegen.unwrap_or_default();
+        arguments.evm_bytecode |= self.evm_bytecode.unwrap_or_default();
+        if arguments.search_paths.is_none() {
+            arguments.search_paths = self.search_paths.map(|search_paths| {
+                search_paths
+                    .into_iter()
+                    .map(|path| self.directory.join(path).to_string_lossy().to_string())
+                    .collect()
+            });
+        }
+        if arguments.format.is_none() {
+            arguments.format = self.format;
+        }
+        if arguments.threads.is_none() {
+            arguments.threads = self.threads;
+        }
+        if arguments.cache_dir.is_none() {
+            arguments.cache_dir = self.cache_dir.map(|path| self.directory.join(path));
+        }
+        arguments.no_cache |= self.no_cache.unwrap_or_default();
+        if arguments.process_timeout.is_none() {
+            arguments.process_timeout = self.process_timeout;
+        }
+        if arguments.process_memory_limit.is_none() {
+            arguments.process_memory_limit = self.process_memory_limit;
+        }
+        if arguments.reproducer_dir.is_none() {
+            arguments.reproducer_dir = self.reproducer_dir.map(|path| self.directory.join(path));
+        }
+        arguments.lll |= self.lll.unwrap_or_default();
+        arguments.llvm_ir |= self.llvm_ir.unwrap_or_default();
+        arguments.eravm_assembly |= self.eravm_assembly.unwrap_or_default();
+        arguments.disassemble |= self.disassemble.unwrap_or_default();
+        if arguments.metadata_hash.is_none() {
+            arguments.metadata_hash = self
+                .metadata_hash
+                .as_deref()
+                .map(|metadata_hash| Self::parse("metadata-hash", metadata_hash))
+                .transpose()?;
+        }
+        arguments.no_bytecode_metadata |= self.no_bytecode_metadata.unwrap_or_default();
+        if arguments.debug_output_dir.is_none() {
+            arguments.debug_output_dir =
+                self.debug_output_dir.map(|path| self.directory.join(path));
+        }
+        if arguments.suppress_warnings.is_none() {
+            arguments.suppress_warnings = self.suppress_warnings;
+        }
+        arguments.llvm_verify_each |= self.llvm_verify_each.unwrap_or_default();
+        arguments.llvm_debug_logging |= self.llvm_debug_logging.unwrap_or_default();
+
+        let mut optimization_overrides = Vec::with_capacity(self.overrides.len());
+        for contract_override in self.overrides.into_iter() {
+            let optimization = contract_override
+                .optimization
+                .map(Optimization::try_into_char)
+                .transpose()?;
+            let llvm_options = contract_override.llvm_options.map(|options| {
+                options
+                    .split_whitespace()
+                    .map(|option| option.to_owned())
+                    .collect::<Vec<String>>()
+            });
+            for pattern in contract_override.contracts.into_iter() {
+                optimization_overrides.push(era_compiler_vyper::OptimizationOverride::new(
+                    pattern,
+                    optimization,
+                    contract_override.fallback_to_optimizing_for_size,
+                    llvm_options.clone(),
+                )?);
+            }
+        }
+        Ok(optimization_overrides)
+    }
+
+    ///
+    /// Returns the path of the configuration file found upward from the current directory.
+    ///
+    /// The path is relative to the current directory, so relative paths in the file stay relative.
+    ///
+    fn discover() -> anyhow::Result<Option<PathBuf>> {
+        let current_directory = std::env::current_dir()?;
+        let mut directory = PathBuf::new();
+        for _ in current_directory.ancestors() {
+            let path = directory.join(Self::FILE_NAME);
+            if path.is_file() {
+                return Ok(Some(path));
+            }
+            directory.push("..");
+        }
+        Ok(None)
+    }
+
+    ///
+    /// Parses the configuration value with the same parser as the command-line option.
+    ///
+    fn parse<T>(key: &str, value: &str) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        T::from_str(value).map_err(|error| {
+            anyhow::anyhow!("Config value `{key} = {value:?}` is invalid: {error}")
+        })
+    }
+}
+
+impl Optimization {
+    ///
+    /// Converts the level to the `-O` option character.
+    ///
+    pub fn try_into_char(self) -> anyhow::Result<char> {
+        match self.try_into_level()? {
+            era_compiler_vyper::OptimizationLevel::Fixed(level) => Ok(level),
+            era_compiler_vyper::OptimizationLevel::Auto => {
+                anyhow::bail!("Optimization auto-tuning cannot be used in overrides")
+            }
+        }
+    }
+
+    ///
+    /// Converts the level to the `--optimization` option value.
+    ///
+    pub fn try_into_level(self) -> anyhow::Result<era_compiler_vyper::OptimizationLevel> {
+        match self {
+            Self::Number(number) => char::from_digit(number, era_compiler_common::BASE_DECIMAL)
+                .map(era_compiler_vyper::OptimizationLevel::Fixed)
+                .ok_or_else(|| anyhow::anyhow!("Invalid optimization level `{number}`")),
+            Self::String(string) => {
+                era_compiler_vyper::OptimizationLevel::from_str(string.as_str())
+            }
+        }
+    }
+}
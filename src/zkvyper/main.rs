@@ -3,6 +3,7 @@
 //!
 
 pub mod arguments;
+pub mod config;
 
 use std::io::Write;
 use std::str::FromStr;
@@ -10,6 +11,7 @@ use std::str::FromStr;
 use clap::Parser;
 
 use self::arguments::Arguments;
+use self::config::Config;
 
 ///
 /// The application entry point.
@@ -21,7 +23,13 @@ fn main() -> anyhow::Result<()> {
             std::io::stderr()
                 .write_all(error.to_string().as_bytes())
                 .expect("Stderr writing error");
-            era_compiler_common::EXIT_CODE_FAILURE
+            if let Some(error) = error.downcast_ref::<era_compiler_vyper::Error>() {
+                error.exit_code()
+            } else if error.is::<era_compiler_vyper::SupervisorError>() {
+                era_compiler_vyper::Error::EXIT_CODE_SUBPROCESS
+            } else {
+                era_compiler_common::EXIT_CODE_FAILURE
+            }
         }
     })
 }
@@ -31,7 +39,28 @@ fn main() -> anyhow::Result<()> {
 ///
 fn main_inner() -> anyhow::Result<()> {
     let mut arguments = Arguments::try_parse()?;
+    let mut optimization_overrides = Vec::new();
+    if !arguments.recursive_process
+        && !arguments.recursive_worker
+        && !arguments.version
+        && arguments.server.is_none()
+        && arguments.replay.is_none()
+    {
+        if let Some(config) = Config::read_or_discover(arguments.config.as_deref())? {
+            optimization_overrides = config.apply(&mut arguments)?;
+        }
+    }
+    if let Some(command_line_overrides) = arguments.optimization_override.clone() {
+        optimization_overrides.extend(command_line_overrides);
+    }
     arguments.validate()?;
+    if let Some(era_compiler_vyper::OptimizationLevel::Auto) = arguments.optimization {
+        if !optimization_overrides.is_empty() {
+            anyhow::bail!(
+                "Optimization overrides cannot be used together with `--optimization auto`."
+            );
+        }
+    }
     arguments.normalize_input_paths()?;
 
     let mut thread_pool_builder = rayon::ThreadPoolBuilder::new();
@@ -60,6 +89,15 @@ fn main_inner() -> anyhow::Result<()> {
     if arguments.recursive_process {
         return era_compiler_vyper::run_recursive();
     }
+    if arguments.recursive_worker {
+        return era_compiler_vyper::run_recursive_worker();
+    }
+
+    if let Some(bundle_path) = arguments.replay {
+        let output = era_compiler_vyper::Reproducer::read(bundle_path.as_path())?.replay()?;
+        std::io::stdout().write_all(output.as_slice())?;
+        return Ok(());
+    }
 
     let debug_config = match arguments.debug_output_dir {
         Some(debug_output_directory) => {
@@ -71,12 +109,55 @@ fn main_inner() -> anyhow::Result<()> {
         None => None,
     };
 
+    let cache_directory = arguments
+        .cache_dir
+        .clone()
+        .or_else(era_compiler_vyper::Cache::default_directory);
+    if arguments.prune_cache {
+        if let Some(cache_directory) = cache_directory.as_ref() {
+            era_compiler_vyper::Cache::new(cache_directory.to_owned()).prune()?;
+        }
+        if arguments.input_paths.is_empty() && arguments.server.is_none() {
+            return Ok(());
+        }
+    }
+    let is_cache_enabled = !arguments.no_cache
+        && debug_config.is_none()
+        && !arguments.llvm_verify_each
+        && !arguments.llvm_debug_logging;
+    if is_cache_enabled {
+        if let Some(cache_directory) = cache_directory {
+            let _ = era_compiler_vyper::CACHE.set(era_compiler_vyper::Cache::new(cache_directory));
+        }
+    }
+
+    if arguments.process_timeout.is_some() || arguments.process_memory_limit.is_some() {
+        let _ = era_compiler_vyper::PROCESS_LIMITS.set(era_compiler_vyper::ProcessLimits::new(
+            arguments
+                .process_timeout
+                .map(std::time::Duration::from_secs),
+            arguments
+                .process_memory_limit
+                .map(|megabytes| megabytes * 1024 * 1024),
+        ));
+    }
+    if let Some(reproducer_directory) = arguments.reproducer_dir {
+        let _ = era_compiler_vyper::REPRODUCER_DIR.set(reproducer_directory);
+    }
+    if let Some(encoding) = arguments.ipc_encoding {
+        let _ = era_compiler_vyper::IPC_ENCODING.set(encoding);
+    }
+
+    if let Some(socket_path) = arguments.server {
+        return era_compiler_vyper::server::run(socket_path.as_deref());
+    }
+
     let suppressed_warnings = match arguments.suppress_warnings {
         Some(warnings) => era_compiler_vyper::WarningType::try_from_strings(warnings.as_slice())?,
         None => vec![],
     };
 
-    let output_selection = match arguments.format.as_ref() {
+    let mut output_selection = match arguments.format.as_ref() {
         Some(format) => format
             .split(',')
             .map(era_compiler_vyper::VyperSelector::from_str)
@@ -91,10 +172,33 @@ fn main_inner() -> anyhow::Result<()> {
             output_selection,
         );
     }
+    if arguments.evm_bytecode && !is_combined_json {
+        output_selection.extend([
+            era_compiler_vyper::VyperSelector::EVMBytecode,
+            era_compiler_vyper::VyperSelector::EVMBytecodeRuntime,
+        ]);
+    }
+    if arguments.artifacts.is_some() {
+        output_selection.extend([
+            era_compiler_vyper::VyperSelector::ABI,
+            era_compiler_vyper::VyperSelector::MethodIdentifiers,
+            era_compiler_vyper::VyperSelector::Layout,
+        ]);
+    }
 
-    let mut optimizer_settings = match arguments.optimization {
-        Some(mode) => era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)?,
-        None => era_compiler_llvm_context::OptimizerSettings::cycles(),
+    let (mut optimizer_settings, tuning) = match arguments.optimization {
+        Some(era_compiler_vyper::OptimizationLevel::Fixed(mode)) => (
+            era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)?,
+            None,
+        ),
+        Some(era_compiler_vyper::OptimizationLevel::Auto) => (
+            era_compiler_llvm_context::OptimizerSettings::cycles(),
+            Some(era_compiler_vyper::Tuning::new(
+                arguments.optimization_objective.unwrap_or_default(),
+                arguments.optimization_size_budget,
+            )),
+        ),
+        None => (era_compiler_llvm_context::OptimizerSettings::cycles(), None),
     };
     if arguments.fallback_to_optimizing_for_size {
         optimizer_settings.enable_fallback_to_size();
@@ -113,60 +217,102 @@ fn main_inner() -> anyhow::Result<()> {
         })
         .unwrap_or_default();
 
-    let vyper_optimizer_enabled = !arguments.disable_vyper_optimizer;
+    let mut compilation_config = era_compiler_vyper::CompilationConfig::default()
+        .with_decimals(arguments.enable_decimals)
+        .with_vyper_optimizer(!arguments.disable_vyper_optimizer)
+        .with_experimental_codegen(arguments.experimental_codegen)
+        .with_evm_bytecode(arguments.evm_bytecode)
+        .with_metadata(
+            arguments
+                .metadata_hash
+                .unwrap_or(era_compiler_common::MetadataHashType::None),
+            !arguments.no_bytecode_metadata,
+        )
+        .with_optimizer_settings(optimizer_settings)
+        .with_llvm_options(llvm_options)
+        .with_optimization_overrides(optimization_overrides)
+        .with_suppressed_warnings(suppressed_warnings);
+    if let Some(vyper) = arguments.vyper.as_deref() {
+        compilation_config = compilation_config.with_vyper(vyper);
+    }
+    if let Some(evm_version) = arguments.evm_version {
+        compilation_config = compilation_config.with_evm_version(evm_version);
+    }
+    if let Some(search_paths) = arguments.search_paths {
+        compilation_config = compilation_config.with_search_paths(search_paths);
+    }
+    if let Some(tuning) = tuning {
+        compilation_config = compilation_config.with_tuning(tuning);
+    }
+    if let Some(debug_config) = debug_config {
+        compilation_config = compilation_config.with_debug_config(debug_config);
+    }
+    let session = era_compiler_vyper::CompilerSession::new(compilation_config);
 
-    let metadata_hash_type = arguments
-        .metadata_hash
-        .unwrap_or(era_compiler_common::MetadataHashType::None);
+    if let Some(era_compiler_common::Target::EVM) = arguments.target {
+        if output_selection.iter().any(|selector| {
+            matches!(
+                selector,
+                era_compiler_vyper::VyperSelector::EraVMAssembly
+                    | era_compiler_vyper::VyperSelector::ErgsEstimates
+            )
+        }) {
+            anyhow::bail!(
+                "EraVM assembly and ergs estimates are not available for the EVM target."
+            );
+        }
+        if arguments.evm_bytecode
+            || output_selection
+                .iter()
+                .any(era_compiler_vyper::VyperSelector::is_evm_bytecode)
+        {
+            anyhow::bail!("EVM bytecode is already emitted for the EVM target.");
+        }
 
-    let append_bytecode_metadata = !arguments.no_bytecode_metadata;
+        if is_combined_json {
+            let combined_json = session.combined_json_evm(arguments.input_paths)?;
 
-    let build = if arguments.llvm_ir {
-        era_compiler_vyper::llvm_ir(
-            arguments.input_paths,
-            output_selection.as_slice(),
-            metadata_hash_type,
-            append_bytecode_metadata,
-            optimizer_settings,
-            llvm_options,
-            suppressed_warnings,
-            debug_config,
-        )
+            match arguments.output_dir {
+                Some(output_directory) => {
+                    combined_json
+                        .write_to_directory(output_directory.as_path(), arguments.overwrite)?;
+                }
+                None => serde_json::to_writer(std::io::stdout(), &combined_json)
+                    .expect("Stdout writing error"),
+            }
+            return Ok(());
+        }
+
+        let build =
+            session.standard_output_evm(arguments.input_paths, output_selection.as_slice())?;
+        match arguments.output_dir {
+            Some(output_directory) => {
+                build.write_to_directory(
+                    output_selection.as_slice(),
+                    output_directory.as_path(),
+                    arguments.base_path.as_deref(),
+                    arguments.overwrite,
+                    arguments.legacy_output_layout,
+                )?;
+            }
+            None => {
+                build.write_to_terminal(output_selection.as_slice())?;
+            }
+        }
+        return Ok(());
+    }
+
+    let build = if arguments.lll {
+        session.lll(arguments.input_paths, output_selection.as_slice())
+    } else if arguments.llvm_ir {
+        session.llvm_ir(arguments.input_paths, output_selection.as_slice())
     } else if arguments.eravm_assembly {
-        era_compiler_vyper::eravm_assembly(
-            arguments.input_paths,
-            output_selection.as_slice(),
-            metadata_hash_type,
-            append_bytecode_metadata,
-            llvm_options,
-            suppressed_warnings,
-            debug_config,
-        )
+        session.eravm_assembly(arguments.input_paths, output_selection.as_slice())
     } else if arguments.disassemble {
         return era_compiler_vyper::disassemble_eravm(arguments.input_paths);
     } else {
-        let vyper = era_compiler_vyper::VyperCompiler::new(
-            arguments
-                .vyper
-                .as_deref()
-                .unwrap_or(era_compiler_vyper::VyperCompiler::DEFAULT_EXECUTABLE_NAME),
-        )?;
-
         if is_combined_json {
-            let combined_json = era_compiler_vyper::combined_json(
-                arguments.input_paths,
-                &vyper,
-                arguments.evm_version,
-                arguments.enable_decimals,
-                arguments.search_paths,
-                metadata_hash_type,
-                append_bytecode_metadata,
-                vyper_optimizer_enabled,
-                optimizer_settings,
-                llvm_options,
-                suppressed_warnings,
-                debug_config,
-            )?;
+            let combined_json = session.combined_json(arguments.input_paths)?;
 
             match arguments.output_dir {
                 Some(output_directory) => {
@@ -176,35 +322,31 @@ fn main_inner() -> anyhow::Result<()> {
                 None => serde_json::to_writer(std::io::stdout(), &combined_json)
                     .expect("Stdout writing error"),
             }
-            std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
+            return Ok(());
         }
 
-        era_compiler_vyper::standard_output(
-            arguments.input_paths,
-            &vyper,
-            output_selection.as_slice(),
-            arguments.evm_version,
-            arguments.enable_decimals,
-            arguments.search_paths,
-            metadata_hash_type,
-            append_bytecode_metadata,
-            vyper_optimizer_enabled,
-            optimizer_settings,
-            llvm_options,
-            suppressed_warnings,
-            debug_config,
-        )
+        session.standard_output(arguments.input_paths, output_selection.as_slice())
     }?;
 
-    match arguments.output_dir {
-        Some(output_directory) => {
+    match (arguments.output_dir, arguments.artifacts) {
+        (Some(output_directory), Some(artifact_format)) => {
+            build.write_artifacts(
+                artifact_format,
+                output_directory.as_path(),
+                arguments.base_path.as_deref(),
+                arguments.overwrite,
+            )?;
+        }
+        (Some(output_directory), None) => {
             build.write_to_directory(
                 output_selection.as_slice(),
                 output_directory.as_path(),
+                arguments.base_path.as_deref(),
                 arguments.overwrite,
+                arguments.legacy_output_layout,
             )?;
         }
-        None => {
+        (None, _) => {
             build.write_to_terminal(output_selection.as_slice())?;
         }
     }
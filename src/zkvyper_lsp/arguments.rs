@@ -0,0 +1,70 @@
+//!
+//! Vyper language server arguments.
+//!
+
+use clap::Parser;
+
+///
+/// Vyper language server with ZKsync diagnostics.
+///
+/// Speaks the Language Server Protocol on stdin and stdout.
+///
+/// Example: `zkvyper-lsp --compile-on-save`
+///
+#[derive(Debug, Parser)]
+#[command(about, long_about = None)]
+pub struct Arguments {
+    /// Specify the path to the `vyper` executable. By default, the one in `${PATH}` is used.
+    #[arg(long)]
+    pub vyper: Option<String>,
+
+    /// The EVM version to generate IR for.
+    #[arg(long)]
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
+
+    /// Enables decimals in the underlying `vyper` compiler.
+    /// Only available in `vyper` v0.4.0 and later.
+    #[arg(long)]
+    pub enable_decimals: bool,
+
+    /// Adds more paths to the list of `vyper` search paths.
+    #[arg(long, num_args = 1..)]
+    pub search_paths: Option<Vec<String>>,
+
+    /// Suppress specified warnings.
+    /// Available arguments: `txorigin`.
+    #[arg(long, num_args = 1..)]
+    pub suppress_warnings: Option<Vec<String>>,
+
+    /// Run the full EraVM compilation on open and save, reporting its errors and the contract size.
+    /// Without this flag, only the `vyper` frontend and the ZKsync AST checks are run.
+    #[arg(long)]
+    pub compile_on_save: bool,
+
+    /// Set the optimization parameter -O[0 | 1 | 2 | 3 | s | z] for the compilation on save.
+    #[arg(short = 'O', long)]
+    pub optimization: Option<char>,
+
+    /// Run this process recursively and provide JSON input to compile a single contract.
+    /// Only for usage from within the language server.
+    #[arg(long, hide = true)]
+    pub recursive_process: bool,
+
+    /// Run this process as a long-lived worker compiling framed JSON inputs until stdin is closed.
+    /// Only for usage from within the language server.
+    #[arg(long, hide = true)]
+    pub recursive_worker: bool,
+}
+
+impl Arguments {
+    ///
+    /// Validates the arguments.
+    ///
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.optimization.is_some() && !self.compile_on_save {
+            anyhow::bail!("`--optimization` is only used together with `--compile-on-save`.");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,244 @@
+//!
+//! The Language Server Protocol diagnostic.
+//!
+
+use era_compiler_vyper::VyperCompilerCombinedJsonContractWarning as Warning;
+
+///
+/// The position in a text document.
+///
+/// Lines are zero-based, and characters are counted in UTF-16 code units.
+///
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct Position {
+    /// The zero-based line number.
+    pub line: usize,
+    /// The zero-based UTF-16 character offset.
+    pub character: usize,
+}
+
+///
+/// The range in a text document.
+///
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct Range {
+    /// The inclusive start position.
+    pub start: Position,
+    /// The exclusive end position.
+    pub end: Position,
+}
+
+impl Range {
+    ///
+    /// Returns the range of the token at the one-based `line` and the zero-based byte `column`.
+    ///
+    /// The token spans identifier characters and dots, so that `tx.origin` is highlighted as a
+    /// whole. If there is no such token at the location, the range spans the rest of the line.
+    ///
+    pub fn at(source_code: &str, line: usize, column: usize) -> Self {
+        let line = line.saturating_sub(1);
+        let text = source_code.lines().nth(line).unwrap_or_default();
+        let mut column = column.min(text.len());
+        while !text.is_char_boundary(column) {
+            column -= 1;
+        }
+
+        let token_length = text[column..]
+            .find(|character: char| !(character.is_alphanumeric() || "_.".contains(character)))
+            .unwrap_or(text.len() - column);
+        let end = if token_length == 0 {
+            text.len()
+        } else {
+            column + token_length
+        };
+
+        Self {
+            start: Position {
+                line,
+                character: Self::utf16_length(&text[..column]),
+            },
+            end: Position {
+                line,
+                character: Self::utf16_length(&text[..end]),
+            },
+        }
+    }
+
+    ///
+    /// Returns the length of the string in UTF-16 code units.
+    ///
+    fn utf16_length(string: &str) -> usize {
+        string.chars().map(char::len_utf16).sum()
+    }
+}
+
+///
+/// The diagnostic severity.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum Severity {
+    /// Reports an error.
+    Error = 1,
+    /// Reports a warning.
+    Warning = 2,
+}
+
+impl serde::Serialize for Severity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+///
+/// The Language Server Protocol diagnostic.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Diagnostic {
+    /// The range at which the message applies.
+    pub range: Range,
+    /// The diagnostic severity.
+    pub severity: Severity,
+    /// The tool reporting the diagnostic.
+    pub source: String,
+    /// The message text.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// The share of the EraVM bytecode size limit in percent, above which a warning is reported.
+    pub const SIZE_WARNING_THRESHOLD_PERCENT: usize = 90;
+
+    ///
+    /// Creates a diagnostic from the EraVM bytecode size, if it is near or over the limit.
+    ///
+    /// The diagnostic is placed at the beginning of the file, as the size has no location.
+    ///
+    pub fn from_bytecode_size(source_code: &str, size: usize) -> Option<Self> {
+        let limit = era_compiler_vyper::r#const::ERAVM_BYTECODE_SIZE_LIMIT;
+        let (severity, message) = if size > limit {
+            (
+                Severity::Error,
+                format!(
+                    "The EraVM bytecode size of {size} bytes exceeds the limit of {limit} bytes"
+                ),
+            )
+        } else if size * 100 >= limit * Self::SIZE_WARNING_THRESHOLD_PERCENT {
+            (
+                Severity::Warning,
+                format!(
+                    "The EraVM bytecode size of {size} bytes is {}% of the limit of {limit} bytes",
+                    size * 100 / limit
+                ),
+            )
+        } else {
+            return None;
+        };
+        Some(Self {
+            range: Range::at(source_code, 1, 0),
+            severity,
+            source: era_compiler_vyper::DEFAULT_EXECUTABLE_NAME.to_owned(),
+            message,
+        })
+    }
+
+    ///
+    /// Creates a diagnostic from a ZKsync AST check warning.
+    ///
+    pub fn from_warning(source_code: &str, warning: &Warning) -> Self {
+        Self {
+            range: Range::at(source_code, warning.line, warning.column),
+            severity: Severity::Warning,
+            source: era_compiler_vyper::DEFAULT_EXECUTABLE_NAME.to_owned(),
+            message: warning.message.trim().to_owned(),
+        }
+    }
+
+    ///
+    /// Creates a diagnostic from a compilation error.
+    ///
+    /// The error is placed at the location reported by `vyper`, at the first usage of the
+    /// unsupported feature it names, or at the beginning of the file if neither is found.
+    ///
+    pub fn from_error(source_code: &str, error: &anyhow::Error) -> Self {
+        let size = match error.downcast_ref::<era_compiler_vyper::Error>() {
+            Some(era_compiler_vyper::Error::SizeLimit { size, .. }) => Some(*size),
+            Some(era_compiler_vyper::Error::Contracts(errors)) => {
+                errors.values().find_map(|error| match error {
+                    era_compiler_vyper::Error::SizeLimit { size, .. } => Some(*size),
+                    _ => None,
+                })
+            }
+            _ => None,
+        };
+        if let Some(diagnostic) = size.and_then(|size| Self::from_bytecode_size(source_code, size))
+        {
+            return diagnostic;
+        }
+
+        let message = error.to_string();
+        let (line, column) = Self::vyper_location(message.as_str())
+            .or_else(|| Self::feature_location(source_code, message.as_str()))
+            .unwrap_or((1, 0));
+        Self {
+            range: Range::at(source_code, line, column),
+            severity: Severity::Error,
+            source: era_compiler_vyper::DEFAULT_EXECUTABLE_NAME.to_owned(),
+            message: message.trim().to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the last `line <line>:<column>` location reported by `vyper`.
+    ///
+    fn vyper_location(message: &str) -> Option<(usize, usize)> {
+        message.match_indices("line ").rev().find_map(|(index, _)| {
+            let location = &message[index + "line ".len()..];
+            let (line, rest) = location.split_once(':')?;
+            let column_length = rest
+                .find(|character: char| !character.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let line = line.parse::<usize>().ok()?;
+            let column = rest[..column_length].parse::<usize>().ok()?;
+            Some((line, column))
+        })
+    }
+
+    ///
+    /// Returns the location of the first identifier quoted in the message, such as the
+    /// `SELFDESTRUCT` instruction or the `raw_create` built-in, found in the source code.
+    ///
+    fn feature_location(source_code: &str, message: &str) -> Option<(usize, usize)> {
+        message
+            .split('`')
+            .skip(1)
+            .step_by(2)
+            .filter(|name| {
+                !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|character| character.is_alphanumeric() || character == '_')
+            })
+            .find_map(|name| {
+                let name = name.to_ascii_lowercase();
+                source_code.lines().enumerate().find_map(|(index, line)| {
+                    let code = line.split('#').next().unwrap_or_default();
+                    code.to_ascii_lowercase()
+                        .match_indices(name.as_str())
+                        .map(|(column, _)| column)
+                        .find(|column| {
+                            let is_boundary = |character: Option<char>| {
+                                character.is_none_or(|character| {
+                                    !(character.is_alphanumeric() || character == '_')
+                                })
+                            };
+                            is_boundary(code[..*column].chars().next_back())
+                                && is_boundary(code[column + name.len()..].chars().next())
+                        })
+                        .map(|column| (index + 1, column))
+                })
+            })
+    }
+}
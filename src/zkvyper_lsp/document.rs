@@ -0,0 +1,108 @@
+//!
+//! The analyzed Vyper source code document.
+//!
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::diagnostic::Diagnostic;
+
+///
+/// The analyzed Vyper source code document.
+///
+#[derive(Debug, Default)]
+pub struct Document {
+    /// The file path.
+    pub path: PathBuf,
+    /// The source code analyzed.
+    pub source_code: String,
+    /// The contract AST, if the `vyper` frontend succeeded.
+    pub ast: Option<serde_json::Value>,
+    /// The method identifiers, if the `vyper` frontend succeeded.
+    pub method_identifiers: BTreeMap<String, String>,
+    /// The EraVM bytecode size, if the compilation on save succeeded.
+    pub bytecode_size: Option<usize>,
+    /// The diagnostics published for the document.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Document {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: PathBuf, source_code: String) -> Self {
+        Self {
+            path,
+            source_code,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Returns the hover text in Markdown for the zero-based `line`.
+    ///
+    /// Shows the method identifiers of the function defined around the line, and the contract
+    /// size if it is known.
+    ///
+    pub fn hover(&self, line: usize) -> Option<String> {
+        let mut sections = Vec::with_capacity(2);
+
+        if let Some(name) = self.function_at(line + 1) {
+            let identifiers: Vec<String> = self
+                .method_identifiers
+                .iter()
+                .filter(|(signature, _identifier)| {
+                    signature.split('(').next() == Some(name.as_str())
+                })
+                .map(|(signature, identifier)| format!("`{identifier}` `{signature}`"))
+                .collect();
+            if !identifiers.is_empty() {
+                sections.push(format!(
+                    "**Method identifiers**\n\n{}",
+                    identifiers.join("  \n")
+                ));
+            }
+        }
+
+        if let Some(bytecode_size) = self.bytecode_size {
+            sections.push(format!("**EraVM contract size**: {bytecode_size} bytes"));
+        }
+
+        if sections.is_empty() {
+            None
+        } else {
+            Some(sections.join("\n\n"))
+        }
+    }
+
+    ///
+    /// Returns the name of the function whose definition spans the one-based `line`.
+    ///
+    fn function_at(&self, line: usize) -> Option<String> {
+        let line = line as u64;
+        self.ast
+            .as_ref()?
+            .get("body")?
+            .as_array()?
+            .iter()
+            .filter(|node| {
+                node.get("ast_type").and_then(serde_json::Value::as_str) == Some("FunctionDef")
+            })
+            .find(|node| {
+                let start = node
+                    .get("decorator_list")
+                    .and_then(serde_json::Value::as_array)
+                    .and_then(|decorators| decorators.first())
+                    .or(Some(node))
+                    .and_then(|node| node.get("lineno"))
+                    .and_then(serde_json::Value::as_u64);
+                let end = node
+                    .get("end_lineno")
+                    .or_else(|| node.get("lineno"))
+                    .and_then(serde_json::Value::as_u64);
+                matches!((start, end), (Some(start), Some(end)) if (start..=end).contains(&line))
+            })
+            .and_then(|node| node.get("name")?.as_str())
+            .map(str::to_owned)
+    }
+}
@@ -0,0 +1,79 @@
+//!
+//! Vyper language server binary.
+//!
+
+pub mod arguments;
+pub mod diagnostic;
+pub mod document;
+pub mod server;
+pub mod transport;
+
+use std::io::Write;
+
+use clap::Parser;
+
+use self::arguments::Arguments;
+use self::server::Server;
+
+///
+/// The application entry point.
+///
+fn main() -> anyhow::Result<()> {
+    std::process::exit(match main_inner() {
+        Ok(()) => era_compiler_common::EXIT_CODE_SUCCESS,
+        Err(error) => {
+            std::io::stderr()
+                .write_all(error.to_string().as_bytes())
+                .expect("Stderr writing error");
+            era_compiler_common::EXIT_CODE_FAILURE
+        }
+    })
+}
+
+///
+/// The auxiliary `main` function to facilitate the `?` error conversion operator.
+///
+fn main_inner() -> anyhow::Result<()> {
+    let arguments = Arguments::try_parse()?;
+    arguments.validate()?;
+
+    rayon::ThreadPoolBuilder::new()
+        .stack_size(era_compiler_vyper::WORKER_THREAD_STACK_SIZE)
+        .build_global()
+        .expect("Thread pool configuration failure");
+
+    inkwell::support::enable_llvm_pretty_stack_trace();
+    era_compiler_llvm_context::initialize_target();
+
+    if arguments.recursive_process {
+        return era_compiler_vyper::run_recursive();
+    }
+    if arguments.recursive_worker {
+        return era_compiler_vyper::run_recursive_worker();
+    }
+
+    let suppressed_warnings = match arguments.suppress_warnings {
+        Some(warnings) => era_compiler_vyper::WarningType::try_from_strings(warnings.as_slice())?,
+        None => vec![],
+    };
+    let optimizer_settings = if arguments.compile_on_save {
+        Some(match arguments.optimization {
+            Some(mode) => era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)?,
+            None => era_compiler_llvm_context::OptimizerSettings::cycles(),
+        })
+    } else {
+        None
+    };
+
+    let mut server = Server::new(
+        arguments.vyper.unwrap_or_else(|| {
+            era_compiler_vyper::VyperCompiler::DEFAULT_EXECUTABLE_NAME.to_owned()
+        }),
+        arguments.evm_version,
+        arguments.enable_decimals,
+        arguments.search_paths,
+        suppressed_warnings,
+        optimizer_settings,
+    );
+    server.run(std::io::stdin().lock(), std::io::stdout().lock())
+}
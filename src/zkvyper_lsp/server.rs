@@ -0,0 +1,344 @@
+//!
+//! The Vyper language server.
+//!
+
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::diagnostic::Diagnostic;
+use crate::document::Document;
+use crate::transport;
+
+///
+/// The Vyper language server.
+///
+#[derive(Debug)]
+pub struct Server {
+    /// The `vyper` executable name.
+    pub vyper: String,
+    /// The EVM version to generate IR for.
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
+    /// Whether decimals are enabled in `vyper`.
+    pub enable_decimals: bool,
+    /// The additional `vyper` search paths.
+    pub search_paths: Option<Vec<String>>,
+    /// The suppressed warnings.
+    pub suppressed_warnings: Vec<era_compiler_vyper::WarningType>,
+    /// The optimizer settings of the compilation on save, if it is enabled.
+    pub optimizer_settings: Option<era_compiler_llvm_context::OptimizerSettings>,
+    /// The open documents by URI.
+    documents: BTreeMap<String, Document>,
+    /// Whether the `shutdown` request has been received.
+    is_shutdown: bool,
+}
+
+impl Server {
+    /// The JSON-RPC error code of an invalid request.
+    const ERROR_CODE_INVALID_REQUEST: i64 = -32600;
+
+    /// The JSON-RPC error code of an unknown method.
+    const ERROR_CODE_METHOD_NOT_FOUND: i64 = -32601;
+
+    /// The JSON-RPC error code of invalid parameters.
+    const ERROR_CODE_INVALID_PARAMS: i64 = -32602;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        vyper: String,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        enable_decimals: bool,
+        search_paths: Option<Vec<String>>,
+        suppressed_warnings: Vec<era_compiler_vyper::WarningType>,
+        optimizer_settings: Option<era_compiler_llvm_context::OptimizerSettings>,
+    ) -> Self {
+        Self {
+            vyper,
+            evm_version,
+            enable_decimals,
+            search_paths,
+            suppressed_warnings,
+            optimizer_settings,
+            documents: BTreeMap::new(),
+            is_shutdown: false,
+        }
+    }
+
+    ///
+    /// Serves messages read from `reader` until the `exit` notification or the end of input.
+    ///
+    pub fn run<R, W>(&mut self, mut reader: R, mut writer: W) -> anyhow::Result<()>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        while let Some(message) = transport::read(&mut reader)? {
+            let Some(method) = message.get("method").and_then(serde_json::Value::as_str) else {
+                continue;
+            };
+            let params = message
+                .get("params")
+                .cloned()
+                .unwrap_or(serde_json::Value::Null);
+
+            if method == "exit" {
+                if !self.is_shutdown {
+                    anyhow::bail!("The `exit` notification received before `shutdown`.");
+                }
+                return Ok(());
+            }
+
+            match message.get("id") {
+                Some(id) => {
+                    let response = match self.request(method, params) {
+                        Ok(result) => serde_json::json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "result": result,
+                        }),
+                        Err((code, message)) => serde_json::json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": {
+                                "code": code,
+                                "message": message,
+                            },
+                        }),
+                    };
+                    transport::write(&mut writer, &response)?;
+                }
+                None => {
+                    for notification in self.notify(method, params) {
+                        transport::write(&mut writer, &notification)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Handles the request, returning its result or the error code and message.
+    ///
+    fn request(
+        &mut self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, (i64, String)> {
+        if self.is_shutdown {
+            return Err((
+                Self::ERROR_CODE_INVALID_REQUEST,
+                "The server is shutting down.".to_owned(),
+            ));
+        }
+
+        match method {
+            "initialize" => Ok(serde_json::json!({
+                "capabilities": {
+                    "textDocumentSync": {
+                        "openClose": true,
+                        "change": 0,
+                        "save": { "includeText": false },
+                    },
+                    "hoverProvider": true,
+                },
+                "serverInfo": {
+                    "name": env!("CARGO_BIN_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+            })),
+            "textDocument/hover" => {
+                let uri = params
+                    .pointer("/textDocument/uri")
+                    .and_then(serde_json::Value::as_str)
+                    .ok_or_else(|| {
+                        (
+                            Self::ERROR_CODE_INVALID_PARAMS,
+                            "The hover request requires a document URI.".to_owned(),
+                        )
+                    })?;
+                let line = params
+                    .pointer("/position/line")
+                    .and_then(serde_json::Value::as_u64)
+                    .unwrap_or_default() as usize;
+                let hover = self
+                    .documents
+                    .get(uri)
+                    .and_then(|document| document.hover(line));
+                Ok(match hover {
+                    Some(value) => serde_json::json!({
+                        "contents": {
+                            "kind": "markdown",
+                            "value": value,
+                        },
+                    }),
+                    None => serde_json::Value::Null,
+                })
+            }
+            "shutdown" => {
+                self.is_shutdown = true;
+                Ok(serde_json::Value::Null)
+            }
+            method => Err((
+                Self::ERROR_CODE_METHOD_NOT_FOUND,
+                format!("Unknown method `{method}`."),
+            )),
+        }
+    }
+
+    ///
+    /// Handles the notification, returning the notifications to send back.
+    ///
+    fn notify(&mut self, method: &str, params: serde_json::Value) -> Vec<serde_json::Value> {
+        let Some(uri) = params
+            .pointer("/textDocument/uri")
+            .and_then(serde_json::Value::as_str)
+        else {
+            return vec![];
+        };
+
+        match method {
+            "textDocument/didOpen" | "textDocument/didSave" => {
+                let Some(path) = Self::uri_to_path(uri) else {
+                    return vec![];
+                };
+                let document = self.analyze(path);
+                let notification = Self::publish_diagnostics(uri, document.diagnostics.as_slice());
+                self.documents.insert(uri.to_owned(), document);
+                vec![notification]
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(uri);
+                vec![Self::publish_diagnostics(uri, &[])]
+            }
+            _ => vec![],
+        }
+    }
+
+    ///
+    /// Runs the `vyper` frontend, the ZKsync AST checks, and optionally the full compilation.
+    ///
+    /// Analyzes the file as saved on disk, since `vyper` reads it from there.
+    ///
+    fn analyze(&self, path: PathBuf) -> Document {
+        let source_code = std::fs::read_to_string(path.as_path()).unwrap_or_default();
+        let mut document = Document::new(path, source_code);
+        if let Err(error) = self.analyze_inner(&mut document) {
+            document.diagnostics.push(Diagnostic::from_error(
+                document.source_code.as_str(),
+                &error,
+            ));
+        }
+        document
+    }
+
+    ///
+    /// The auxiliary `analyze` function to facilitate the `?` error conversion operator.
+    ///
+    fn analyze_inner(&self, document: &mut Document) -> anyhow::Result<()> {
+        let vyper = era_compiler_vyper::VyperCompiler::new(self.vyper.as_str())?;
+        let project = vyper.batch(
+            &vyper.version.default,
+            vec![document.path.clone()],
+            &[
+                era_compiler_vyper::VyperSelector::IRJson,
+                era_compiler_vyper::VyperSelector::AST,
+                era_compiler_vyper::VyperSelector::MethodIdentifiers,
+            ],
+            self.evm_version,
+            self.enable_decimals,
+            self.search_paths.clone(),
+            true,
+            false,
+        )?;
+
+        for contract in project.contracts.values() {
+            if let era_compiler_vyper::Contract::Vyper(contract) = contract {
+                document.diagnostics.extend(
+                    contract
+                        .ast
+                        .get_warnings(&contract.ast.ast, self.suppressed_warnings.as_slice())
+                        .iter()
+                        .map(|warning| {
+                            Diagnostic::from_warning(document.source_code.as_str(), warning)
+                        }),
+                );
+                document.ast = Some(contract.ast.ast.clone());
+                document.method_identifiers = contract.method_identifiers.clone();
+            }
+        }
+
+        let Some(optimizer_settings) = self.optimizer_settings.clone() else {
+            return Ok(());
+        };
+        let build = project.compile(
+            self.evm_version,
+            era_compiler_common::MetadataHashType::None,
+            false,
+            optimizer_settings,
+            vec![],
+            vec![],
+            None,
+            self.suppressed_warnings.clone(),
+            None,
+            era_compiler_vyper::ProcessStrategy::default(),
+        )?;
+        document.bytecode_size = build
+            .contracts
+            .get(document.path.to_string_lossy().as_ref())
+            .map(|contract| contract.build.bytecode.len());
+        if let Some(bytecode_size) = document.bytecode_size {
+            document.diagnostics.extend(Diagnostic::from_bytecode_size(
+                document.source_code.as_str(),
+                bytecode_size,
+            ));
+        }
+        Ok(())
+    }
+
+    ///
+    /// Creates the `textDocument/publishDiagnostics` notification.
+    ///
+    fn publish_diagnostics(uri: &str, diagnostics: &[Diagnostic]) -> serde_json::Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": {
+                "uri": uri,
+                "diagnostics": diagnostics,
+            },
+        })
+    }
+
+    ///
+    /// Converts the `file` URI to a file system path.
+    ///
+    fn uri_to_path(uri: &str) -> Option<PathBuf> {
+        let path = uri.strip_prefix("file://")?;
+
+        let mut bytes = Vec::with_capacity(path.len());
+        let mut iterator = path.bytes();
+        while let Some(byte) = iterator.next() {
+            if byte == b'%' {
+                let high = iterator.next()?;
+                let low = iterator.next()?;
+                let hex = [high, low];
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            } else {
+                bytes.push(byte);
+            }
+        }
+        let path = String::from_utf8(bytes).ok()?;
+
+        let path = match path.strip_prefix('/') {
+            Some(windows_path) if windows_path.as_bytes().get(1) == Some(&b':') => {
+                windows_path.to_owned()
+            }
+            _ => path,
+        };
+        Some(PathBuf::from(path))
+    }
+}
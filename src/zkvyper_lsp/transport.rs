@@ -0,0 +1,59 @@
+//!
+//! The Language Server Protocol base transport.
+//!
+//! Messages are JSON-RPC objects preceded by a `Content-Length` header and an empty line.
+//!
+
+use std::io::BufRead;
+use std::io::Write;
+
+///
+/// Reads the next message.
+///
+/// Returns `None` at the end of input.
+///
+pub fn read<R>(reader: &mut R) -> anyhow::Result<Option<serde_json::Value>>
+where
+    R: BufRead,
+{
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                content_length = Some(value.trim().parse::<usize>().map_err(|error| {
+                    anyhow::anyhow!("Invalid `Content-Length` header `{line}`: {error}")
+                })?);
+            }
+        }
+    }
+
+    let content_length =
+        content_length.ok_or_else(|| anyhow::anyhow!("Missing `Content-Length` header"))?;
+    let mut content = vec![0; content_length];
+    reader.read_exact(content.as_mut_slice())?;
+    let message = serde_json::from_slice(content.as_slice())
+        .map_err(|error| anyhow::anyhow!("Message parsing error: {error}"))?;
+    Ok(Some(message))
+}
+
+///
+/// Writes the message.
+///
+pub fn write<W>(writer: &mut W, message: &serde_json::Value) -> anyhow::Result<()>
+where
+    W: Write,
+{
+    let content = serde_json::to_vec(message).expect("Always valid");
+    write!(writer, "Content-Length: {}\r\n\r\n", content.len())?;
+    writer.write_all(content.as_slice())?;
+    writer.flush()?;
+    Ok(())
+}
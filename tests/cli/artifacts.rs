@@ -0,0 +1,207 @@
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use crate::common;
+
+#[test]
+fn foundry() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-o",
+        tmp_dir_path,
+        "--artifacts",
+        "foundry",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    let artifact: serde_json::Value = serde_json::from_slice(
+        std::fs::read(format!(
+            "{tmp_dir_path}/{}/greeter.json",
+            common::TEST_GREETER_CONTRACT_NAME
+        ))?
+        .as_slice(),
+    )?;
+    assert!(artifact["abi"].is_array());
+    assert!(artifact["bytecode"]["object"]
+        .as_str()
+        .expect("Always exists")
+        .starts_with("0x"));
+    assert!(artifact["methodIdentifiers"].is_object());
+    assert!(artifact["storageLayout"].is_object());
+    assert!(artifact["hash"].is_string());
+    assert!(artifact["factoryDependencies"].is_object());
+
+    let build_info_id = artifact["buildInfoId"].as_str().expect("Always exists");
+    let build_info: serde_json::Value = serde_json::from_slice(
+        std::fs::read(format!("{tmp_dir_path}/build-info/{build_info_id}.json"))?.as_slice(),
+    )?;
+    assert_eq!(build_info["id"], build_info_id);
+    assert!(
+        build_info["input"]["sources"][common::TEST_GREETER_CONTRACT_PATH]["content"].is_string()
+    );
+    assert_eq!(
+        build_info["output"]["contracts"][common::TEST_GREETER_CONTRACT_PATH]["greeter"]["evm"]
+            ["bytecode"]["object"],
+        artifact["bytecode"]["object"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn hardhat() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-o",
+        tmp_dir_path,
+        "--artifacts",
+        "hardhat",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    let artifact_directory = format!("{tmp_dir_path}/{}", common::TEST_GREETER_CONTRACT_PATH);
+    let artifact: serde_json::Value = serde_json::from_slice(
+        std::fs::read(format!("{artifact_directory}/greeter.json"))?.as_slice(),
+    )?;
+    assert_eq!(artifact["_format"], "hh-zkvyper-artifact-1");
+    assert_eq!(artifact["contractName"], "greeter");
+    assert_eq!(artifact["sourceName"], common::TEST_GREETER_CONTRACT_PATH);
+    assert_eq!(artifact["bytecode"], artifact["deployedBytecode"]);
+    assert!(artifact["factoryDeps"].is_object());
+
+    let debug: serde_json::Value = serde_json::from_slice(
+        std::fs::read(format!("{artifact_directory}/greeter.dbg.json"))?.as_slice(),
+    )?;
+    let build_info_path = debug["buildInfo"].as_str().expect("Always exists");
+    assert!(build_info_path.starts_with("../../../../../build-info/"));
+    let build_info: serde_json::Value = serde_json::from_slice(
+        std::fs::read(format!("{artifact_directory}/{build_info_path}"))?.as_slice(),
+    )?;
+    assert!(
+        build_info["input"]["sources"][common::TEST_GREETER_CONTRACT_PATH]["content"].is_string()
+    );
+    assert!(
+        build_info["output"]["contracts"][common::TEST_GREETER_CONTRACT_PATH]["greeter"]["abi"]
+            .is_array()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn foundry_same_file_name() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir_sources = TempDir::new().expect("Failed to create temp dir");
+    for directory in ["a", "b"] {
+        let directory = tmp_dir_sources.path().join(directory);
+        std::fs::create_dir_all(directory.as_path())?;
+        std::fs::copy(
+            common::TEST_GREETER_CONTRACT_PATH,
+            directory.join(common::TEST_GREETER_CONTRACT_NAME),
+        )?;
+    }
+    let tmp_dir_output = TempDir::new().expect("Failed to create temp dir");
+
+    let source_a = tmp_dir_sources
+        .path()
+        .join("a")
+        .join(common::TEST_GREETER_CONTRACT_NAME);
+    let source_b = tmp_dir_sources
+        .path()
+        .join("b")
+        .join(common::TEST_GREETER_CONTRACT_NAME);
+    let args = &[
+        source_a.to_str().unwrap(),
+        source_b.to_str().unwrap(),
+        "-o",
+        tmp_dir_output.path().to_str().unwrap(),
+        "--base-path",
+        tmp_dir_sources.path().to_str().unwrap(),
+        "--artifacts",
+        "foundry",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("in the `foundry` layout"));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_format() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-o",
+        tmp_dir_path,
+        "--artifacts",
+        "truffle",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Unknown artifact format `truffle`",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn with_format() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-o",
+        tmp_dir_path,
+        "--artifacts",
+        "foundry",
+        "-f",
+        "abi",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Artifacts cannot be requested together with `--format`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn without_output_dir() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[common::TEST_GREETER_CONTRACT_PATH, "--artifacts", "hardhat"];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Artifacts can only be written together with `--output-dir`.",
+    ));
+
+    Ok(())
+}
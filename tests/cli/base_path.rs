@@ -0,0 +1,206 @@
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use crate::common;
+
+///
+/// Copies the greeter contract to `Token.vy` in the `a` and `b` subdirectories of `directory`.
+///
+fn create_sources(directory: &str) -> anyhow::Result<(String, String)> {
+    let mut paths = Vec::with_capacity(2);
+    for subdirectory in ["a", "b"] {
+        std::fs::create_dir_all(format!("{directory}/{subdirectory}"))?;
+        let path = format!("{directory}/{subdirectory}/Token.vy");
+        std::fs::copy(common::TEST_GREETER_CONTRACT_PATH, path.as_str())?;
+        paths.push(path);
+    }
+    let path_b = paths.pop().expect("Always exists");
+    let path_a = paths.pop().expect("Always exists");
+    Ok((path_a, path_b))
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir_sources =
+        TempDir::new_in(std::env::current_dir()?).expect("Failed to create temp dir");
+    let tmp_dir_name_sources = tmp_dir_sources
+        .path()
+        .file_name()
+        .expect("Always exists")
+        .to_str()
+        .unwrap();
+    let (path_a, path_b) = create_sources(tmp_dir_name_sources)?;
+
+    let tmp_dir_output = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_output = tmp_dir_output.path().to_str().unwrap();
+
+    let args = &[path_a.as_str(), "-o", tmp_dir_path_output];
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    let args = &[
+        path_a.as_str(),
+        path_b.as_str(),
+        "-o",
+        tmp_dir_path_output,
+        "--overwrite",
+    ];
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    for subdirectory in ["a", "b"] {
+        assert_eq!(
+            false,
+            common::is_file_empty(&format!(
+                "{tmp_dir_path_output}/{tmp_dir_name_sources}/{subdirectory}/Token.vy{}",
+                common::BIN_EXTENSION
+            ))?
+        );
+    }
+    assert!(
+        !std::fs::exists(format!("{tmp_dir_path_output}/a")).expect("Always valid"),
+        "Output path depends on the other sources"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn outside_current_directory() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir_sources = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_sources = tmp_dir_sources.path().to_str().unwrap();
+    let (path_a, _) = create_sources(tmp_dir_path_sources)?;
+
+    let tmp_dir_output = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_output = tmp_dir_output.path().to_str().unwrap();
+
+    let args = &[path_a.as_str(), "-o", tmp_dir_path_output];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    assert_eq!(
+        false,
+        common::is_file_empty(&format!(
+            "{tmp_dir_path_output}/Token.vy{}",
+            common::BIN_EXTENSION
+        ))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn explicit() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir_sources = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_sources = tmp_dir_sources.path().to_str().unwrap();
+    let (path_a, _) = create_sources(tmp_dir_path_sources)?;
+
+    let tmp_dir_output = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_output = tmp_dir_output.path().to_str().unwrap();
+
+    let args = &[
+        path_a.as_str(),
+        "-o",
+        tmp_dir_path_output,
+        "--base-path",
+        tmp_dir_path_sources,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    assert_eq!(
+        false,
+        common::is_file_empty(&format!(
+            "{tmp_dir_path_output}/a/Token.vy{}",
+            common::BIN_EXTENSION
+        ))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn source_outside() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir_sources = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_sources = tmp_dir_sources.path().to_str().unwrap();
+    let (path_a, _) = create_sources(tmp_dir_path_sources)?;
+
+    let tmp_dir_output = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_output = tmp_dir_output.path().to_str().unwrap();
+
+    let args = &[
+        path_a.as_str(),
+        "-o",
+        tmp_dir_path_output,
+        "--base-path",
+        &format!("{tmp_dir_path_sources}/b"),
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("is outside of the base path"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn collision() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir_sources = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_sources = tmp_dir_sources.path().to_str().unwrap();
+    let (path_a, _) = create_sources(tmp_dir_path_sources)?;
+    let path_link = format!("{tmp_dir_path_sources}/Link.vy");
+    std::os::unix::fs::symlink(path_a.as_str(), path_link.as_str())?;
+
+    let tmp_dir_output = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_output = tmp_dir_output.path().to_str().unwrap();
+
+    let args = &[
+        path_a.as_str(),
+        path_link.as_str(),
+        "-o",
+        tmp_dir_path_output,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("are both written to"));
+    assert!(
+        std::fs::read_dir(tmp_dir_path_output)?.next().is_none(),
+        "Output written despite the collision"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn without_output_dir() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--base-path",
+        "tests/data/contracts/vyper",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The base path can only be used together with `--output-dir`.",
+    ));
+
+    Ok(())
+}
@@ -146,7 +146,7 @@ fn multiple_modes() -> anyhow::Result<()> {
 
     let result = common::execute_zkvyper(args)?;
     result.failure().stderr(predicate::str::contains(
-        "Only one mode is allowed at the same time: format, LLVM IR, EraVM assembly, disassembler.",
+        "Only one mode is allowed at the same time: format, LLL IR, LLVM IR, EraVM assembly, disassembler.",
     ));
 
     Ok(())
@@ -0,0 +1,138 @@
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use crate::common;
+
+///
+/// Returns the number of cache entries in `directory`.
+///
+fn entries_count(directory: &str) -> usize {
+    std::fs::read_dir(directory)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| {
+                    entry
+                        .path()
+                        .extension()
+                        .and_then(|extension| extension.to_str())
+                        == Some(era_compiler_vyper::Cache::ENTRY_EXTENSION)
+                })
+                .count()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--cache-dir",
+        tmp_dir_path,
+    ];
+
+    let first_output = common::execute_zkvyper(args)?
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert_eq!(entries_count(tmp_dir_path), 2);
+
+    let second_output = common::execute_zkvyper(args)?
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert_eq!(entries_count(tmp_dir_path), 2);
+    assert_eq!(first_output, second_output);
+
+    Ok(())
+}
+
+#[test]
+fn settings_change() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    for level in ["-O3", "-Oz"] {
+        let args = &[
+            common::TEST_GREETER_CONTRACT_PATH,
+            level,
+            "--cache-dir",
+            tmp_dir_path,
+        ];
+        common::execute_zkvyper(args)?.success();
+    }
+    assert_eq!(entries_count(tmp_dir_path), 4);
+
+    Ok(())
+}
+
+#[test]
+fn no_cache() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[common::TEST_GREETER_CONTRACT_PATH, "--no-cache"];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success().stdout(predicate::str::contains("0x"));
+
+    Ok(())
+}
+
+#[test]
+fn no_cache_with_cache_dir() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--no-cache",
+        "--cache-dir",
+        tmp_dir_path,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The cache directory cannot be set together with `--no-cache`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn prune() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    let unrelated_file_path = format!(
+        "{tmp_dir_path}/unrelated.{}",
+        era_compiler_vyper::Cache::ENTRY_EXTENSION
+    );
+    std::fs::write(unrelated_file_path.as_str(), "{}")?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--cache-dir",
+        tmp_dir_path,
+    ];
+    common::execute_zkvyper(args)?.success();
+    assert_eq!(entries_count(tmp_dir_path), 3);
+
+    let args = &["--cache-dir", tmp_dir_path, "--prune-cache"];
+    common::execute_zkvyper(args)?.success();
+    assert_eq!(entries_count(tmp_dir_path), 1);
+    assert!(std::path::Path::new(unrelated_file_path.as_str()).exists());
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use crate::common;
+
+///
+/// Writes the configuration file to `directory` and returns its path.
+///
+fn write_config(directory: &str, contents: &str) -> anyhow::Result<String> {
+    let path = format!("{directory}/zkvyper.toml");
+    std::fs::write(path.as_str(), contents)?;
+    Ok(path)
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    let config_path = write_config(
+        tmp_dir_path,
+        r#"
+format = "project_metadata"
+optimization = "z"
+
+[[overrides]]
+contracts = ["greeter.vy"]
+optimization = 3
+llvm-options = "-eravm-jump-table-density-threshold=10"
+"#,
+    )?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("optimization_overrides"))
+        .stdout(predicate::str::contains(
+            "-eravm-jump-table-density-threshold=10",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn effective_settings_in_metadata() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    let config_path = write_config(
+        tmp_dir_path,
+        r#"
+format = "project_metadata"
+enable-decimals = true
+disable-vyper-optimizer = true
+search-paths = ["."]
+suppress-warnings = ["txorigin"]
+metadata-hash = "none"
+"#,
+    )?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"enable_decimals\":true"))
+        .stdout(predicate::str::contains("\"optimize\":false"))
+        .stdout(predicate::str::contains("\"search_paths\":["))
+        .stdout(predicate::str::contains(
+            "\"suppressed_warnings\":[\"TxOrigin\"]",
+        ))
+        .stdout(predicate::str::contains("\"metadata_hash_type\""));
+
+    Ok(())
+}
+
+#[test]
+fn input_paths_relative_to_config() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    std::fs::copy(
+        common::TEST_GREETER_CONTRACT_PATH,
+        format!("{tmp_dir_path}/{}", common::TEST_GREETER_CONTRACT_NAME),
+    )?;
+    let config_path = write_config(
+        tmp_dir_path,
+        format!(
+            r#"
+input-paths = ["{}"]
+output-dir = "build"
+"#,
+            common::TEST_GREETER_CONTRACT_NAME
+        )
+        .as_str(),
+    )?;
+
+    let args = &["--config", config_path.as_str()];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    assert_eq!(
+        false,
+        common::is_file_empty(&format!(
+            "{tmp_dir_path}/build/{}",
+            common::VYPER_BIN_OUTPUT_NAME
+        ))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn command_line_precedence() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    let config_path = write_config(tmp_dir_path, r#"format = "unknown""#)?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+        "--format",
+        "abi",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    Ok(())
+}
+
+#[test]
+fn unknown_key() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    let config_path = write_config(tmp_dir_path, r#"optimisation = "3""#)?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("parsing error"))
+        .stderr(predicate::str::contains("optimisation"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_override_pattern() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    let config_path = write_config(
+        tmp_dir_path,
+        r#"
+[[overrides]]
+contracts = ["contracts/[*.vy"]
+optimization = "z"
+"#,
+    )?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid contract pattern"));
+
+    Ok(())
+}
+
+#[test]
+fn evm_bytecode_with_evm_target() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    let config_path = write_config(
+        tmp_dir_path,
+        r#"
+target = "evm"
+evm-bytecode = true
+"#,
+    )?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "EVM bytecode is already emitted for the EVM target.",
+    ));
+
+    Ok(())
+}
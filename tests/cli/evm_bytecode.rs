@@ -0,0 +1,176 @@
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use crate::common;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &["--evm-bytecode", common::TEST_GREETER_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("EVM bytecode"))
+        .stderr(predicate::str::contains("EVM runtime bytecode"));
+
+    Ok(())
+}
+
+#[test]
+fn combined_json() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        "--evm-bytecode",
+        "-f",
+        "combined_json",
+        common::TEST_GREETER_CONTRACT_PATH,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("evm_bytecode"))
+        .stdout(predicate::str::contains("evm_bytecode_runtime"));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    let args = &[
+        "--evm-bytecode",
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-o",
+        tmp_dir_path,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    assert!(!common::is_file_empty(&format!(
+        "{tmp_dir_path}/{}.evm.bin",
+        common::TEST_GREETER_CONTRACT_PATH
+    ))?);
+    assert!(!common::is_file_empty(&format!(
+        "{tmp_dir_path}/{}.evm.runtime.bin",
+        common::TEST_GREETER_CONTRACT_PATH
+    ))?);
+
+    Ok(())
+}
+
+#[test]
+fn selector() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        "-f",
+        "evm_bytecode_runtime",
+        common::TEST_GREETER_CONTRACT_PATH,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("EVM runtime bytecode"))
+        .stderr(predicate::str::contains("EVM bytecode:").not());
+
+    Ok(())
+}
+
+#[test]
+fn selector_distinct_from_eravm_bytecode() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &["-f", "bytecode_runtime", common::TEST_GREETER_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("runtime bytecode"))
+        .stderr(predicate::str::contains("EVM runtime bytecode").not());
+
+    Ok(())
+}
+
+#[test]
+fn lll_mode() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &["--evm-bytecode", "--lll", common::TEST_LLL_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "EVM bytecode is not built in LLL IR, LLVM IR and EraVM assembly modes.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn evm_target() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        "--evm-bytecode",
+        "--target",
+        "evm",
+        common::TEST_GREETER_CONTRACT_PATH,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "EVM bytecode is already emitted for the EVM target.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn same_as_vyper() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    let args = &[
+        "--evm-bytecode",
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-o",
+        tmp_dir_path,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    let vyper = PathBuf::from(common::VYPER_DOWNLOAD_DIR).join(format!(
+        "{}{}",
+        era_compiler_vyper::VyperCompiler::DEFAULT_EXECUTABLE_NAME,
+        std::env::consts::EXE_SUFFIX,
+    ));
+    let output = std::process::Command::new(vyper)
+        .args(["-f", "bytecode", common::TEST_GREETER_CONTRACT_PATH])
+        .output()?;
+    assert!(output.status.success());
+    let expected = String::from_utf8_lossy(output.stdout.as_slice())
+        .trim()
+        .to_owned();
+
+    let actual = std::fs::read_to_string(format!(
+        "{tmp_dir_path}/{}.evm.bin",
+        common::TEST_GREETER_CONTRACT_PATH
+    ))?;
+    assert_eq!(actual.trim(), expected);
+
+    Ok(())
+}
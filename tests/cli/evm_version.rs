@@ -33,7 +33,7 @@ fn llvm_ir_mode() -> anyhow::Result<()> {
 
     let result = common::execute_zkvyper(args)?;
     result.failure().stderr(predicate::str::contains(
-        "EVM version is not used in LLVM IR and EraVM assembly modes.",
+        "EVM version is not used in LLL IR, LLVM IR and EraVM assembly modes.",
     ));
 
     Ok(())
@@ -53,7 +53,7 @@ fn eravm_assembly_mode() -> anyhow::Result<()> {
 
     let result = common::execute_zkvyper(args)?;
     result.failure().stderr(predicate::str::contains(
-        "EVM version is not used in LLVM IR and EraVM assembly modes.",
+        "EVM version is not used in LLL IR, LLVM IR and EraVM assembly modes.",
     ));
 
     Ok(())
@@ -0,0 +1,92 @@
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use crate::common;
+
+#[test]
+fn unsupported() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[common::TEST_SELFDESTRUCT_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .code(era_compiler_vyper::Error::EXIT_CODE_UNSUPPORTED)
+        .stderr(predicate::str::contains("SELFDESTRUCT"));
+
+    Ok(())
+}
+
+#[test]
+fn io() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &["--lll", "unknown"];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .code(era_compiler_vyper::Error::EXIT_CODE_IO)
+        .stderr(predicate::str::contains("reading error"));
+
+    Ok(())
+}
+
+#[test]
+fn vyper() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let contract_path = tmp_dir.path().join("invalid.vy");
+    std::fs::write(contract_path.as_path(), "@external\ndef broken(\n")?;
+
+    let args = &[contract_path.to_str().expect("Always valid")];
+
+    let result = common::execute_zkvyper(args)?;
+    result.code(era_compiler_vyper::Error::EXIT_CODE_VYPER);
+
+    Ok(())
+}
+
+#[test]
+fn size_limit() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let mut assembly = std::fs::read_to_string(common::TEST_ERAVM_ASSEMBLY_CONTRACT_PATH)?;
+    assembly.push_str("CPI0_PADDING:\n");
+    let cell_count = era_compiler_vyper::r#const::ERAVM_BYTECODE_SIZE_LIMIT
+        / era_compiler_common::BYTE_LENGTH_FIELD
+        + 1;
+    for index in 0..cell_count {
+        assembly.push_str(format!("        .cell {index}\n").as_str());
+    }
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let contract_path = tmp_dir.path().join("large.zasm");
+    std::fs::write(contract_path.as_path(), assembly)?;
+
+    let args = &[
+        "--eravm-assembly",
+        contract_path.to_str().expect("Always valid"),
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .code(era_compiler_vyper::Error::EXIT_CODE_SIZE_LIMIT)
+        .stderr(predicate::str::contains("exceeds the EraVM limit"));
+
+    Ok(())
+}
+
+#[test]
+fn compilation() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &["--lll", common::TEST_GREETER_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .code(era_compiler_vyper::Error::EXIT_CODE_COMPILATION)
+        .stderr(predicate::str::contains("LLL IR parsing error"));
+
+    Ok(())
+}
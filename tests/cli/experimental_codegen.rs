@@ -0,0 +1,51 @@
+use predicates::prelude::*;
+
+use crate::common;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &["--experimental-codegen", common::TEST_GREETER_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success().stdout(predicate::str::contains("0x"));
+
+    Ok(())
+}
+
+#[test]
+fn lll_mode() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        "--experimental-codegen",
+        "--lll",
+        common::TEST_GREETER_CONTRACT_PATH,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The experimental code generator is not used in LLL IR, LLVM IR and EraVM assembly modes.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn llvm_ir_mode() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        "--experimental-codegen",
+        "--llvm-ir",
+        common::TEST_GREETER_CONTRACT_PATH,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The experimental code generator is not used in LLL IR, LLVM IR and EraVM assembly modes.",
+    ));
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use predicates::prelude::*;
+use tempfile::TempDir;
 use test_case::test_case;
 
 use era_compiler_vyper::VyperSelector;
@@ -7,13 +8,23 @@ use crate::common;
 
 #[test_case(VyperSelector::CombinedJson)]
 #[test_case(VyperSelector::IRJson)]
+#[test_case(VyperSelector::IR)]
 #[test_case(VyperSelector::AST)]
 #[test_case(VyperSelector::ABI)]
 #[test_case(VyperSelector::MethodIdentifiers)]
 #[test_case(VyperSelector::Layout)]
 #[test_case(VyperSelector::UserDocumentation)]
 #[test_case(VyperSelector::DeveloperDocumentation)]
+#[test_case(VyperSelector::Interface)]
+#[test_case(VyperSelector::ExternalInterface)]
+#[test_case(VyperSelector::AnnotatedAST)]
+#[test_case(VyperSelector::Metadata)]
+#[test_case(VyperSelector::IRRuntime)]
+#[test_case(VyperSelector::Bytecode)]
+#[test_case(VyperSelector::BytecodeRuntime)]
 #[test_case(VyperSelector::EraVMAssembly)]
+#[test_case(VyperSelector::ErgsEstimates)]
+#[test_case(VyperSelector::CallGraph)]
 #[test_case(VyperSelector::ProjectMetadata)]
 fn default(selector: VyperSelector) -> anyhow::Result<()> {
     let _ = common::setup();
@@ -33,6 +44,7 @@ fn all() -> anyhow::Result<()> {
 
     let format = [
         VyperSelector::IRJson,
+        VyperSelector::IR,
         VyperSelector::AST,
         VyperSelector::ABI,
         VyperSelector::MethodIdentifiers,
@@ -40,6 +52,8 @@ fn all() -> anyhow::Result<()> {
         VyperSelector::UserDocumentation,
         VyperSelector::DeveloperDocumentation,
         VyperSelector::EraVMAssembly,
+        VyperSelector::ErgsEstimates,
+        VyperSelector::CallGraph,
         VyperSelector::ProjectMetadata,
     ]
     .into_iter()
@@ -69,6 +83,81 @@ fn unsupported_selector() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn interface_forwarded() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[common::TEST_GREETER_CONTRACT_PATH, "-f", "interface"];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("interface:"))
+        .stdout(predicate::str::contains("def "));
+
+    Ok(())
+}
+
+#[test]
+fn bytecode_runtime() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[common::TEST_GREETER_CONTRACT_PATH, "-f", "bytecode_runtime"];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("runtime bytecode:"));
+
+    Ok(())
+}
+
+#[test]
+fn bytecode_runtime_output_dir() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-f",
+        "bytecode_runtime",
+        "-o",
+        tmp_dir_path,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    assert!(!common::is_file_empty(&format!(
+        "{tmp_dir_path}/{}.runtime.zbin",
+        common::TEST_GREETER_CONTRACT_PATH
+    ))?);
+
+    Ok(())
+}
+
+#[test_case("opcodes", "eravm_assembly")]
+#[test_case("opcodes_runtime", "eravm_assembly")]
+#[test_case("asm", "eravm_assembly")]
+#[test_case("source_map", "source maps are not supported")]
+#[test_case("source_map_runtime", "source maps are not supported")]
+#[test_case("blueprint_bytecode", "blueprints are not supported")]
+fn evm_specific_selector(selector: &str, explanation: &str) -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[common::TEST_GREETER_CONTRACT_PATH, "-f", selector];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("is EVM-specific"))
+        .stderr(predicate::str::contains(explanation));
+
+    Ok(())
+}
+
 #[test]
 fn combined_json_with_other_option() -> anyhow::Result<()> {
     let _ = common::setup();
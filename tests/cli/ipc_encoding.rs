@@ -0,0 +1,36 @@
+use predicates::prelude::*;
+use test_case::test_case;
+
+use crate::common;
+
+#[test_case("binary")]
+#[test_case("json")]
+fn default(encoding: &str) -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--ipc-encoding",
+        encoding,
+        "--no-cache",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success().stdout(predicate::str::contains("0x"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[common::TEST_GREETER_CONTRACT_PATH, "--ipc-encoding", "yaml"];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid IPC encoding `yaml`"));
+
+    Ok(())
+}
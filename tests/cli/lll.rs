@@ -0,0 +1,55 @@
+use predicates::prelude::*;
+
+use crate::common;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &["--lll", common::TEST_LLL_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success().stdout(predicate::str::contains("0x"));
+
+    Ok(())
+}
+
+#[test]
+fn json() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &["--lll", common::TEST_LLL_JSON_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success().stdout(predicate::str::contains("0x"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &["--lll", common::TEST_GREETER_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("LLL IR parsing error"));
+
+    Ok(())
+}
+
+#[test]
+fn not_found() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &["--lll", "unknown"];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("reading error"));
+
+    Ok(())
+}
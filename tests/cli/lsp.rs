@@ -0,0 +1,222 @@
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
+
+use crate::common;
+
+///
+/// Starts the language server with the given extra arguments.
+///
+fn start_lsp(args: &[&str]) -> anyhow::Result<std::process::Child> {
+    let executable = assert_cmd::cargo::cargo_bin("zkvyper-lsp");
+    let child = Command::new(executable)
+        .env(
+            "PATH",
+            std::fs::canonicalize(PathBuf::from(common::VYPER_DOWNLOAD_DIR))?,
+        )
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    Ok(child)
+}
+
+///
+/// Sends the message with the `Content-Length` header.
+///
+fn send<W>(writer: &mut W, message: serde_json::Value) -> anyhow::Result<()>
+where
+    W: Write,
+{
+    let content = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{content}", content.len())?;
+    writer.flush()?;
+    Ok(())
+}
+
+///
+/// Receives the next message.
+///
+fn receive<R>(reader: &mut R) -> anyhow::Result<serde_json::Value>
+where
+    R: BufRead,
+{
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:") {
+            content_length = value.trim().parse()?;
+        }
+    }
+    let mut content = vec![0; content_length];
+    reader.read_exact(content.as_mut_slice())?;
+    Ok(serde_json::from_slice(content.as_slice())?)
+}
+
+///
+/// Returns the `file` URI of the test contract.
+///
+fn uri(path: &str) -> anyhow::Result<String> {
+    let path = std::fs::canonicalize(path)?;
+    Ok(format!("file://{}", path.to_string_lossy()))
+}
+
+#[test]
+fn diagnostics() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let mut lsp = start_lsp(&[])?;
+    let mut writer = lsp.stdin.take().expect("Always exists");
+    let mut reader = BufReader::new(lsp.stdout.take().expect("Always exists"));
+
+    send(
+        &mut writer,
+        serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+    )?;
+    let response = receive(&mut reader)?;
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["capabilities"]["hoverProvider"], true);
+    send(
+        &mut writer,
+        serde_json::json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+    )?;
+
+    let uri = uri(common::TEST_TX_ORIGIN_CONTRACT_PATH)?;
+    send(
+        &mut writer,
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": {
+                    "uri": uri,
+                    "languageId": "vyper",
+                    "version": 1,
+                    "text": std::fs::read_to_string(common::TEST_TX_ORIGIN_CONTRACT_PATH)?,
+                },
+            },
+        }),
+    )?;
+    let notification = receive(&mut reader)?;
+    assert_eq!(notification["method"], "textDocument/publishDiagnostics");
+    assert_eq!(notification["params"]["uri"], uri.as_str());
+    let diagnostic = &notification["params"]["diagnostics"][0];
+    assert_eq!(diagnostic["severity"], 2);
+    assert_eq!(diagnostic["range"]["start"]["line"], 2);
+    assert_eq!(diagnostic["range"]["start"]["character"], 11);
+    assert_eq!(diagnostic["range"]["end"]["character"], 20);
+    assert!(diagnostic["message"]
+        .as_str()
+        .expect("Always exists")
+        .contains("tx.origin"));
+
+    send(
+        &mut writer,
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "textDocument/hover",
+            "params": {
+                "textDocument": { "uri": uri },
+                "position": { "line": 1, "character": 4 },
+            },
+        }),
+    )?;
+    let response = receive(&mut reader)?;
+    assert_eq!(response["id"], 2);
+    assert!(response["result"]["contents"]["value"]
+        .as_str()
+        .expect("Always exists")
+        .contains("`test()`"));
+
+    send(
+        &mut writer,
+        serde_json::json!({ "jsonrpc": "2.0", "id": 3, "method": "shutdown" }),
+    )?;
+    let response = receive(&mut reader)?;
+    assert_eq!(response["id"], 3);
+    send(
+        &mut writer,
+        serde_json::json!({ "jsonrpc": "2.0", "method": "exit" }),
+    )?;
+    assert!(lsp.wait()?.success());
+
+    Ok(())
+}
+
+#[test]
+fn compile_on_save() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let mut lsp = start_lsp(&["--compile-on-save"])?;
+    let mut writer = lsp.stdin.take().expect("Always exists");
+    let mut reader = BufReader::new(lsp.stdout.take().expect("Always exists"));
+
+    let uri = uri(common::TEST_GREETER_CONTRACT_PATH)?;
+    send(
+        &mut writer,
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didSave",
+            "params": { "textDocument": { "uri": uri } },
+        }),
+    )?;
+    let notification = receive(&mut reader)?;
+    assert_eq!(notification["params"]["diagnostics"], serde_json::json!([]));
+
+    send(
+        &mut writer,
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "textDocument/hover",
+            "params": {
+                "textDocument": { "uri": uri },
+                "position": { "line": 0, "character": 0 },
+            },
+        }),
+    )?;
+    let response = receive(&mut reader)?;
+    assert!(response["result"]["contents"]["value"]
+        .as_str()
+        .expect("Always exists")
+        .contains("EraVM contract size"));
+
+    drop(writer);
+    assert!(lsp.wait()?.success());
+
+    Ok(())
+}
+
+#[test]
+fn unknown_method() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let mut lsp = start_lsp(&[])?;
+    let mut writer = lsp.stdin.take().expect("Always exists");
+    let mut reader = BufReader::new(lsp.stdout.take().expect("Always exists"));
+
+    send(
+        &mut writer,
+        serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "textDocument/completion" }),
+    )?;
+    let response = receive(&mut reader)?;
+    assert_eq!(response["error"]["code"], -32601);
+
+    send(
+        &mut writer,
+        serde_json::json!({ "jsonrpc": "2.0", "method": "exit" }),
+    )?;
+    assert!(!lsp.wait()?.success());
+
+    Ok(())
+}
@@ -2,26 +2,42 @@
 //! The CLI/e2e tests entry module.
 //!
 
+mod artifacts;
+mod base_path;
 mod basic;
+mod cache;
+mod config;
 mod debug_output_dir;
 mod disable_vyper_optimizer;
 mod disassemble;
 mod eravm_assembly;
+mod evm_bytecode;
 mod evm_version;
+mod exit_codes;
+mod experimental_codegen;
 mod fallback_oz;
 mod format;
+mod ipc_encoding;
+mod lll;
 mod llvm_debug_logging;
 mod llvm_ir;
 mod llvm_options;
 mod llvm_verify_each;
+mod lsp;
 mod metadata_hash;
 mod no_bytecode_metadata;
 mod optimization;
+mod optimization_override;
 mod output_dir;
 mod overwrite;
+mod process_limits;
 mod recursive_process;
+mod recursive_worker;
+mod reproducer;
 mod search_paths;
+mod server;
 mod suppress_warnings;
+mod target;
 mod threads;
 mod version;
 mod vyper;
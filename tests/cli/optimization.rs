@@ -38,3 +38,119 @@ fn eravm_assembly_mode() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test_case("size")]
+#[test_case("cycles")]
+fn auto(objective: &str) -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--optimization",
+        "auto",
+        "--optimization-objective",
+        objective,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("0x"))
+        .stderr(predicate::str::contains("optimization candidates:"))
+        .stderr(predicate::str::contains("(selected)"));
+
+    Ok(())
+}
+
+#[test]
+fn auto_metadata() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-Oauto",
+        "--optimization-objective",
+        "cycles",
+        "--format",
+        "project_metadata",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "\"tuning\":{\"objective\":\"cycles\"}",
+        ))
+        .stdout(predicate::str::contains(format!(
+            "\"contracts\":{{\"{}\"",
+            common::TEST_GREETER_CONTRACT_PATH
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn auto_size_budget_exceeded() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--optimization",
+        "auto",
+        "--optimization-objective",
+        "cycles",
+        "--optimization-size-budget",
+        "1",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "None of the optimization candidates is suitable",
+        ))
+        .stderr(predicate::str::contains(
+            "exceeds the size budget of 1 bytes",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn objective_without_auto() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-O3",
+        "--optimization-objective",
+        "size",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The optimization objective and size budget can only be used together with `--optimization auto`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn auto_with_override() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--optimization",
+        "auto",
+        "--optimization-override",
+        "greeter.vy=z",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Optimization overrides cannot be used together with `--optimization auto`.",
+    ));
+
+    Ok(())
+}
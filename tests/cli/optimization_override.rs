@@ -0,0 +1,67 @@
+use predicates::prelude::*;
+
+use crate::common;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        common::TEST_OPTIMIZER_CONTRACT_PATH,
+        "-O3",
+        "--optimization-override",
+        "greeter.vy=z",
+        "--format",
+        "project_metadata",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("optimization_overrides"))
+        .stdout(predicate::str::contains(format!(
+            "\"contracts\":{{\"{}\"",
+            common::TEST_GREETER_CONTRACT_PATH
+        )))
+        .stdout(predicate::str::contains(common::TEST_OPTIMIZER_CONTRACT_PATH).not());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_format() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--optimization-override",
+        "greeter.vy",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid optimization override `greeter.vy`. Expected `<pattern>=<level>`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn eravm_assembly_mode() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[
+        "--optimization-override",
+        "default.zasm=z",
+        "--eravm-assembly",
+        common::TEST_ERAVM_ASSEMBLY_CONTRACT_PATH,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Optimization overrides are not supported in EraVM assembly mode.",
+    ));
+
+    Ok(())
+}
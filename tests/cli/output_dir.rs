@@ -26,7 +26,7 @@ fn default() -> anyhow::Result<()> {
         false,
         common::is_file_empty(&format!(
             "{tmp_dir_path_zk_vyper}/{}",
-            common::VYPER_BIN_OUTPUT_NAME
+            common::VYPER_BIN_OUTPUT_PATH
         ))?
     );
 
@@ -112,14 +112,14 @@ fn eravm_assembly_only() -> anyhow::Result<()> {
         false,
         common::is_file_empty(&format!(
             "{tmp_dir_path_zk_vyper}/{}",
-            common::VYPER_BIN_OUTPUT_NAME
+            common::VYPER_BIN_OUTPUT_PATH
         ))?
     );
     assert_eq!(
         false,
         common::is_file_empty(&format!(
             "{tmp_dir_path_zk_vyper}/{}",
-            common::VYPER_ASM_OUTPUT_NAME
+            common::VYPER_ASM_OUTPUT_PATH
         ))?
     );
 
@@ -172,23 +172,105 @@ fn all_output() -> anyhow::Result<()> {
         false,
         common::is_file_empty(&format!(
             "{tmp_dir_path_zk_vyper}/{}",
-            common::VYPER_BIN_OUTPUT_NAME
+            common::VYPER_BIN_OUTPUT_PATH
         ))?
     );
     assert_eq!(
         false,
         common::is_file_empty(&format!(
             "{tmp_dir_path_zk_vyper}/{}",
-            common::VYPER_ASM_OUTPUT_NAME
+            common::VYPER_ASM_OUTPUT_PATH
         ))?
     );
-    assert_eq!(
-        false,
-        common::is_file_empty(&format!(
+    for selector in [
+        VyperSelector::IRJson,
+        VyperSelector::AST,
+        VyperSelector::ABI,
+        VyperSelector::MethodIdentifiers,
+        VyperSelector::Layout,
+        VyperSelector::UserDocumentation,
+        VyperSelector::DeveloperDocumentation,
+    ] {
+        assert_eq!(
+            false,
+            common::is_file_empty(&format!(
+                "{tmp_dir_path_zk_vyper}/{}.{selector}.{}",
+                common::TEST_GREETER_CONTRACT_PATH,
+                era_compiler_common::EXTENSION_JSON,
+            ))?
+        );
+    }
+    assert!(
+        !std::fs::exists(format!(
             "{tmp_dir_path_zk_vyper}/{}",
-            common::TEST_GREETER_CONTRACT_NAME
-        ))?
+            common::TEST_GREETER_CONTRACT_PATH
+        ))
+        .expect("Always valid"),
+        "Legacy concatenated output file found"
+    );
+
+    let manifest: serde_json::Value = serde_json::from_slice(
+        std::fs::read(format!("{tmp_dir_path_zk_vyper}/manifest.json"))?.as_slice(),
+    )?;
+    let files = manifest["contracts"][common::TEST_GREETER_CONTRACT_PATH]
+        .as_object()
+        .expect("Contract not found in the manifest");
+    assert_eq!(
+        files["abi"],
+        format!(
+            "{}.abi.{}",
+            common::TEST_GREETER_CONTRACT_PATH,
+            era_compiler_common::EXTENSION_JSON
+        )
+    );
+    assert_eq!(files["bytecode"], common::VYPER_BIN_OUTPUT_PATH);
+    assert_eq!(manifest["project_metadata"], "meta.json");
+
+    Ok(())
+}
+
+#[test]
+fn legacy_layout() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir_zk_vyper = TempDir::new().expect("Failed to create temp dir");
+    let tmp_dir_path_zk_vyper = tmp_dir_zk_vyper.path().to_str().unwrap();
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "-o",
+        tmp_dir_path_zk_vyper,
+        "-f",
+        "abi,layout",
+        "--legacy-output-layout",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success();
+
+    let legacy_output = std::fs::read_to_string(format!(
+        "{tmp_dir_path_zk_vyper}/{}",
+        common::TEST_GREETER_CONTRACT_PATH
+    ))?;
+    assert_eq!(legacy_output.lines().count(), 2);
+    assert!(
+        !std::fs::exists(format!("{tmp_dir_path_zk_vyper}/manifest.json")).expect("Always valid"),
+        "Manifest found in the legacy layout"
     );
 
     Ok(())
 }
+
+#[test]
+fn legacy_layout_without_output_dir() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &[common::TEST_GREETER_CONTRACT_PATH, "--legacy-output-layout"];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The legacy output layout can only be used together with `--output-dir`.",
+    ));
+
+    Ok(())
+}
@@ -19,12 +19,12 @@ fn default() -> anyhow::Result<()> {
         &[
             &format!(
                 "{}{}",
-                common::TEST_GREETER_CONTRACT_NAME,
+                common::TEST_GREETER_CONTRACT_PATH,
                 common::BIN_EXTENSION
             ),
             &format!(
                 "{}{}",
-                common::TEST_GREETER_CONTRACT_NAME,
+                common::TEST_GREETER_CONTRACT_PATH,
                 common::ERAVM_ASSEMBLY_EXTENSION
             ),
         ],
@@ -51,7 +51,7 @@ fn default() -> anyhow::Result<()> {
     // Verify that files are not empty
     assert_eq!(
         false,
-        common::is_file_empty(&format!("{tmp_dir_path}/{}", common::VYPER_BIN_OUTPUT_NAME))?
+        common::is_file_empty(&format!("{tmp_dir_path}/{}", common::VYPER_BIN_OUTPUT_PATH))?
     );
 
     Ok(())
@@ -70,12 +70,12 @@ fn all_output() -> anyhow::Result<()> {
         &[
             &format!(
                 "{}{}",
-                common::TEST_GREETER_CONTRACT_NAME,
+                common::TEST_GREETER_CONTRACT_PATH,
                 common::BIN_EXTENSION
             ),
             &format!(
                 "{}{}",
-                common::TEST_GREETER_CONTRACT_NAME,
+                common::TEST_GREETER_CONTRACT_PATH,
                 common::ERAVM_ASSEMBLY_EXTENSION
             ),
         ],
@@ -120,17 +120,17 @@ fn all_output() -> anyhow::Result<()> {
     // Verify that files are not empty
     assert_eq!(
         false,
-        common::is_file_empty(&format!("{tmp_dir_path}/{}", common::VYPER_BIN_OUTPUT_NAME))?
+        common::is_file_empty(&format!("{tmp_dir_path}/{}", common::VYPER_BIN_OUTPUT_PATH))?
     );
     assert_eq!(
         false,
-        common::is_file_empty(&format!("{tmp_dir_path}/{}", common::VYPER_ASM_OUTPUT_NAME))?
+        common::is_file_empty(&format!("{tmp_dir_path}/{}", common::VYPER_ASM_OUTPUT_PATH))?
     );
     assert_eq!(
         false,
         common::is_file_empty(&format!(
             "{tmp_dir_path}/{}",
-            common::TEST_GREETER_CONTRACT_NAME
+            common::TEST_GREETER_CONTRACT_PATH
         ))?
     );
 
@@ -206,7 +206,7 @@ fn one_output_not_passed_bytecode_deleted(selector: VyperSelector) -> anyhow::Re
         tmp_dir_path,
         &[&format!(
             "{}{}",
-            common::TEST_GREETER_CONTRACT_NAME,
+            common::TEST_GREETER_CONTRACT_PATH,
             common::BIN_EXTENSION
         )],
     );
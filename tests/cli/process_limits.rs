@@ -0,0 +1,99 @@
+use predicates::prelude::*;
+
+use crate::common;
+
+///
+/// Writes an executable shell script standing in for `vyper`.
+///
+#[cfg(unix)]
+fn fake_vyper(directory: &std::path::Path, body: &str) -> anyhow::Result<String> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = directory.join("vyper");
+    std::fs::write(path.as_path(), format!("#!/bin/sh\n{body}\n"))?;
+    std::fs::set_permissions(path.as_path(), std::fs::Permissions::from_mode(0o755))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--process-timeout",
+        "600",
+        "--process-memory-limit",
+        "65536",
+        "--no-cache",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.success().stdout(predicate::str::contains("0x"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn timeout() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let tmp_dir = tempfile::TempDir::new()?;
+    let vyper = fake_vyper(tmp_dir.path(), "exec sleep 30")?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--vyper",
+        vyper.as_str(),
+        "--process-timeout",
+        "1",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "timed out after 1s and was killed",
+    ));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn signal() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let tmp_dir = tempfile::TempDir::new()?;
+    let vyper = fake_vyper(
+        tmp_dir.path(),
+        "echo 'Fatal Python error' >&2\nkill -SEGV $$",
+    )?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--vyper",
+        vyper.as_str(),
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(
+        predicate::str::contains("terminated by signal 11 (SIGSEGV)")
+            .and(predicate::str::contains("Fatal Python error")),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn zero_timeout() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[common::TEST_GREETER_CONTRACT_PATH, "--process-timeout", "0"];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The process timeout must be positive.",
+    ));
+
+    Ok(())
+}
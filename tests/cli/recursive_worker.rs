@@ -0,0 +1,98 @@
+use predicates::prelude::*;
+
+use era_compiler_vyper::process::frame;
+use era_compiler_vyper::process::handshake::Handshake;
+use era_compiler_vyper::process::worker::Worker;
+
+use crate::common;
+
+#[test]
+fn invalid_input() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let handshake = Handshake::new(era_compiler_vyper::ProcessEncoding::Json);
+    let mut input = Vec::new();
+    frame::write(&mut input, serde_json::to_vec(&handshake)?.as_slice())?;
+    frame::write(&mut input, b"not json")?;
+    frame::write(&mut input, b"{}")?;
+
+    let output =
+        assert_cmd::Command::cargo_bin(era_compiler_vyper::r#const::DEFAULT_EXECUTABLE_NAME)?
+            .arg("--recursive-worker")
+            .write_stdin(input)
+            .output()?;
+    assert!(output.status.success());
+
+    let mut stdout = output.stdout.as_slice();
+    let response = frame::read(&mut stdout)?.expect("Always exists");
+    assert_eq!(serde_json::from_slice::<Handshake>(&response)?, handshake);
+    for _ in 0..2 {
+        let response = frame::read(&mut stdout)?.expect("Always exists");
+        assert_eq!(response[0], Worker::STATUS_FAILURE);
+        let error: era_compiler_vyper::Error = serde_json::from_slice(&response[1..])?;
+        assert!(matches!(
+            error,
+            era_compiler_vyper::Error::Compilation { .. }
+        ));
+        assert!(error.to_string().contains("Stdin reading error"));
+    }
+    assert!(frame::read(&mut stdout)?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn protocol_mismatch() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let mut handshake = Handshake::new(era_compiler_vyper::ProcessEncoding::Binary);
+    handshake.protocol_version = 0;
+    let mut input = Vec::new();
+    frame::write(&mut input, serde_json::to_vec(&handshake)?.as_slice())?;
+
+    assert_cmd::Command::cargo_bin(era_compiler_vyper::r#const::DEFAULT_EXECUTABLE_NAME)?
+        .arg("--recursive-worker")
+        .write_stdin(input)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Recursive process protocol mismatch",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn compiler_version_mismatch() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let mut handshake = Handshake::new(era_compiler_vyper::ProcessEncoding::Binary);
+    handshake.compiler_version = "0.0.0".to_owned();
+    let mut input = Vec::new();
+    frame::write(&mut input, serde_json::to_vec(&handshake)?.as_slice())?;
+
+    assert_cmd::Command::cargo_bin(era_compiler_vyper::r#const::DEFAULT_EXECUTABLE_NAME)?
+        .arg("--recursive-worker")
+        .write_stdin(input)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Recursive process protocol mismatch",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn excess_args() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &["--recursive-worker", common::TEST_GREETER_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "No other options are allowed in recursive mode.",
+    ));
+
+    Ok(())
+}
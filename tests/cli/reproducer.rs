@@ -0,0 +1,85 @@
+use predicates::prelude::*;
+
+use crate::common;
+
+#[test]
+fn write_and_replay() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let tmp_dir = tempfile::TempDir::new()?;
+    let reproducer_dir = tmp_dir.path().to_string_lossy().to_string();
+
+    let args = &[
+        common::TEST_SELFDESTRUCT_CONTRACT_PATH,
+        "--reproducer-dir",
+        reproducer_dir.as_str(),
+        "--no-cache",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("The reproducer of"));
+
+    let bundles = std::fs::read_dir(tmp_dir.path())?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(bundles.len(), 1, "Expected exactly one reproducer bundle");
+    let bundle = bundles[0].to_string_lossy().to_string();
+    assert!(bundle.ends_with(era_compiler_vyper::Reproducer::EXTENSION));
+
+    let args = &["--replay", bundle.as_str()];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The `SELFDESTRUCT` instruction is not supported",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn disabled_by_default() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[common::TEST_SELFDESTRUCT_CONTRACT_PATH, "--no-cache"];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("The reproducer of").not());
+
+    Ok(())
+}
+
+#[test]
+fn replay_with_input_files() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        common::TEST_GREETER_CONTRACT_PATH,
+        "--replay",
+        "bundle.reproducer.json",
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Input files cannot be passed together with `--replay`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn replay_missing_bundle() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &["--replay", "tests/data/missing.reproducer.json"];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("reading error"));
+
+    Ok(())
+}
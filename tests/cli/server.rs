@@ -0,0 +1,238 @@
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
+
+use crate::common;
+
+///
+/// Starts the compile server with the given extra arguments.
+///
+fn start_server(args: &[&str]) -> anyhow::Result<std::process::Child> {
+    let executable =
+        assert_cmd::cargo::cargo_bin(era_compiler_vyper::r#const::DEFAULT_EXECUTABLE_NAME);
+    let child = Command::new(executable)
+        .env(
+            "PATH",
+            std::fs::canonicalize(PathBuf::from(common::VYPER_DOWNLOAD_DIR))?,
+        )
+        .arg("--server")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    Ok(child)
+}
+
+///
+/// Sends the request and reads the response line.
+///
+fn request<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    request: serde_json::Value,
+) -> anyhow::Result<serde_json::Value>
+where
+    R: BufRead,
+    W: Write,
+{
+    writeln!(writer, "{request}")?;
+    writer.flush()?;
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    Ok(serde_json::from_str(line.as_str())?)
+}
+
+#[test]
+fn stdio() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let mut server = start_server(&["--no-cache"])?;
+    let mut writer = server.stdin.take().expect("Always exists");
+    let mut reader = BufReader::new(server.stdout.take().expect("Always exists"));
+
+    let response = request(
+        &mut reader,
+        &mut writer,
+        serde_json::json!({ "id": 1, "method": "version" }),
+    )?;
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["version"], env!("CARGO_PKG_VERSION"));
+
+    for id in 2..=3 {
+        let response = request(
+            &mut reader,
+            &mut writer,
+            serde_json::json!({
+                "id": id,
+                "method": "compile",
+                "params": {
+                    "input_paths": [common::TEST_GREETER_CONTRACT_PATH],
+                    "optimization": "3",
+                },
+            }),
+        )?;
+        assert_eq!(response["id"], id);
+        assert!(response.get("error").is_none(), "{response}");
+        let contracts = response["result"]
+            .as_object()
+            .expect("Always exists")
+            .iter()
+            .filter(|(path, _)| path.ends_with(common::TEST_GREETER_CONTRACT_NAME))
+            .count();
+        assert_eq!(contracts, 1);
+    }
+
+    let response = request(
+        &mut reader,
+        &mut writer,
+        serde_json::json!({ "id": "unknown", "method": "link" }),
+    )?;
+    assert_eq!(response["id"], "unknown");
+    assert!(response["error"]["message"]
+        .as_str()
+        .expect("Always exists")
+        .contains("Unknown method `link`"));
+
+    let response = request(
+        &mut reader,
+        &mut writer,
+        serde_json::json!({ "id": 4, "method": "shutdown" }),
+    )?;
+    assert_eq!(response["id"], 4);
+    assert!(server.wait()?.success());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_request() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let mut server = start_server(&["--no-cache"])?;
+    let mut writer = server.stdin.take().expect("Always exists");
+    let mut reader = BufReader::new(server.stdout.take().expect("Always exists"));
+
+    writeln!(writer, "not json")?;
+    writer.flush()?;
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let response: serde_json::Value = serde_json::from_str(line.as_str())?;
+    assert!(response["id"].is_null());
+    assert!(response["error"]["message"]
+        .as_str()
+        .expect("Always exists")
+        .contains("Request parsing error"));
+
+    drop(writer);
+    assert!(server.wait()?.success());
+
+    Ok(())
+}
+
+///
+/// Connects to the server socket, waiting for the server to bind it.
+///
+#[cfg(unix)]
+fn connect(socket_path: &std::path::Path) -> std::os::unix::net::UnixStream {
+    for _ in 0..100 {
+        if let Ok(stream) = std::os::unix::net::UnixStream::connect(socket_path) {
+            return stream;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    panic!("Server socket is not available");
+}
+
+#[cfg(unix)]
+#[test]
+fn unix_socket() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let socket_path = tmp_dir.path().join("zkvyper.sock");
+
+    let mut server = start_server(&[socket_path.to_str().expect("Always valid"), "--no-cache"])?;
+    let mut writer = connect(socket_path.as_path());
+    let mut reader = BufReader::new(writer.try_clone()?);
+
+    let response = request(
+        &mut reader,
+        &mut writer,
+        serde_json::json!({ "id": 1, "method": "version" }),
+    )?;
+    assert_eq!(response["result"]["version"], env!("CARGO_PKG_VERSION"));
+
+    request(
+        &mut reader,
+        &mut writer,
+        serde_json::json!({ "id": 2, "method": "shutdown" }),
+    )?;
+    assert!(server.wait()?.success());
+    assert!(!socket_path.exists());
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn unix_socket_dropped_client() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let socket_path = tmp_dir.path().join("zkvyper.sock");
+
+    let mut server = start_server(&[socket_path.to_str().expect("Always valid"), "--no-cache"])?;
+
+    let mut dropped = connect(socket_path.as_path());
+    let request_line = serde_json::json!({
+        "id": 1,
+        "method": "compile",
+        "params": { "input_paths": [common::TEST_GREETER_CONTRACT_PATH] },
+    });
+    writeln!(dropped, "{request_line}")?;
+    dropped.flush()?;
+    drop(dropped);
+
+    let mut invalid = connect(socket_path.as_path());
+    invalid.write_all(b"\xff\xfe\n")?;
+    invalid.flush()?;
+    drop(invalid);
+
+    let mut writer = connect(socket_path.as_path());
+    let mut reader = BufReader::new(writer.try_clone()?);
+    let response = request(
+        &mut reader,
+        &mut writer,
+        serde_json::json!({ "id": 2, "method": "version" }),
+    )?;
+    assert_eq!(response["id"], 2);
+    assert_eq!(response["result"]["version"], env!("CARGO_PKG_VERSION"));
+
+    request(
+        &mut reader,
+        &mut writer,
+        serde_json::json!({ "id": 3, "method": "shutdown" }),
+    )?;
+    assert!(server.wait()?.success());
+    assert!(!socket_path.exists());
+
+    Ok(())
+}
+
+#[test]
+fn input_files() -> anyhow::Result<()> {
+    let _ = common::setup();
+
+    let args = &["--server", "--no-cache", common::TEST_GREETER_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicates::str::contains(
+        "Input files cannot be passed in server mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use predicates::prelude::*;
+
+use crate::common;
+
+#[test]
+fn evm() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &["--target", "evm", common::TEST_GREETER_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("0x"))
+        .stderr(predicate::str::contains("runtime"));
+
+    Ok(())
+}
+
+#[test]
+fn evm_combined_json() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        "--target",
+        "evm",
+        "-f",
+        "combined_json",
+        common::TEST_GREETER_CONTRACT_PATH,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("bytecode_runtime"));
+
+    Ok(())
+}
+
+#[test]
+fn evm_lll_mode() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &["--target", "evm", "--lll", common::TEST_LLL_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLL IR, LLVM IR, EraVM assembly and disassembler modes are not supported for the EVM target.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn evm_experimental_codegen() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        "--target",
+        "evm",
+        "--experimental-codegen",
+        common::TEST_GREETER_CONTRACT_PATH,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The experimental code generator is not supported for the EVM target.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn evm_eravm_assembly_output() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &[
+        "--target",
+        "evm",
+        "-f",
+        "eravm_assembly",
+        common::TEST_GREETER_CONTRACT_PATH,
+    ];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "EraVM assembly and ergs estimates are not available for the EVM target.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    common::setup()?;
+
+    let args = &["--target", "wasm", common::TEST_GREETER_CONTRACT_PATH];
+
+    let result = common::execute_zkvyper(args)?;
+    result.failure();
+
+    Ok(())
+}
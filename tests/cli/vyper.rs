@@ -49,7 +49,7 @@ fn llvm_ir_mode() -> anyhow::Result<()> {
 
     let result = common::execute_zkvyper(args)?;
     result.failure().stderr(predicate::str::contains(
-        "`vyper` is not used in LLVM IR and EraVM assembly modes.",
+        "`vyper` is not used in LLL IR, LLVM IR and EraVM assembly modes.",
     ));
 
     Ok(())
@@ -69,7 +69,7 @@ fn eravm_assembly_mode() -> anyhow::Result<()> {
 
     let result = common::execute_zkvyper(args)?;
     result.failure().stderr(predicate::str::contains(
-        "`vyper` is not used in LLVM IR and EraVM assembly modes.",
+        "`vyper` is not used in LLL IR, LLVM IR and EraVM assembly modes.",
     ));
 
     Ok(())
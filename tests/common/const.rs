@@ -35,6 +35,12 @@ pub const VYPER_BIN_OUTPUT_NAME: &'static str = "greeter.vy.zbin";
 /// A test output file.
 pub const VYPER_ASM_OUTPUT_NAME: &'static str = "greeter.vy.zasm";
 
+/// A test output file path mirroring the input file path in the output directory.
+pub const VYPER_BIN_OUTPUT_PATH: &'static str = "tests/data/contracts/vyper/greeter.vy.zbin";
+
+/// A test output file path mirroring the input file path in the output directory.
+pub const VYPER_ASM_OUTPUT_PATH: &'static str = "tests/data/contracts/vyper/greeter.vy.zasm";
+
 /// A test input file.
 pub const TEST_TX_ORIGIN_CONTRACT_PATH: &'static str = "tests/data/contracts/vyper/tx_origin.vy";
 
@@ -60,6 +66,12 @@ pub const TEST_RAW_CREATE_CONTRACT_PATH: &'static str = "tests/data/contracts/vy
 pub const TEST_ERAVM_ASSEMBLY_CONTRACT_PATH: &'static str =
     "tests/data/contracts/eravm/default.zasm";
 
+/// A test input file.
+pub const TEST_LLL_CONTRACT_PATH: &'static str = "tests/data/contracts/lll/default.lll";
+
+/// A test input file.
+pub const TEST_LLL_JSON_CONTRACT_PATH: &'static str = "tests/data/contracts/lll/default.json";
+
 /// A test input file.
 pub const TEST_LLVM_CONTRACT_PATH: &'static str = "tests/data/contracts/llvm/default.ll";
 
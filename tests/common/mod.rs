@@ -142,6 +142,9 @@ pub fn build_vyper_standard_json(
         vec![],
         vec![],
         None,
+        vec![],
+        None,
+        era_compiler_vyper::ProcessStrategy::default(),
     )?;
     build.link(BTreeMap::new())?;
     Ok(build)
@@ -170,6 +173,7 @@ pub fn build_vyper_combined_json(
         true,
         None,
         true,
+        false,
     )?;
 
     let mut build = project.compile(
@@ -180,6 +184,9 @@ pub fn build_vyper_combined_json(
         vec![],
         vec![],
         None,
+        vec![],
+        None,
+        era_compiler_vyper::ProcessStrategy::default(),
     )?;
     build.link(BTreeMap::new())?;
     Ok(build)
@@ -240,6 +247,9 @@ pub fn is_output_same_as_file(file_path: &str, output: &str) -> anyhow::Result<b
 pub fn create_files(dir: &str, files: &[&str]) {
     for file in files {
         let path = Path::new(dir).join(Path::new(file));
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("Failed to create directory");
+        }
         let mut file = File::create(path).expect("Failed to create file");
         writeln!(file, "").expect("Failed to write to file");
     }
@@ -0,0 +1,120 @@
+//!
+//! The Vyper compiler unit tests for the call graph.
+//!
+
+use era_compiler_vyper::project::contract::vyper::call_graph::edge::Kind as EdgeKind;
+use era_compiler_vyper::project::contract::vyper::call_graph::node::Kind as NodeKind;
+use era_compiler_vyper::project::contract::vyper::call_graph::CallGraph;
+use era_compiler_vyper::project::contract::vyper::expression::Expression;
+
+const IR: &str = r#"{
+  "seq": [
+    {
+      "deploy": [
+        0,
+        {
+          "seq": [
+            {
+              "seq": [
+                {
+                  "label": [
+                    "external_foo__common",
+                    "var_list",
+                    { "seq": [{ "goto": ["internal_bar"] }, { "log1": [0, 0, 1] }] }
+                  ]
+                }
+              ]
+            },
+            {
+              "seq": [
+                {
+                  "label": [
+                    "internal_bar",
+                    "var_list",
+                    { "seq": [{ "goto": ["internal_bar"] }, "pass"] }
+                  ]
+                }
+              ]
+            },
+            {
+              "seq": [
+                {
+                  "label": ["internal_dead", "var_list", { "seq": ["pass"] }]
+                }
+              ]
+            },
+            { "goto": ["external_foo__common"] }
+          ]
+        },
+        0
+      ]
+    }
+  ]
+}"#;
+
+fn call_graph() -> CallGraph {
+    let ir: Expression = serde_json::from_str(IR).expect("Invalid LLL IR");
+    CallGraph::try_from_ir(&ir).expect("Call graph building failed")
+}
+
+#[test]
+fn edges() {
+    let call_graph = call_graph();
+
+    assert!(call_graph
+        .edges
+        .iter()
+        .any(|edge| edge.to == "external_foo__common"
+            && edge.kind == EdgeKind::Goto
+            && call_graph
+                .nodes
+                .iter()
+                .any(|node| node.id == edge.from && node.kind == NodeKind::Entry)));
+    for (from, to, kind) in [
+        ("external_foo__common", "internal_bar", EdgeKind::Goto),
+        ("external_foo__common", "LOG1", EdgeKind::Log1),
+        ("internal_bar", "internal_bar", EdgeKind::Goto),
+    ] {
+        assert!(
+            call_graph
+                .edges
+                .iter()
+                .any(|edge| edge.from == from && edge.to == to && edge.kind == kind),
+            "Edge `{from}` -> `{to}` not found"
+        );
+    }
+}
+
+#[test]
+fn recursion() {
+    let call_graph = call_graph();
+
+    let node = call_graph
+        .nodes
+        .iter()
+        .find(|node| node.id == "internal_bar")
+        .expect("Node not found");
+    assert!(node.recursive);
+    assert!(node.reachable);
+}
+
+#[test]
+fn unreachable() {
+    let call_graph = call_graph();
+
+    let node = call_graph
+        .nodes
+        .iter()
+        .find(|node| node.id == "internal_dead")
+        .expect("Node not found");
+    assert!(!node.reachable);
+    assert!(!node.recursive);
+}
+
+#[test]
+fn dot() {
+    let dot = call_graph().to_dot("default.vy");
+
+    assert!(dot.starts_with("digraph \"default.vy\" {"));
+    assert!(dot.contains("\"external_foo__common\" -> \"internal_bar\" [label=\"goto\"];"));
+}
@@ -0,0 +1,127 @@
+//!
+//! The Vyper compiler differential tests of the LLL IR interpreter against the LLVM lowering.
+//!
+
+use num::BigInt;
+use num::BigUint;
+use test_case::test_case;
+
+use era_compiler_llvm_context::IContext;
+
+use era_compiler_vyper::project::contract::vyper::expression::Expression;
+use era_compiler_vyper::project::contract::vyper::interpreter::word;
+use era_compiler_vyper::project::contract::vyper::interpreter::Interpreter;
+
+/// The name of the function the expression is lowered into.
+const FUNCTION_NAME: &str = "differential";
+
+///
+/// Lowers the closed expression into a function returning its value, optimizes the module
+/// at `-O3`, and returns the constant the function has been folded into.
+///
+fn lower(expression: Expression) -> anyhow::Result<BigUint> {
+    era_compiler_llvm_context::initialize_target();
+
+    let optimizer_settings = era_compiler_llvm_context::OptimizerSettings::cycles();
+    let target_machine = era_compiler_llvm_context::TargetMachine::new(
+        era_compiler_common::Target::EraVM,
+        &optimizer_settings,
+        &[],
+    )?;
+
+    let llvm = inkwell::context::Context::create();
+    let mut context = era_compiler_llvm_context::EraVMContext::new(
+        &llvm,
+        llvm.create_module(FUNCTION_NAME),
+        vec![],
+        era_compiler_llvm_context::Optimizer::new(optimizer_settings.clone()),
+        None,
+    );
+    context.set_vyper_data(era_compiler_llvm_context::EraVMContextVyperData::new(
+        0, false,
+    ));
+
+    let function = context.add_function(
+        FUNCTION_NAME,
+        context.function_type(vec![], 1, false),
+        1,
+        Some(inkwell::module::Linkage::External),
+    )?;
+    function
+        .borrow_mut()
+        .set_vyper_data(era_compiler_llvm_context::EraVMFunctionVyperData::default());
+    context.set_current_function(FUNCTION_NAME)?;
+    context.set_basic_block(context.current_function().borrow().entry_block());
+
+    let value = expression
+        .into_llvm_value(&mut context)?
+        .ok_or_else(|| anyhow::anyhow!("The expression has no value"))?;
+    context.build_unconditional_branch(context.current_function().borrow().return_block())?;
+    context.set_basic_block(context.current_function().borrow().return_block());
+    context.build_return(Some(&value))?;
+
+    context
+        .module()
+        .verify()
+        .map_err(|error| anyhow::anyhow!("Invalid LLVM IR: {error}"))?;
+    era_compiler_llvm_context::Optimizer::new(optimizer_settings)
+        .run(&target_machine, context.module())
+        .map_err(|error| anyhow::anyhow!("LLVM optimizer error: {error}"))?;
+
+    let function = context
+        .module()
+        .get_function(FUNCTION_NAME)
+        .ok_or_else(|| anyhow::anyhow!("Function `{FUNCTION_NAME}` does not exist"))?;
+    let ir = function.print_to_string().to_string();
+    let constant = ir
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("ret i256 "))
+        .and_then(|constant| constant.parse::<BigInt>().ok())
+        .ok_or_else(|| anyhow::anyhow!("The function has not been folded:\n{ir}"))?;
+    Ok(word::from_signed(constant))
+}
+
+#[test_case("[add, -1, 2]" ; "add")]
+#[test_case("[sub, 0, 1]" ; "sub")]
+#[test_case("[mul, [shl, 128, 1], [shl, 128, 1]]" ; "mul_overflow")]
+#[test_case("[div, 7, 2]" ; "div")]
+#[test_case("[div, 7, 0]" ; "div_by_zero")]
+#[test_case("[mod, 7, 0]" ; "mod_by_zero")]
+#[test_case("[sdiv, -7, 2]" ; "sdiv")]
+#[test_case("[sdiv, [shl, 255, 1], -1]" ; "sdiv_overflow")]
+#[test_case("[sdiv, 1, 0]" ; "sdiv_by_zero")]
+#[test_case("[smod, -7, 2]" ; "smod_dividend_sign")]
+#[test_case("[smod, 7, -2]" ; "smod_divisor_sign")]
+#[test_case("[smod, 7, 0]" ; "smod_by_zero")]
+#[test_case("[addmod, -1, 2, 3]" ; "addmod")]
+#[test_case("[mulmod, -1, -1, 7]" ; "mulmod")]
+#[test_case("[signextend, 0, 0xff]" ; "signextend_negative")]
+#[test_case("[signextend, 1, 0x8000]" ; "signextend_two_bytes")]
+#[test_case("[signextend, 31, 0xff]" ; "signextend_full_word")]
+#[test_case("[byte, 31, 0x1234]" ; "byte")]
+#[test_case("[byte, 32, -1]" ; "byte_out_of_range")]
+#[test_case("[shl, 256, 1]" ; "shl_overflow")]
+#[test_case("[shr, 4, 0x1234]" ; "shr")]
+#[test_case("[sar, 1, -3]" ; "sar_rounds_down")]
+#[test_case("[sar, 300, -1]" ; "sar_negative_overflow")]
+#[test_case("[slt, -1, 0]" ; "slt")]
+#[test_case("[sgt, -1, 0]" ; "sgt")]
+#[test_case("[lt, -1, 0]" ; "lt")]
+#[test_case("[iszero, 0]" ; "iszero")]
+#[test_case("[not, 0]" ; "not")]
+#[test_case("[xor, [or, 0xf0, 0x0f], [and, 0xff, 0x3c]]" ; "bitwise")]
+#[test_case("[ceil32, 33]" ; "ceil32")]
+#[test_case("[clamp, -5, -3, 10]" ; "clamp_signed")]
+#[test_case("[if, [gt, 2, 1], 10, 20]" ; "if")]
+#[test_case("[with, x, 20, [seq, [set, x, [add, x, 1]], [mul, x, 2]]]" ; "with_set")]
+fn interpreter_matches_llvm(lll: &str) {
+    let expression = Expression::try_from_lll(lll).expect("Invalid LLL IR");
+
+    let interpreted = Interpreter::fold(&expression).expect("The expression is not foldable");
+    let lowered = lower(expression).expect("LLVM lowering error");
+
+    assert_eq!(
+        interpreted, lowered,
+        "The interpreter and the LLVM lowering disagree on `{lll}`"
+    );
+}
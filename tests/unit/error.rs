@@ -0,0 +1,66 @@
+//!
+//! The typed error unit tests.
+//!
+
+use crate::common;
+
+#[test]
+fn source_location() {
+    let source_code = "# comment\n@external\ndef f():\n    selfdestruct(msg.sender)\n";
+    let offset = source_code.find("selfdestruct").expect("Always exists");
+
+    let location = era_compiler_vyper::SourceLocation::from_offset(source_code, offset);
+    assert_eq!(location, era_compiler_vyper::SourceLocation::new(4, 4));
+    assert_eq!(location.to_string(), "line 4:4");
+}
+
+#[test]
+fn unsupported() {
+    let error = common::build_vyper_combined_json(
+        vec![common::TEST_SELFDESTRUCT_CONTRACT_PATH],
+        &semver::Version::new(0, 4, 3),
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect_err("Must fail");
+
+    let error = error
+        .downcast_ref::<era_compiler_vyper::Error>()
+        .expect("Must be typed");
+    assert_eq!(
+        error.exit_code(),
+        era_compiler_vyper::Error::EXIT_CODE_UNSUPPORTED
+    );
+}
+
+#[test]
+fn serde_roundtrip() {
+    let error =
+        era_compiler_vyper::Error::unsupported("The `PC` instruction is not supported".to_owned());
+
+    let json = serde_json::to_vec(&error).expect("Always valid");
+    let decoded: era_compiler_vyper::Error =
+        serde_json::from_slice(json.as_slice()).expect("Always valid");
+    assert_eq!(decoded.to_string(), error.to_string());
+    assert_eq!(decoded.exit_code(), error.exit_code());
+}
+
+#[test]
+fn serde_roundtrip_subprocess() {
+    let error = era_compiler_vyper::Error::Contracts(std::collections::BTreeMap::from([(
+        "greeter.vy".to_owned(),
+        era_compiler_vyper::Error::Subprocess(era_compiler_vyper::SupervisorError::new(
+            "zkvyper".to_owned(),
+            era_compiler_vyper::SupervisorErrorKind::Timeout(std::time::Duration::from_secs(5)),
+            "stderr".to_owned(),
+        )),
+    )]));
+
+    let json = serde_json::to_vec(&error).expect("Always valid");
+    let decoded: era_compiler_vyper::Error =
+        serde_json::from_slice(json.as_slice()).expect("Always valid");
+    assert_eq!(decoded.to_string(), error.to_string());
+    assert_eq!(
+        decoded.exit_code(),
+        era_compiler_vyper::Error::EXIT_CODE_SUBPROCESS
+    );
+}
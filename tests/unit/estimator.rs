@@ -0,0 +1,80 @@
+//!
+//! The Vyper compiler unit tests for the static ergs estimator.
+//!
+
+use std::collections::BTreeMap;
+
+const ASSEMBLY: &str = r#"
+        .text
+        .file   "main"
+        .globl  __entry
+__entry:
+.func_begin0:
+        sub.s!  code[@CPI0_0], r1, r0
+        jump.eq @.BB0_2
+        sub.s!  code[@CPI0_1], r1, r0
+        jump.ne @.BB0_5
+        add     1, r0, r2
+.BB0_3:
+        sub.s!  10, r2, r0
+        jump.eq @.BB0_4
+        add     1, r2, r2
+        jump    @.BB0_3
+.BB0_4:
+        retl    r1, @DEFAULT_FAR_RETURN
+.BB0_2:
+        add     42, r0, r1
+        stm.h   r0, r1
+        retl    r1, @DEFAULT_FAR_RETURN
+.BB0_5:
+        revl    r1, @DEFAULT_FAR_REVERT
+.func_end0:
+
+DEFAULT_FAR_RETURN:
+        retl    r1, @DEFAULT_FAR_RETURN
+DEFAULT_FAR_REVERT:
+        revl    r1, @DEFAULT_FAR_REVERT
+
+        .rodata
+CPI0_0:
+        .cell 1039457780
+CPI0_1:
+        .cell 1519042605
+"#;
+
+fn estimates() -> BTreeMap<String, era_compiler_vyper::estimator::estimate::Estimate> {
+    let method_identifiers = BTreeMap::from([
+        ("first()".to_owned(), "0x3df4ddf4".to_owned()),
+        ("second()".to_owned(), "0x5a8ac02d".to_owned()),
+        ("unknown()".to_owned(), "0x12345678".to_owned()),
+    ]);
+    era_compiler_vyper::estimator::estimate(ASSEMBLY, &method_identifiers)
+        .expect("Estimation failed")
+}
+
+#[test]
+fn bounded() {
+    let estimates = estimates();
+    let estimate = estimates.get("first()").expect("Estimate not found");
+
+    assert!(!estimate.unbounded_loops);
+    assert_eq!(estimate.best_case.cycles, 5);
+    assert_eq!(estimate.worst_case, Some(estimate.best_case));
+}
+
+#[test]
+fn unbounded_loop() {
+    let estimates = estimates();
+    let estimate = estimates.get("second()").expect("Estimate not found");
+
+    assert!(estimate.unbounded_loops);
+    assert!(estimate.worst_case.is_none());
+    assert_eq!(estimate.best_case.cycles, 8);
+}
+
+#[test]
+fn dispatch_not_found() {
+    let estimates = estimates();
+
+    assert!(!estimates.contains_key("unknown()"));
+}
@@ -0,0 +1,111 @@
+//!
+//! The Vyper compiler unit tests for the LLL IR interpreter.
+//!
+
+use std::collections::BTreeMap;
+
+use num::BigUint;
+use num::One;
+use test_case::test_case;
+
+use era_compiler_vyper::project::contract::vyper::expression::Expression;
+use era_compiler_vyper::project::contract::vyper::interpreter::environment::Environment;
+use era_compiler_vyper::project::contract::vyper::interpreter::log::Log;
+use era_compiler_vyper::project::contract::vyper::interpreter::outcome::Outcome;
+use era_compiler_vyper::project::contract::vyper::interpreter::word;
+use era_compiler_vyper::project::contract::vyper::interpreter::Interpreter;
+
+fn fold(lll: &str) -> Option<BigUint> {
+    let expression = Expression::try_from_lll(lll).expect("Invalid LLL IR");
+    Interpreter::fold(&expression)
+}
+
+fn signed(value: i64) -> BigUint {
+    word::from_signed(value.into())
+}
+
+#[test_case("[sdiv, -7, 2]", signed(-3) ; "sdiv_rounds_towards_zero")]
+#[test_case("[sdiv, [shl, 255, 1], -1]", BigUint::one() << 255 ; "sdiv_overflow")]
+#[test_case("[sdiv, 1, 0]", signed(0) ; "sdiv_by_zero")]
+#[test_case("[smod, -7, 2]", signed(-1) ; "smod_dividend_sign")]
+#[test_case("[smod, 7, -2]", signed(1) ; "smod_divisor_sign_ignored")]
+#[test_case("[smod, 7, 0]", signed(0) ; "smod_by_zero")]
+#[test_case("[exp, 2, 256]", signed(0) ; "exp_overflow")]
+#[test_case("[exp, 0, 0]", signed(1) ; "exp_zero_power_zero")]
+#[test_case("[exp, 3, 5]", signed(243) ; "exp")]
+#[test_case("[signextend, 0, 0xff]", signed(-1) ; "signextend_negative")]
+#[test_case("[signextend, 0, 0x17f]", signed(0x7f) ; "signextend_positive")]
+#[test_case("[signextend, 1, 0x8000]", signed(-0x8000) ; "signextend_two_bytes")]
+#[test_case("[signextend, 31, 0xff]", signed(0xff) ; "signextend_full_word")]
+#[test_case("[sar, 1, -3]", signed(-2) ; "sar_rounds_down")]
+#[test_case("[sar, 300, -1]", signed(-1) ; "sar_negative_overflow")]
+#[test_case("[sar, 300, 1]", signed(0) ; "sar_positive_overflow")]
+#[test_case("[byte, 31, 0x1234]", signed(0x34) ; "byte_least_significant")]
+#[test_case("[byte, 30, 0x1234]", signed(0x12) ; "byte")]
+#[test_case("[byte, 32, -1]", signed(0) ; "byte_out_of_range")]
+#[test_case("[addmod, -1, 2, 3]", signed(2) ; "addmod_no_overflow")]
+#[test_case("[mulmod, -1, -1, 7]", signed(1) ; "mulmod_no_overflow")]
+#[test_case("[clamp, -5, -3, 10]", signed(-3) ; "clamp_signed")]
+#[test_case("[ceil32, 33]", signed(64) ; "ceil32")]
+#[test_case("[with, x, 20, [seq, [set, x, [add, x, 1]], [mul, x, 2]]]", signed(42) ; "with_set")]
+fn folding(lll: &str, expected: BigUint) {
+    assert_eq!(fold(lll), Some(expected));
+}
+
+#[test_case("[uclamplt, 5, 3]" ; "uclamplt")]
+#[test_case("[clamp, 0, -1, 10]" ; "clamp_signed")]
+#[test_case("[uclamp, 0, -1, 10]" ; "uclamp")]
+#[test_case("[sload, 0]" ; "storage")]
+#[test_case("[calldataload, 0]" ; "calldata")]
+fn not_folded(lll: &str) {
+    assert_eq!(fold(lll), None);
+}
+
+#[test]
+fn program() {
+    let code = Expression::try_from_lll(
+        r#"
+[seq,
+  [seq,
+    [label, internal_double, [var_list, return_buffer, return_pc],
+      [seq,
+        [mstore, return_buffer, [mul, 2, [mload, return_buffer]]],
+        [exit_to, return_pc]]]],
+  [mstore, 64, [calldataload, 0]],
+  [goto, internal_double, 64, _sym_label_ret],
+  [repeat, i, 0, 10, 10, [seq, [if, [eq, i, 3], break], [sstore, i, [mload, 64]]]],
+  [log1, 64, 32, 7],
+  [return, 64, 32]]
+"#,
+    )
+    .expect("Invalid LLL IR");
+
+    let mut calldata = vec![0u8; 32];
+    calldata[31] = 21;
+    let mut interpreter = Interpreter::new(Environment::new(calldata));
+    let outcome = interpreter.run(&code).expect("Interpretation failed");
+
+    let result = word::to_bytes(&BigUint::from(42u8)).to_vec();
+    assert_eq!(outcome, Outcome::Return(result.clone()));
+    assert_eq!(
+        interpreter.storage,
+        (0u8..3)
+            .map(|key| (BigUint::from(key), BigUint::from(42u8)))
+            .collect::<BTreeMap<BigUint, BigUint>>()
+    );
+    assert_eq!(
+        interpreter.logs,
+        vec![Log::new(vec![BigUint::from(7u8)], result)]
+    );
+}
+
+#[test]
+fn assertion() {
+    let code = Expression::try_from_lll("[seq, [sstore, 0, 1], [assert, [lt, 1, 0]], stop]")
+        .expect("Invalid LLL IR");
+
+    let mut interpreter = Interpreter::new(Environment::default());
+    let outcome = interpreter.run(&code).expect("Interpretation failed");
+
+    assert_eq!(outcome, Outcome::Revert(vec![]));
+}
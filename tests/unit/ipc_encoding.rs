@@ -0,0 +1,36 @@
+//!
+//! The worker process IPC encoding unit tests.
+//!
+
+use test_case::test_case;
+
+use era_compiler_vyper::ProcessEncoding;
+
+#[test_case(ProcessEncoding::Binary)]
+#[test_case(ProcessEncoding::Json)]
+fn round_trip(encoding: ProcessEncoding) {
+    let value = serde_json::json!({
+        "ast_type": "Int",
+        "value": "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+            .parse::<serde_json::Number>()
+            .expect("Always valid"),
+        "body": [{ "lineno": 1 }, null, "text", true],
+    });
+
+    let encoded = encoding.encode(&value);
+    let decoded: serde_json::Value = encoding.decode(encoded.as_slice()).expect("Always valid");
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn frame_length_limit() {
+    let mut input = (era_compiler_vyper::process::frame::MAX_PAYLOAD_LENGTH + 1)
+        .to_le_bytes()
+        .to_vec();
+    input.extend_from_slice(b"payload");
+
+    let error =
+        era_compiler_vyper::process::frame::read(&mut input.as_slice()).expect_err("Always fails");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("exceeds the limit"));
+}
@@ -0,0 +1,85 @@
+//!
+//! The Vyper compiler unit tests for the label arguments lowering.
+//!
+
+use era_compiler_vyper::project::contract::vyper::expression::Expression;
+
+fn lower(ir: serde_json::Value) -> serde_json::Value {
+    let ir: Expression = serde_json::from_value(ir).expect("Invalid LLL IR");
+    let ir = ir
+        .lower_label_arguments()
+        .expect("Label arguments lowering failed");
+    serde_json::to_value(ir).expect("Always valid")
+}
+
+#[test]
+fn cleanup_goto() {
+    let ir = serde_json::json!({
+        "seq": [
+            {
+                "label": [
+                    "loop_cleanup",
+                    { "var_list": ["x", "y"] },
+                    { "seq": ["pass"] }
+                ]
+            },
+            { "goto": ["loop_cleanup", 1, 2] }
+        ]
+    });
+
+    let expected = serde_json::json!({
+        "seq": [
+            { "set": ["x", 1] },
+            { "set": ["y", 2] },
+            { "goto": ["loop_cleanup"] }
+        ]
+    });
+    assert_eq!(lower(ir)["seq"][1], expected);
+}
+
+#[test]
+fn exit_to_with_prefix() {
+    let ir = serde_json::json!({
+        "seq": [
+            {
+                "label": [
+                    "external_foo_cleanup",
+                    { "var_list": ["result", "return_pc"] },
+                    { "seq": ["pass"] }
+                ]
+            },
+            { "exit_to": ["_sym_external_foo_cleanup", 42] }
+        ]
+    });
+
+    let expected = serde_json::json!({
+        "seq": [
+            { "set": ["result", 42] },
+            { "exit_to": ["_sym_external_foo_cleanup"] }
+        ]
+    });
+    assert_eq!(lower(ir)["seq"][1], expected);
+}
+
+#[test]
+fn function_call_untouched() {
+    let ir = serde_json::json!({
+        "seq": [
+            {
+                "label": [
+                    "internal_bar",
+                    { "var_list": ["x", "return_pc"] },
+                    { "seq": [{ "exit_to": ["return_pc", 1] }] }
+                ]
+            },
+            { "goto": ["internal_bar", 64, "_sym_label_1"] }
+        ]
+    });
+
+    let lowered = lower(ir.clone());
+    assert_eq!(
+        lowered,
+        serde_json::to_value(serde_json::from_value::<Expression>(ir).expect("Invalid LLL IR"))
+            .expect("Always valid")
+    );
+}
@@ -3,6 +3,22 @@
 //!
 
 mod builtins;
+mod call_graph;
+mod differential;
+mod error;
+mod estimator;
+mod interpreter;
+mod ipc_encoding;
+mod label_arguments;
+mod optimization_override;
 mod optimizer;
+mod parser;
+mod printer;
+mod process_strategy;
+mod session;
+mod source_key;
+mod supervisor;
+mod tuning;
 mod unsupported_opcodes;
+mod venom;
 mod warnings;
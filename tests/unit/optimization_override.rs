@@ -0,0 +1,90 @@
+//!
+//! The Vyper compiler unit tests for per-contract optimization overrides.
+//!
+
+use std::str::FromStr;
+
+use era_compiler_vyper::OptimizationOverride;
+
+fn optimization_override(pattern: &str, optimization: char) -> OptimizationOverride {
+    OptimizationOverride::new(pattern.to_owned(), Some(optimization), false, None)
+        .expect("Always valid")
+}
+
+#[test]
+fn file_name_pattern() {
+    let optimization_override = optimization_override("Pool*.vy", 'z');
+    assert!(optimization_override.is_match("contracts/amm/Pool.vy"));
+    assert!(optimization_override.is_match("PoolFactory.vy"));
+    assert!(!optimization_override.is_match("contracts/Token.vy"));
+}
+
+#[test]
+fn path_pattern() {
+    let optimization_override = optimization_override("contracts/amm/*.vy", 'z');
+    assert!(optimization_override.is_match("contracts/amm/Pool.vy"));
+    assert!(!optimization_override.is_match("Pool.vy"));
+}
+
+#[test]
+fn resolve_in_order() {
+    let overrides = vec![
+        optimization_override("*.vy", 'z'),
+        OptimizationOverride::new(
+            "Pool.vy".to_owned(),
+            None,
+            false,
+            Some(vec!["-eravm-jump-table-density-threshold=10".to_owned()]),
+        )
+        .expect("Always valid"),
+    ];
+
+    let (optimizer_settings, llvm_options) = OptimizationOverride::resolve(
+        overrides.as_slice(),
+        "contracts/Pool.vy",
+        &era_compiler_llvm_context::OptimizerSettings::cycles(),
+        &[],
+    )
+    .expect("Always valid");
+    assert_eq!(
+        optimizer_settings,
+        era_compiler_llvm_context::OptimizerSettings::try_from_cli('z').expect("Always valid")
+    );
+    assert_eq!(
+        llvm_options,
+        vec!["-eravm-jump-table-density-threshold=10".to_owned()]
+    );
+
+    let (optimizer_settings, llvm_options) = OptimizationOverride::resolve(
+        overrides.as_slice(),
+        "contracts/Token.vy",
+        &era_compiler_llvm_context::OptimizerSettings::cycles(),
+        &[],
+    )
+    .expect("Always valid");
+    assert_eq!(
+        optimizer_settings,
+        era_compiler_llvm_context::OptimizerSettings::try_from_cli('z').expect("Always valid")
+    );
+    assert!(llvm_options.is_empty());
+}
+
+#[test]
+fn invalid_level() {
+    assert!(OptimizationOverride::new("Pool.vy".to_owned(), Some('9'), false, None).is_err());
+}
+
+#[test]
+fn from_str() {
+    assert_eq!(
+        OptimizationOverride::from_str("contracts/*.vy=z").expect("Always valid"),
+        optimization_override("contracts/*.vy", 'z')
+    );
+}
+
+#[test]
+fn from_str_invalid() {
+    assert!(OptimizationOverride::from_str("Pool.vy").is_err());
+    assert!(OptimizationOverride::from_str("Pool.vy=").is_err());
+    assert!(OptimizationOverride::from_str("Pool.vy=zz").is_err());
+}
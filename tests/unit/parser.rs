@@ -0,0 +1,42 @@
+//!
+//! The Vyper compiler unit tests for the LLL IR parser.
+//!
+
+use era_compiler_vyper::project::contract::vyper::expression::parser;
+use era_compiler_vyper::project::contract::vyper::expression::Expression;
+
+#[test]
+fn round_trip() {
+    let json = r#"{"seq": [{"mstore": [64, 128]}, {"if": [{"lt": ["calldatasize", 4]}, {"goto": ["external_foo"]}]}, {"with": ["x", -1, {"seq": []}]}, {"sstore": [0, 1]}, "stop"]}"#;
+    let ir: Expression = serde_json::from_str(json).expect("Invalid LLL IR");
+
+    let value = parser::parse(ir.to_string().as_str()).expect("Parsing failed");
+    assert_eq!(
+        value,
+        serde_json::from_str::<serde_json::Value>(json).expect("Always valid")
+    );
+}
+
+#[test]
+fn comments_and_literals() {
+    let value = parser::parse(
+        r#"
+# The line comment.
+(seq /* the block
+comment */ (mstore 0x40 0080) [sstore, 0, -0x1])
+"#,
+    )
+    .expect("Parsing failed");
+
+    assert_eq!(
+        value,
+        serde_json::json!({"seq": [{"mstore": [64, 80]}, {"sstore": [0, -1]}]})
+    );
+}
+
+#[test]
+fn unterminated_list() {
+    let error = parser::parse("[seq,\n  [mstore, 0, 1]").expect_err("Must fail");
+
+    assert!(error.to_string().contains("1:1: Unterminated list"));
+}
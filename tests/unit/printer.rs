@@ -0,0 +1,53 @@
+//!
+//! The Vyper compiler unit tests for the LLL IR printer.
+//!
+
+use era_compiler_vyper::project::contract::vyper::expression::Expression;
+
+#[test]
+fn single_line() {
+    let ir: Expression = serde_json::from_str(
+        r#"{"seq": [{"mstore": [64, 128]}, {"if": [{"lt": ["calldatasize", 4]}, {"goto": ["fallback"]}]}, "stop"]}"#,
+    )
+    .expect("Invalid LLL IR");
+
+    assert_eq!(
+        ir.to_string(),
+        "[seq, [mstore, 64, 128], [if, [lt, calldatasize, 4], [goto, fallback]], stop]"
+    );
+}
+
+#[test]
+fn multi_line() {
+    let ir: Expression = serde_json::from_str(
+        r#"{"seq": [{"mstore": [64, 128]}, {"if": [{"lt": ["calldatasize", 4]}, {"goto": ["external_foo"]}]}, {"sstore": [0, 1]}, "stop"]}"#,
+    )
+    .expect("Invalid LLL IR");
+
+    assert_eq!(
+        ir.to_string(),
+        r#"[seq,
+  [mstore, 64, 128],
+  [if, [lt, calldatasize, 4], [goto, external_foo]],
+  [sstore, 0, 1],
+  stop]"#
+    );
+}
+
+#[test]
+fn nested_multi_line() {
+    let ir: Expression = serde_json::from_str(
+        r#"{"seq": [{"seq": [{"mstore": [64, 128]}, {"if": [{"lt": ["calldatasize", 4]}, {"goto": ["external_foo"]}]}, {"sstore": [0, 1]}, "stop"]}]}"#,
+    )
+    .expect("Invalid LLL IR");
+
+    assert_eq!(
+        ir.to_string(),
+        r#"[seq,
+  [seq,
+    [mstore, 64, 128],
+    [if, [lt, calldatasize, 4], [goto, external_foo]],
+    [sstore, 0, 1],
+    stop]]"#
+    );
+}
@@ -0,0 +1,61 @@
+//!
+//! The execution strategy unit tests.
+//!
+
+use std::path::Path;
+
+use era_compiler_vyper::ProcessStrategy;
+use era_compiler_vyper::Project;
+use era_compiler_vyper::VyperSelector;
+
+use crate::common;
+
+///
+/// Compiles the LLL test contract with the specified execution strategy.
+///
+fn compile(strategy: ProcessStrategy) -> anyhow::Result<era_compiler_vyper::Build> {
+    common::setup()?;
+    era_compiler_llvm_context::initialize_target();
+
+    let project = Project::try_from_lll_paths(
+        &[Path::new(common::TEST_LLL_CONTRACT_PATH)],
+        &[VyperSelector::EraVMAssembly],
+    )?;
+    project.compile(
+        None,
+        era_compiler_common::MetadataHashType::None,
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+        vec![],
+        vec![],
+        None,
+        vec![],
+        None,
+        strategy,
+    )
+}
+
+#[test]
+fn in_process() -> anyhow::Result<()> {
+    let build = compile(ProcessStrategy::InProcess)?;
+    let contract = build
+        .contracts
+        .get(common::TEST_LLL_CONTRACT_PATH)
+        .expect("Always exists");
+    assert!(!contract.build.bytecode.is_empty());
+    Ok(())
+}
+
+#[test]
+fn same_as_subprocess() -> anyhow::Result<()> {
+    let in_process = compile(ProcessStrategy::InProcess)?;
+    let subprocess = compile(ProcessStrategy::Subprocess)?;
+    for (path, contract) in in_process.contracts.iter() {
+        let expected = subprocess.contracts.get(path).expect("Always exists");
+        assert_eq!(
+            contract.build.bytecode, expected.build.bytecode,
+            "Contract `{path}`"
+        );
+    }
+    Ok(())
+}
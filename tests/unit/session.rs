@@ -0,0 +1,68 @@
+//!
+//! The compiler session unit tests.
+//!
+
+use std::path::PathBuf;
+
+use era_compiler_vyper::CompilationConfig;
+use era_compiler_vyper::CompilerSession;
+use era_compiler_vyper::ProcessStrategy;
+use era_compiler_vyper::VyperCompiler;
+
+use crate::common;
+
+#[test]
+fn lll() -> anyhow::Result<()> {
+    common::setup()?;
+    era_compiler_llvm_context::initialize_target();
+
+    let config = CompilationConfig::default()
+        .with_metadata(era_compiler_common::MetadataHashType::Keccak256, true)
+        .with_optimizer_settings(era_compiler_llvm_context::OptimizerSettings::size())
+        .with_strategy(ProcessStrategy::InProcess);
+    let build = CompilerSession::new(config)
+        .lll(vec![PathBuf::from(common::TEST_LLL_CONTRACT_PATH)], &[])?;
+
+    assert!(build.contracts.contains_key(common::TEST_LLL_CONTRACT_PATH));
+    Ok(())
+}
+
+#[test]
+fn combined_json() -> anyhow::Result<()> {
+    common::setup()?;
+    era_compiler_llvm_context::initialize_target();
+
+    let version = VyperCompiler::SUPPORTED_VERSIONS
+        .last()
+        .expect("Always exists");
+    let vyper = common::get_vyper_compiler(version)?;
+
+    let config = CompilationConfig::default().with_vyper(vyper.executable.as_str());
+    let combined_json = CompilerSession::new(config)
+        .combined_json(vec![PathBuf::from(common::TEST_GREETER_CONTRACT_PATH)])?;
+
+    assert!(combined_json
+        .contracts
+        .keys()
+        .any(|path| path.ends_with(common::TEST_GREETER_CONTRACT_NAME)));
+    Ok(())
+}
+
+#[test]
+fn missing_vyper() {
+    let config = CompilationConfig::default().with_vyper("zkvyper-test-missing-vyper");
+    let result = CompilerSession::new(config)
+        .combined_json(vec![PathBuf::from(common::TEST_GREETER_CONTRACT_PATH)]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn standard_output_evm_with_evm_bytecode() {
+    let config = CompilationConfig::default().with_evm_bytecode(true);
+    let result = CompilerSession::new(config)
+        .standard_output_evm(vec![PathBuf::from(common::TEST_GREETER_CONTRACT_PATH)], &[]);
+    assert!(result
+        .expect_err("Always fails")
+        .to_string()
+        .contains("EVM bytecode is already emitted for the EVM target."));
+}
@@ -0,0 +1,107 @@
+//!
+//! The source-level cache key unit tests.
+//!
+
+use std::path::PathBuf;
+
+use era_compiler_vyper::SourceKey;
+
+///
+/// Writes the project with the main contract importing a module, returning the main path.
+///
+fn write_project(directory: &std::path::Path) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(directory.join("lib"))?;
+    std::fs::write(
+        directory.join("main.vy"),
+        "from .lib import (\n    math,\n    tokens as t, # comment\n)\nimport ethereum.ercs.IERC20\n",
+    )?;
+    std::fs::write(directory.join("lib/math.vy"), "from . import util\n")?;
+    std::fs::write(directory.join("lib/tokens.vyi"), "")?;
+    std::fs::write(directory.join("lib/util.vy"), "")?;
+    std::fs::write(directory.join("unrelated.vy"), "")?;
+    Ok(directory.join("main.vy"))
+}
+
+#[test]
+fn imports() {
+    let imports = SourceKey::imports(
+        "import a.b as c\nfrom . import d, e as f\nfrom ..g import (\n    h,\n)\n# import x\n",
+    );
+    assert_eq!(
+        imports,
+        vec![
+            (0, vec!["a".to_owned(), "b".to_owned()]),
+            (1, vec!["d".to_owned()]),
+            (1, vec!["e".to_owned()]),
+            (2, vec!["g".to_owned()]),
+            (2, vec!["g".to_owned(), "h".to_owned()]),
+        ]
+    );
+}
+
+#[test]
+fn resolves_imports() -> anyhow::Result<()> {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let main_path = write_project(tmp_dir.path())?;
+
+    let source_key = SourceKey::new(&[main_path], &[])?;
+    let file_names: Vec<String> = source_key
+        .files
+        .keys()
+        .map(|path| {
+            path.strip_prefix(tmp_dir.path())
+                .expect("Always valid")
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect();
+    assert_eq!(
+        file_names,
+        vec!["lib/math.vy", "lib/tokens.vyi", "lib/util.vy", "main.vy"]
+    );
+    assert!(source_key
+        .unresolved_imports
+        .contains("ethereum.ercs.IERC20"));
+
+    Ok(())
+}
+
+#[test]
+fn import_change() -> anyhow::Result<()> {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let main_path = write_project(tmp_dir.path())?;
+    let settings = serde_json::json!({});
+
+    let key = SourceKey::new(std::slice::from_ref(&main_path), &[])?.hash(&settings);
+
+    std::fs::write(tmp_dir.path().join("unrelated.vy"), "x: uint256\n")?;
+    assert_eq!(
+        SourceKey::new(std::slice::from_ref(&main_path), &[])?.hash(&settings),
+        key
+    );
+
+    std::fs::write(tmp_dir.path().join("lib/util.vy"), "x: uint256\n")?;
+    assert_ne!(SourceKey::new(&[main_path], &[])?.hash(&settings), key);
+
+    Ok(())
+}
+
+#[test]
+fn settings_change() -> anyhow::Result<()> {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let main_path = write_project(tmp_dir.path())?;
+    let source_key = SourceKey::new(&[main_path], &[])?;
+
+    assert_ne!(
+        source_key.hash(&serde_json::json!({ "optimizer_settings": "M3B3" })),
+        source_key.hash(&serde_json::json!({ "optimizer_settings": "Mz" })),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn missing_source() {
+    let result = SourceKey::new(&[PathBuf::from("zkvyper-test-missing.vy")], &[]);
+    assert!(result.is_err());
+}
@@ -0,0 +1,39 @@
+//!
+//! The subprocess supervisor unit tests.
+//!
+
+use era_compiler_vyper::SupervisorError;
+use era_compiler_vyper::SupervisorErrorKind;
+
+#[test]
+fn stack_trace() {
+    let stderr = "LLVM ERROR: out of memory\nStack dump:\n0.\tProgram arguments: zkvyper --recursive-worker\n";
+    let error = SupervisorError::new(
+        "zkvyper".to_owned(),
+        SupervisorErrorKind::Signal(6),
+        stderr.to_owned(),
+    );
+
+    assert_eq!(
+        error.stack_trace(),
+        Some("Stack dump:\n0.\tProgram arguments: zkvyper --recursive-worker\n")
+    );
+    let message = error.to_string();
+    assert!(message.contains("terminated by signal 6 (SIGABRT)"));
+    assert!(message.contains("LLVM ERROR: out of memory"));
+}
+
+#[test]
+fn no_stack_trace() {
+    let error = SupervisorError::new(
+        "vyper".to_owned(),
+        SupervisorErrorKind::Timeout(std::time::Duration::from_secs(5)),
+        String::new(),
+    );
+
+    assert_eq!(error.stack_trace(), None);
+    assert_eq!(
+        error.to_string(),
+        "vyper subprocess timed out after 5s and was killed"
+    );
+}
@@ -0,0 +1,52 @@
+//!
+//! The Vyper compiler unit tests for the optimization auto-tuning.
+//!
+
+use std::str::FromStr;
+
+use era_compiler_vyper::OptimizationLevel;
+use era_compiler_vyper::Tuning;
+use era_compiler_vyper::TuningObjective;
+
+#[test]
+fn optimization_level() {
+    assert_eq!(
+        OptimizationLevel::from_str("z").expect("Always valid"),
+        OptimizationLevel::Fixed('z')
+    );
+    assert_eq!(
+        OptimizationLevel::from_str("auto").expect("Always valid"),
+        OptimizationLevel::Auto
+    );
+    assert!(OptimizationLevel::from_str("9").is_err());
+    assert!(OptimizationLevel::from_str("zz").is_err());
+}
+
+#[test]
+fn objective() {
+    assert_eq!(
+        TuningObjective::from_str("cycles").expect("Always valid"),
+        TuningObjective::Cycles
+    );
+    assert!(TuningObjective::from_str("speed").is_err());
+}
+
+#[test]
+fn candidates() {
+    let mut optimizer_settings = era_compiler_llvm_context::OptimizerSettings::cycles();
+    optimizer_settings.is_verify_each_enabled = true;
+
+    let tuning = Tuning::new(TuningObjective::Size, None);
+    let candidates = tuning.candidates(&optimizer_settings);
+
+    assert_eq!(candidates.len(), Tuning::LEVELS.len());
+    for (candidate, level) in candidates.iter().zip(Tuning::LEVELS) {
+        assert_eq!(
+            candidate.to_string(),
+            era_compiler_llvm_context::OptimizerSettings::try_from_cli(level)
+                .expect("Always valid")
+                .to_string()
+        );
+        assert!(candidate.is_verify_each_enabled);
+    }
+}
@@ -0,0 +1,152 @@
+//!
+//! The Vyper compiler unit tests for the Venom IR parser.
+//!
+
+use era_compiler_vyper::project::contract::vyper::venom::operand::Operand;
+use era_compiler_vyper::project::contract::vyper::venom::program::Program;
+
+#[test]
+fn round_trip() {
+    let input = r#"
+function __global {
+  __global:
+    %1 = calldatasize
+    %2 = lt %1, 4
+    jnz %2, @fallback, @selector ; the dispatcher
+  selector:
+    %3 = shr 224, %4
+    %4 = calldataload 0
+    %5 = invoke @"internal 0 add(uint256)", %3
+    mstore 0, %5
+    return 32, 0
+  fallback:
+    revert 0, 0
+}
+
+function "internal 0 add(uint256)" {
+  "internal 0 add(uint256)":
+    %x = param
+    %pc = param
+    %y = add %x, 1
+    ret %y, %pc
+}
+"#;
+    let program: Program = input.parse().expect("Parsing failed");
+    assert_eq!(program.functions.len(), 2);
+    assert_eq!(program.functions[0].blocks.len(), 3);
+    assert_eq!(program.functions[1].arguments(), vec!["x"]);
+    assert_eq!(program.functions[1].return_address(), Some("pc"));
+    assert!(program.functions[1].has_return_value());
+
+    let reparsed: Program = program.to_string().parse().expect("Reparsing failed");
+    assert_eq!(program, reparsed);
+}
+
+#[test]
+fn legacy_layout() {
+    let input = r#"
+IRContext:
+IRFunction: __global
+__global:  IN=[] OUT=[] => {}
+    %1 = 42
+    sstore 0, %1
+    exit
+Data segment:
+dbname _sym_selector_buckets
+db @selector_bucket_0
+"#;
+    let program: Program = input.parse().expect("Parsing failed");
+    assert_eq!(program.functions.len(), 1);
+
+    let block = &program.functions[0].blocks[0];
+    assert_eq!(block.label, "__global");
+    assert_eq!(block.instructions.len(), 3);
+    assert_eq!(
+        block.instructions[0].operands,
+        vec![Operand::Literal("42".to_owned())]
+    );
+}
+
+#[test]
+fn phi_and_data_section() {
+    let input = r#"
+function __global {
+  __global:
+    jnz 1, @then, @else
+  then:
+    jmp @join
+  else:
+    jmp @join
+  join:
+    %1 = phi @then, 1, @else, 2
+    stop
+}
+
+data readonly {
+  dbsection jump_table:
+    db @then
+}
+"#;
+    let program: Program = input.parse().expect("Parsing failed");
+    let function = &program.functions[0];
+    assert_eq!(function.blocks.len(), 4);
+    assert_eq!(
+        function.blocks[3].instructions[0].operands,
+        vec![
+            Operand::Label("then".to_owned()),
+            Operand::Literal("1".to_owned()),
+            Operand::Label("else".to_owned()),
+            Operand::Literal("2".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn reverse_post_order() {
+    let input = r#"
+function __global {
+  entry:
+    jnz 1, @second, @first
+  unreachable:
+    stop
+  first:
+    jmp @second
+  second:
+    stop
+}
+"#;
+    let program: Program = input.parse().expect("Parsing failed");
+    let order = program.functions[0]
+        .reverse_post_order()
+        .expect("Always valid");
+    assert_eq!(order, vec![0, 2, 3]);
+}
+
+#[test]
+fn literals() {
+    assert_eq!(
+        "-1".parse::<Operand>().expect("Always valid"),
+        Operand::Literal(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+                .to_owned()
+        )
+    );
+    assert_eq!(
+        "0xff".parse::<Operand>().expect("Always valid"),
+        Operand::Literal("255".to_owned())
+    );
+    assert!(
+        "0x1_0000000000000000000000000000000000000000000000000000000000000000"
+            .parse::<Operand>()
+            .is_err()
+    );
+}
+
+#[test]
+fn missing_block_label() {
+    let error = "function __global {\n  %1 = add 1, 2\n}"
+        .parse::<Program>()
+        .expect_err("Must fail");
+
+    assert!(error.to_string().contains("2: Expected a block label"));
+}